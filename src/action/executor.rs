@@ -1,10 +1,12 @@
 use crate::config::schema::{Action, HttpAction, HttpMethod};
+use crate::context::AppContext;
 use crate::error::{Result, TermStackError};
-use crate::template::engine::{TemplateContext, TemplateEngine};
-use crate::globals;
+use crate::template::engine::TemplateContext;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::process::Stdio;
 use std::sync::Arc;
+use tokio::io::AsyncWriteExt;
 
 #[derive(Debug, Clone)]
 pub enum ActionResult {
@@ -16,15 +18,12 @@ pub enum ActionResult {
 
 #[derive(Clone)]
 pub struct ActionExecutor {
-    template_engine: Arc<TemplateEngine>,
-    // No longer store http_client - use global instead
+    ctx: Arc<AppContext>,
 }
 
 impl ActionExecutor {
-    pub fn new(template_engine: Arc<TemplateEngine>) -> Self {
-        Self {
-            template_engine,
-        }
+    pub fn new(ctx: Arc<AppContext>) -> Self {
+        Self { ctx }
     }
 
     pub async fn execute(
@@ -100,6 +99,7 @@ impl ActionExecutor {
     ) -> Result<ActionResult> {
         // Render command with template
         let rendered_command = self
+            .ctx
             .template_engine
             .render_string(command, context)
             .map_err(|e| TermStackError::Template(e.to_string()))?;
@@ -108,23 +108,51 @@ impl ActionExecutor {
         let mut rendered_args = Vec::new();
         for arg in &action.args {
             let rendered_arg = self
+                .ctx
                 .template_engine
                 .render_string(arg, context)
                 .map_err(|e| TermStackError::Template(e.to_string()))?;
             rendered_args.push(rendered_arg);
         }
 
-        // Execute command
-        let output = tokio::process::Command::new(&rendered_command)
-            .args(&rendered_args)
-            .output()
-            .await
-            .map_err(TermStackError::Io)?;
+        let mut cmd = tokio::process::Command::new(&rendered_command);
+        cmd.args(&rendered_args);
+
+        // Execute command, piping the rendered stdin template if configured
+        let output = if let Some(stdin_template) = &action.stdin {
+            let input = self
+                .ctx
+                .template_engine
+                .render_string(stdin_template, context)
+                .map_err(|e| TermStackError::Template(e.to_string()))?;
+
+            cmd.stdin(Stdio::piped())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped());
+            let mut child = cmd.spawn().map_err(TermStackError::Io)?;
+
+            // Write stdin from a separate task so a chatty child can't deadlock on a full stdout pipe
+            let writer = child.stdin.take().map(|mut stdin| {
+                tokio::spawn(async move {
+                    // Dropping stdin afterwards closes the pipe and signals EOF
+                    let _ = stdin.write_all(input.as_bytes()).await;
+                })
+            });
+
+            let output = child.wait_with_output().await.map_err(TermStackError::Io)?;
+            if let Some(writer) = writer {
+                let _ = writer.await;
+            }
+            output
+        } else {
+            cmd.output().await.map_err(TermStackError::Io)?
+        };
 
         if output.status.success() {
             let message = if let Some(msg) = &action.success_message {
                 Some(
-                    self.template_engine
+                    self.ctx
+                        .template_engine
                         .render_string(msg, context)
                         .map_err(|e| TermStackError::Template(e.to_string()))?,
                 )
@@ -140,7 +168,8 @@ impl ActionExecutor {
         } else {
             let stderr = String::from_utf8_lossy(&output.stderr).to_string();
             let message = if let Some(msg) = &action.error_message {
-                self.template_engine
+                self.ctx
+                    .template_engine
                     .render_string(msg, context)
                     .map_err(|e| TermStackError::Template(e.to_string()))?
             } else {
@@ -158,12 +187,13 @@ impl ActionExecutor {
     ) -> Result<ActionResult> {
         // Render URL with template
         let rendered_url = self
+            .ctx
             .template_engine
             .render_string(&http.url, context)
             .map_err(|e| TermStackError::Template(e.to_string()))?;
 
-        // Build request using global HTTP client
-        let client = globals::http_client();
+        // Build request using the global HTTP client, or the action's own
+        let client = crate::data::client::client(&self.ctx, http.client.as_ref())?;
         let mut request = match http.method {
             HttpMethod::GET => client.get(&rendered_url),
             HttpMethod::POST => client.post(&rendered_url),
@@ -175,6 +205,7 @@ impl ActionExecutor {
         // Add headers
         for (key, value) in &http.headers {
             let rendered_value = self
+                .ctx
                 .template_engine
                 .render_string(value, context)
                 .map_err(|e| TermStackError::Template(e.to_string()))?;
@@ -184,19 +215,29 @@ impl ActionExecutor {
         // Add body if present
         if let Some(body) = &http.body {
             let rendered_body = self
+                .ctx
                 .template_engine
                 .render_string(body, context)
                 .map_err(|e| TermStackError::Template(e.to_string()))?;
             request = request.body(rendered_body);
         }
 
-        // Execute request
-        let response = request.send().await.map_err(TermStackError::Http)?;
+        // Execute request, with credentials if configured
+        let response = crate::data::auth::send(
+            &client,
+            &self.ctx.tokens,
+            request,
+            http.auth.as_ref(),
+            &self.ctx.template_engine,
+            context,
+        )
+        .await?;
 
         if response.status().is_success() {
             let message = if let Some(msg) = &action.success_message {
                 Some(
-                    self.template_engine
+                    self.ctx
+                        .template_engine
                         .render_string(msg, context)
                         .map_err(|e| TermStackError::Template(e.to_string()))?,
                 )
@@ -216,7 +257,8 @@ impl ActionExecutor {
                 .await
                 .unwrap_or_else(|_| "Failed to read response body".to_string());
             let message = if let Some(msg) = &action.error_message {
-                self.template_engine
+                self.ctx
+                    .template_engine
                     .render_string(msg, context)
                     .map_err(|e| TermStackError::Template(e.to_string()))?
             } else {
@@ -227,10 +269,3 @@ impl ActionExecutor {
     }
 }
 
-impl Default for ActionExecutor {
-    fn default() -> Self {
-        Self::new(Arc::new(
-            TemplateEngine::new().expect("Failed to create template engine"),
-        ))
-    }
-}
@@ -3,26 +3,24 @@ use async_trait::async_trait;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::process::Command;
 
 use super::DataSourceAdapter;
 use crate::config::schema::SingleDataSource;
+use crate::context::AppContext;
 use crate::data::provider::DataContext;
 use crate::template::engine::{TemplateContext, TemplateEngine};
 
 /// CLI command data adapter
-pub struct CliAdapter;
-
-impl Default for CliAdapter {
-    fn default() -> Self {
-        Self::new()
-    }
+pub struct CliAdapter {
+    ctx: Arc<AppContext>,
 }
 
 impl CliAdapter {
-    pub fn new() -> Self {
-        Self
+    pub fn new(ctx: Arc<AppContext>) -> Self {
+        Self { ctx }
     }
 
     /// Extract CLI configuration from data source
@@ -103,7 +101,7 @@ impl DataSourceAdapter for CliAdapter {
 
     async fn fetch(&self, source: &SingleDataSource, ctx: &DataContext) -> Result<Value> {
         let config = Self::extract_config(source)?;
-        let template_engine = TemplateEngine::new()?;
+        let template_engine = &self.ctx.template_engine;
         let template_ctx = Self::to_template_context(ctx);
 
         // Render templates in args
@@ -139,7 +137,8 @@ impl DataSourceAdapter for CliAdapter {
             let full_command = format!("{} {}", config.command, rendered_args.join(" "));
 
             let mut cmd = Command::new(shell_cmd);
-            cmd.arg(shell_arg).arg(full_command);
+            // Killed if the fetch times out or is cancelled (e.g. the user navigated away)
+            cmd.arg(shell_arg).arg(full_command).kill_on_drop(true);
 
             if let Some(dir) = &config.working_dir {
                 cmd.current_dir(dir);
@@ -155,7 +154,7 @@ impl DataSourceAdapter for CliAdapter {
         } else {
             // Direct execution
             let mut cmd = Command::new(&config.command);
-            cmd.args(&rendered_args);
+            cmd.args(&rendered_args).kill_on_drop(true);
 
             if let Some(dir) = &config.working_dir {
                 cmd.current_dir(dir);
@@ -172,11 +171,14 @@ impl DataSourceAdapter for CliAdapter {
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(anyhow!(
-                "Command failed with status {}: {}",
-                output.status,
-                stderr
-            ));
+            return Err(match output.status.code() {
+                Some(code) => anyhow!(
+                    "Command failed with exit code {}: {}",
+                    code,
+                    stderr.trim_end()
+                ),
+                None => anyhow!("Command was killed by a signal: {}", stderr.trim_end()),
+            });
         }
 
         let stdout = String::from_utf8_lossy(&output.stdout);
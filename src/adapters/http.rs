@@ -1,28 +1,85 @@
 use anyhow::{Result, anyhow};
 use async_trait::async_trait;
-use reqwest::Method;
+use reqwest::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::{Method, StatusCode};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use super::DataSourceAdapter;
-use crate::config::schema::{HttpMethod, SingleDataSource};
+use crate::config::schema::{HttpAuth, HttpClientOptions, HttpMethod, SingleDataSource};
+use crate::context::AppContext;
 use crate::data::provider::DataContext;
-use crate::globals;
 use crate::template::engine::{TemplateContext, TemplateEngine};
 
-/// HTTP data adapter
-pub struct HttpAdapter;
+/// Bodies of GET responses that came with an ETag or Last-Modified, by request
+/// (see [`cache_key`]), reused when a refresh gets 304 Not Modified; one per
+/// running instance (see [`AppContext`]). Only the most recently used are
+/// kept, so templated URLs can't grow it without bound.
+#[derive(Default)]
+pub struct ResponseCache(Mutex<VecDeque<(String, CachedResponse)>>);
+
+/// How many responses a [`ResponseCache`] keeps
+const MAX_CACHED_RESPONSES: usize = 64;
+
+#[derive(Clone)]
+struct CachedResponse {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    body: Value,
+}
 
-impl Default for HttpAdapter {
-    fn default() -> Self {
-        Self::new()
+impl ResponseCache {
+    /// The response cached for `key`, now the most recently used
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        let mut entries = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        let index = entries.iter().position(|(k, _)| k == key)?;
+        let entry = entries.remove(index)?;
+        let cached = entry.1.clone();
+        entries.push_back(entry);
+        Some(cached)
+    }
+
+    /// Cache a response, dropping the least recently used beyond the limit
+    fn insert(&self, key: String, response: CachedResponse) {
+        let mut entries = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        entries.retain(|(k, _)| *k != key);
+        entries.push_back((key, response));
+        while entries.len() > MAX_CACHED_RESPONSES {
+            entries.pop_front();
+        }
     }
+
+    fn remove(&self, key: &str) {
+        let mut entries = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        entries.retain(|(k, _)| k != key);
+    }
+}
+
+/// What a GET is cached under: its URL, with a hash of its headers and the
+/// credentials it's sent with, so requests that differ in either (or in whose
+/// they are) don't share a response
+fn cache_key(request: &reqwest::Request, auth: Option<&str>) -> String {
+    let mut hasher = DefaultHasher::new();
+    let mut headers: Vec<(&str, &[u8])> = (request.headers().iter())
+        .map(|(name, value)| (name.as_str(), value.as_bytes()))
+        .collect();
+    headers.sort();
+    headers.hash(&mut hasher);
+    auth.hash(&mut hasher);
+    format!("{} {:016x}", request.url(), hasher.finish())
+}
+
+/// HTTP data adapter
+pub struct HttpAdapter {
+    ctx: Arc<AppContext>,
 }
 
 impl HttpAdapter {
-    pub fn new() -> Self {
-        Self
+    pub fn new(ctx: Arc<AppContext>) -> Self {
+        Self { ctx }
     }
 
     /// Extract HTTP configuration from data source
@@ -85,6 +142,20 @@ impl HttpAdapter {
             .and_then(|v| v.as_str())
             .map(String::from);
 
+        let auth = source
+            .config
+            .get("auth")
+            .map(|v| serde_json::from_value::<HttpAuth>(v.clone()))
+            .transpose()
+            .map_err(|e| anyhow!("Invalid 'auth': {}", e))?;
+
+        let client = source
+            .config
+            .get("client")
+            .map(|v| serde_json::from_value::<HttpClientOptions>(v.clone()))
+            .transpose()
+            .map_err(|e| anyhow!("Invalid 'client': {}", e))?;
+
         let timeout = source.timeout.as_deref().unwrap_or("30s");
         let timeout_duration = parse_duration(timeout)?;
 
@@ -94,6 +165,8 @@ impl HttpAdapter {
             headers,
             params,
             body,
+            auth,
+            client,
             timeout: timeout_duration,
         })
     }
@@ -119,7 +192,7 @@ impl DataSourceAdapter for HttpAdapter {
 
     async fn fetch(&self, source: &SingleDataSource, ctx: &DataContext) -> Result<Value> {
         let config = Self::extract_config(source)?;
-        let template_engine = TemplateEngine::new()?;
+        let template_engine = &self.ctx.template_engine;
         let template_ctx = Self::to_template_context(ctx);
 
         // Render URL template
@@ -129,8 +202,8 @@ impl DataSourceAdapter for HttpAdapter {
             config.url.clone()
         };
 
-        // Get HTTP client
-        let client = globals::http_client();
+        // Get HTTP client, built with the source's own options if it has any
+        let client = crate::data::client::client(&self.ctx, config.client.as_ref())?;
 
         // Convert HttpMethod to reqwest::Method
         let method = match config.method {
@@ -186,26 +259,82 @@ impl DataSourceAdapter for HttpAdapter {
         // Set timeout
         request = request.timeout(config.timeout);
 
-        // Execute request
-        let response = request
-            .send()
-            .await
-            .map_err(|e| anyhow!("HTTP request failed: {}", e))?;
+        // Ask GETs seen before to skip the body when it hasn't changed. The
+        // credentials are told apart by their config, templates rendered.
+        let auth = match &config.auth {
+            Some(auth) => Some(template_engine.render_string(
+                &serde_json::to_string(auth)?,
+                &template_ctx,
+            )?),
+            None => None,
+        };
+        let cache_key = match config.method {
+            HttpMethod::GET => request
+                .try_clone()
+                .and_then(|r| r.build().ok())
+                .map(|r| cache_key(&r, auth.as_deref())),
+            _ => None,
+        };
+        if let Some(key) = &cache_key
+            && let Some(cached) = self.ctx.responses.get(key)
+        {
+            if let Some(etag) = &cached.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &cached.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        // Execute request, with credentials if configured
+        let response = crate::data::auth::send(
+            &client,
+            &self.ctx.tokens,
+            request,
+            config.auth.as_ref(),
+            template_engine,
+            &template_ctx,
+        )
+        .await
+        .map_err(|e| anyhow!("HTTP request failed: {}", e))?;
+
+        if response.status() == StatusCode::NOT_MODIFIED
+            && let Some(key) = &cache_key
+            && let Some(cached) = self.ctx.responses.get(key)
+        {
+            return Ok(cached.body);
+        }
 
         if !response.status().is_success() {
             return Err(anyhow!(
-                "HTTP request failed with status: {}",
+                "HTTP request failed with status {}",
                 response.status()
             ));
         }
 
+        let header = |name| {
+            response.headers().get(name).and_then(|v| v.to_str().ok()).map(String::from)
+        };
+        let (etag, last_modified) = (header(ETAG), header(LAST_MODIFIED));
+
         let text = response
             .text()
             .await
             .map_err(|e| anyhow!("Failed to read response body: {}", e))?;
 
         // Parse as JSON
-        serde_json::from_str(&text).map_err(|e| anyhow!("Failed to parse response as JSON: {}", e))
+        let body: Value = serde_json::from_str(&text)
+            .map_err(|e| anyhow!("Failed to parse response as JSON: {}", e))?;
+
+        if let Some(key) = cache_key {
+            if etag.is_some() || last_modified.is_some() {
+                let cached = CachedResponse { etag, last_modified, body: body.clone() };
+                self.ctx.responses.insert(key, cached);
+            } else {
+                self.ctx.responses.remove(&key);
+            }
+        }
+        Ok(body)
     }
 }
 
@@ -216,6 +345,8 @@ struct HttpConfig {
     headers: HashMap<String, String>,
     params: HashMap<String, String>,
     body: Option<String>,
+    auth: Option<HttpAuth>,
+    client: Option<HttpClientOptions>,
     timeout: Duration,
 }
 
@@ -266,4 +397,85 @@ mod tests {
         assert_eq!(parse_duration("500ms").unwrap(), Duration::from_millis(500));
         assert_eq!(parse_duration("10").unwrap(), Duration::from_secs(10));
     }
+
+    #[test]
+    fn test_response_cache() {
+        let cache = ResponseCache::default();
+        let response = |n: i32| CachedResponse {
+            etag: Some(format!("\"v{}\"", n)),
+            last_modified: None,
+            body: serde_json::json!(n),
+        };
+        for n in 0..MAX_CACHED_RESPONSES as i32 {
+            cache.insert(format!("url/{}", n), response(n));
+        }
+        // Using one keeps it when the oldest is dropped for a new response
+        assert!(cache.get("url/0").is_some());
+        cache.insert("url/new".to_string(), response(-1));
+        assert!(cache.get("url/0").is_some());
+        assert!(cache.get("url/1").is_none());
+        assert_eq!(cache.0.lock().unwrap().len(), MAX_CACHED_RESPONSES);
+
+        // Requests for one URL are told apart by headers and credentials
+        let client = reqwest::Client::new();
+        let get = |token: &str| {
+            (client.get("http://host/items"))
+                .header("X-Tenant", token)
+                .build()
+                .unwrap()
+        };
+        let key = cache_key(&get("a"), None);
+        assert!(key.starts_with("http://host/items "));
+        assert_eq!(key, cache_key(&get("a"), None));
+        assert_ne!(key, cache_key(&get("b"), None));
+        assert_ne!(key, cache_key(&get("a"), Some("bearer alice")));
+        assert_ne!(
+            cache_key(&get("a"), Some("bearer alice")),
+            cache_key(&get("a"), Some("bearer bob"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_conditional_refresh() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // Answers with an ETag, then 304 to requests that send it back
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for _ in 0..2 {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0; 4096];
+                let n = socket.read(&mut buf).await.unwrap();
+                let request = String::from_utf8_lossy(&buf[..n]).to_lowercase();
+                let response = if request.contains("if-none-match: \"v1\"") {
+                    "HTTP/1.1 304 Not Modified\r\nETag: \"v1\"\r\n\
+                     Connection: close\r\n\r\n"
+                } else {
+                    "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Length: 6\r\n\
+                     Connection: close\r\n\r\n[1, 2]"
+                };
+                socket.write_all(response.as_bytes()).await.unwrap();
+                requests.push(request);
+            }
+            requests
+        });
+
+        let source: SingleDataSource =
+            serde_yaml::from_str(&format!("adapter: http\nurl: http://{}/items", addr)).unwrap();
+        let config = crate::config::ConfigLoader::load_from_string(
+            "version: v1\napp: {name: t}\nstart: main\n\
+             pages: {main: {title: M, view: {type: text}}}",
+        )
+        .unwrap();
+        let adapter = HttpAdapter::new(AppContext::new(config).unwrap());
+        let ctx = DataContext::default();
+        assert_eq!(adapter.fetch(&source, &ctx).await.unwrap(), serde_json::json!([1, 2]));
+        assert_eq!(adapter.fetch(&source, &ctx).await.unwrap(), serde_json::json!([1, 2]));
+
+        let requests = server.await.unwrap();
+        assert!(!requests[0].contains("if-none-match"));
+        assert!(requests[1].contains("if-none-match: \"v1\""));
+    }
 }
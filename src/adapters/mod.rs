@@ -6,8 +6,11 @@ use serde_json::Value;
 
 pub mod cli;
 pub mod http;
+pub mod plugin;
+pub mod pool;
 pub mod registry;
 pub mod script;
+pub mod wasm;
 
 /// Trait for data source adapters
 ///
@@ -27,4 +30,8 @@ pub trait DataSourceAdapter: Send + Sync {
     /// # Returns
     /// A JSON Value containing the fetched data
     async fn fetch(&self, source: &SingleDataSource, ctx: &DataContext) -> Result<Value>;
+
+    /// Releases held resources (pooled connections, see [`pool::ConnectionManager`])
+    /// when the app quits. The default does nothing.
+    async fn shutdown(&self) {}
 }
@@ -0,0 +1,246 @@
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use super::DataSourceAdapter;
+use crate::config::schema::SingleDataSource;
+use crate::context::AppContext;
+use crate::data::provider::DataContext;
+use crate::template::engine::{TemplateContext, TemplateEngine};
+
+/// Version of the request plugins are sent, so they can tell future changes apart
+pub const PROTOCOL_VERSION: u64 = 1;
+
+/// External plugin adapter
+///
+/// Runs an executable per fetch and speaks JSON over stdio: the plugin reads one
+/// request from stdin, `{"protocol": 1, "config": {...}, "context": {...}}`
+/// with the source's `config` rendered, and writes one response to stdout,
+/// `{"data": ...}` or `{"error": "..."}`. Data sources can be written in any
+/// language without rebuilding termstack.
+pub struct PluginAdapter {
+    ctx: Arc<AppContext>,
+}
+
+impl PluginAdapter {
+    pub fn new(ctx: Arc<AppContext>) -> Self {
+        Self { ctx }
+    }
+
+    /// Extract plugin configuration from data source
+    fn extract_config(source: &SingleDataSource) -> Result<PluginConfig> {
+        let plugin = source
+            .config
+            .get("plugin")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow!("Missing 'plugin' field for plugin adapter"))?
+            .to_string();
+
+        let args = source
+            .config
+            .get("args")
+            .and_then(|v| v.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let config = source.config.get("config").cloned().unwrap_or(json!({}));
+
+        let timeout = source.timeout.as_deref().unwrap_or("30s");
+        let timeout = humantime::parse_duration(timeout)
+            .map_err(|e| anyhow!("Invalid timeout '{}': {}", timeout, e))?;
+
+        Ok(PluginConfig {
+            plugin,
+            args,
+            config,
+            timeout,
+        })
+    }
+
+    /// Convert DataContext to TemplateContext
+    fn to_template_context(ctx: &DataContext) -> TemplateContext {
+        let mut template_ctx = TemplateContext::new().with_globals(ctx.globals.clone());
+
+        // Add each page context individually
+        for (page, data) in &ctx.page_contexts {
+            template_ctx = template_ctx.with_page_context(page.clone(), data.clone());
+        }
+
+        template_ctx
+    }
+
+    /// `value` with the templates among its strings rendered, at any depth
+    pub(crate) fn render_config(
+        value: &Value,
+        engine: &TemplateEngine,
+        ctx: &TemplateContext,
+    ) -> Result<Value> {
+        Ok(match value {
+            Value::String(s) if TemplateEngine::is_template(s) => {
+                Value::String(engine.render_string(s, ctx).map_err(|e| anyhow!("{}", e))?)
+            }
+            Value::Array(items) => Value::Array(
+                items
+                    .iter()
+                    .map(|item| Self::render_config(item, engine, ctx))
+                    .collect::<Result<_>>()?,
+            ),
+            Value::Object(fields) => Value::Object(
+                fields
+                    .iter()
+                    .map(|(k, v)| Ok((k.clone(), Self::render_config(v, engine, ctx)?)))
+                    .collect::<Result<_>>()?,
+            ),
+            other => other.clone(),
+        })
+    }
+
+    /// The data of a plugin's response, or the error it reported
+    fn parse_response(stdout: &str) -> Result<Value> {
+        let response: Value = serde_json::from_str(stdout)
+            .map_err(|e| anyhow!("Plugin did not answer with JSON: {}", e))?;
+        if let Some(error) = response.get("error").filter(|e| !e.is_null()) {
+            let message = error
+                .as_str()
+                .map(String::from)
+                .unwrap_or(error.to_string());
+            return Err(anyhow!("Plugin error: {}", message));
+        }
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| anyhow!("Plugin response has neither 'data' nor 'error'"))
+    }
+}
+
+#[async_trait]
+impl DataSourceAdapter for PluginAdapter {
+    fn name(&self) -> &str {
+        "plugin"
+    }
+
+    async fn fetch(&self, source: &SingleDataSource, ctx: &DataContext) -> Result<Value> {
+        let config = Self::extract_config(source)?;
+        let template_engine = &self.ctx.template_engine;
+        let template_ctx = Self::to_template_context(ctx);
+
+        // Render templates in args
+        let rendered_args: Vec<String> = config
+            .args
+            .iter()
+            .map(|arg| {
+                if TemplateEngine::is_template(arg) {
+                    template_engine
+                        .render_string(arg, &template_ctx)
+                        .map_err(|e| anyhow!("{}", e))
+                } else {
+                    Ok(arg.clone())
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let request = json!({
+            "protocol": PROTOCOL_VERSION,
+            "config": Self::render_config(&config.config, template_engine, &template_ctx)?,
+            "context": ctx,
+        });
+
+        let mut child = Command::new(&config.plugin)
+            .args(&rendered_args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            // Killed if the fetch times out or is cancelled (e.g. the user navigated away)
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| anyhow!("Failed to start plugin '{}': {}", config.plugin, e))?;
+
+        let exchange = async {
+            if let Some(mut stdin) = child.stdin.take() {
+                // A plugin that doesn't read the request may close stdin early
+                let _ = stdin.write_all(format!("{}\n", request).as_bytes()).await;
+            }
+            child.wait_with_output().await
+        };
+        let output = tokio::time::timeout(config.timeout, exchange)
+            .await
+            .map_err(|_| anyhow!("Plugin timed out after {:?}", config.timeout))??;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow!(
+                "Plugin failed (exit code {}): {}",
+                output.status.code().unwrap_or(-1),
+                stderr.trim_end()
+            ));
+        }
+
+        Self::parse_response(&String::from_utf8_lossy(&output.stdout))
+    }
+}
+
+/// Plugin configuration extracted from data source
+struct PluginConfig {
+    plugin: String,
+    args: Vec<String>,
+    /// Passed to the plugin as `config`, templates rendered
+    config: Value,
+    timeout: Duration,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_response() {
+        let data = PluginAdapter::parse_response(r#"{"data": [{"id": 1}]}"#).unwrap();
+        assert_eq!(data, json!([{"id": 1}]));
+
+        let err = PluginAdapter::parse_response(r#"{"error": "bad token"}"#).unwrap_err();
+        assert_eq!(err.to_string(), "Plugin error: bad token");
+        assert!(PluginAdapter::parse_response(r#"{"rows": []}"#).is_err());
+        assert!(PluginAdapter::parse_response("not json").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fetch() {
+        let source: SingleDataSource = serde_yaml::from_str(
+            r#"
+adapter: plugin
+plugin: sh
+args: ["-c", "read request; echo \"{\\\"data\\\": $request}\""]
+config:
+  namespace: "{{ namespace }}"
+  limits: [10, "{{ limit }}"]
+"#,
+        )
+        .unwrap();
+        let mut ctx = DataContext::new().with_globals([("limit".to_string(), json!(5))].into());
+        ctx.set_page_context("namespace".to_string(), json!("default"));
+
+        let config = crate::config::ConfigLoader::load_from_string(
+            "version: v1\napp: {name: t}\nstart: main\n\
+             pages: {main: {title: M, view: {type: text}}}",
+        )
+        .unwrap();
+        let adapter = PluginAdapter::new(AppContext::new(config).unwrap());
+
+        let request = adapter.fetch(&source, &ctx).await.unwrap();
+        assert_eq!(request["protocol"], PROTOCOL_VERSION);
+        assert_eq!(
+            request["config"],
+            json!({"namespace": "default", "limits": [10, "5"]})
+        );
+        assert_eq!(request["context"]["page_contexts"]["namespace"], "default");
+    }
+}
@@ -0,0 +1,303 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Default cap on open connections per connection string
+pub const DEFAULT_MAX_CONNECTIONS: usize = 4;
+
+/// Default time an unused connection is kept before it's closed
+pub const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// A connection an adapter keeps open between fetches (database, queue, ...)
+#[async_trait]
+pub trait Connection: Send + 'static {
+    /// Cheap liveness check (e.g. `SELECT 1`) run before an idle connection is reused
+    async fn is_healthy(&mut self) -> bool;
+
+    /// Close the connection gracefully; called when it's evicted or on teardown
+    async fn close(&mut self) {}
+}
+
+/// Opens connections for a [`ConnectionManager`]
+#[async_trait]
+pub trait Connector: Send + Sync + 'static {
+    type Conn: Connection;
+
+    async fn connect(&self, target: &str) -> Result<Self::Conn>;
+}
+
+struct Idle<T> {
+    conn: T,
+    since: Instant,
+}
+
+struct Pool<T> {
+    idle: Vec<Idle<T>>,
+    /// Limits open connections (idle plus checked out) for this target
+    permits: Arc<Semaphore>,
+}
+
+struct Shared<T> {
+    pools: Mutex<HashMap<String, Pool<T>>>,
+    closed: Mutex<bool>,
+}
+
+/// Shared connections for adapters, keyed by connection string.
+///
+/// Connections are opened lazily on first use and returned to the pool when
+/// the [`PooledConnection`] is dropped, so a page refreshing every few seconds
+/// reuses one connection instead of reconnecting. Idle connections are health
+/// checked before reuse and replaced once idle longer than `idle_timeout`;
+/// [`close_all`] tears everything down on quit.
+///
+/// [`close_all`]: ConnectionManager::close_all
+pub struct ConnectionManager<C: Connector> {
+    connector: C,
+    max_connections: usize,
+    idle_timeout: Duration,
+    shared: Arc<Shared<C::Conn>>,
+}
+
+impl<C: Connector> ConnectionManager<C> {
+    pub fn new(connector: C) -> Self {
+        Self {
+            connector,
+            max_connections: DEFAULT_MAX_CONNECTIONS,
+            idle_timeout: DEFAULT_IDLE_TIMEOUT,
+            shared: Arc::new(Shared {
+                pools: Mutex::new(HashMap::new()),
+                closed: Mutex::new(false),
+            }),
+        }
+    }
+
+    pub fn with_max_connections(mut self, max: usize) -> Self {
+        self.max_connections = max.max(1);
+        self
+    }
+
+    pub fn with_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = timeout;
+        self
+    }
+
+    /// Check out a connection for `target`, reusing a healthy idle one or
+    /// connecting. Waits while `max_connections` are checked out.
+    pub async fn get(&self, target: &str) -> Result<PooledConnection<C::Conn>> {
+        if *lock(&self.shared.closed) {
+            anyhow::bail!("Connection manager is shut down");
+        }
+
+        let permits = {
+            let mut pools = lock(&self.shared.pools);
+            let pool = pools.entry(target.to_string()).or_insert_with(|| Pool {
+                idle: Vec::new(),
+                permits: Arc::new(Semaphore::new(self.max_connections)),
+            });
+            pool.permits.clone()
+        };
+        let permit = permits.acquire_owned().await?;
+
+        // Reuse the most recently returned connection that's still fresh and healthy
+        loop {
+            let idle = lock(&self.shared.pools)
+                .get_mut(target)
+                .and_then(|pool| pool.idle.pop());
+            let Some(Idle { mut conn, since }) = idle else {
+                break;
+            };
+            if since.elapsed() < self.idle_timeout && conn.is_healthy().await {
+                return Ok(self.checked_out(target, conn, permit));
+            }
+            conn.close().await;
+        }
+
+        let conn = self.connector.connect(target).await?;
+        Ok(self.checked_out(target, conn, permit))
+    }
+
+    fn checked_out(
+        &self,
+        target: &str,
+        conn: C::Conn,
+        permit: OwnedSemaphorePermit,
+    ) -> PooledConnection<C::Conn> {
+        PooledConnection {
+            conn: Some(conn),
+            target: target.to_string(),
+            shared: self.shared.clone(),
+            _permit: permit,
+        }
+    }
+
+    /// Open connections per target: (idle, checked out)
+    pub fn stats(&self) -> HashMap<String, (usize, usize)> {
+        lock(&self.shared.pools)
+            .iter()
+            .map(|(target, pool)| {
+                let in_use = self.max_connections - pool.permits.available_permits();
+                (target.clone(), (pool.idle.len(), in_use))
+            })
+            .collect()
+    }
+
+    /// Close every idle connection and refuse new checkouts. Connections still
+    /// checked out are dropped (not pooled) when they're returned.
+    pub async fn close_all(&self) {
+        *lock(&self.shared.closed) = true;
+        let idle: Vec<Idle<C::Conn>> = lock(&self.shared.pools)
+            .drain()
+            .flat_map(|(_, pool)| pool.idle)
+            .collect();
+        for Idle { mut conn, .. } in idle {
+            conn.close().await;
+        }
+    }
+}
+
+/// A checked-out connection; goes back to its pool when dropped
+pub struct PooledConnection<T: Send + 'static> {
+    conn: Option<T>,
+    target: String,
+    shared: Arc<Shared<T>>,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl<T: Send + 'static> PooledConnection<T> {
+    /// Drop the connection instead of pooling it, e.g. after an I/O error
+    pub fn discard(mut self) {
+        self.conn = None;
+    }
+}
+
+impl<T: Send + 'static> Deref for PooledConnection<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.conn.as_ref().expect("connection is present until dropped")
+    }
+}
+
+impl<T: Send + 'static> DerefMut for PooledConnection<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.conn.as_mut().expect("connection is present until dropped")
+    }
+}
+
+impl<T: Send + 'static> Drop for PooledConnection<T> {
+    fn drop(&mut self) {
+        let Some(conn) = self.conn.take() else {
+            return;
+        };
+        if *lock(&self.shared.closed) {
+            return;
+        }
+        if let Some(pool) = lock(&self.shared.pools).get_mut(&self.target) {
+            pool.idle.push(Idle {
+                conn,
+                since: Instant::now(),
+            });
+        }
+    }
+}
+
+/// Lock a mutex, recovering the data if another thread panicked while holding it
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    #[derive(Default)]
+    struct Counters {
+        connects: AtomicUsize,
+        closes: AtomicUsize,
+        unhealthy: AtomicBool,
+    }
+
+    struct FakeConn {
+        counters: Arc<Counters>,
+    }
+
+    #[async_trait]
+    impl Connection for FakeConn {
+        async fn is_healthy(&mut self) -> bool {
+            !self.counters.unhealthy.load(Ordering::SeqCst)
+        }
+
+        async fn close(&mut self) {
+            self.counters.closes.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    struct FakeConnector(Arc<Counters>);
+
+    #[async_trait]
+    impl Connector for FakeConnector {
+        type Conn = FakeConn;
+
+        async fn connect(&self, _target: &str) -> Result<FakeConn> {
+            self.0.connects.fetch_add(1, Ordering::SeqCst);
+            Ok(FakeConn {
+                counters: self.0.clone(),
+            })
+        }
+    }
+
+    fn manager() -> (ConnectionManager<FakeConnector>, Arc<Counters>) {
+        let counters = Arc::new(Counters::default());
+        (ConnectionManager::new(FakeConnector(counters.clone())), counters)
+    }
+
+    #[tokio::test]
+    async fn test_reuse_and_keying() {
+        let (manager, counters) = manager();
+
+        drop(manager.get("db://a").await.unwrap());
+        let _conn = manager.get("db://a").await.unwrap();
+        assert_eq!(counters.connects.load(Ordering::SeqCst), 1);
+
+        let _other = manager.get("db://b").await.unwrap();
+        assert_eq!(counters.connects.load(Ordering::SeqCst), 2);
+        assert_eq!(manager.stats()["db://a"], (0, 1));
+    }
+
+    #[tokio::test]
+    async fn test_unhealthy_and_expired_connections_are_replaced() {
+        let (manager, counters) = manager();
+
+        drop(manager.get("db://a").await.unwrap());
+        counters.unhealthy.store(true, Ordering::SeqCst);
+        drop(manager.get("db://a").await.unwrap());
+        assert_eq!(counters.connects.load(Ordering::SeqCst), 2);
+        assert_eq!(counters.closes.load(Ordering::SeqCst), 1);
+
+        counters.unhealthy.store(false, Ordering::SeqCst);
+        let manager = manager.with_idle_timeout(Duration::ZERO);
+        drop(manager.get("db://a").await.unwrap());
+        drop(manager.get("db://a").await.unwrap());
+        assert_eq!(counters.connects.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_max_connections_and_close_all() {
+        let (manager, counters) = manager();
+        let manager = manager.with_max_connections(1);
+
+        let conn = manager.get("db://a").await.unwrap();
+        let waiting = tokio::time::timeout(Duration::from_millis(50), manager.get("db://a"));
+        assert!(waiting.await.is_err(), "second checkout should wait");
+        drop(conn);
+
+        manager.close_all().await;
+        assert_eq!(counters.closes.load(Ordering::SeqCst), 1);
+        assert!(manager.get("db://a").await.is_err());
+    }
+}
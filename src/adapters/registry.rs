@@ -1,8 +1,11 @@
 use super::DataSourceAdapter;
 use super::cli::CliAdapter;
 use super::http::HttpAdapter;
+use super::plugin::PluginAdapter;
 use super::script::ScriptAdapter;
+use super::wasm::WasmAdapter;
 use crate::config::schema::SingleDataSource;
+use crate::context::AppContext;
 use crate::data::provider::DataContext;
 use anyhow::{Result, anyhow};
 use serde_json::Value;
@@ -18,21 +21,31 @@ pub struct AdapterRegistry {
 }
 
 impl AdapterRegistry {
-    /// Creates a new empty adapter registry
+    /// Creates a new empty adapter registry. There's no `Default`: the
+    /// built-ins need an [`AppContext`], see [`Self::with_defaults`]
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
         Self {
             adapters: HashMap::new(),
         }
     }
 
-    /// Creates a registry with default built-in adapters registered
-    pub fn with_defaults() -> Self {
+    /// Creates a registry with default built-in adapters and those of `ctx`'s
+    /// plugins registered, sharing `ctx` (the template engine, and the http
+    /// adapter's client) between them
+    pub fn with_defaults(ctx: Arc<AppContext>) -> Self {
         let mut registry = Self::new();
 
         // Register built-in adapters
-        registry.register(Arc::new(CliAdapter::new()));
-        registry.register(Arc::new(HttpAdapter::new()));
-        registry.register(Arc::new(ScriptAdapter::new()));
+        registry.register(Arc::new(CliAdapter::new(ctx.clone())));
+        registry.register(Arc::new(HttpAdapter::new(ctx.clone())));
+        registry.register(Arc::new(ScriptAdapter::new(ctx.clone())));
+        registry.register(Arc::new(PluginAdapter::new(ctx.clone())));
+
+        // Adapters WASM plugins registered (their names can't clash with these)
+        for (name, module) in ctx.plugins.adapters() {
+            registry.register(Arc::new(WasmAdapter::new(ctx.clone(), name, module.clone())));
+        }
 
         registry
     }
@@ -76,14 +89,15 @@ impl AdapterRegistry {
         adapter.fetch(source, ctx).await
     }
 
+    /// Shuts down every adapter, closing pooled connections
+    pub async fn shutdown(&self) {
+        for adapter in self.adapters.values() {
+            adapter.shutdown().await;
+        }
+    }
+
     /// Returns the list of registered adapter names
     pub fn list_adapters(&self) -> Vec<String> {
         self.adapters.keys().cloned().collect()
     }
 }
-
-impl Default for AdapterRegistry {
-    fn default() -> Self {
-        Self::with_defaults()
-    }
-}
@@ -2,11 +2,13 @@ use anyhow::{Result, anyhow};
 use async_trait::async_trait;
 use serde_json::Value;
 use std::path::Path;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::process::Command;
 
 use super::DataSourceAdapter;
 use crate::config::schema::SingleDataSource;
+use crate::context::AppContext;
 use crate::data::provider::DataContext;
 use crate::template::engine::{TemplateContext, TemplateEngine};
 
@@ -14,17 +16,13 @@ use crate::template::engine::{TemplateContext, TemplateEngine};
 ///
 /// Executes shell scripts that output JSON data.
 /// This allows users to integrate custom data sources without writing Rust code.
-pub struct ScriptAdapter;
-
-impl Default for ScriptAdapter {
-    fn default() -> Self {
-        Self::new()
-    }
+pub struct ScriptAdapter {
+    ctx: Arc<AppContext>,
 }
 
 impl ScriptAdapter {
-    pub fn new() -> Self {
-        Self
+    pub fn new(ctx: Arc<AppContext>) -> Self {
+        Self { ctx }
     }
 
     /// Extract script configuration from data source
@@ -79,7 +77,7 @@ impl DataSourceAdapter for ScriptAdapter {
 
     async fn fetch(&self, source: &SingleDataSource, ctx: &DataContext) -> Result<Value> {
         let config = Self::extract_config(source)?;
-        let template_engine = TemplateEngine::new()?;
+        let template_engine = &self.ctx.template_engine;
         let template_ctx = Self::to_template_context(ctx);
 
         // Validate script exists
@@ -112,6 +110,7 @@ impl DataSourceAdapter for ScriptAdapter {
             Command::new(&config.script)
                 .args(&rendered_args)
                 .env("TERMSTACK_CONTEXT", context_json)
+                .kill_on_drop(true)
                 .output(),
         )
         .await
@@ -0,0 +1,64 @@
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use serde_json::{Map, Value};
+use std::sync::Arc;
+
+use super::DataSourceAdapter;
+use super::plugin::PluginAdapter;
+use crate::config::schema::SingleDataSource;
+use crate::context::AppContext;
+use crate::data::provider::DataContext;
+use crate::template::engine::TemplateContext;
+use crate::wasm::WasmModule;
+
+/// Adapter a WASM plugin registered (see [`crate::wasm`])
+///
+/// Sends the data source's fields, templates rendered, to the module's `fetch`
+/// on a blocking thread, so a slow module doesn't hold up the runtime.
+pub struct WasmAdapter {
+    ctx: Arc<AppContext>,
+    name: String,
+    module: Arc<WasmModule>,
+}
+
+impl WasmAdapter {
+    pub fn new(ctx: Arc<AppContext>, name: &str, module: Arc<WasmModule>) -> Self {
+        Self {
+            ctx,
+            name: name.to_string(),
+            module,
+        }
+    }
+
+    /// Convert DataContext to TemplateContext
+    fn to_template_context(ctx: &DataContext) -> TemplateContext {
+        let mut template_ctx = TemplateContext::new().with_globals(ctx.globals.clone());
+
+        // Add each page context individually
+        for (page, data) in &ctx.page_contexts {
+            template_ctx = template_ctx.with_page_context(page.clone(), data.clone());
+        }
+
+        template_ctx
+    }
+}
+
+#[async_trait]
+impl DataSourceAdapter for WasmAdapter {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn fetch(&self, source: &SingleDataSource, ctx: &DataContext) -> Result<Value> {
+        let template_ctx = Self::to_template_context(ctx);
+        let fields = Value::Object(Map::from_iter(source.config.clone()));
+        let config =
+            PluginAdapter::render_config(&fields, &self.ctx.template_engine, &template_ctx)?;
+        let context = serde_json::to_value(ctx)?;
+
+        let (module, name) = (self.module.clone(), self.name.clone());
+        tokio::task::spawn_blocking(move || module.fetch(&name, config, context))
+            .await
+            .map_err(|e| anyhow!("Plugin adapter '{}' panicked: {}", self.name, e))?
+    }
+}
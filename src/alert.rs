@@ -0,0 +1,207 @@
+// Page alerts: per-row conditions checked after every fetch, reported on transitions
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+use std::time::Duration;
+use tokio::process::Command;
+
+use crate::config::Alert;
+use crate::template::engine::{TemplateContext, TemplateEngine};
+use crate::view::style::{condition_template, is_truthy};
+
+const HOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertState {
+    Firing,
+    Resolved,
+}
+
+impl AlertState {
+    pub fn label(self) -> &'static str {
+        match self {
+            AlertState::Firing => "FIRING",
+            AlertState::Resolved => "RESOLVED",
+        }
+    }
+}
+
+/// An alert that started or stopped matching
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlertEvent {
+    pub page: String,
+    pub alert: String,
+    pub state: AlertState,
+    pub message: String,
+}
+
+/// Remembers which alerts are firing, so only changes are reported
+#[derive(Debug, Default)]
+pub struct AlertTracker {
+    /// (page, alert name) -> messages of the matching rows
+    firing: HashMap<(String, String), BTreeSet<String>>,
+}
+
+impl AlertTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check a page's alerts against freshly fetched rows. Returns the alerts
+    /// that started firing or resolved since the previous call for the page;
+    /// on the first call everything that matches counts as started. Conditions
+    /// that fail to render don't match.
+    pub fn evaluate(
+        &mut self,
+        engine: &TemplateEngine,
+        page_id: &str,
+        alerts: &[Alert],
+        rows: &[Value],
+        base: &TemplateContext,
+    ) -> Vec<AlertEvent> {
+        let mut events = Vec::new();
+        let mut ctx = base.clone();
+
+        for alert in alerts {
+            let condition = condition_template(&alert.condition);
+            let mut matching = BTreeSet::new();
+            for row in rows {
+                ctx.set_current(Some(row.clone()));
+                let matched = engine
+                    .render_cached(&condition, &ctx)
+                    .is_ok_and(|rendered| is_truthy(&rendered));
+                if !matched {
+                    continue;
+                }
+                let message = alert
+                    .message
+                    .as_deref()
+                    .and_then(|template| engine.render_cached(template, &ctx).ok())
+                    .map(|m| m.trim().to_string())
+                    .unwrap_or_else(|| alert.name.clone());
+                matching.insert(message);
+            }
+
+            let key = (page_id.to_string(), alert.name.clone());
+            let before = self.firing.remove(&key).unwrap_or_default();
+            let event = |state, message: &String| AlertEvent {
+                page: page_id.to_string(),
+                alert: alert.name.clone(),
+                state,
+                message: message.clone(),
+            };
+            events.extend(matching.difference(&before).map(|m| event(AlertState::Firing, m)));
+            events.extend(before.difference(&matching).map(|m| event(AlertState::Resolved, m)));
+            self.firing.insert(key, matching);
+        }
+
+        events
+    }
+
+    /// Number of distinct alert messages currently firing
+    pub fn firing_count(&self) -> usize {
+        self.firing.values().map(BTreeSet::len).sum()
+    }
+}
+
+/// Run an alert's `command` for a transition. The event is passed in
+/// `TERMSTACK_ALERT_PAGE`, `_NAME`, `_STATE` (`FIRING`/`RESOLVED`) and `_MESSAGE`.
+pub async fn run_hook(command: &str, event: &AlertEvent) -> Result<(), String> {
+    let mut cmd = if cfg!(target_os = "windows") {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C");
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c");
+        cmd
+    };
+    cmd.arg(command)
+        .env("TERMSTACK_ALERT_PAGE", &event.page)
+        .env("TERMSTACK_ALERT_NAME", &event.alert)
+        .env("TERMSTACK_ALERT_STATE", event.state.label())
+        .env("TERMSTACK_ALERT_MESSAGE", &event.message)
+        .kill_on_drop(true);
+
+    let status = tokio::time::timeout(HOOK_TIMEOUT, cmd.status())
+        .await
+        .map_err(|_| format!("timed out after {}", humantime::format_duration(HOOK_TIMEOUT)))?
+        .map_err(|e| format!("failed to run: {}", e))?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(status.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn alert(condition: &str, message: Option<&str>) -> Alert {
+        Alert {
+            name: "Failing".to_string(),
+            condition: condition.to_string(),
+            message: message.map(str::to_string),
+            command: None,
+        }
+    }
+
+    #[test]
+    fn test_transitions() {
+        let engine = TemplateEngine::new().unwrap();
+        let ctx = TemplateContext::new();
+        let alerts = [alert("row.status == 'Failed'", Some("{{ row.name }} failed"))];
+        let mut tracker = AlertTracker::new();
+
+        let rows = [
+            json!({"name": "a", "status": "Failed"}),
+            json!({"name": "b", "status": "Running"}),
+        ];
+        let events = tracker.evaluate(&engine, "pods", &alerts, &rows, &ctx);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].state, AlertState::Firing);
+        assert_eq!(events[0].message, "a failed");
+
+        // Still failing: nothing new
+        assert!(tracker.evaluate(&engine, "pods", &alerts, &rows, &ctx).is_empty());
+
+        let rows = [
+            json!({"name": "a", "status": "Running"}),
+            json!({"name": "b", "status": "Failed"}),
+        ];
+        let events = tracker.evaluate(&engine, "pods", &alerts, &rows, &ctx);
+        let states: Vec<_> = events.iter().map(|e| (e.state, e.message.as_str())).collect();
+        assert_eq!(
+            states,
+            vec![(AlertState::Firing, "b failed"), (AlertState::Resolved, "a failed")]
+        );
+        assert_eq!(tracker.firing_count(), 1);
+    }
+
+    #[test]
+    fn test_default_message_and_bad_condition() {
+        let engine = TemplateEngine::new().unwrap();
+        let ctx = TemplateContext::new();
+        let alerts = [alert("row.missing.field > 1", None), alert("{{ true }}", None)];
+        let rows = [json!({"name": "a"}), json!({"name": "b"})];
+
+        let events = AlertTracker::new().evaluate(&engine, "pods", &alerts, &rows, &ctx);
+        // Matching rows collapse into one alert named after the rule
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].message, "Failing");
+    }
+
+    #[tokio::test]
+    async fn test_run_hook() {
+        let event = AlertEvent {
+            page: "pods".to_string(),
+            alert: "Failing".to_string(),
+            state: AlertState::Firing,
+            message: "a failed".to_string(),
+        };
+        run_hook("test \"$TERMSTACK_ALERT_STATE\" = FIRING", &event).await.unwrap();
+        assert!(run_hook("exit 2", &event).await.is_err());
+    }
+}
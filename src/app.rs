@@ -1,46 +1,78 @@
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use ratatui::{
     DefaultTerminal, Frame,
-    layout::{Constraint, Layout, Rect},
-    style::{Color, Modifier, Style},
+    buffer::{Buffer, Cell as BufferCell},
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Cell, Paragraph, Row, Table},
 };
 use serde_json::Value;
-use std::collections::{HashMap, VecDeque};
-use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+use std::collections::{HashMap, HashSet, VecDeque};
+use unicode_width::UnicodeWidthStr;
 use std::sync::Arc;
 use tokio::sync::mpsc;
 
 use crate::{
     action::executor::{ActionExecutor, ActionResult},
-    config::{Config, View as ConfigView, TableColumn},
+    config::{
+        DetailKind, LogFormat, LogsView, SplitDetail, SplitOrientation, TableColumn,
+        View as ConfigView,
+    },
     data::{JsonPathExtractor, StreamMessage},
+    context::AppContext,
     error::Result,
-    globals,
-    navigation::{NavigationContext, NavigationFrame, NavigationStack},
+    hook::HookEvent,
+    input::history::History,
+    input::keymap::{CHORD_TIMEOUT, KeyAction, KeyCombo, Keymap},
+    navigation::{
+        Bookmark, NavigationContext, NavigationFrame, NavigationStack, Router, RowLink,
+        ViewSnapshot, Visit, VisitHistory,
+    },
     template::engine::TemplateContext,
+    ui::{
+        breadcrumb::{self, Crumb},
+        terminal::Capabilities,
+        theme::Theme,
+        toast::{ToastKind, ToastManager},
+    },
+    util::state::ViewState,
+    view::chart::{self, ChartData},
+    view::diff::{self, Baseline, CellChange},
+    view::filter::{self, FilterOp},
+    view::form::{self, FieldValue, FormState},
+    view::logline::{self, Timestamps},
+    view::selection::{self, Selection},
 };
 use regex::Regex;
 
 /// Global search state that works across all views
 /// Search mode for global search
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default)]
 enum SearchMode {
     /// Search across all columns
+    #[default]
     Global,
     /// Search within a specific column
     ColumnSpecific {
         column_display_name: String,  // User-friendly name from "display" field
-        column_path: String,          // JSONPath from "path" field
         search_term: String,
     },
+    /// `column:value` / `column>value` terms, plus free text searched in all columns
+    Filters {
+        filters: Vec<ColumnFilter>,
+        text: String,
+    },
 }
 
-impl Default for SearchMode {
-    fn default() -> Self {
-        SearchMode::Global
-    }
+/// A `field<op>value` search term resolved to a column
+#[derive(Debug, Clone)]
+struct ColumnFilter {
+    column_display_name: String,
+    op: FilterOp,
+    value: String,
+    /// The pattern of a `col:!regex` filter, compiled when the query is parsed
+    regex: Option<Regex>,
 }
 
 struct GlobalSearch {
@@ -56,6 +88,10 @@ struct GlobalSearch {
     case_sensitive: bool,
     /// Current search mode (global or column-specific)
     mode: SearchMode,
+    /// Highlight regex with the query and case sensitivity it was built for
+    highlight_cache: std::cell::RefCell<Option<(String, bool, Option<Regex>)>>,
+    /// Entered queries, recalled with Up/Down
+    history: History,
 }
 
 impl Default for GlobalSearch {
@@ -67,6 +103,8 @@ impl Default for GlobalSearch {
             regex_pattern: None,
             case_sensitive: false,
             mode: SearchMode::Global,
+            highlight_cache: std::cell::RefCell::new(None),
+            history: History::default(),
         }
     }
 }
@@ -80,9 +118,9 @@ impl GlobalSearch {
         }
 
         // Check if query starts with '!' for regex mode
-        let pattern_str = if self.query.starts_with('!') {
+        let pattern_str = if let Some(pattern) = self.query.strip_prefix('!') {
             // Regex mode: use query after '!'
-            self.query[1..].to_string()
+            pattern.to_string()
         } else {
             // Literal mode: escape special regex characters
             regex::escape(&self.query)
@@ -129,6 +167,11 @@ impl GlobalSearch {
     /// Deactivate and apply filter
     fn apply(&mut self) {
         self.active = false;
+        self.preview();
+    }
+
+    /// Filter with the query typed so far, keeping the input open
+    fn preview(&mut self) {
         self.filter_active = !self.query.is_empty();
         self.compile_pattern();
     }
@@ -154,11 +197,30 @@ impl GlobalSearch {
     /// Add character to query
     fn push_char(&mut self, c: char) {
         self.query.push(c);
+        self.history.reset();
     }
 
     /// Remove last character from query
     fn pop_char(&mut self) {
         self.query.pop();
+        self.history.reset();
+    }
+
+    /// Replace the query with an older (`back`) or newer entry from the history.
+    /// Returns whether the query changed.
+    fn recall(&mut self, back: bool) -> bool {
+        let recalled = if back {
+            self.history.older(&self.query)
+        } else {
+            self.history.newer()
+        };
+        match recalled {
+            Some(query) => {
+                self.query = query.to_string();
+                true
+            }
+            None => false,
+        }
     }
 
     /// Toggle case sensitivity
@@ -174,109 +236,120 @@ impl GlobalSearch {
     /// E.g. `%Project Type% active` matches column "Project Type" with term "active".
     fn parse_mode(&self, table_columns: &[TableColumn]) -> SearchMode {
         // Check for %column_name% pattern
-        if self.query.starts_with('%') {
-            if let Some(end_pct) = self.query[1..].find('%') {
-                let column_name = self.query[1..1 + end_pct].trim();
-                let after_delim = &self.query[2 + end_pct..];
-
-                // Must have a space then search term after closing %
-                if after_delim.starts_with(' ') {
-                    let search_term = after_delim[1..].trim();
-                    if !search_term.is_empty() {
-                        if let Some(col) = table_columns.iter()
-                            .find(|c| c.display.eq_ignore_ascii_case(column_name))
-                        {
-                            return SearchMode::ColumnSpecific {
-                                column_display_name: col.display.clone(),
-                                column_path: col.path.clone(),
-                                search_term: search_term.to_string(),
-                            };
-                        }
-                    }
+        if self.query.starts_with('%')
+            && let Some(end_pct) = self.query[1..].find('%')
+        {
+            let column_name = self.query[1..1 + end_pct].trim();
+            let after_delim = &self.query[2 + end_pct..];
+
+            // Must have a space then search term after closing %
+            if let Some(rest) = after_delim.strip_prefix(' ') {
+                let search_term = rest.trim();
+                if !search_term.is_empty()
+                    && let Some(col) = table_columns
+                        .iter()
+                        .find(|c| c.display.eq_ignore_ascii_case(column_name))
+                {
+                    return SearchMode::ColumnSpecific {
+                        column_display_name: col.display.clone(),
+                        search_term: search_term.to_string(),
+                    };
                 }
             }
         }
 
+        // `status:Failed cpu>80` terms naming a column filter on that column;
+        // the other words are searched for across all columns
+        if !self.query.starts_with('!') {
+            let mut filters = Vec::new();
+            let mut text = Vec::new();
+            for token in self.query.split_whitespace() {
+                let resolved = filter::parse_term(token).and_then(|term| {
+                    let col = table_columns.iter().find(|c| Self::names_column(c, term.field))?;
+                    Some(ColumnFilter {
+                        column_display_name: col.display.clone(),
+                        op: term.op,
+                        value: term.value.to_string(),
+                        regex: term.op.regex(term.value, self.case_sensitive),
+                    })
+                });
+                match resolved {
+                    Some(column_filter) => filters.push(column_filter),
+                    None => text.push(token),
+                }
+            }
+            if !filters.is_empty() {
+                return SearchMode::Filters {
+                    filters,
+                    text: text.join(" "),
+                };
+            }
+        }
+
         // Default to global search
         SearchMode::Global
     }
 
+    /// Whether a filter's field names the column: its display name (spaces may
+    /// be typed as `_` or `-`), or the last segment of its path (`phase` for
+    /// `$.status.phase`), ignoring case
+    fn names_column(col: &TableColumn, field: &str) -> bool {
+        let normalize = |name: &str| -> String {
+            name.chars()
+                .filter(|c| !matches!(c, ' ' | '_' | '-'))
+                .flat_map(char::to_lowercase)
+                .collect()
+        };
+        let field = normalize(field);
+        let path_tail = (!col.is_computed())
+            .then(|| col.path.rsplit(['.', '$']).next())
+            .flatten()
+            .unwrap_or_default();
+        normalize(&col.display) == field || (!path_tail.is_empty() && normalize(path_tail) == field)
+    }
+
     /// Highlight search matches within spans by splitting them at match boundaries.
-    /// Match regions get yellow background with black foreground overlay.
-    fn highlight_search_in_spans<'a>(&self, spans: Vec<Span<'a>>) -> Vec<Span<'a>> {
+    /// Match regions get the theme's highlight background with inverse text.
+    fn highlight_search_in_spans<'a>(&self, spans: Vec<Span<'a>>, theme: &Theme) -> Vec<Span<'a>> {
         if !self.filter_active || self.query.is_empty() {
             return spans;
         }
+        match self.highlight_regex() {
+            Some(regex) => crate::view::style::highlight_matches(spans, &regex, theme),
+            None => spans,
+        }
+    }
 
-        // For ColumnSpecific mode, use the search_term for highlighting
+    /// Regex for highlighting matches (the column term in column mode), compiled
+    /// once per query rather than for every cell on every frame
+    fn highlight_regex(&self) -> Option<Regex> {
         let effective_query = match &self.mode {
             SearchMode::ColumnSpecific { search_term, .. } => search_term.as_str(),
+            SearchMode::Filters { text, .. } => text.as_str(),
             SearchMode::Global => &self.query,
         };
 
-        if effective_query.is_empty() {
-            return spans;
+        let mut cache = self.highlight_cache.borrow_mut();
+        if let Some((query, case_sensitive, regex)) = cache.as_ref()
+            && query == effective_query
+            && *case_sensitive == self.case_sensitive
+        {
+            return regex.clone();
         }
 
-        // Build a regex for finding matches in text
-        let pattern = if effective_query.starts_with('!') {
-            let pat = &effective_query[1..];
-            if pat.is_empty() {
-                return spans;
-            }
-            if self.case_sensitive {
-                Regex::new(pat)
-            } else {
-                Regex::new(&format!("(?i){}", pat))
-            }
-        } else {
-            let escaped = regex::escape(effective_query);
-            if self.case_sensitive {
-                Regex::new(&escaped)
-            } else {
-                Regex::new(&format!("(?i){}", escaped))
-            }
-        };
-
-        let regex = match pattern {
-            Ok(r) => r,
-            Err(_) => return spans,
+        let pattern = match effective_query.strip_prefix('!') {
+            Some(pat) => pat.to_string(),
+            None => regex::escape(effective_query),
         };
-
-        let highlight_style_modifier = |base: Style| -> Style {
-            base.bg(Color::Yellow).fg(Color::Black)
+        let regex = if pattern.is_empty() {
+            None
+        } else if self.case_sensitive {
+            Regex::new(&pattern).ok()
+        } else {
+            Regex::new(&format!("(?i){}", pattern)).ok()
         };
-
-        let mut result = Vec::new();
-        for span in spans {
-            let text = span.content.as_ref();
-            let style = span.style;
-
-            let mut last_end = 0;
-            for m in regex.find_iter(text) {
-                // Add text before match with original style
-                if m.start() > last_end {
-                    result.push(Span::styled(
-                        text[last_end..m.start()].to_string(),
-                        style,
-                    ));
-                }
-                // Add matched text with highlight style
-                result.push(Span::styled(
-                    text[m.start()..m.end()].to_string(),
-                    highlight_style_modifier(style),
-                ));
-                last_end = m.end();
-            }
-            // Add remaining text after last match
-            if last_end < text.len() {
-                result.push(Span::styled(text[last_end..].to_string(), style));
-            } else if last_end == 0 {
-                // No matches found in this span, keep as-is
-                result.push(span);
-            }
-        }
-        result
+        *cache = Some((effective_query.to_string(), self.case_sensitive, regex.clone()));
+        regex
     }
 }
 
@@ -284,13 +357,28 @@ impl GlobalSearch {
 struct LogLine {
     raw: String,            // ANSI-stripped plain text (for search matching)
     parsed: Line<'static>,  // Pre-parsed styled spans (for rendering)
+    received: chrono::DateTime<chrono::Local>,
+}
+
+/// A `detach: true` stream left running while another page is shown
+struct DetachedStream {
+    /// None once the command has ended
+    receiver: Option<mpsc::Receiver<StreamMessage>>,
+    buffer: VecDeque<LogLine>,
+    status: StreamStatus,
+    highlights: Vec<(Regex, Style)>,
+    buffer_time: Option<std::time::Duration>,
 }
 
 pub struct App {
+    /// Config, template engine and HTTP client of this instance
+    ctx: Arc<AppContext>,
     running: bool,
     current_page: String,
     nav_stack: NavigationStack,
     nav_context: NavigationContext,
+    // Checks a page's params are set before it loads
+    router: Router,
     action_executor: ActionExecutor,
     adapter_registry: Arc<crate::adapters::registry::AdapterRegistry>,
 
@@ -302,7 +390,10 @@ pub struct App {
     table_state: ratatui::widgets::TableState,
     activity: ActivityState,
     spinner_frame: usize, // Current spinner animation frame (0-9)
-    error_message: Option<String>,
+    page_error: Option<PageError>,
+    // Sources of a multi-source page that failed on the last fetch while the
+    // others loaded, named in a banner above the data
+    failed_sources: Vec<SourceFailure>,
 
     // Global search (works across all views)
     global_search: GlobalSearch,
@@ -314,6 +405,16 @@ pub struct App {
 
     // Auto-refresh timer
     last_refresh: std::time::Instant,
+    // Auto-refresh of the current page: its interval, when the watcher fires next
+    // (None while paused or while a one-off load runs instead), and whether the
+    // user paused it (`p`)
+    refresh_interval: Option<std::time::Duration>,
+    next_refresh: Option<std::time::Instant>,
+    refresh_paused: bool,
+    // The task feeding `refresh_receiver` (the watcher or a one-off load)
+    refresh_task: Option<tokio::task::AbortHandle>,
+    // Seconds until the next refresh as last drawn, to redraw the countdown
+    refresh_countdown: Option<u64>,
 
     // Stream state
     stream_active: bool,
@@ -322,11 +423,30 @@ pub struct App {
     stream_frozen_snapshot: Option<Arc<VecDeque<LogLine>>>, // Frozen snapshot when paused (Arc for efficient cloning)
     stream_receiver: Option<mpsc::Receiver<StreamMessage>>,
     stream_status: StreamStatus,
+    /// Reconnect attempts since the stream last sent a line
+    stream_attempt: u32,
+    /// When the first line not yet rendered arrived, for `buffer_time`
+    stream_unrendered_since: Option<std::time::Instant>,
+    /// The stream's `buffer_time`, parsed when it starts
+    stream_buffer_time: Option<std::time::Duration>,
+    /// The logs view's `highlights`, compiled when its stream starts
+    log_highlights: Vec<(Regex, Style)>,
+    /// Page the stream belongs to
+    stream_page: String,
+    /// A `rows:` stream feeding the table, with the parser for its lines
+    row_stream: Option<(mpsc::Receiver<StreamMessage>, crate::data::RowParser)>,
+    /// `detach: true` streams kept running while other pages are shown, by page
+    detached_streams: HashMap<String, DetachedStream>,
 
     // Logs view settings
     logs_follow: bool,
     logs_wrap: bool,
     logs_horizontal_scroll: usize,
+    /// Timestamps picked with `t`; None shows the page's `show_timestamps`
+    logs_timestamps: Option<Timestamps>,
+    /// `n` / `N` jump between the search's matches with every line shown,
+    /// instead of the search filtering the lines
+    logs_find: bool,
 
     // Background action execution
     pending_action_info: Option<PendingActionInfo>,
@@ -336,6 +456,44 @@ pub struct App {
     show_action_menu: bool,
     action_menu_selected: usize,
 
+    // Row context menu (m to open)
+    context_menu: Option<ContextMenu>,
+
+    // Command palette (: to open)
+    command_palette: Option<CommandPalette>,
+
+    // Full value of the selected cell (v to open)
+    value_popup: Option<ValuePopup>,
+
+    // Split views' row pane (Shift+Tab to focus)
+    detail_pane: DetailPane,
+
+    // Field values of the current form page
+    form: Option<FormState>,
+
+    // Table rows expanded in place with `x` (by row identity, like marks)
+    expanded_rows: HashSet<String>,
+
+    // Rows marked for bulk actions (by row identity), and each current_data row's identity
+    marked_rows: Selection,
+    row_keys: Vec<String>,
+
+    // Baseline snapshots per page (`b`), and whether cells that differ are highlighted (`d`)
+    baselines: HashMap<String, Baseline>,
+    show_diff: bool,
+
+    // What the last refresh changed, for tables with `highlight_changes`
+    recent_changes: Option<RecentChanges>,
+
+    // Bumped whenever the rows the filter shows change (data, search or sort),
+    // and the table column sizes measured over them for that generation
+    data_generation: u64,
+    column_stats: Option<ColumnStats>,
+
+    // Points plotted by chart pages, per page (kept when navigating away, so
+    // charts with `history` keep theirs)
+    chart_data: HashMap<String, ChartData>,
+
     // UI state
     needs_clear: bool,
     needs_render: bool,
@@ -345,13 +503,197 @@ pub struct App {
 
     // Page data cache for instant back navigation
     page_cache: HashMap<String, Vec<Value>>,
+
+    // OSC 8 hyperlink regions (screen rect + URL) from the current and previous render
+    hyperlinks: Vec<(Rect, String)>,
+    last_hyperlinks: Vec<(Rect, String)>,
+
+    // Safe mode: no auto-refresh, streams only start on `r`
+    safe_mode: bool,
+
+    // Read-only mode (`--read-only`): page actions are hidden and can't run
+    read_only: bool,
+
+    // Key -> built-in action resolution (defaults + config keybindings)
+    keymap: Keymap,
+
+    // Colors and glyphs the terminal supports; frames are fitted to them
+    caps: Capabilities,
+
+    // Action results and other messages, and the history of them
+    toasts: ToastManager,
+
+    // Scroll of the notification history overlay (`M`) while it's open
+    notification_history: Option<u16>,
+
+    // Keys typed so far of an incomplete multi-key chord (e.g. `g` of `g p`)
+    pending_chord: Option<PendingChord>,
+
+    // Last edit of the search query not yet applied as a live filter
+    search_edited: Option<std::time::Instant>,
+
+    // Vim-style count typed before a motion (the `5` of `5j`)
+    count_prefix: Option<usize>,
+
+    // Rows (or lines) visible in the content area, for page-wise movement
+    page_height: usize,
+
+    // Page title rendered against the fetched data, refreshed after every fetch
+    page_title: Option<String>,
+    // Context values the current page was opened with, for its breadcrumb
+    opened_with: Vec<String>,
+    // Context the current page was opened with, by name, to open it that way again
+    opened_context: HashMap<String, Value>,
+    // Pages gone back from, the next to go forward to last
+    forward_stack: Vec<NavigationFrame>,
+    // Pages visited this session, for the history picker (`H`)
+    visits: VisitHistory,
+    // Selected entry while the history picker is open
+    history_picker: Option<usize>,
+    // Bookmarked pages (`B`), saved with `app.save_bookmarks`
+    bookmarks: Vec<Bookmark>,
+    // Selected bookmark while the bookmarks overlay is open
+    bookmark_picker: Option<usize>,
+    // Page under the modal page shown, restored when it's dismissed
+    modal: Option<NavigationFrame>,
+    // The last screen drawn without a modal page, shown dimmed under one
+    last_screen: Option<ratatui::buffer::Buffer>,
+
+    // Focused table column (position in display order), moved with h/l
+    focused_column: usize,
+
+    // First unpinned column shown when a table is scrolled sideways
+    column_offset: usize,
+
+    // Sort picked with `s`, replacing the page's configured sort until the page changes
+    runtime_sort: Option<RuntimeSort>,
+
+    // Persisted runtime view adjustments (column order) and where they're saved
+    view_state: ViewState,
+    view_state_path: Option<std::path::PathBuf>,
+
+    // Page alerts currently firing, so only new ones are announced
+    alerts: crate::alert::AlertTracker,
+    // Hook commands that failed, reported as notifications
+    hook_failures: (
+        mpsc::UnboundedSender<String>,
+        mpsc::UnboundedReceiver<String>,
+    ),
+    // Logs piped to a `save.command` in the background, reported when it exits
+    #[allow(clippy::type_complexity)]
+    exports: (
+        mpsc::UnboundedSender<(String, ToastKind)>,
+        mpsc::UnboundedReceiver<(String, ToastKind)>,
+    ),
+    // Page and row key the cursor was on when `on_row_select` hooks last ran,
+    // and the one it moved to since, with when
+    hook_row: Option<(String, String)>,
+    hook_row_pending: Option<((String, String), std::time::Instant)>,
+
+    // Dashboard pages: each panel's data, and the tasks fetching them
+    dashboard_panels: Vec<PanelState>,
+    dashboard_receiver: Option<mpsc::Receiver<PanelMessage>>,
+    dashboard_tasks: Vec<tokio::task::AbortHandle>,
+}
+
+/// A dashboard panel's fetch result, by panel index
+struct PanelMessage {
+    panel: usize,
+    result: std::result::Result<Vec<Value>, String>,
+}
+
+/// What a dashboard panel last fetched
+#[derive(Default)]
+struct PanelState {
+    rows: Vec<Value>,
+    chart: ChartData,
+    error: Option<String>,
+    updated: Option<chrono::DateTime<chrono::Local>>,
 }
 
 #[derive(Debug)]
 enum RefreshMessage {
     Started { page_name: String },
-    Completed { page_name: String, data: Vec<Value>, reset_selection: bool },
-    Error { page_name: String, error: String },
+    Completed {
+        page_name: String,
+        data: Vec<Value>,
+        reset_selection: bool,
+        /// Sources of a multi-source page that failed while others loaded
+        failed: Vec<SourceFailure>,
+    },
+    Error { page_name: String, error: String, elapsed: std::time::Duration },
+}
+
+/// A required source of a multi-source page that failed to load
+#[derive(Debug, Clone)]
+pub(crate) struct SourceFailure {
+    pub id: String,
+    pub error: String,
+}
+
+/// A page's rows, with the sources that failed while the rest loaded
+pub(crate) struct PageData {
+    pub rows: Vec<Value>,
+    pub failed: Vec<SourceFailure>,
+}
+
+/// Why the current page has nothing to show, drawn in place of its view
+struct PageError {
+    /// What failed, e.g. "Failed to load data"
+    title: String,
+    /// The error, its causes joined with ": " as the adapters report them
+    message: String,
+    /// The command or URL that failed, templates rendered
+    source: Option<String>,
+    /// How long the fetch ran before failing
+    elapsed: Option<std::time::Duration>,
+    at: chrono::DateTime<chrono::Local>,
+}
+
+impl PageError {
+    fn new(title: &str, message: impl ToString) -> Self {
+        Self {
+            title: title.to_string(),
+            message: message.to_string(),
+            source: None,
+            elapsed: None,
+            at: chrono::Local::now(),
+        }
+    }
+
+    /// The error and its causes, outermost first
+    fn chain(&self) -> Vec<&str> {
+        self.message
+            .split(": ")
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .collect()
+    }
+
+    /// Plain-text form, for the clipboard
+    fn report(&self) -> String {
+        let mut out = format!("{}\n", self.title);
+        for (depth, cause) in self.chain().iter().enumerate() {
+            out.push_str(&format!("{}{}\n", "  ".repeat(depth + 1), cause));
+        }
+        if let Some(source) = &self.source {
+            out.push_str(&format!("Source: {}\n", source));
+        }
+        out.push_str(&format!("Failed: {}", self.failed()));
+        out
+    }
+
+    /// "after 1.2s at 14:02:11"
+    fn failed(&self) -> String {
+        match self.elapsed {
+            Some(elapsed) => format!(
+                "after {:.1}s at {}",
+                elapsed.as_secs_f64(),
+                self.at.format("%H:%M:%S")
+            ),
+            None => format!("at {}", self.at.format("%H:%M:%S")),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -361,20 +703,128 @@ struct ActionConfirm {
     executing: bool,
 }
 
-#[derive(Clone, Copy, PartialEq)]
-#[allow(dead_code)]
-enum MessageType {
-    Success,
-    Error,
-    Info,
-    Warning,
+/// Row-level context menu (opened with `m`): navigation targets and actions in one list
+struct ContextMenu {
+    entries: Vec<ContextMenuEntry>,
+    selected: usize,
+}
+
+/// Full text of the selected cell or log line (`v`), which the table truncates
+struct ValuePopup {
+    title: String,
+    text: String,
+    scroll: u16,
+}
+
+/// Scroll state of a split view's detail pane
+#[derive(Default)]
+struct DetailPane {
+    /// Movement keys scroll the pane instead of moving through the table
+    focused: bool,
+    scroll: u16,
+    /// Data index of the row shown, so another row starts at the top
+    row: Option<usize>,
+    /// Visible lines and furthest scroll position as of the last render
+    height: u16,
+    last: u16,
+}
+
+/// Rows as they were before the last refresh, to highlight what it changed
+struct RecentChanges {
+    page: String,
+    before: Baseline,
+    /// Rows the refresh dropped, shown struck through with `show_removed`
+    removed: Vec<Value>,
+    at: std::time::Instant,
+}
+
+/// A table's columns measured over every row the filter shows, so widths and
+/// alignment don't shift as the table scrolls. Indexed like the config's columns.
+#[derive(Clone)]
+struct ColumnStats {
+    page: String,
+    generation: u64,
+    /// Widest cell, or header with room for a sort arrow
+    widths: Vec<u16>,
+    has_numeric: Vec<bool>,
+    has_text: Vec<bool>,
+}
+
+/// Command palette (opened with `:`): fuzzy search over pages, page actions and built-ins
+struct CommandPalette {
+    query: String,
+    selected: usize,
+}
+
+enum PaletteCommand {
+    Goto(String),
+    /// `:N` jumps to row / line N
+    Jump(usize),
+    Action(Box<crate::config::schema::Action>),
+    Builtin(KeyAction),
+    /// `:baseline NAME` snapshots the page under that name
+    Baseline(String),
+}
+
+struct PaletteEntry {
+    kind: &'static str,
+    label: String,
+    /// Page id or key hint, shown dimmed and matched along with the label
+    hint: String,
+    command: PaletteCommand,
+}
+
+/// Shown in a computed column's cell when its transform fails to render
+const COMPUTED_ERROR: &str = "⚠ error";
+
+/// Largest count prefix accepted (`9999j`)
+const MAX_COUNT: usize = 9999;
+
+/// Pause in typing after which the search filter is applied live
+const SEARCH_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(150);
+
+/// How long the cursor rests on a row before its `on_row_select` hooks run,
+/// so scrolling past rows doesn't start a command for each
+const ROW_SELECT_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(300);
+
+/// How long rows and cells a refresh changed stay highlighted
+const CHANGE_HIGHLIGHT: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// What a completed key (or key chord) resolves to
+enum KeyBinding {
+    Page(Box<crate::config::schema::Action>),
+    Goto(String),
+    Builtin(KeyAction),
+}
+
+/// A sort chosen at runtime, overriding the table's configured `sort`
+#[derive(Debug, Clone)]
+enum RuntimeSort {
+    Unsorted,
+    By(crate::config::TableSort),
+}
+
+/// An incomplete key chord waiting for its next key
+struct PendingChord {
+    keys: Vec<KeyCombo>,
+    started: std::time::Instant,
+    /// Binding for the keys typed so far, run if the chord times out
+    fallback: Option<KeyBinding>,
+}
+
+enum ContextMenuEntry {
+    Navigate {
+        page: String,
+        context: HashMap<String, String>,
+        modal: bool,
+    },
+    Action(Box<crate::config::schema::Action>),
 }
 
 #[derive(Clone)]
 enum ActivityState {
     Idle,
     Loading { message: String },
-    Result { message: String, kind: MessageType, timestamp: std::time::Instant },
 }
 
 impl ActivityState {
@@ -390,12 +840,21 @@ enum StreamStatus {
     Streaming,
     Stopped,
     Error(String),
+    /// Waiting to restart a `reconnect: true` stream (or started again, until
+    /// its first line arrives)
+    Reconnecting {
+        attempt: u32,
+        at: std::time::Instant,
+        reason: Option<String>,
+    },
 }
 
 /// Info captured at action trigger time for processing results later
 struct PendingActionInfo {
     action: crate::config::schema::Action,
     template_ctx: TemplateContext,
+    /// Number of marked rows the action runs for (0 for the selected row only)
+    bulk: usize,
 }
 
 /// Message sent from background action task to main event loop
@@ -405,18 +864,28 @@ enum ActionResultMsg {
 
 impl App {
     pub fn new(
-        config: Config,
+        ctx: Arc<AppContext>,
         adapter_registry: crate::adapters::registry::AdapterRegistry,
     ) -> Result<Self> {
+        let config = &ctx.config;
         let current_page = config.start.clone();
         let nav_context = NavigationContext::new().with_globals(config.globals.clone());
-        let action_executor = ActionExecutor::new(Arc::new(globals::template_engine().clone()));
+        let action_executor = ActionExecutor::new(ctx.clone());
+        let keymap = Keymap::from_config(config.keybindings.as_ref())
+            .map_err(crate::error::TermStackError::Config)?;
+        let view_state_path = ViewState::default_path();
+        let view_state = view_state_path
+            .as_deref()
+            .map(ViewState::load)
+            .unwrap_or_default();
 
         Ok(Self {
+            ctx: ctx.clone(),
             running: false,
             current_page,
             nav_stack: NavigationStack::default(),
             nav_context,
+            router: Router::new(ctx.clone()),
             action_executor,
             adapter_registry: Arc::new(adapter_registry),
             current_data: Vec::new(),
@@ -426,37 +895,228 @@ impl App {
             table_state: ratatui::widgets::TableState::default(),
             activity: ActivityState::Idle,
             spinner_frame: 0,
-            error_message: None,
-            global_search: GlobalSearch::default(),
+            page_error: None,
+            failed_sources: Vec::new(),
+            global_search: GlobalSearch {
+                history: if config.app.save_search_history {
+                    History::from_entries(view_state.search_history().to_vec())
+                } else {
+                    History::default()
+                },
+                ..GlobalSearch::default()
+            },
             show_quit_confirm: false,
             action_confirm: None,
             last_refresh: std::time::Instant::now(),
+            refresh_interval: None,
+            next_refresh: None,
+            refresh_paused: false,
+            refresh_task: None,
+            refresh_countdown: None,
             stream_active: false,
             stream_paused: false,
             stream_buffer: VecDeque::new(),
             stream_frozen_snapshot: None,
             stream_receiver: None,
             stream_status: StreamStatus::Idle,
+            stream_attempt: 0,
+            stream_unrendered_since: None,
+            stream_buffer_time: None,
+            log_highlights: Vec::new(),
+            stream_page: String::new(),
+            row_stream: None,
+            detached_streams: HashMap::new(),
             logs_follow: true,
             logs_wrap: true,
             logs_horizontal_scroll: 0,
+            logs_timestamps: None,
+            logs_find: false,
             pending_action_info: None,
             action_result_receiver: None,
             show_action_menu: false,
             action_menu_selected: 0,
+            context_menu: None,
+            command_palette: None,
+            value_popup: None,
+            detail_pane: DetailPane::default(),
+            form: None,
+            expanded_rows: HashSet::new(),
+            marked_rows: Selection::default(),
+            row_keys: Vec::new(),
+            baselines: HashMap::new(),
+            recent_changes: None,
+            data_generation: 0,
+            column_stats: None,
+            chart_data: HashMap::new(),
+            show_diff: false,
+            pending_chord: None,
+            search_edited: None,
+            count_prefix: None,
+            page_height: 1,
+            page_title: None,
+            opened_with: Vec::new(),
+            opened_context: HashMap::new(),
+            forward_stack: Vec::new(),
+            visits: VisitHistory::default(),
+            history_picker: None,
+            bookmarks: if config.app.save_bookmarks {
+                view_state.bookmarks(&config.app.name).to_vec()
+            } else {
+                Vec::new()
+            },
+            bookmark_picker: None,
+            modal: None,
+            last_screen: None,
+            focused_column: 0,
+            column_offset: 0,
+            runtime_sort: None,
+            view_state,
+            view_state_path,
+            alerts: crate::alert::AlertTracker::new(),
+            hook_failures: mpsc::unbounded_channel(),
+            exports: mpsc::unbounded_channel(),
+            hook_row: None,
+            hook_row_pending: None,
+            dashboard_panels: Vec::new(),
+            dashboard_receiver: None,
+            dashboard_tasks: Vec::new(),
             needs_clear: false,
             needs_render: true, // Initial render needed
             refresh_receiver: None,
             page_cache: HashMap::new(),
+            hyperlinks: Vec::new(),
+            last_hyperlinks: Vec::new(),
+            safe_mode: false,
+            read_only: false,
+            keymap,
+            caps: Capabilities::detect(),
+            toasts: ToastManager::new(config.app.notifications.as_ref()),
+            notification_history: None,
         })
     }
 
+    /// Start in safe mode: auto-refresh is disabled and streams are not started on
+    /// page load (press `r` to start them). Used to recover from a config whose data
+    /// source hangs or floods the terminal.
+    pub fn with_safe_mode(mut self, safe_mode: bool) -> Self {
+        self.safe_mode = safe_mode;
+        self
+    }
+
+    /// Read-only mode: page actions are hidden from menus, the palette and their
+    /// keys, so a dashboard can be shared without anyone being able to change things
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Start with navigation context already set (`--context pod=api-1`), as if
+    /// the start page had been opened from a row with it
+    pub fn with_context(mut self, context: HashMap<String, Value>) -> Self {
+        self.opened_with = context.values().filter_map(context_label).collect();
+        self.opened_with.sort();
+        self.set_opened_context(context);
+        self
+    }
+
+    /// Neither read nor write saved state (column order, bookmarks), and draw
+    /// for a full-featured terminal whatever this one is, so a run doesn't
+    /// depend on the machine it's on (`termstack::testing`)
+    pub(crate) fn isolated(mut self) -> Self {
+        self.view_state = ViewState::default();
+        self.view_state_path = None;
+        self.bookmarks.clear();
+        self.caps = Capabilities::default();
+        self
+    }
+
+    /// Id of the page shown
+    pub(crate) fn current_page(&self) -> &str {
+        &self.current_page
+    }
+
+    /// The current page's actions; none in read-only mode
+    fn page_actions(&self) -> &[crate::config::schema::Action] {
+        if self.read_only {
+            return &[];
+        }
+        self.ctx
+            .config
+            .pages
+            .get(&self.current_page)
+            .and_then(|p| p.actions.as_deref())
+            .unwrap_or(&[])
+    }
+
+    /// A line from the page's stream as it's kept in the buffer
+    fn stream_log_line(
+        page: &crate::config::Page,
+        line: &str,
+        is_stderr: bool,
+        highlights: &[(Regex, Style)],
+        theme: &Theme,
+    ) -> LogLine {
+        let logs_view = match &page.view {
+            ConfigView::Logs(logs_view) => Some(logs_view),
+            _ => None,
+        };
+        match (is_stderr, &page.data) {
+            // stderr is never JSON to parse, and keeps its tag in front
+            (
+                true,
+                Some(crate::config::DataSource::SingleOrStream(
+                    crate::config::SingleOrStream::Stream(stream),
+                )),
+            ) => Self::tag_stderr_line(
+                Self::parse_and_store_line(line, None, highlights, theme),
+                &stream.stderr,
+                theme,
+            ),
+            _ => Self::parse_and_store_line(line, logs_view, highlights, theme),
+        }
+    }
+
+    /// Put a stderr line's tag in front of it, in the tag's color
+    fn tag_stderr_line(
+        mut log_line: LogLine,
+        stderr: &crate::config::StreamStderr,
+        theme: &Theme,
+    ) -> LogLine {
+        if stderr.prefix.is_empty() {
+            return log_line;
+        }
+        let style = stderr
+            .color
+            .as_deref()
+            .and_then(|color| theme.color(color))
+            .map_or(Style::default(), |color| Style::default().fg(color));
+        log_line.parsed.spans.insert(0, Span::styled(stderr.prefix.clone(), style));
+        log_line.raw.insert_str(0, &stderr.prefix);
+        log_line
+    }
+
     /// Parse a raw ANSI string into a LogLine with pre-parsed styled spans.
     /// Called once per line at insertion time. Sanitizes span content to remove
     /// any residual control characters (ESC, CR, BS, etc.) that ansi_to_tui
     /// didn't convert — these corrupt terminal state during ratatui rendering.
-    fn parse_and_store_line(raw_ansi: &str) -> LogLine {
+    /// With `parse: json`, lines that are JSON objects show their fields
+    /// instead. `highlights` restyle their matches either way.
+    fn parse_and_store_line(
+        raw_ansi: &str,
+        logs_view: Option<&LogsView>,
+        highlights: &[(Regex, Style)],
+        theme: &Theme,
+    ) -> LogLine {
         use ansi_to_tui::IntoText;
+
+        if let Some(view) = logs_view
+            && view.parse == Some(LogFormat::Json)
+            && let Some(values) = logline::parse(&view.fields, raw_ansi)
+        {
+            let parsed = Self::highlight_log_line(logline::to_line(values, theme), highlights);
+            let raw: String = parsed.spans.iter().map(|s| s.content.as_ref()).collect();
+            return LogLine { raw, parsed, received: chrono::Local::now() };
+        }
         let parsed = match raw_ansi.into_text() {
             Ok(text) => {
                 if text.lines.is_empty() {
@@ -482,16 +1142,45 @@ impl App {
                 .collect();
             Span::styled(clean, span.style)
         }).collect();
-        let parsed = Line::from(sanitized_spans);
+        let parsed = Self::highlight_log_line(Line::from(sanitized_spans), highlights);
         // Build ANSI-stripped plain text by concatenating span contents
         let raw: String = parsed.spans.iter().map(|s| s.content.as_ref()).collect();
-        LogLine { raw, parsed }
+        LogLine { raw, parsed, received: chrono::Local::now() }
+    }
+
+    /// The logs view's timestamps: the ones picked with `t`, else the page's
+    /// `show_timestamps`
+    fn log_timestamps(&self) -> Timestamps {
+        self.logs_timestamps.unwrap_or_else(|| {
+            match self
+                .ctx
+                .config
+                .pages
+                .get(&self.current_page)
+                .map(|p| &p.view)
+            {
+                Some(ConfigView::Logs(logs_view)) if logs_view.show_timestamps => {
+                    Timestamps::Received
+                }
+                _ => Timestamps::Off,
+            }
+        })
+    }
+
+    /// Apply a logs view's compiled `highlights` to a line, in order
+    fn highlight_log_line(line: Line<'static>, highlights: &[(Regex, Style)]) -> Line<'static> {
+        let spans = highlights.iter().fold(line.spans, |spans, (regex, style)| {
+            crate::view::style::style_matches(spans, regex, |s| s.patch(*style))
+        });
+        Line::from(spans)
     }
 
-    /// Truncate a pre-parsed Line at character boundaries using unicode widths.
+    /// Truncate a pre-parsed Line at grapheme boundaries using unicode widths.
     /// Skips `char_offset` display columns, then takes up to `width` columns.
     /// Preserves span styles through truncation.
     fn format_log_line(line: &Line<'static>, char_offset: usize, width: usize) -> Line<'static> {
+        use crate::util::text;
+
         let mut result_spans: Vec<Span<'static>> = Vec::new();
         let mut cols_skipped: usize = 0;
         let mut cols_taken: usize = 0;
@@ -500,21 +1189,18 @@ impl App {
             if cols_taken >= width {
                 break;
             }
-            let mut sliced = String::new();
-            for ch in span.content.chars() {
-                let cw = UnicodeWidthChar::width(ch).unwrap_or(0);
-                if cols_skipped < char_offset {
-                    cols_skipped += cw;
-                    continue;
-                }
-                if cols_taken + cw > width {
-                    break;
-                }
-                sliced.push(ch);
-                cols_taken += cw;
+            let mut content = span.content.as_ref();
+            if cols_skipped < char_offset {
+                content = text::skip(content, char_offset - cols_skipped);
+                cols_skipped = match content.is_empty() {
+                    true => cols_skipped + text::width(&span.content),
+                    false => char_offset,
+                };
             }
+            let sliced = text::cut(content, width - cols_taken);
+            cols_taken += text::width(sliced);
             if !sliced.is_empty() {
-                result_spans.push(Span::styled(sliced, span.style));
+                result_spans.push(Span::styled(sliced.to_string(), span.style));
             }
         }
 
@@ -533,45 +1219,19 @@ impl App {
                 self.needs_clear = false;
             }
 
-            // Check for background load / refresh updates
-            self.check_refresh_updates();
-
-            // Check for stream updates
-            self.check_stream_updates();
-
-            // Check for background action completion
-            if let Some(action_result) = self.check_action_result() {
-                match action_result {
-                    ActionResult::Navigate(page, context_map) => {
-                        self.navigate_to_page(&page, context_map).await;
-                    }
-                    ActionResult::Refresh => {
-                        self.load_current_page_background();
-                    }
-                    _ => {}
-                }
-            }
-
-            // Auto-dismiss notifications after 3 seconds
-            if let ActivityState::Result { timestamp, .. } = &self.activity {
-                if timestamp.elapsed() > std::time::Duration::from_secs(3) {
-                    self.activity = ActivityState::Idle;
-                    self.needs_render = true;
-                }
-            }
-
-            // Advance spinner animation if loading
-            if self.activity.is_loading() {
-                self.advance_spinner();
-                self.needs_render = true;
-            }
+            self.tick().await;
 
             // Only render if needed (data changed, user input, etc.)
             if self.needs_render {
                 // Update table state to match selected_index
                 self.table_state.select(Some(self.selected_index));
 
-                terminal.draw(|frame| self.render(frame))?;
+                self.last_hyperlinks = std::mem::take(&mut self.hyperlinks);
+                let completed = terminal.draw(|frame| self.render(frame))?;
+                let link_cells = self.hyperlink_cells(completed.buffer);
+                if !link_cells.is_empty() {
+                    Self::emit_hyperlinks(terminal.backend_mut(), &link_cells)?;
+                }
                 self.needs_render = false;
             }
 
@@ -586,9 +1246,131 @@ impl App {
             }
         }
 
+        self.shutdown().await;
         Ok(())
     }
 
+    /// Take in whatever arrived in the background (loads, streams, action
+    /// results) and advance timers, once per pass of the event loop
+    async fn tick(&mut self) {
+        // Check for background load / refresh updates
+        self.check_refresh_updates();
+        self.check_dashboard_updates();
+
+        // Check for stream updates
+        self.check_stream_updates();
+        self.check_stream_reconnect();
+        self.check_detached_streams();
+        self.check_row_stream_updates();
+
+        self.check_hook_failures();
+        self.check_exports();
+        self.check_row_select();
+
+        // Check for background action completion
+        if let Some(action_result) = self.check_action_result() {
+            match action_result {
+                ActionResult::Navigate(page, context_map) => {
+                    self.navigate_to_page(&page, context_map, false).await;
+                }
+                ActionResult::Refresh => {
+                    self.load_current_page_background();
+                }
+                _ => {}
+            }
+        }
+
+        // Toasts go away after their kind's timeout
+        if self.toasts.expire(std::time::Instant::now()) {
+            self.needs_render = true;
+        }
+
+        // Incomplete key chords time out: run the binding for the keys typed so far
+        // (if any) and drop the rest
+        if self
+            .pending_chord
+            .as_ref()
+            .is_some_and(|chord| chord.started.elapsed() > CHORD_TIMEOUT)
+        {
+            let fallback = self.pending_chord.take().and_then(|chord| chord.fallback);
+            self.needs_render = true;
+            if let Some(binding) = fallback {
+                self.run_binding(binding).await;
+            }
+        }
+
+        // Count down to the next auto-refresh
+        let countdown = self.seconds_to_refresh();
+        if countdown != self.refresh_countdown {
+            self.refresh_countdown = countdown;
+            self.needs_render = true;
+        }
+
+        // Let refresh highlights fade
+        if self.recent_changes.as_ref().is_some_and(|c| c.at.elapsed() > CHANGE_HIGHLIGHT) {
+            self.recent_changes = None;
+            self.needs_render = true;
+        }
+
+        // Filter as the user types, once they pause
+        if self
+            .search_edited
+            .is_some_and(|edited| edited.elapsed() >= SEARCH_DEBOUNCE)
+        {
+            self.search_edited = None;
+            self.global_search.preview();
+            self.refilter_for_search();
+        }
+
+        // Advance spinner animation if loading
+        if self.activity.is_loading() {
+            self.advance_spinner();
+            self.needs_render = true;
+        }
+    }
+
+    /// Collect the drawn cells for each hyperlink region. Regions from the previous
+    /// render that are gone are returned without a URL so they get repainted unlinked.
+    #[allow(clippy::type_complexity)]
+    fn hyperlink_cells(&self, buffer: &Buffer) -> Vec<(Option<&str>, Vec<(u16, u16, BufferCell)>)> {
+        let cells_in = |rect: &Rect| -> Vec<(u16, u16, BufferCell)> {
+            rect.positions()
+                .filter_map(|pos| buffer.cell(pos).map(|c| (pos.x, pos.y, c.clone())))
+                .collect()
+        };
+
+        let stale = self
+            .last_hyperlinks
+            .iter()
+            .filter(|old| !self.hyperlinks.contains(old))
+            .map(|(rect, _)| (None, cells_in(rect)));
+        let current = self
+            .hyperlinks
+            .iter()
+            .map(|(rect, url)| (Some(url.as_str()), cells_in(rect)));
+
+        stale.chain(current).collect()
+    }
+
+    /// Re-draw hyperlink cells wrapped in OSC 8 escape sequences. The cell contents are
+    /// identical to what ratatui just flushed, so its diffing stays consistent.
+    #[allow(clippy::type_complexity)]
+    fn emit_hyperlinks<B: ratatui::backend::Backend + std::io::Write>(
+        backend: &mut B,
+        links: &[(Option<&str>, Vec<(u16, u16, BufferCell)>)],
+    ) -> std::io::Result<()> {
+        for (url, cells) in links {
+            if let Some(url) = url {
+                write!(backend, "\x1b]8;;{}\x1b\\", url)?;
+            }
+            backend.draw(cells.iter().map(|(x, y, cell)| (*x, *y, cell)))?;
+            if url.is_some() {
+                write!(backend, "\x1b]8;;\x1b\\")?;
+            }
+        }
+        std::io::Write::flush(backend)
+    }
+
     fn load_current_page_background(&mut self) {
         // Show spinner while loading fresh data in background
         self.activity = ActivityState::Loading { message: "Refreshing...".into() };
@@ -596,23 +1378,30 @@ impl App {
         self.needs_render = true;
 
         // Get the page config
-        let page = match globals::config().pages.get(&self.current_page).cloned() {
+        let page = match self.ctx.config.pages.get(&self.current_page).cloned() {
             Some(p) => p,
             None => return,
         };
 
+        // Dashboards re-fetch every panel
+        if let ConfigView::Dashboard(dashboard) = &page.view {
+            self.start_dashboard(dashboard);
+            return;
+        }
+        if matches!(page.view, ConfigView::Form(_)) {
+            self.activity = ActivityState::Idle;
+            return;
+        }
+
         // Create a one-time channel for this background load
-        let (tx, rx) = mpsc::channel(10);
-        
-        // Replace the existing refresh receiver (if any) with the new one
-        self.refresh_receiver = Some(rx);
+        let tx = self.refresh_channel();
 
         let current_page = self.current_page.clone();
         let nav_context = self.nav_context.clone();
         let adapter_registry = self.adapter_registry.clone();
 
         // Spawn background task for one-time refresh
-        tokio::spawn(async move {
+        let task = tokio::spawn(async move {
             // Send started notification
             let _ = tx
                 .send(RefreshMessage::Started {
@@ -620,13 +1409,15 @@ impl App {
                 })
                 .await;
 
-            match Self::fetch_data_static(&page, &nav_context, &adapter_registry).await {
-                Ok(data) => {
+            let started = std::time::Instant::now();
+            match Self::fetch_page_static(&page, &nav_context, &adapter_registry).await {
+                Ok(PageData { rows, failed }) => {
                     let _ = tx
                         .send(RefreshMessage::Completed {
                             page_name: current_page,
-                            data,
+                            data: rows,
                             reset_selection: false,
+                            failed,
                         })
                         .await;
                 }
@@ -635,106 +1426,229 @@ impl App {
                         .send(RefreshMessage::Error {
                             page_name: current_page,
                             error: e.to_string(),
+                            elapsed: started.elapsed(),
                         })
                         .await;
                 }
             }
         });
+        self.refresh_task = Some(task.abort_handle());
     }
 
     async fn load_current_page(&mut self) {
         self.activity = ActivityState::Loading { message: format!("Loading {}...", self.current_page) };
         self.spinner_frame = 0; // Reset spinner animation
-        self.error_message = None;
+        self.page_error = None;
+        self.failed_sources.clear();
         self.current_data.clear();
         self.filtered_indices.clear();
+        self.update_page_title();
+        self.record_visit();
         self.needs_render = true; // Force render to show spinner
 
-        // Stop any active stream from previous page
-        self.stop_stream();
+        // Stop (or detach) any active stream or auto-refresh from previous page
+        self.leave_stream();
+        self.stop_page_refresh();
 
-        let page = match globals::config().pages.get(&self.current_page).cloned() {
+        let page = match self.ctx.config.pages.get(&self.current_page).cloned() {
             Some(p) => p,
             None => {
-                self.error_message = Some(format!("Page not found: {}", self.current_page));
+                self.page_error = Some(PageError::new("Page not found", &self.current_page));
                 self.activity = ActivityState::Idle;
                 return;
             }
         };
-
-        // Check if this is a stream data source
-        if let crate::config::DataSource::SingleOrStream(crate::config::SingleOrStream::Stream(_)) =
-            &page.data
-        {
-            // Start streaming (needs &mut self, must be synchronous)
-            if let Err(e) = self.start_stream(&page).await {
-                self.error_message = Some(format!("Failed to start stream: {}", e));
-                self.activity = ActivityState::Idle;
-            } else {
-                self.activity = ActivityState::Idle;
-            }
+        if let Err(e) = self.router.resolve(&self.current_page, &self.nav_context) {
+            self.page_error = Some(PageError::new("Missing page parameters", e));
+            self.activity = ActivityState::Idle;
             return;
         }
 
-        // Non-stream: spawn background task so the event loop keeps rendering the spinner
-        let (tx, rx) = mpsc::channel(10);
-        self.refresh_receiver = Some(rx);
+        if let Some(table_view) = page.view.table() {
+            self.precompile_table_templates(table_view);
+        }
+
+        // Dashboards fetch per panel
+        if let ConfigView::Dashboard(dashboard) = &page.view {
+            self.dashboard_panels.clear();
+            self.start_dashboard(dashboard);
+            return;
+        }
+
+        // Forms have nothing to fetch; going back to one keeps its values
+        if let ConfigView::Form(form) = &page.view {
+            if self.form.is_none() {
+                self.form = Some(self.new_form(form));
+            }
+            self.activity = ActivityState::Idle;
+            return;
+        }
+
+        // Check if this is a stream data source
+        if let Some(crate::config::DataSource::SingleOrStream(
+            crate::config::SingleOrStream::Stream(_),
+        )) = &page.data
+        {
+            if let Some(detached) = self.detached_streams.remove(&self.current_page) {
+                self.resume_stream(detached);
+                return;
+            }
+            if self.safe_mode {
+                // Safe mode: don't start streams automatically, wait for `r`
+                self.notify(
+                    "Safe mode: stream not started (press r to start)",
+                    ToastKind::Warning,
+                );
+                return;
+            }
+            self.start_page_stream(&page).await;
+            return;
+        }
+
+        // Non-stream: spawn background task so the event loop keeps rendering the spinner
+        let tx = self.refresh_channel();
 
         let current_page = self.current_page.clone();
         let nav_context = self.nav_context.clone();
         let adapter_registry = self.adapter_registry.clone();
 
-        tokio::spawn(async move {
-            match Self::fetch_data_static(&page, &nav_context, &adapter_registry).await {
-                Ok(data) => {
+        let task = tokio::spawn(async move {
+            let started = std::time::Instant::now();
+            match Self::fetch_page_static(&page, &nav_context, &adapter_registry).await {
+                Ok(PageData { rows, failed }) => {
                     let _ = tx.send(RefreshMessage::Completed {
                         page_name: current_page,
-                        data,
+                        data: rows,
                         reset_selection: true,
+                        failed,
                     }).await;
                 }
                 Err(e) => {
                     let _ = tx.send(RefreshMessage::Error {
                         page_name: current_page,
                         error: e.to_string(),
+                        elapsed: started.elapsed(),
                     }).await;
                 }
             }
         });
+        self.refresh_task = Some(task.abort_handle());
     }
 
-    fn spawn_refresh_watcher(&mut self, page_name: String, page: crate::config::Page) {
-        use crate::config::DataSource;
+    /// Start the stream for a stream page, surfacing failures as the page error
+    async fn start_page_stream(&mut self, page: &crate::config::Page) {
+        self.page_error = None;
+        // Start streaming (needs &mut self, must be synchronous)
+        let started = match Self::stream_source(page).and_then(|stream| stream.rows.as_ref()) {
+            Some(rows) => self.start_row_stream(page, rows),
+            None => self.start_stream(page).await,
+        };
+        if let Err(e) = started {
+            let mut error = PageError::new("Failed to start stream", e);
+            error.source = self.data_source_line(page);
+            self.page_error = Some(error);
+        }
+        self.activity = ActivityState::Idle;
+    }
 
-        // Get refresh interval
-        let refresh_interval = match &page.data {
-            DataSource::SingleOrStream(crate::config::SingleOrStream::Single(single)) => {
-                if let Some(interval_str) = &single.refresh_interval {
-                    humantime::parse_duration(interval_str).ok()
-                } else {
-                    None
+    /// Start a `rows:` stream: the table starts empty and fills as lines arrive
+    fn start_row_stream(
+        &mut self,
+        page: &crate::config::Page,
+        rows: &crate::config::StreamRows,
+    ) -> Result<()> {
+        let parser = crate::data::RowParser::new(rows)?;
+        let Some(receiver) = self.open_stream(page)? else {
+            return Ok(());
+        };
+        self.row_stream = Some((receiver, parser));
+        self.current_data.clear();
+        self.update_row_keys();
+        self.apply_sort_and_filter();
+        self.selected_index = 0;
+        self.scroll_offset = 0;
+        Ok(())
+    }
+
+    /// Parse what a `rows:` stream sent into rows, replacing rows by key
+    fn check_row_stream_updates(&mut self) {
+        let Some((receiver, parser)) = &mut self.row_stream else {
+            return;
+        };
+        let limit = self
+            .ctx
+            .config
+            .pages
+            .get(&self.current_page)
+            .and_then(Self::stream_source)
+            .map_or(100, |stream| stream.buffer_size);
+        let mut rows = None;
+        let mut ended = None;
+        while let Ok(msg) = receiver.try_recv() {
+            match msg {
+                StreamMessage::Data(line) => {
+                    if let Some(row) = parser.parse(&line) {
+                        let rows = rows.get_or_insert_with(|| self.current_data.clone());
+                        parser.upsert(rows, row, limit);
+                    }
+                }
+                StreamMessage::Stderr(_) | StreamMessage::Connected => {}
+                StreamMessage::End => ended = Some(("Stream ended".to_string(), ToastKind::Info)),
+                StreamMessage::Error(err) => {
+                    ended = Some((format!("Stream error: {}", err), ToastKind::Error));
                 }
             }
-            _ => None,
-        };
+        }
+        if let Some(rows) = rows {
+            let selected_key = self.selected_row_key();
+            self.current_data = rows;
+            self.update_row_keys();
+            self.update_chart_data();
+            self.update_page_title();
+            self.apply_sort_and_filter();
+            if let Some(key) = selected_key {
+                self.select_row_by_key(&key);
+            }
+            self.needs_render = true;
+        }
+        if let Some((message, kind)) = ended {
+            self.row_stream = None;
+            self.notify(message, kind);
+            self.needs_render = true;
+        }
+    }
+
+    fn spawn_refresh_watcher(&mut self, page_name: String, page: crate::config::Page) {
+        self.stop_page_refresh();
+
+        // No auto-refresh in safe mode
+        if self.safe_mode {
+            return;
+        }
 
-        // Only spawn watcher if refresh_interval is set
-        let interval = match refresh_interval {
+        // Only spawn watcher if the page (or app) sets refresh_interval; the
+        // watcher fetches through the adapter registry like the initial load
+        let interval = match self.ctx.config.refresh_interval(&page) {
             Some(i) => i,
             None => return,
         };
+        self.refresh_interval = Some(interval);
+        if self.refresh_paused {
+            return;
+        }
 
         // Create channel for sending refresh updates
-        let (tx, rx) = mpsc::channel(10);
-        self.refresh_receiver = Some(rx);
+        let tx = self.refresh_channel();
+        self.next_refresh = Some(std::time::Instant::now() + interval);
 
         // Clone necessary data for the background task
         let nav_context = self.nav_context.clone();
         let adapter_registry = self.adapter_registry.clone();
 
-        // Spawn background task
-        tokio::spawn(async move {
-            let mut interval_timer = tokio::time::interval(interval);
+        // Spawn background task; the first refresh is one interval from now
+        let watcher = tokio::spawn(async move {
+            let start = tokio::time::Instant::now() + interval;
+            let mut interval_timer = tokio::time::interval_at(start, interval);
             interval_timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
 
             loop {
@@ -751,26 +1665,140 @@ impl App {
                     break;
                 }
 
-                // Fetch data in background
-                let data = Self::fetch_data_static(&page, &nav_context, &adapter_registry).await;
+                // Fetch data in background; a failure is reported like one of
+                // the initial load, and the watcher keeps going
+                let started = std::time::Instant::now();
+                let data = Self::fetch_page_static(&page, &nav_context, &adapter_registry).await;
+                let message = match data {
+                    Ok(PageData { rows, failed }) => RefreshMessage::Completed {
+                        page_name: page_name.clone(),
+                        data: rows,
+                        reset_selection: false,
+                        failed,
+                    },
+                    Err(e) => RefreshMessage::Error {
+                        page_name: page_name.clone(),
+                        error: e.to_string(),
+                        elapsed: started.elapsed(),
+                    },
+                };
+                if tx.send(message).await.is_err() {
+                    // Channel closed, exit background task
+                    break;
+                }
+            }
+        });
+        self.refresh_task = Some(watcher.abort_handle());
+    }
 
-                if let Ok(data) = data {
-                    // Send completion update through channel
-                    if tx
-                        .send(RefreshMessage::Completed {
-                            page_name: page_name.clone(),
-                            data,
-                            reset_selection: false,
-                        })
+    /// A new channel for the current page's background fetches. The task
+    /// feeding the old one is cancelled, killing the command it was running.
+    fn refresh_channel(&mut self) -> mpsc::Sender<RefreshMessage> {
+        if let Some(task) = self.refresh_task.take() {
+            task.abort();
+        }
+        self.next_refresh = None;
+        let (tx, rx) = mpsc::channel(10);
+        self.refresh_receiver = Some(rx);
+        tx
+    }
+
+    /// Cancel the page's background fetch and auto-refresh, e.g. when leaving it
+    fn stop_page_refresh(&mut self) {
+        if let Some(task) = self.refresh_task.take() {
+            task.abort();
+        }
+        self.refresh_receiver = None;
+        self.refresh_interval = None;
+        self.next_refresh = None;
+        for task in self.dashboard_tasks.drain(..) {
+            task.abort();
+        }
+        self.dashboard_receiver = None;
+    }
+
+    /// Fetch every panel of a dashboard, each in its own task that re-fetches
+    /// on the panel's refresh interval. Panels keep what they show until their
+    /// next fetch lands.
+    fn start_dashboard(&mut self, dashboard: &crate::config::DashboardView) {
+        for task in self.dashboard_tasks.drain(..) {
+            task.abort();
+        }
+        self.dashboard_panels.resize_with(dashboard.panels.len(), PanelState::default);
+        self.activity = ActivityState::Idle;
+
+        let (tx, rx) = mpsc::channel(dashboard.panels.len().max(1) * 2);
+        self.dashboard_receiver = Some(rx);
+        for (panel, config) in dashboard.panels.iter().enumerate() {
+            // No auto-refresh in safe mode
+            let interval = match self.safe_mode {
+                true => None,
+                false => self.ctx.config.source_refresh_interval(&config.data),
+            };
+            let source = config.data.clone();
+            let tx = tx.clone();
+            let nav_context = self.nav_context.clone();
+            let adapter_registry = self.adapter_registry.clone();
+            let task = tokio::spawn(async move {
+                loop {
+                    let result = Self::fetch_source_static(&source, &nav_context, &adapter_registry)
                         .await
-                        .is_err()
-                    {
-                        // Channel closed, exit background task
+                        .map_err(|e| e.to_string());
+                    if tx.send(PanelMessage { panel, result }).await.is_err() {
                         break;
                     }
+                    match interval {
+                        Some(interval) => tokio::time::sleep(interval).await,
+                        None => break,
+                    }
+                }
+            });
+            self.dashboard_tasks.push(task.abort_handle());
+        }
+    }
+
+    fn check_dashboard_updates(&mut self) {
+        let Some(receiver) = &mut self.dashboard_receiver else {
+            return;
+        };
+        let mut messages = Vec::new();
+        while let Ok(msg) = receiver.try_recv() {
+            messages.push(msg);
+        }
+        let Some(ConfigView::Dashboard(dashboard)) = self
+            .ctx
+            .config
+            .pages
+            .get(&self.current_page)
+            .map(|p| &p.view)
+        else {
+            return;
+        };
+
+        for PanelMessage { panel, result } in messages {
+            let (Some(state), Some(config)) =
+                (self.dashboard_panels.get_mut(panel), dashboard.panels.get(panel))
+            else {
+                continue;
+            };
+            match result {
+                Ok(rows) => {
+                    if let ConfigView::Chart(chart_view) = &config.view {
+                        let now = chrono::Utc::now().timestamp_millis() as f64 / 1000.0;
+                        let fetched = ChartData::from_rows(&self.ctx.paths, chart_view, &rows, now);
+                        match chart_view.history {
+                            Some(limit) => state.chart.append(fetched, limit),
+                            None => state.chart = fetched,
+                        }
+                    }
+                    state.rows = rows;
+                    state.error = None;
+                    state.updated = Some(chrono::Local::now());
                 }
+                Err(error) => state.error = Some(error),
             }
-        });
+            self.needs_render = true;
+        }
     }
 
     fn check_refresh_updates(&mut self) {
@@ -793,36 +1821,204 @@ impl App {
                         self.needs_render = true;
                     }
                 }
-                RefreshMessage::Completed { page_name, data, reset_selection } => {
+                RefreshMessage::Completed { page_name, data, reset_selection, failed } => {
                     // Cache the refreshed data
                     self.page_cache.insert(page_name.clone(), data.clone());
 
                     // Update data and stop loading indicator
                     if page_name == self.current_page {
-                        self.current_data = data;
+                        self.page_error = None;
+                        self.failed_sources = failed;
+                        let selected_key = self.selected_row_key();
+                        let before_data = std::mem::replace(&mut self.current_data, data);
+                        let before_keys = std::mem::take(&mut self.row_keys);
+                        self.update_row_keys();
+                        if !reset_selection {
+                            self.note_refresh_changes(before_keys, before_data);
+                        }
+                        self.update_chart_data();
+                        self.update_page_title();
+                        self.record_visit();
                         self.apply_sort_and_filter();
                         if reset_selection {
                             self.selected_index = 0;
                             self.scroll_offset = 0;
+                        } else if let Some(key) = selected_key {
+                            // Keep the cursor on the same row even if rows moved
+                            self.select_row_by_key(&key);
+                        }
+                        if matches!(self.activity, ActivityState::Loading { .. }) {
+                            self.activity = ActivityState::Idle;
                         }
-                        self.activity = ActivityState::Idle;
                         self.last_refresh = std::time::Instant::now();
                         self.needs_render = true;
+                        self.check_alerts();
+                        let count = serde_json::json!(self.current_data.len());
+                        self.run_hooks(HookEvent::Load, vec![("count", count)]);
 
                         // Spawn/restart refresh watcher if page has refresh_interval
-                        if let Some(page_config) = globals::config().pages.get(&self.current_page).cloned() {
+                        if let Some(page_config) =
+                            self.ctx.config.pages.get(&self.current_page).cloned()
+                        {
                             self.spawn_refresh_watcher(self.current_page.clone(), page_config);
                         }
                     }
                 }
-                RefreshMessage::Error { page_name, error } => {
+                RefreshMessage::Error { page_name, error, elapsed } => {
                     if page_name == self.current_page {
-                        self.error_message = Some(format!("Failed to load data: {}", error));
+                        let message = Value::String(error.clone());
+                        self.run_hooks(HookEvent::Error, vec![("error", message)]);
+                        let mut error = PageError::new("Failed to load data", error);
+                        error.elapsed = Some(elapsed);
+                        error.source = (self.ctx.config.pages.get(&self.current_page))
+                            .and_then(|page| self.data_source_line(page));
+                        self.page_error = Some(error);
                         self.activity = ActivityState::Idle;
                         self.needs_render = true;
+
+                        // Count down to the next try as after a successful refresh
+                        if let Some(page_config) =
+                            self.ctx.config.pages.get(&self.current_page).cloned()
+                        {
+                            self.spawn_refresh_watcher(self.current_page.clone(), page_config);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Evaluate the current page's alerts against its fresh data and announce
+    /// the ones that started firing
+    fn check_alerts(&mut self) {
+        let Some(page) = self.ctx.config.pages.get(&self.current_page) else {
+            return;
+        };
+        if page.alerts.is_empty() {
+            return;
+        }
+
+        let events = self.alerts.evaluate(
+            &self.ctx.template_engine,
+            &self.current_page,
+            &page.alerts,
+            &self.current_data,
+            &self.create_template_context(None),
+        );
+        let firing: Vec<&str> = events
+            .iter()
+            .filter(|e| e.state == crate::alert::AlertState::Firing)
+            .map(|e| e.message.as_str())
+            .collect();
+        let message = match firing.as_slice() {
+            [] => return,
+            [one] => one.to_string(),
+            [first, rest @ ..] => format!("{} (+{} more)", first, rest.len()),
+        };
+        self.notify(message, ToastKind::Warning);
+    }
+
+    /// Run the current page's hooks for `event`, with the selected row and
+    /// `vars` available to their templates
+    fn run_hooks(&mut self, event: HookEvent, vars: Vec<(&str, Value)>) {
+        let ctx = self.ctx.clone();
+        let Some(page) = ctx.config.pages.get(&self.current_page) else {
+            return;
+        };
+        let hooks = event.hooks(&page.hooks);
+        if hooks.is_empty() {
+            return;
+        }
+        let mut template_ctx = self.create_template_context(self.get_selected_row());
+        for (name, value) in vars {
+            template_ctx.add_page_context(name.to_string(), value);
+        }
+        let render = |template: &str| {
+            (ctx.template_engine.render_string(template, &template_ctx)).map_err(|e| e.to_string())
+        };
+
+        // Straight to the toasts: notify() would end a refresh's loading state
+        let warn = |what: &str, e: String| format!("Hook {} {}: {}", event.name(), what, e);
+        let mut warnings = Vec::new();
+        for hook in hooks {
+            if let Some(assignment) = &hook.set
+                && let Err(e) = render(assignment)
+                    .and_then(|a| crate::config::assign_value(&mut self.nav_context.globals, &a))
+            {
+                warnings.push(warn("set failed", e));
+            }
+            if let Some(message) = &hook.notify {
+                match render(message) {
+                    Ok(message) => self.toasts.push(message.trim(), ToastKind::Info),
+                    Err(e) => warnings.push(warn("notify failed", e)),
+                }
+            }
+            if let Some(command) = &hook.command {
+                match render(command) {
+                    Ok(command) => {
+                        let failures = self.hook_failures.0.clone();
+                        let page = self.current_page.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = crate::hook::run_command(&command, &page, event).await {
+                                let message =
+                                    format!("Hook {} command failed: {}", event.name(), e);
+                                let _ = failures.send(message);
+                            }
+                        });
                     }
+                    Err(e) => warnings.push(warn("command failed", e)),
+                }
+            }
+        }
+        for warning in warnings {
+            self.toasts.push(warning, ToastKind::Warning);
+        }
+        self.needs_render = true;
+    }
+
+    /// Announce hook commands that failed in the background
+    fn check_hook_failures(&mut self) {
+        while let Ok(message) = self.hook_failures.1.try_recv() {
+            self.toasts.push(message, ToastKind::Warning);
+            self.needs_render = true;
+        }
+    }
+
+    /// Announce how logs piped to a command in the background went
+    fn check_exports(&mut self) {
+        while let Ok((message, kind)) = self.exports.1.try_recv() {
+            self.toasts.push(message, kind);
+            self.needs_render = true;
+        }
+    }
+
+    /// Run `on_row_select` hooks once the cursor has rested on another row
+    /// for `ROW_SELECT_DEBOUNCE`
+    fn check_row_select(&mut self) {
+        let watched = (self.ctx.config.pages.get(&self.current_page))
+            .is_some_and(|page| !page.hooks.on_row_select.is_empty());
+        if !watched {
+            self.hook_row = None;
+            self.hook_row_pending = None;
+            return;
+        }
+        let Some(key) = self.selected_row_key() else {
+            return;
+        };
+        let selected = (self.current_page.clone(), key);
+        if self.hook_row.as_ref() == Some(&selected) {
+            self.hook_row_pending = None;
+            return;
+        }
+        match &self.hook_row_pending {
+            Some((pending, moved)) if *pending == selected => {
+                if moved.elapsed() >= ROW_SELECT_DEBOUNCE {
+                    self.hook_row = Some(selected);
+                    self.hook_row_pending = None;
+                    self.run_hooks(HookEvent::RowSelect, Vec::new());
                 }
             }
+            _ => self.hook_row_pending = Some((selected, std::time::Instant::now())),
         }
     }
 
@@ -832,12 +2028,36 @@ impl App {
     }
 
     async fn start_stream(&mut self, page: &crate::config::Page) -> Result<()> {
+        let Some(receiver) = self.open_stream(page)? else {
+            return Ok(());
+        };
+
+        // Update state
+        self.stream_receiver = Some(receiver);
+        self.stream_active = true;
+        self.stream_paused = false;
+        self.stream_buffer.clear();
+        self.stream_status = StreamStatus::Connected;
+        self.stream_attempt = 0;
+        self.stream_page = self.current_page.clone();
+        self.selected_index = 0;
+        self.scroll_offset = 0;
+        self.needs_clear = true; // Force full terminal clear on stream start
+
+        Ok(())
+    }
+
+    /// Spawn the page's stream command; None when the page isn't a stream
+    fn open_stream(
+        &mut self,
+        page: &crate::config::Page,
+    ) -> Result<Option<mpsc::Receiver<StreamMessage>>> {
         use crate::config::{DataSource, SingleOrStream};
         use crate::data::StreamProvider;
 
         let stream_source = match &page.data {
-            DataSource::SingleOrStream(SingleOrStream::Stream(stream)) => stream,
-            _ => return Ok(()),
+            Some(DataSource::SingleOrStream(SingleOrStream::Stream(stream))) => stream,
+            _ => return Ok(None),
         };
 
         // Only support CLI streaming for now
@@ -847,18 +2067,19 @@ impl App {
 
         // Render command and args with templates
         let ctx = self.create_template_context(None);
-        let rendered_command = globals::template_engine().render_string(command, &ctx)?;
+        let rendered_command = self.ctx.template_engine.render_string(command, &ctx)?;
         let rendered_args: Result<Vec<String>> = stream_source
             .args
             .iter()
-            .map(|arg| globals::template_engine().render_string(arg, &ctx))
+            .map(|arg| self.ctx.template_engine.render_string(arg, &ctx))
             .collect();
         let rendered_args = rendered_args?;
 
         // Create stream provider
         let mut provider = StreamProvider::new(rendered_command)
             .with_args(rendered_args)
-            .with_shell(stream_source.shell);
+            .with_shell(stream_source.shell)
+            .with_stderr(!stream_source.stderr.hide);
 
         if let Some(working_dir) = &stream_source.working_dir {
             provider = provider.with_working_dir(working_dir.clone());
@@ -868,63 +2089,258 @@ impl App {
             provider = provider.with_env(stream_source.env.clone());
         }
 
-        // Start streaming
-        let receiver = provider.start_stream()?;
+        // Source-side filters drop lines before they reach the buffer
+        let compile = |pattern: &String| {
+            Regex::new(pattern).map_err(|e| {
+                crate::error::TermStackError::DataProvider(format!(
+                    "Invalid stream filter '{}': {}",
+                    pattern, e
+                ))
+            })
+        };
+        if let Some(grep) = &stream_source.grep {
+            provider = provider.with_grep(compile(grep)?);
+        }
+        if let Some(exclude) = &stream_source.exclude {
+            provider = provider.with_exclude(compile(exclude)?);
+        }
 
-        // Update state
-        self.stream_receiver = Some(receiver);
-        self.stream_active = true;
-        self.stream_paused = false;
-        self.stream_buffer.clear();
-        self.stream_status = StreamStatus::Connected;
-        self.selected_index = 0;
-        self.scroll_offset = 0;
-        self.needs_clear = true; // Force full terminal clear on stream start
+        // Highlights are compiled once per stream rather than per line
+        self.log_highlights = match &page.view {
+            ConfigView::Logs(logs_view) => logs_view
+                .highlights
+                .iter()
+                .filter_map(|rule| {
+                    let regex = Regex::new(&rule.pattern).ok()?;
+                    Some((regex, crate::view::style::highlight_style(rule, self.theme())))
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        self.stream_buffer_time = (stream_source.buffer_time.as_deref())
+            .and_then(|time| humantime::parse_duration(time).ok());
 
-        Ok(())
+        // Start streaming
+        Ok(Some(provider.start_stream()?))
     }
 
-    fn stop_stream(&mut self) {
-        if self.stream_active {
-            self.needs_clear = true;
-        }
+    /// After a `reconnect: true` stream ends or fails, wait out an exponential
+    /// backoff (1s doubling up to 30s) before starting it again. The buffer is
+    /// kept, and the attempt count resets once the stream sends a line.
+    fn schedule_reconnect(&mut self, reason: Option<String>) {
+        self.stream_attempt += 1;
         self.stream_receiver = None;
-        self.stream_active = false;
-        self.stream_paused = false;
-        self.stream_status = StreamStatus::Stopped;
+        self.stream_status = StreamStatus::Reconnecting {
+            attempt: self.stream_attempt,
+            at: std::time::Instant::now() + Self::reconnect_delay(self.stream_attempt),
+            reason,
+        };
+        self.needs_render = true;
     }
 
-    fn check_stream_updates(&mut self) {
-        if !self.stream_active {
+    /// How long to wait before reconnect attempt `attempt` (counting from 1)
+    fn reconnect_delay(attempt: u32) -> std::time::Duration {
+        let secs = 1u64 << attempt.saturating_sub(1).min(5);
+        std::time::Duration::from_secs(secs.min(30))
+    }
+
+    /// Start a stream waiting to reconnect once its backoff has passed
+    fn check_stream_reconnect(&mut self) {
+        let StreamStatus::Reconnecting { attempt, at, .. } = &self.stream_status else {
+            return;
+        };
+        // Already started again, or the stream was stopped meanwhile
+        if !self.stream_active
+            || self.stream_receiver.is_some()
+            || std::time::Instant::now() < *at
+        {
             return;
         }
-
-        // Get buffer size limit from config
-        let page = match globals::config().pages.get(&self.current_page) {
-            Some(p) => p,
-            None => return,
+        let attempt = *attempt;
+        let Some(page) = self.ctx.config.pages.get(&self.current_page).cloned() else {
+            return;
         };
+        match self.open_stream(&page) {
+            Ok(Some(receiver)) => {
+                self.stream_receiver = Some(receiver);
+                self.stream_status = StreamStatus::Reconnecting {
+                    attempt,
+                    at: std::time::Instant::now(),
+                    reason: None,
+                };
+            }
+            Ok(None) => self.stop_stream(),
+            Err(e) => self.schedule_reconnect(Some(e.to_string())),
+        }
+        self.needs_render = true;
+    }
 
-        let buffer_size = match &page.data {
-            crate::config::DataSource::SingleOrStream(crate::config::SingleOrStream::Stream(
-                stream,
-            )) => stream.buffer_size,
-            _ => 100,
-        };
+    /// Whether the current page's stream restarts by itself when it ends
+    fn stream_reconnects(&self) -> bool {
+        self.ctx
+            .config
+            .pages
+            .get(&self.current_page)
+            .and_then(Self::stream_source)
+            .is_some_and(|stream| stream.reconnect)
+    }
 
-        // Check for new messages
-        if let Some(receiver) = &mut self.stream_receiver {
+    /// Leave the current page's stream: a `detach: true` stream keeps running in
+    /// the background with its buffer, anything else is stopped
+    fn leave_stream(&mut self) {
+        let detach = self
+            .ctx
+            .config
+            .pages
+            .get(&self.stream_page)
+            .and_then(Self::stream_source)
+            .is_some_and(|stream| stream.detach);
+        if detach && (self.stream_active || !self.stream_buffer.is_empty()) {
+            let detached = DetachedStream {
+                receiver: self.stream_receiver.take(),
+                buffer: std::mem::take(&mut self.stream_buffer),
+                status: self.stream_status.clone(),
+                highlights: std::mem::take(&mut self.log_highlights),
+                buffer_time: self.stream_buffer_time,
+            };
+            self.detached_streams.insert(self.stream_page.clone(), detached);
+        }
+        self.stop_stream();
+    }
+
+    /// Show a detached stream again, with the lines it buffered meanwhile
+    fn resume_stream(&mut self, detached: DetachedStream) {
+        let reconnecting = matches!(detached.status, StreamStatus::Reconnecting { .. });
+        self.stream_active = detached.receiver.is_some() || reconnecting;
+        self.stream_receiver = detached.receiver;
+        self.stream_buffer = detached.buffer;
+        self.stream_status = detached.status;
+        self.log_highlights = detached.highlights;
+        self.stream_buffer_time = detached.buffer_time;
+        self.stream_paused = false;
+        self.stream_frozen_snapshot = None;
+        self.stream_page = self.current_page.clone();
+        // A `reconnect: true` stream that ended meanwhile starts its backoff now
+        if !self.stream_active && self.stream_reconnects() {
+            self.stream_active = true;
+            self.schedule_reconnect(None);
+        }
+        let last = self.stream_buffer.len().saturating_sub(1);
+        self.selected_index = if self.logs_follow { last } else { self.selected_index.min(last) };
+        self.activity = ActivityState::Idle;
+        self.needs_clear = true;
+        self.needs_render = true;
+    }
+
+    /// Keep detached streams' buffers filling (and their commands from blocking
+    /// on a full pipe) while other pages are shown
+    fn check_detached_streams(&mut self) {
+        let ctx = self.ctx.clone();
+        let config = &ctx.config;
+        for (page_id, detached) in &mut self.detached_streams {
+            let (Some(receiver), Some(page)) = (&mut detached.receiver, config.pages.get(page_id))
+            else {
+                continue;
+            };
+            let buffer_size = Self::stream_source(page).map_or(100, |stream| stream.buffer_size);
+            let mut ended = false;
             while let Ok(msg) = receiver.try_recv() {
+                let is_stderr = matches!(msg, StreamMessage::Stderr(_));
                 match msg {
-                    StreamMessage::Connected => {
-                        self.stream_status = StreamStatus::Streaming;
-                        self.needs_render = true;
+                    StreamMessage::Data(line) | StreamMessage::Stderr(line) => {
+                        detached.status = StreamStatus::Streaming;
+                        detached.buffer.push_back(Self::stream_log_line(
+                            page,
+                            &line,
+                            is_stderr,
+                            &detached.highlights,
+                            ctx.themes.page(page_id),
+                        ));
+                        while detached.buffer.len() > buffer_size {
+                            detached.buffer.pop_front();
+                        }
                     }
-                    StreamMessage::Data(line) => {
-                        self.stream_status = StreamStatus::Streaming;
-
-                        // Add to buffer (parse ANSI once at insertion time)
-                        self.stream_buffer.push_back(Self::parse_and_store_line(&line));
+                    StreamMessage::Connected => {}
+                    StreamMessage::End => {
+                        detached.status = StreamStatus::Stopped;
+                        ended = true;
+                        break;
+                    }
+                    StreamMessage::Error(err) => {
+                        detached.status = StreamStatus::Error(err);
+                        ended = true;
+                        break;
+                    }
+                }
+            }
+            if ended {
+                detached.receiver = None;
+            }
+        }
+    }
+
+    /// The page's stream source, if it streams
+    fn stream_source(page: &crate::config::Page) -> Option<&crate::config::StreamDataSource> {
+        match &page.data {
+            Some(crate::config::DataSource::SingleOrStream(
+                crate::config::SingleOrStream::Stream(stream),
+            )) => Some(stream),
+            _ => None,
+        }
+    }
+
+    fn stop_stream(&mut self) {
+        if self.stream_active {
+            self.needs_clear = true;
+        }
+        self.row_stream = None;
+        self.stream_receiver = None;
+        self.stream_active = false;
+        self.stream_paused = false;
+        self.stream_status = StreamStatus::Stopped;
+    }
+
+    fn check_stream_updates(&mut self) {
+        if !self.stream_active {
+            return;
+        }
+
+        // Get buffer size limit from config
+        let page = match self.ctx.config.pages.get(&self.current_page) {
+            Some(p) => p,
+            None => return,
+        };
+
+        let buffer_size = Self::stream_source(page).map_or(100, |stream| stream.buffer_size);
+        let buffer_time = self.stream_buffer_time;
+
+        // Check for new messages
+        let reconnect = self.stream_reconnects();
+        let mut lost = None;
+        let mut failed = None;
+        if let Some(receiver) = &mut self.stream_receiver {
+            while let Ok(msg) = receiver.try_recv() {
+                let is_stderr = matches!(msg, StreamMessage::Stderr(_));
+                match msg {
+                    StreamMessage::Connected => {
+                        // A reconnecting stream only counts as back once it sends a line
+                        if !matches!(self.stream_status, StreamStatus::Reconnecting { .. }) {
+                            self.stream_status = StreamStatus::Streaming;
+                        }
+                        self.needs_render = true;
+                    }
+                    StreamMessage::Data(line) | StreamMessage::Stderr(line) => {
+                        self.stream_status = StreamStatus::Streaming;
+                        self.stream_attempt = 0;
+
+                        // Add to buffer (parse ANSI once at insertion time)
+                        self.stream_buffer.push_back(Self::stream_log_line(
+                            page,
+                            &line,
+                            is_stderr,
+                            &self.log_highlights,
+                            self.ctx.themes.page(&self.current_page),
+                        ));
 
                         // Remove oldest if buffer is full
                         while self.stream_buffer.len() > buffer_size {
@@ -937,11 +2353,24 @@ impl App {
                             if self.logs_follow {
                                 self.selected_index = self.stream_buffer.len().saturating_sub(1);
                             }
-                            self.needs_render = true;
+                            // With buffer_time, lines arriving within the window share a render
+                            if buffer_time.is_none() {
+                                self.needs_render = true;
+                            } else if self.stream_unrendered_since.is_none() {
+                                self.stream_unrendered_since = Some(std::time::Instant::now());
+                            }
                         }
                         // When paused: buffer is updated but NO render triggered
                         // View stays frozen on the same content
                     }
+                    StreamMessage::End if reconnect => {
+                        lost = Some(None);
+                        break;
+                    }
+                    StreamMessage::Error(err) if reconnect => {
+                        lost = Some(Some(err));
+                        break;
+                    }
                     StreamMessage::End => {
                         self.stream_status = StreamStatus::Stopped;
                         self.stream_active = false;
@@ -950,12 +2379,26 @@ impl App {
                     StreamMessage::Error(err) => {
                         self.stream_status = StreamStatus::Error(err.clone());
                         self.stream_active = false;
-                        self.error_message = Some(format!("Stream error: {}", err));
+                        failed = Some(err);
                         self.needs_render = true;
                     }
                 }
             }
         }
+        if let Some(err) = failed {
+            let mut error = PageError::new("Stream error", err);
+            error.source = self.data_source_line(page);
+            self.page_error = Some(error);
+        }
+        if let Some(reason) = lost {
+            self.schedule_reconnect(reason);
+        }
+        if let Some(since) = self.stream_unrendered_since
+            && buffer_time.is_none_or(|window| since.elapsed() >= window)
+        {
+            self.stream_unrendered_since = None;
+            self.needs_render = true;
+        }
     }
 
     fn create_template_context(&self, current_row: Option<&Value>) -> TemplateContext {
@@ -975,47 +2418,234 @@ impl App {
     }
 
     // Static version of fetch_page_data for background tasks
-    async fn fetch_data_static(
+    pub(crate) async fn fetch_data_static(
         page: &crate::config::Page,
         nav_context: &NavigationContext,
         adapter_registry: &crate::adapters::registry::AdapterRegistry,
     ) -> Result<Vec<Value>> {
-        use crate::config::DataSource;
+        Ok(Self::fetch_page_static(page, nav_context, adapter_registry)
+            .await?
+            .rows)
+    }
 
-        let data_source = &page.data;
+    /// Shut down the adapters, closing pooled connections
+    pub(crate) async fn shutdown(&self) {
+        self.adapter_registry.shutdown().await;
+    }
 
-        match data_source {
-            DataSource::SingleOrStream(crate::config::SingleOrStream::Single(single)) => {
-                // Create data context for template rendering
-                let data_context = crate::data::provider::DataContext {
-                    globals: nav_context.globals.clone(),
-                    page_contexts: nav_context.page_contexts.clone(),
-                };
+    /// Load the current page and wait until its data (each panel's, on a
+    /// dashboard) has arrived or failed, without a terminal (`--print`)
+    pub(crate) async fn load_headless(&mut self) {
+        self.load_current_page().await;
+        self.settle().await;
+    }
 
-                // Fetch data using adapter registry
-                let result = adapter_registry
-                    .fetch(single, &data_context)
-                    .await
-                    .map_err(|e| crate::error::TermStackError::DataProvider(e.to_string()))?;
+    /// Wait for the page's background load (and a running action) to land
+    pub(crate) async fn settle(&mut self) {
+        loop {
+            self.tick().await;
+            let dashboard = self
+                .ctx
+                .config
+                .pages
+                .get(&self.current_page)
+                .is_some_and(|p| matches!(p.view, ConfigView::Dashboard(_)));
+            let panels_loading = dashboard
+                && self
+                    .dashboard_panels
+                    .iter()
+                    .any(|panel| panel.updated.is_none() && panel.error.is_none());
+            if !self.activity.is_loading()
+                && !panels_loading
+                && self.action_result_receiver.is_none()
+            {
+                return;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+    }
 
-                // Extract items using JSONPath
-                let items = if let Some(items_path) = &single.items {
-                    let extractor = JsonPathExtractor::new(items_path)?;
-                    extractor.extract(&result)?
-                } else {
-                    vec![result]
-                };
+    /// Draw one frame on `terminal` as the TUI would, e.g. on a `TestBackend`
+    pub(crate) fn draw<B: ratatui::backend::Backend>(
+        &mut self,
+        terminal: &mut ratatui::Terminal<B>,
+    ) -> Result<()> {
+        self.table_state.select(Some(self.selected_index));
+        self.last_hyperlinks = std::mem::take(&mut self.hyperlinks);
+        terminal.draw(|frame| self.render(frame))?;
+        self.needs_render = false;
+        Ok(())
+    }
+
+    /// Draw the current page once on `terminal`, e.g. a `TestBackend`, as
+    /// `--print` shows it: without the status bar or a selected row
+    pub(crate) fn draw_print<B: ratatui::backend::Backend>(
+        &mut self,
+        terminal: &mut ratatui::Terminal<B>,
+    ) -> Result<()> {
+        self.table_state.select(None);
+        terminal.draw(|frame| self.render_print(frame))?;
+        Ok(())
+    }
+
+    /// Why the current page has nothing to show, if it failed
+    pub(crate) fn page_error(&self) -> Option<String> {
+        self.page_error
+            .as_ref()
+            .map(|error| format!("{}: {}", error.title, error.message))
+    }
+
+    /// Lines `--print` needs for every row of the current table page, so it
+    /// isn't cut short. None for other pages.
+    pub(crate) fn full_height(&self) -> Option<u16> {
+        let page = self.ctx.config.pages.get(&self.current_page)?;
+        let ConfigView::Table(table_view) = &page.view else {
+            return None;
+        };
+        if self.page_error.is_some() {
+            return None;
+        }
+        // Breadcrumb, content borders and the table header
+        let mut chrome = 3 + 3;
+        if !self.failed_sources.is_empty() {
+            chrome += 1;
+        }
+        if table_view.columns.iter().any(|c| c.aggregate.is_some()) {
+            chrome += 1;
+        }
+        u16::try_from(self.filtered_indices.len() + chrome).ok()
+    }
+
+    /// Fetch the current page's rows once, without the TUI (`termstack fetch`).
+    /// Stream, dashboard and form pages have no rows to fetch this way.
+    pub(crate) async fn fetch_current_page(&self) -> Result<PageData> {
+        let page = self.router.resolve(&self.current_page, &self.nav_context)?;
+        if matches!(page.view, ConfigView::Dashboard(_) | ConfigView::Form(_))
+            || matches!(
+                page.data,
+                Some(crate::config::DataSource::SingleOrStream(
+                    crate::config::SingleOrStream::Stream(_)
+                )) | None
+            )
+        {
+            return Err(crate::error::TermStackError::Navigation(format!(
+                "Page '{}' is a stream, dashboard or form page",
+                self.current_page
+            )));
+        }
+        Self::fetch_page_static(page, &self.nav_context, &self.adapter_registry).await
+    }
+
+    /// A page's rows, along with the sources of a multi-source page that
+    /// failed while others loaded
+    pub(crate) async fn fetch_page_static(
+        page: &crate::config::Page,
+        nav_context: &NavigationContext,
+        adapter_registry: &crate::adapters::registry::AdapterRegistry,
+    ) -> Result<PageData> {
+        use crate::config::DataSource;
 
-                Ok(items)
+        let mut data = match &page.data {
+            Some(DataSource::SingleOrStream(crate::config::SingleOrStream::Single(single))) => {
+                PageData {
+                    rows: Self::fetch_source_static(single, nav_context, adapter_registry).await?,
+                    failed: Vec::new(),
+                }
             }
-            DataSource::Multi(_) => Err(crate::error::TermStackError::DataProvider(
-                "Multi-source not yet implemented".to_string(),
-            )),
-            DataSource::SingleOrStream(crate::config::SingleOrStream::Stream(_)) => Ok(Vec::new()),
+            Some(DataSource::Multi(multi)) => {
+                Self::fetch_multi_static(multi, nav_context, adapter_registry).await?
+            }
+            Some(DataSource::SingleOrStream(crate::config::SingleOrStream::Stream(_))) | None => {
+                PageData {
+                    rows: Vec::new(),
+                    failed: Vec::new(),
+                }
+            }
+        };
+
+        // Lua `on_data` has the last word on the rows, on every load and refresh
+        if let Some(code) = &page.lua.on_data {
+            data.rows = crate::lua::on_data(code, data.rows, &nav_context.globals).map_err(|e| {
+                crate::error::TermStackError::DataProvider(format!("Lua on_data failed: {}", e))
+            })?;
+        }
+        Ok(data)
+    }
+
+    /// Fetch each source of a multi-source page in turn. With `merge` the rows
+    /// are every source's items in order, else one row with each source's
+    /// items under its id. Optional sources may fail quietly; a required one
+    /// failing is reported alongside whatever did load, and only fails the
+    /// page when nothing did.
+    async fn fetch_multi_static(
+        multi: &crate::config::MultiDataSource,
+        nav_context: &NavigationContext,
+        adapter_registry: &crate::adapters::registry::AdapterRegistry,
+    ) -> Result<PageData> {
+        let mut loaded = Vec::new();
+        let mut failed = Vec::new();
+        for named in &multi.sources {
+            match Self::fetch_source_static(&named.source, nav_context, adapter_registry).await {
+                Ok(items) => loaded.push((named.id.clone(), items)),
+                Err(_) if named.optional => {}
+                Err(e) => failed.push(SourceFailure {
+                    id: named.id.clone(),
+                    error: e.to_string(),
+                }),
+            }
+        }
+
+        if loaded.is_empty()
+            && let Some(first) = failed.first()
+        {
+            return Err(crate::error::TermStackError::DataProvider(format!(
+                "Source '{}' failed: {}",
+                first.id, first.error
+            )));
         }
+
+        let rows = if multi.merge {
+            loaded.into_iter().flat_map(|(_, items)| items).collect()
+        } else {
+            let sources = loaded
+                .into_iter()
+                .map(|(id, items)| (id, Value::Array(items)))
+                .collect();
+            vec![Value::Object(sources)]
+        };
+        Ok(PageData { rows, failed })
+    }
+
+    /// Fetch one data source and extract its items (a page's, or a dashboard panel's)
+    async fn fetch_source_static(
+        single: &crate::config::SingleDataSource,
+        nav_context: &NavigationContext,
+        adapter_registry: &crate::adapters::registry::AdapterRegistry,
+    ) -> Result<Vec<Value>> {
+        // Create data context for template rendering
+        let data_context = crate::data::provider::DataContext {
+            globals: nav_context.globals.clone(),
+            page_contexts: nav_context.page_contexts.clone(),
+        };
+
+        // Fetch data using adapter registry
+        let result = adapter_registry
+            .fetch(single, &data_context)
+            .await
+            .map_err(|e| crate::error::TermStackError::DataProvider(e.to_string()))?;
+
+        // Extract items using JSONPath
+        let items = if let Some(items_path) = &single.items {
+            let extractor = JsonPathExtractor::new(items_path)?;
+            extractor.extract(&result)?
+        } else {
+            vec![result]
+        };
+
+        Ok(items)
     }
 
-    async fn handle_key(&mut self, key: KeyEvent) {
+    pub(crate) async fn handle_key(&mut self, key: KeyEvent) {
         // Handle action confirmation dialog
         if let Some(confirm) = &self.action_confirm {
             if confirm.executing {
@@ -1056,6 +2686,43 @@ impl App {
             return;
         }
 
+        // Handle row context menu
+        if self.context_menu.is_some() {
+            self.handle_context_menu_key(key).await;
+            return;
+        }
+
+        // Handle command palette
+        if self.command_palette.is_some() {
+            self.handle_palette_key(key).await;
+            return;
+        }
+
+        if self.value_popup.is_some() {
+            self.handle_value_popup_key(key);
+            return;
+        }
+
+        if self.notification_history.is_some() {
+            self.handle_notification_history_key(key);
+            return;
+        }
+
+        if self.history_picker.is_some() {
+            self.handle_history_picker_key(key).await;
+            return;
+        }
+
+        if self.bookmark_picker.is_some() {
+            self.handle_bookmark_picker_key(key).await;
+            return;
+        }
+
+        if self.page_error.is_some() && self.handle_page_error_key(key).await {
+            self.needs_render = true;
+            return;
+        }
+
         // Handle global search mode
         if self.global_search.active {
             match key.code {
@@ -1067,88 +2734,261 @@ impl App {
                 {
                     // Ctrl+C: Toggle case sensitivity
                     self.global_search.toggle_case_sensitive();
+                    self.search_edited = Some(std::time::Instant::now());
+                    self.needs_render = true;
                     return;
                 }
                 KeyCode::Char(c) => {
                     self.global_search.push_char(c);
                     self.update_search_mode();
+                    self.search_edited = Some(std::time::Instant::now());
                     self.needs_render = true;
                     return;
                 }
                 KeyCode::Backspace => {
                     self.global_search.pop_char();
                     self.update_search_mode();
+                    self.search_edited = Some(std::time::Instant::now());
                     self.needs_render = true;
                     return;
                 }
+                KeyCode::Up | KeyCode::Down => {
+                    if self.global_search.recall(key.code == KeyCode::Up) {
+                        self.update_search_mode();
+                        self.search_edited = Some(std::time::Instant::now());
+                        self.needs_render = true;
+                    }
+                    return;
+                }
                 KeyCode::Enter => {
                     // Apply the search filter
+                    self.search_edited = None;
+                    self.remember_search();
                     self.global_search.apply();
-                    // Re-filter the data for table views
-                    if !self.stream_active {
-                        self.apply_sort_and_filter();
-                        self.selected_index = 0;
-                        self.needs_render = true;
-                    } else {
-                        // For stream views, trigger render to apply filter
-                        self.selected_index = 0;
-                        self.needs_render = true;
-                    }
+                    self.refilter_for_search();
                     return;
                 }
                 KeyCode::Esc => {
                     // Cancel search and clear filter
+                    self.search_edited = None;
                     self.global_search.cancel();
-                    // Re-filter the data for table views
-                    if !self.stream_active {
-                        self.apply_sort_and_filter();
-                        self.selected_index = 0;
-                        self.needs_render = true;
-                    } else {
-                        // For stream views, trigger render to clear filter
-                        self.selected_index = 0;
-                        self.needs_render = true;
-                    }
+                    self.refilter_for_search();
                     return;
                 }
                 _ => return,
             }
         }
 
-        // Clear result notification on any key
-        if matches!(self.activity, ActivityState::Result { .. }) {
-            self.activity = ActivityState::Idle;
+        // Form pages take typed keys; Esc and Ctrl/Alt keys still go to the keymap
+        if self.form.is_some() && self.handle_form_key(key).await {
+            return;
         }
 
-        // Block action-triggering input while loading
-        if self.activity.is_loading() {
-            // Allow: q/Esc (quit), j/k/arrows (scroll), / (search), Backspace (back)
-            // Block: Ctrl+key actions, Shift+A menu, Enter (drill-down)
-            match key.code {
-                KeyCode::Char('q') | KeyCode::Esc | KeyCode::Char('j') | KeyCode::Char('k')
-                | KeyCode::Up | KeyCode::Down | KeyCode::Char('/') | KeyCode::Backspace => {
-                    // Allow these through
+        let combo = KeyCombo::from_event(&key);
+
+        // A Lua `on_key` hook sees the key before the keymap and may swallow it
+        let ctx = self.ctx.clone();
+        if let Some(code) = (ctx.config.pages.get(&self.current_page))
+            .and_then(|page| page.lua.on_key.as_deref())
+        {
+            let (row, globals) = (self.get_selected_row(), &self.nav_context.globals);
+            let verdict = crate::lua::on_key(code, &combo.display(), row, globals);
+            if !self.lua_allows("on_key", verdict) {
+                return;
+            }
+        }
+
+        // Alt+number jumps back to that page of the breadcrumb, unless bound
+        if self.pending_chord.is_none()
+            && let KeyCode::Char(c @ '1'..='9') = combo.code
+            && combo.modifiers == KeyModifiers::ALT
+            && matches!(self.match_keys(&[combo]), (None, false))
+        {
+            self.count_prefix = None;
+            self.jump_back(c.to_digit(10).unwrap_or(0) as usize).await;
+            return;
+        }
+
+        // Count prefix: unbound digits accumulate a count for the next motion
+        if self.pending_chord.is_none()
+            && let KeyCode::Char(c @ '0'..='9') = combo.code
+            && combo.modifiers.is_empty()
+            && (c != '0' || self.count_prefix.is_some())
+            && matches!(self.match_keys(&[combo]), (None, false))
+        {
+            let digit = c.to_digit(10).unwrap_or(0) as usize;
+            let count = self.count_prefix.unwrap_or(0);
+            self.count_prefix = Some((count * 10 + digit).min(MAX_COUNT));
+            self.needs_render = true;
+            return;
+        }
+
+        // Esc cancels a pending count instead of going back
+        if self.count_prefix.is_some() && key.code == KeyCode::Esc {
+            self.count_prefix = None;
+            self.needs_render = true;
+            return;
+        }
+
+        // Resolve the key, possibly as the next step of a multi-key chord. A key
+        // that breaks a chord first runs the binding for the keys typed before it.
+        let (broken, binding) = self.resolve_chord(combo);
+        if let Some(fallback) = broken {
+            self.run_binding(fallback).await;
+        }
+        if self.pending_chord.is_some() {
+            return;
+        }
+        if let Some(binding) = binding {
+            self.run_binding(binding).await;
+        } else if self.count_prefix.take().is_some() {
+            // Unbound key: drop the count
+            self.needs_render = true;
+        }
+    }
+
+    /// Add a key to the pending chord. Returns the binding once the chord is complete,
+    /// or None while more keys are expected or when nothing is bound. When the key
+    /// breaks the chord, it's resolved on its own and the binding for the keys typed
+    /// before it (if any) comes first.
+    fn resolve_chord(&mut self, combo: KeyCombo) -> (Option<KeyBinding>, Option<KeyBinding>) {
+        let (mut keys, fallback) = self
+            .pending_chord
+            .take()
+            .map(|chord| (chord.keys, chord.fallback))
+            .unwrap_or_default();
+        keys.push(combo);
+
+        let (exact, is_prefix) = self.match_keys(&keys);
+        if is_prefix {
+            self.pending_chord = Some(PendingChord {
+                keys,
+                started: std::time::Instant::now(),
+                fallback: exact,
+            });
+            self.needs_render = true;
+            return (None, None);
+        }
+
+        if keys.len() > 1 {
+            // Clear the pending chord indicator
+            self.needs_render = true;
+            if exact.is_none() {
+                // Broken chord: treat the last key on its own
+                let (_, binding) = self.resolve_chord(combo);
+                return (fallback, binding);
+            }
+        }
+        (None, exact)
+    }
+
+    /// Look up a key sequence. Returns the binding for exactly these keys and whether
+    /// they are also the start of a longer chord.
+    ///
+    /// Page actions with a direct key (Ctrl/Alt/special keys, sequences) take precedence
+    /// over built-ins and are ignored while loading.
+    fn match_keys(&self, keys: &[KeyCombo]) -> (Option<KeyBinding>, bool) {
+        let mut exact = None;
+        let mut is_prefix = false;
+
+        if !self.activity.is_loading() {
+            for action in self.page_actions() {
+                let Ok(parsed_key) = action.parse_key() else {
+                    continue;
+                };
+                if !parsed_key.is_direct() {
+                    continue;
+                }
+                let bound = parsed_key.to_keys();
+                if bound == keys {
+                    exact.get_or_insert_with(|| KeyBinding::Page(Box::new(action.clone())));
+                } else if bound.starts_with(keys) {
+                    is_prefix = true;
                 }
-                _ => return,
             }
         }
 
-        // Handle Ctrl+key combinations for direct action execution
-        if key.modifiers.contains(KeyModifiers::CONTROL) {
-            if let KeyCode::Char(c) = key.code {
-                self.handle_ctrl_action(c).await;
-                return;
+        // Go-to-page shortcuts (`keybindings.custom`) come before built-ins
+        let (page, page_prefix) = self.keymap.match_page_sequence(keys);
+        let exact = exact.or_else(|| page.map(|p| KeyBinding::Goto(p.to_string())));
+
+        let (builtin, builtin_prefix) = self.keymap.match_sequence(keys);
+        (
+            exact.or(builtin.map(KeyBinding::Builtin)),
+            is_prefix || page_prefix || builtin_prefix,
+        )
+    }
+
+    /// Run a completed binding, consuming any count prefix (motions repeat `count` times)
+    async fn run_binding(&mut self, binding: KeyBinding) {
+        let count = self.count_prefix.take();
+        if count.is_some() {
+            // Clear the pending count indicator
+            self.needs_render = true;
+        }
+
+        match binding {
+            // `5G` / `5g`: jump to row (line) 5
+            KeyBinding::Builtin(KeyAction::Top | KeyAction::Bottom) if let Some(n) = count => {
+                self.jump_to_line(n);
+            }
+            KeyBinding::Builtin(key_action) if key_action.is_motion() => {
+                for _ in 0..count.unwrap_or(1) {
+                    self.run_key_action(key_action).await;
+                }
+            }
+            KeyBinding::Page(action) => {
+                // Close action menu if it's open
+                if self.show_action_menu {
+                    self.show_action_menu = false;
+                    self.needs_render = true;
+                }
+                self.trigger_action(*action).await;
+            }
+            KeyBinding::Goto(page) => {
+                self.show_action_menu = false;
+                self.navigate_to_target(&page, &HashMap::new(), false).await;
             }
+            KeyBinding::Builtin(key_action) => self.run_key_action(key_action).await,
         }
+    }
 
-        // Normal key handling
-        match key.code {
-            KeyCode::Char('q') => {
+    async fn run_key_action(&mut self, key_action: KeyAction) {
+        // A focused split detail pane takes the movement keys
+        if self.detail_pane.focused && self.scroll_detail_pane(key_action) {
+            return;
+        }
+
+        // Block action-triggering input while loading
+        // Allow: quit, back, scrolling, search
+        // Block: action menus, drill-down, refresh
+        if self.activity.is_loading()
+            && !matches!(
+                key_action,
+                KeyAction::Quit
+                    | KeyAction::Back
+                    | KeyAction::Forward
+                    | KeyAction::Down
+                    | KeyAction::Up
+                    | KeyAction::HalfPageDown
+                    | KeyAction::HalfPageUp
+                    | KeyAction::PageDown
+                    | KeyAction::PageUp
+                    | KeyAction::Search
+            )
+        {
+            return;
+        }
+
+        let has_log_buffer = self.stream_active || !self.stream_buffer.is_empty();
+
+        match key_action {
+            KeyAction::Quit => {
                 // Always show quit confirmation
                 self.show_quit_confirm = true;
                 self.needs_render = true;
             }
-            KeyCode::Esc => {
+            KeyAction::Back => {
                 // If action menu is open, close it first
                 if self.show_action_menu {
                     self.show_action_menu = false;
@@ -1157,190 +2997,196 @@ impl App {
                 // If search filter is active, clear it first
                 else if self.global_search.filter_active {
                     self.global_search.clear();
+                    self.logs_find = false;
                     // Re-filter the data for table views
                     if !self.stream_active {
                         self.apply_sort_and_filter();
                         self.selected_index = 0;
                     }
                     self.needs_render = true;
+                } else if self.modal.is_some() {
+                    self.close_modal().await;
                 } else if !self.nav_stack.is_empty() {
                     self.go_back().await;
                 }
             }
-            KeyCode::Char('j') | KeyCode::Down => {
+            KeyAction::Down => {
                 if self.show_action_menu {
                     // Navigate action menu down
-                    let page = match globals::config().pages.get(&self.current_page) {
-                        Some(p) => p,
-                        None => return,
-                    };
-                    if let Some(actions) = &page.actions {
-                        if !actions.is_empty() {
-                            self.action_menu_selected = (self.action_menu_selected + 1) % actions.len();
-                            self.needs_render = true;
-                        }
+                    let actions = self.page_actions();
+                    if !actions.is_empty() {
+                        self.action_menu_selected = (self.action_menu_selected + 1) % actions.len();
+                        self.needs_render = true;
                     }
                 } else {
                     self.move_down();
                 }
             }
-            KeyCode::Char('k') | KeyCode::Up => {
+            KeyAction::Up => {
                 if self.show_action_menu {
                     // Navigate action menu up
-                    let page = match globals::config().pages.get(&self.current_page) {
-                        Some(p) => p,
-                        None => return,
-                    };
-                    if let Some(actions) = &page.actions {
-                        if !actions.is_empty() {
-                            if self.action_menu_selected == 0 {
-                                self.action_menu_selected = actions.len() - 1;
-                            } else {
-                                self.action_menu_selected -= 1;
-                            }
-                            self.needs_render = true;
+                    let actions = self.page_actions();
+                    if !actions.is_empty() {
+                        if self.action_menu_selected == 0 {
+                            self.action_menu_selected = actions.len() - 1;
+                        } else {
+                            self.action_menu_selected -= 1;
                         }
+                        self.needs_render = true;
                     }
                 } else {
                     self.move_up();
                 }
             }
-            KeyCode::Char('g') => {
-                self.move_top();
-            }
-            KeyCode::Char('G') => self.move_bottom(),
-            KeyCode::Char('r') => {
-                if self.stream_active {
-                    // Restart the stream
+            KeyAction::HalfPageDown => self.move_lines((self.page_height / 2).max(1), true),
+            KeyAction::HalfPageUp => self.move_lines((self.page_height / 2).max(1), false),
+            KeyAction::PageDown => self.move_lines(self.page_height, true),
+            KeyAction::PageUp => self.move_lines(self.page_height, false),
+            KeyAction::ParagraphDown => self.move_paragraph(true),
+            KeyAction::ParagraphUp => self.move_paragraph(false),
+            KeyAction::Top => self.move_top(),
+            KeyAction::Bottom => self.move_bottom(),
+            KeyAction::Refresh => {
+                let ctx = self.ctx.clone();
+                let stream_page = ctx
+                    .config
+                    .pages
+                    .get(&self.current_page)
+                    .filter(|p| {
+                        matches!(
+                            p.data,
+                            Some(crate::config::DataSource::SingleOrStream(
+                                crate::config::SingleOrStream::Stream(_)
+                            ))
+                        )
+                    });
+                if let Some(page) = stream_page {
+                    // (Re)start the stream - also the only way to start one in safe mode
                     self.stop_stream();
-                    self.load_current_page().await;
+                    self.start_page_stream(page).await;
+                    self.needs_render = true;
                 } else {
                     // Manual refresh - use background loading for animated spinner
                     self.load_current_page_background();
                 }
             }
-            KeyCode::Char('/') => {
+            KeyAction::Search => {
                 // Activate global search
                 self.global_search.activate();
+                self.logs_find = false;
                 self.needs_render = true;
             }
-            KeyCode::Char('f') => {
-                // Toggle follow in logs view (when paused, 'f' resumes LIVE mode)
-                if self.stream_active || !self.stream_buffer.is_empty() {
-                    if self.stream_paused {
-                        // Currently paused, resume to LIVE
-                        self.stream_paused = false;
-                        self.logs_follow = true;
-                        // Clear the frozen snapshot
-                        self.stream_frozen_snapshot = None;
-                        if !self.stream_buffer.is_empty() {
-                            self.selected_index = self.stream_buffer.len() - 1;
-                        }
-                        self.needs_render = true; // Force render when resuming
-                    } else {
-                        // Currently live, pause at current position
-                        self.stream_paused = true;
-                        self.logs_follow = false;
-                        // Take a snapshot of the current buffer
-                        self.stream_frozen_snapshot = Some(Arc::new(self.stream_buffer.clone()));
-                        self.needs_render = true; // Force render to update status indicator
+            KeyAction::Follow if has_log_buffer => {
+                // Toggle follow in logs view (when paused, this resumes LIVE mode)
+                if self.stream_paused {
+                    // Currently paused, resume to LIVE
+                    self.stream_paused = false;
+                    self.logs_follow = true;
+                    // Clear the frozen snapshot
+                    self.stream_frozen_snapshot = None;
+                    if !self.stream_buffer.is_empty() {
+                        self.selected_index = self.stream_buffer.len() - 1;
                     }
+                    self.needs_render = true; // Force render when resuming
+                } else {
+                    // Currently live, pause at current position
+                    self.pause_stream();
                 }
             }
-            KeyCode::Char('w') => {
+            KeyAction::Wrap if has_log_buffer => {
                 // Toggle wrap in logs view
-                if self.stream_active || !self.stream_buffer.is_empty() {
-                    self.logs_wrap = !self.logs_wrap;
-                    // Reset horizontal scroll when enabling wrap
-                    if self.logs_wrap {
-                        self.logs_horizontal_scroll = 0;
-                    }
-                    // Always render user actions, even when paused
-                    self.needs_render = true;
+                self.logs_wrap = !self.logs_wrap;
+                // Reset horizontal scroll when enabling wrap
+                if self.logs_wrap {
+                    self.logs_horizontal_scroll = 0;
                 }
+                // Always render user actions, even when paused
+                self.needs_render = true;
             }
-            KeyCode::Left => {
-                // Scroll left in logs view (when wrap is off)
-                if (self.stream_active || !self.stream_buffer.is_empty()) && !self.logs_wrap {
-                    self.logs_horizontal_scroll = self.logs_horizontal_scroll.saturating_sub(5);
-                    // Always render user actions, even when paused
-                    self.needs_render = true;
-                }
+            KeyAction::NextMatch if has_log_buffer => self.jump_to_match(true),
+            KeyAction::SaveBuffer if has_log_buffer => self.save_log_buffer(),
+            KeyAction::PrevMatch if has_log_buffer => self.jump_to_match(false),
+            KeyAction::Timestamps if has_log_buffer => {
+                self.logs_timestamps = Some(self.log_timestamps().next());
+                self.needs_render = true;
             }
-            KeyCode::Right => {
-                // Scroll right in logs view (when wrap is off)
-                if (self.stream_active || !self.stream_buffer.is_empty()) && !self.logs_wrap {
-                    self.logs_horizontal_scroll = self.logs_horizontal_scroll.saturating_add(5);
-                    // Always render user actions, even when paused
-                    self.needs_render = true;
-                }
+            KeyAction::ScrollLeft if self.current_table_view().is_some() => {
+                self.move_column_focus(false);
             }
-            KeyCode::Char('h') => {
-                if (self.stream_active || !self.stream_buffer.is_empty()) && !self.logs_wrap {
-                    // Horizontal scroll left in logs view
-                    self.logs_horizontal_scroll = self.logs_horizontal_scroll.saturating_sub(5);
-                    // Always render user actions, even when paused
-                    self.needs_render = true;
-                }
+            KeyAction::ScrollRight if self.current_table_view().is_some() => {
+                self.move_column_focus(true);
             }
-            KeyCode::Char('l') => {
-                if (self.stream_active || !self.stream_buffer.is_empty()) && !self.logs_wrap {
-                    // Horizontal scroll right in logs view
-                    self.logs_horizontal_scroll = self.logs_horizontal_scroll.saturating_add(5);
-                    // Always render user actions, even when paused
-                    self.needs_render = true;
-                }
+            KeyAction::Sort => self.cycle_sort(),
+            KeyAction::ToggleMark => self.toggle_mark(),
+            KeyAction::MarkAll => self.mark_all(),
+            KeyAction::ClearMarks => {
+                self.marked_rows.clear();
+                self.needs_render = true;
             }
-            KeyCode::Enter => {
+            KeyAction::Baseline => {
+                let name = chrono::Local::now().format("%H:%M:%S").to_string();
+                self.capture_baseline(name);
+            }
+            KeyAction::ToggleDiff => self.toggle_diff(),
+            KeyAction::ViewValue => self.open_value_popup(),
+            KeyAction::PauseRefresh => self.toggle_refresh_pause(),
+            KeyAction::Notifications => {
+                self.notification_history = Some(0);
+                self.needs_render = true;
+            }
+            KeyAction::Forward => self.go_forward().await,
+            KeyAction::PrevRow => self.open_sibling(false).await,
+            KeyAction::NextRow => self.open_sibling(true).await,
+            KeyAction::Bookmark => self.toggle_bookmark(),
+            KeyAction::Bookmarks => {
+                self.bookmark_picker = Some(0);
+                self.needs_render = true;
+            }
+            KeyAction::History => {
+                // The current page is the newest visit; start on the one before
+                self.history_picker = Some(1.min(self.visits.len().saturating_sub(1)));
+                self.needs_render = true;
+            }
+            KeyAction::SwitchFocus if self.current_split_view() => {
+                self.detail_pane.focused = !self.detail_pane.focused;
+                self.needs_render = true;
+            }
+            KeyAction::MoveColumnLeft => self.move_focused_column(false),
+            KeyAction::MoveColumnRight => self.move_focused_column(true),
+            KeyAction::ScrollLeft if has_log_buffer && !self.logs_wrap => {
+                // Scroll left in logs view (when wrap is off)
+                self.logs_horizontal_scroll = self.logs_horizontal_scroll.saturating_sub(5);
+                // Always render user actions, even when paused
+                self.needs_render = true;
+            }
+            KeyAction::ScrollRight if has_log_buffer && !self.logs_wrap => {
+                // Scroll right in logs view (when wrap is off)
+                self.logs_horizontal_scroll = self.logs_horizontal_scroll.saturating_add(5);
+                // Always render user actions, even when paused
+                self.needs_render = true;
+            }
+            KeyAction::Select => {
                 if self.show_action_menu {
                     // Execute selected action from menu
-                    let action_to_execute = {
-                        let page = match globals::config().pages.get(&self.current_page) {
-                            Some(p) => p,
-                            None => return,
-                        };
-                        page.actions.as_ref().and_then(|actions| {
-                            if self.action_menu_selected < actions.len() {
-                                Some(actions[self.action_menu_selected].clone())
-                            } else {
-                                None
-                            }
-                        })
-                    };
+                    let action_to_execute =
+                        self.page_actions().get(self.action_menu_selected).cloned();
 
                     if let Some(action) = action_to_execute {
                         self.show_action_menu = false;
                         self.needs_render = true;
-                        // Check if confirmation is needed
-                        if let Some(confirm_msg) = &action.confirm {
-                            let rendered_msg = globals::template_engine()
-                                .render_string(
-                                    confirm_msg,
-                                    &self.create_template_context(self.get_selected_row()),
-                                )
-                                .unwrap_or_else(|_| confirm_msg.clone());
-                            self.action_confirm = Some(ActionConfirm {
-                                action: action.clone(),
-                                message: rendered_msg,
-                                executing: false,
-                            });
-                        } else {
-                            self.execute_action(&action).await;
-                        }
+                        self.trigger_action(action).await;
                     }
                 } else {
                     // Normal mode: navigate to next page
                     self.navigate_next().await;
                 }
             }
-            KeyCode::Char('A') => {
-                // Shift+A: Toggle action menu (lazygit-style)
-                let page = globals::config().pages.get(&self.current_page);
-                let has_actions = page
-                    .and_then(|p| p.actions.as_ref())
-                    .map(|a| !a.is_empty())
-                    .unwrap_or(false);
-                if has_actions {
+            KeyAction::ActionMenu => {
+                // Toggle action menu (lazygit-style)
+                if self.read_only {
+                    self.notify("Read-only mode: actions are disabled", ToastKind::Info);
+                    self.needs_render = true;
+                } else if !self.page_actions().is_empty() {
                     self.show_action_menu = !self.show_action_menu;
                     if self.show_action_menu {
                         self.action_menu_selected = 0; // Reset selection when opening
@@ -1348,202 +3194,848 @@ impl App {
                     self.needs_render = true;
                 }
             }
-            KeyCode::Char(_) => {
-                // Ignore unmapped keys
+            KeyAction::OpenLink => {
+                // Open the selected row's link column
+                self.open_selected_link();
             }
-            _ => {}
+            KeyAction::RowMenu => {
+                // Row context menu: navigation targets and actions for the selected row
+                self.open_context_menu();
+            }
+            KeyAction::Expand => self.toggle_row_expansion(),
+            KeyAction::CommandPalette => {
+                self.show_action_menu = false;
+                self.command_palette = Some(CommandPalette {
+                    query: String::new(),
+                    selected: 0,
+                });
+                self.needs_render = true;
+            }
+            // Logs-only actions outside a logs view, split-only outside a split view
+            KeyAction::Follow
+            | KeyAction::Wrap
+            | KeyAction::Timestamps
+            | KeyAction::NextMatch
+            | KeyAction::PrevMatch
+            | KeyAction::SaveBuffer
+            | KeyAction::ScrollLeft
+            | KeyAction::ScrollRight
+            | KeyAction::SwitchFocus => {}
         }
     }
 
-    async fn handle_ctrl_action(&mut self, key_char: char) {
-
-        // Find matching action by Ctrl+key or fallback to simple key for backward compatibility
-        let action_to_execute = {
-            let page = match globals::config().pages.get(&self.current_page) {
-                Some(p) => p,
-                None => return,
-            };
+    /// Build the context menu entries for the selected row: every navigation target
+    /// that applies to it (matching conditions plus the default), followed by the page actions.
+    fn context_menu_entries(&self) -> Vec<ContextMenuEntry> {
+        use crate::config::Navigation;
 
-            // Look for action with matching Ctrl+key first, then try simple key
-            page.actions
-                .as_ref()
-                .and_then(|actions| {
-                    actions
-                        .iter()
-                        .find(|action| {
-                            if let Ok(parsed_key) = action.parse_key() {
-                                // Try to match with a Ctrl key event
-                                let ctrl_event = KeyEvent::new(
-                                    KeyCode::Char(key_char),
-                                    KeyModifiers::CONTROL
-                                );
-                                parsed_key.matches(&ctrl_event)
-                            } else {
-                                false
-                            }
-                        })
-                        .cloned()
-                })
+        let page = match self.ctx.config.pages.get(&self.current_page) {
+            Some(p) => p,
+            None => return Vec::new(),
         };
 
-        if let Some(action) = action_to_execute {
-            // Close action menu if it's open
-            if self.show_action_menu {
-                self.show_action_menu = false;
-                self.needs_render = true;
-            }
+        let mut entries = Vec::new();
+        let selected_row = self.get_selected_row();
 
-            // Check if confirmation is needed
-            if let Some(confirm_msg) = &action.confirm {
-                // Render confirmation message with context
-                let rendered_msg = globals::template_engine()
-                    .render_string(
-                        confirm_msg,
-                        &self.create_template_context(self.get_selected_row()),
-                    )
-                    .unwrap_or_else(|_| confirm_msg.clone());
-
-                self.action_confirm = Some(ActionConfirm {
-                    action: action.clone(),
-                    message: rendered_msg,
-                    executing: false,
-                });
-            } else {
-                // Execute immediately
-                self.execute_action(&action).await;
+        if let Some(row) = selected_row {
+            match &page.next {
+                Some(Navigation::Simple(simple)) => entries.push(ContextMenuEntry::Navigate {
+                    page: simple.page.clone(),
+                    context: simple.context.clone(),
+                    modal: simple.modal,
+                }),
+                Some(Navigation::Conditional(conditionals)) => {
+                    for cond in conditionals {
+                        let applies = cond.default
+                            || cond
+                                .condition
+                                .as_ref()
+                                .is_some_and(|c| self.condition_matches(c, row));
+                        let duplicate = entries.iter().any(|e| {
+                            matches!(e, ContextMenuEntry::Navigate { page, .. } if *page == cond.page)
+                        });
+                        if applies && !duplicate {
+                            entries.push(ContextMenuEntry::Navigate {
+                                page: cond.page.clone(),
+                                context: cond.context.clone(),
+                                modal: cond.modal,
+                            });
+                        }
+                    }
+                }
+                None => {}
             }
         }
+
+        entries.extend(
+            self.page_actions()
+                .iter()
+                .map(|a| ContextMenuEntry::Action(Box::new(a.clone()))),
+        );
+
+        entries
     }
 
-    /// Returns filtered line indices for the logs buffer when search filter is active.
-    /// Returns None if not in logs/stream mode or no filter is active.
-    fn get_logs_filtered_indices(&self) -> Option<Vec<usize>> {
-        if !self.global_search.filter_active {
-            return None;
+    fn open_context_menu(&mut self) {
+        let entries = self.context_menu_entries();
+        if entries.is_empty() {
+            return;
         }
-        if !self.stream_active && self.stream_buffer.is_empty() {
-            return None;
+        self.show_action_menu = false;
+        self.context_menu = Some(ContextMenu { entries, selected: 0 });
+        self.needs_render = true;
+    }
+
+    async fn handle_context_menu_key(&mut self, key: KeyEvent) {
+        let Some(menu) = &mut self.context_menu else {
+            return;
+        };
+        let len = menu.entries.len();
+
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                menu.selected = (menu.selected + 1) % len;
+                self.needs_render = true;
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                menu.selected = (menu.selected + len - 1) % len;
+                self.needs_render = true;
+            }
+            KeyCode::Char('g') | KeyCode::Home => {
+                menu.selected = 0;
+                self.needs_render = true;
+            }
+            KeyCode::Char('G') | KeyCode::End => {
+                menu.selected = len - 1;
+                self.needs_render = true;
+            }
+            KeyCode::Esc | KeyCode::Char('m') | KeyCode::Char('q') => {
+                self.context_menu = None;
+                self.needs_render = true;
+            }
+            KeyCode::Enter => {
+                let Some(mut menu) = self.context_menu.take() else {
+                    return;
+                };
+                self.needs_render = true;
+                match menu.entries.swap_remove(menu.selected) {
+                    ContextMenuEntry::Navigate {
+                        page,
+                        context,
+                        modal,
+                    } => {
+                        self.navigate_to_target(&page, &context, modal).await;
+                    }
+                    ContextMenuEntry::Action(action) => {
+                        self.trigger_action(*action).await;
+                    }
+                }
+            }
+            _ => {}
         }
-        let display_buffer: &VecDeque<LogLine> = if self.stream_paused {
-            if let Some(ref snapshot) = self.stream_frozen_snapshot {
-                snapshot.as_ref()
-            } else {
-                &self.stream_buffer
+    }
+
+    /// Show the focused cell of the selected row (or the selected log line) in full
+    fn open_value_popup(&mut self) {
+        let popup = if let Some(table_view) = self.current_table_view() {
+            let order = self.column_order(table_view);
+            let (Some(row), Some(col)) = (
+                self.get_selected_row(),
+                order.get(self.focused_column).and_then(|&idx| table_view.columns.get(idx)),
+            ) else {
+                return;
+            };
+            let text = match self.column_value(col, row) {
+                Some(Value::String(s)) => s,
+                Some(Value::Null) | None => String::new(),
+                Some(value) => serde_json::to_string_pretty(&value).unwrap_or_default(),
+            };
+            ValuePopup {
+                title: col.display.clone(),
+                text,
+                scroll: 0,
+            }
+        } else if let Some((line, _)) = self.log_selection_text() {
+            ValuePopup {
+                title: format!("Line {}", self.selected_index + 1),
+                text: line,
+                scroll: 0,
             }
         } else {
-            &self.stream_buffer
+            return;
         };
-        let indices: Vec<usize> = display_buffer
-            .iter()
-            .enumerate()
-            .filter(|(_, log_line)| self.global_search.matches(&log_line.raw))
-            .map(|(idx, _)| idx)
-            .collect();
-        Some(indices)
-    }
-
-    fn get_selected_row(&self) -> Option<&Value> {
-        self.filtered_indices
-            .get(self.selected_index)
-            .and_then(|&idx| self.current_data.get(idx))
+        self.value_popup = Some(popup);
+        self.needs_render = true;
     }
 
-    fn create_template_context_map(&self) -> std::collections::HashMap<String, Value> {
-        let mut context = std::collections::HashMap::new();
-
-        // Add globals
-        for (key, value) in &self.nav_context.globals {
-            context.insert(key.clone(), value.clone());
+    fn handle_notification_history_key(&mut self, key: KeyEvent) {
+        let Some(scroll) = &mut self.notification_history else {
+            return;
+        };
+        self.needs_render = true;
+        let last_line = self.toasts.history_len().saturating_sub(1) as u16;
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('M') => {
+                self.notification_history = None
+            }
+            KeyCode::Down | KeyCode::Char('j') => *scroll = (*scroll + 1).min(last_line),
+            KeyCode::Up | KeyCode::Char('k') => *scroll = scroll.saturating_sub(1),
+            KeyCode::Char('g') => *scroll = 0,
+            KeyCode::Char('G') => *scroll = last_line,
+            _ => {}
         }
+    }
 
-        // Add page contexts
-        for (page, data) in &self.nav_context.page_contexts {
-            context.insert(page.clone(), data.clone());
+    async fn handle_history_picker_key(&mut self, key: KeyEvent) {
+        let Some(selected) = &mut self.history_picker else {
+            return;
+        };
+        self.needs_render = true;
+        let last = self.visits.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('H') => self.history_picker = None,
+            KeyCode::Down | KeyCode::Char('j') => *selected = (*selected + 1).min(last),
+            KeyCode::Up | KeyCode::Char('k') => *selected = selected.saturating_sub(1),
+            KeyCode::Char('g') => *selected = 0,
+            KeyCode::Char('G') => *selected = last,
+            KeyCode::Enter => {
+                let visit = self.visits.get(*selected).cloned();
+                self.history_picker = None;
+                if let Some(visit) = visit {
+                    self.reopen(visit.page_id, visit.context, visit.opened_with).await;
+                }
+            }
+            _ => {}
         }
+    }
 
-        // Add current row data
-        if let Some(row) = self.get_selected_row() {
-            context.insert("row".to_string(), row.clone());
-            context.insert("value".to_string(), row.clone());
-
-            // Flatten current object fields
-            if let Value::Object(map) = row {
-                for (key, value) in map {
-                    context.insert(key.clone(), value.clone());
+    async fn handle_bookmark_picker_key(&mut self, key: KeyEvent) {
+        let Some(selected) = &mut self.bookmark_picker else {
+            return;
+        };
+        self.needs_render = true;
+        let last = self.bookmarks.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('\'') => self.bookmark_picker = None,
+            KeyCode::Down | KeyCode::Char('j') => *selected = (*selected + 1).min(last),
+            KeyCode::Up | KeyCode::Char('k') => *selected = selected.saturating_sub(1),
+            KeyCode::Char('g') => *selected = 0,
+            KeyCode::Char('G') => *selected = last,
+            KeyCode::Char('d') => {
+                let index = *selected;
+                if index < self.bookmarks.len() {
+                    self.bookmarks.remove(index);
+                    self.bookmark_picker = Some(index.min(self.bookmarks.len().saturating_sub(1)));
+                    self.save_bookmarks();
+                }
+            }
+            KeyCode::Enter => {
+                let bookmark = self.bookmarks.get(*selected).cloned();
+                self.bookmark_picker = None;
+                if let Some(bookmark) = bookmark {
+                    self.reopen(bookmark.page_id, bookmark.context, bookmark.opened_with).await;
                 }
             }
+            _ => {}
         }
+    }
 
-        context
+    fn handle_value_popup_key(&mut self, key: KeyEvent) {
+        let Some(popup) = &mut self.value_popup else {
+            return;
+        };
+        self.needs_render = true;
+        let last_line = popup.text.lines().count().saturating_sub(1) as u16;
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('v') => self.value_popup = None,
+            KeyCode::Down | KeyCode::Char('j') => popup.scroll = (popup.scroll + 1).min(last_line),
+            KeyCode::Up | KeyCode::Char('k') => popup.scroll = popup.scroll.saturating_sub(1),
+            KeyCode::Char('g') => popup.scroll = 0,
+            KeyCode::Char('G') => popup.scroll = last_line,
+            KeyCode::Char('y') => {
+                let (message, kind) = match crate::util::clipboard::copy(&popup.text) {
+                    Ok(()) => (
+                        format!("Copied {} characters", popup.text.chars().count()),
+                        ToastKind::Success,
+                    ),
+                    Err(e) => (format!("Copy failed: {}", e), ToastKind::Error),
+                };
+                self.notify(message, kind);
+            }
+            _ => {}
+        }
     }
 
-    /// Update protected pages in NavigationContext based on current navigation stack
-    /// Protected pages won't be evicted from the LRU cache
-    fn update_protected_pages(&mut self) {
-        // Clear existing protections
-        self.nav_context.clear_protected();
+    /// Palette entries matching the query, best match first
+    fn palette_entries(&self, query: &str) -> Vec<PaletteEntry> {
+        let ctx = self.ctx.clone();
+        let config = &ctx.config;
+        let mut entries = Vec::new();
+
+        // Pages (goto)
+        let mut page_ids: Vec<&String> = config.pages.keys().collect();
+        page_ids.sort();
+        for id in page_ids {
+            let shortcuts = self.keymap.keys_for_page(id);
+            let hint = if shortcuts.is_empty() {
+                id.clone()
+            } else {
+                format!("{}  {}", id, shortcuts.join("/"))
+            };
+            entries.push(PaletteEntry {
+                kind: "page",
+                label: format!("Go to {}", config.pages[id].title),
+                hint,
+                command: PaletteCommand::Goto(id.clone()),
+            });
+        }
 
-        // Protect all pages in the navigation stack (active navigation path)
-        for frame in self.nav_stack.frames() {
-            self.nav_context.protect_page(&frame.page_id);
+        // Actions of the current page
+        for action in self.page_actions() {
+            entries.push(PaletteEntry {
+                kind: "action",
+                label: action.description.clone().unwrap_or_else(|| action.name.clone()),
+                hint: action
+                    .parse_key()
+                    .map(|k| k.display())
+                    .unwrap_or_else(|_| action.key.clone()),
+                command: PaletteCommand::Action(Box::new(action.clone())),
+            });
         }
 
-        // Also protect the current page
-        self.nav_context.protect_page(&self.current_page);
-    }
+        // Built-in commands
+        let has_log_buffer = self.stream_active || !self.stream_buffer.is_empty();
+        for &key_action in KeyAction::ALL {
+            let applicable = match key_action {
+                // Movement and the palette itself make no sense as commands
+                KeyAction::Down
+                | KeyAction::Up
+                | KeyAction::Select
+                | KeyAction::ScrollLeft
+                | KeyAction::ScrollRight
+                | KeyAction::CommandPalette => false,
+                KeyAction::Follow
+                | KeyAction::Wrap
+                | KeyAction::Timestamps
+                | KeyAction::SaveBuffer => has_log_buffer,
+                KeyAction::NextMatch | KeyAction::PrevMatch => {
+                    has_log_buffer && self.global_search.filter_active
+                }
+                KeyAction::MoveColumnLeft | KeyAction::MoveColumnRight | KeyAction::Sort => {
+                    self.current_table_view().is_some()
+                }
+                KeyAction::ToggleMark | KeyAction::MarkAll | KeyAction::ClearMarks => {
+                    self.current_table_view().is_some_and(|t| t.multi_select)
+                }
+                KeyAction::Baseline => self.current_table_view().is_some(),
+                KeyAction::ViewValue => self.current_table_view().is_some() || has_log_buffer,
+                KeyAction::PauseRefresh => self.refresh_interval.is_some(),
+                KeyAction::SwitchFocus => self.current_split_view(),
+                KeyAction::ToggleDiff => self.baselines.contains_key(&self.current_page),
+                _ => true,
+            };
+            if applicable {
+                entries.push(PaletteEntry {
+                    kind: "cmd",
+                    label: key_action.description().to_string(),
+                    hint: self.keymap.keys_for(key_action).join("/"),
+                    command: PaletteCommand::Builtin(key_action),
+                });
+            }
+        }
 
-    async fn execute_action(&mut self, action: &crate::config::schema::Action) {
-        // Block concurrent actions
-        if self.activity.is_loading() {
-            return;
+        // `:N` jumps to a row / line
+        let jump = query
+            .trim()
+            .parse::<usize>()
+            .ok()
+            .filter(|&n| n > 0)
+            .map(|n| PaletteEntry {
+                kind: "jump",
+                label: format!("Go to line {}", n),
+                hint: String::new(),
+                command: PaletteCommand::Jump(n),
+            });
+        if let Some(jump) = jump {
+            return vec![jump];
         }
 
-        // Page navigation is instant — handle inline (no I/O)
-        if let Some(page) = &action.page
-            && !page.is_empty()
+        // `:baseline NAME` snapshots the page under a name
+        if let Some(name) = query.trim().strip_prefix("baseline ").map(str::trim)
+            && !name.is_empty()
+            && self.current_table_view().is_some()
         {
-            let page = page.clone();
-            let context_map = action.context.clone();
-            self.activity = ActivityState::Loading { message: format!("{}...", action.name) };
-            self.navigate_to_page(&page, context_map).await;
-            return;
+            return vec![PaletteEntry {
+                kind: "cmd",
+                label: format!("Snapshot as baseline '{}'", name),
+                hint: String::new(),
+                command: PaletteCommand::Baseline(name.to_string()),
+            }];
         }
 
-        // Capture template context and context map NOW (before user scrolls away)
-        let selected_row = self.get_selected_row();
-        let template_ctx = self.create_template_context(selected_row);
-        let context = self.create_template_context_map();
+        let mut scored: Vec<(i64, PaletteEntry)> = entries
+            .into_iter()
+            .filter_map(|entry| {
+                let text = format!("{} {}", entry.label, entry.hint);
+                crate::util::fuzzy::score(query, &text).map(|score| (score, entry))
+            })
+            .collect();
+        if !query.trim().is_empty() {
+            scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        }
+        scored.into_iter().map(|(_, entry)| entry).collect()
+    }
 
-        // Set up background execution state
-        self.activity = ActivityState::Loading { message: format!("Executing: {}...", action.name) };
-        self.spinner_frame = 0;
+    async fn handle_palette_key(&mut self, key: KeyEvent) {
+        let Some(count) = self
+            .command_palette
+            .as_ref()
+            .map(|p| self.palette_entries(&p.query).len())
+        else {
+            return;
+        };
+        let Some(palette) = &mut self.command_palette else {
+            return;
+        };
         self.needs_render = true;
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let last = count.saturating_sub(1);
 
-        // Store pending info for result handling
-        self.pending_action_info = Some(PendingActionInfo {
-            action: action.clone(),
-            template_ctx,
-        });
-
-        // Create channel for result
-        let (tx, rx) = mpsc::channel(1);
-        self.action_result_receiver = Some(rx);
-
-        // Clone what we need for the spawned task
-        let executor = self.action_executor.clone();
-        let action_owned = action.clone();
-
-        // Spawn background task
-        tokio::spawn(async move {
-            let result = executor.execute(&action_owned, &context).await;
-            let msg = match result {
-                Ok(action_result) => ActionResultMsg::Completed(Ok(action_result)),
-                Err(e) => ActionResultMsg::Completed(Err(e.to_string())),
+        match key.code {
+            KeyCode::Esc => self.command_palette = None,
+            KeyCode::Down | KeyCode::Tab => palette.selected = (palette.selected + 1).min(last),
+            KeyCode::Char('n') if ctrl => palette.selected = (palette.selected + 1).min(last),
+            KeyCode::Up | KeyCode::BackTab => palette.selected = palette.selected.saturating_sub(1),
+            KeyCode::Char('p') if ctrl => palette.selected = palette.selected.saturating_sub(1),
+            KeyCode::Backspace => {
+                // Backspace on an empty query closes the palette
+                if palette.query.pop().is_none() {
+                    self.command_palette = None;
+                } else {
+                    palette.selected = 0;
+                }
+            }
+            KeyCode::Char(c) if !ctrl => {
+                palette.query.push(c);
+                palette.selected = 0;
+            }
+            KeyCode::Enter => {
+                let Some(palette) = self.command_palette.take() else {
+                    return;
+                };
+                let mut entries = self.palette_entries(&palette.query);
+                if entries.is_empty() {
+                    return;
+                }
+                let entry = entries.swap_remove(palette.selected.min(entries.len() - 1));
+                match entry.command {
+                    PaletteCommand::Goto(page) => {
+                        self.navigate_to_target(&page, &HashMap::new(), false).await;
+                    }
+                    PaletteCommand::Action(action) => {
+                        if !self.activity.is_loading() {
+                            self.trigger_action(*action).await;
+                        }
+                    }
+                    PaletteCommand::Jump(n) => self.jump_to_line(n),
+                    PaletteCommand::Builtin(key_action) => self.run_key_action(key_action).await,
+                    PaletteCommand::Baseline(name) => self.capture_baseline(name),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Run an action, asking for confirmation first if the action defines a `confirm`
+    /// message. Actions on marked rows ask too when some of the rows are filtered out.
+    async fn trigger_action(&mut self, action: crate::config::schema::Action) {
+        // Actions are never offered in read-only mode; this is a backstop
+        if self.read_only {
+            return;
+        }
+        let (marked, hidden) = self.bulk_rows();
+        if !marked.is_empty()
+            && Self::is_bulk_action(&action)
+            && (action.confirm.is_some() || hidden > 0)
+        {
+            let message = match hidden {
+                0 => format!("{} {} selected rows?", action.name, marked.len()),
+                _ => format!(
+                    "{} {} selected rows ({} hidden by filter)?",
+                    action.name,
+                    marked.len(),
+                    hidden
+                ),
             };
-            let _ = tx.send(msg).await;
+            self.action_confirm = Some(ActionConfirm {
+                action,
+                message,
+                executing: false,
+            });
+        } else if let Some(confirm_msg) = &action.confirm {
+            // Render confirmation message with context
+            let rendered_msg = self
+                .ctx
+                .template_engine
+                .render_string(
+                    confirm_msg,
+                    &self.create_template_context(self.get_selected_row()),
+                )
+                .unwrap_or_else(|_| confirm_msg.clone());
+
+            self.action_confirm = Some(ActionConfirm {
+                action,
+                message: rendered_msg,
+                executing: false,
+            });
+        } else {
+            // Execute immediately
+            self.execute_action(&action).await;
+        }
+        self.needs_render = true;
+    }
+
+    /// Returns filtered line indices for the logs buffer when search filter is active.
+    /// Returns None if not in logs/stream mode or no filter is active.
+    fn get_logs_filtered_indices(&self) -> Option<Vec<usize>> {
+        // Jumping between matches shows every line
+        if self.logs_find {
+            return None;
+        }
+        self.log_matches()
+    }
+
+    /// Log lines matching the applied search, whether they filter the view or
+    /// are jumped between with `n` / `N`
+    fn log_matches(&self) -> Option<Vec<usize>> {
+        if !self.global_search.filter_active {
+            return None;
+        }
+        if !self.stream_active && self.stream_buffer.is_empty() {
+            return None;
+        }
+        let indices: Vec<usize> = self
+            .display_buffer()
+            .iter()
+            .enumerate()
+            .filter(|(_, log_line)| self.global_search.matches(&log_line.raw))
+            .map(|(idx, _)| idx)
+            .collect();
+        Some(indices)
+    }
+
+    /// Select the next (or previous) log line matching the search, wrapping
+    /// around, and show all lines around it instead of only the matches.
+    /// Follow stops so the line stays selected.
+    fn jump_to_match(&mut self, forward: bool) {
+        let Some(matches) = self.log_matches() else {
+            return;
+        };
+        let current = self.selected_index;
+        let target = match forward {
+            true => matches.iter().find(|&&idx| idx > current).or(matches.first()),
+            false => matches.iter().rev().find(|&&idx| idx < current).or(matches.last()),
+        };
+        let Some(&target) = target else {
+            return;
+        };
+        self.selected_index = target;
+        self.logs_find = true;
+        self.logs_follow = false;
+        self.needs_render = true;
+    }
+
+    /// Pause a live log stream at the current position, freezing a snapshot of the buffer
+    fn pause_stream(&mut self) {
+        self.stream_paused = true;
+        self.logs_follow = false;
+        // Take a snapshot of the current buffer
+        self.stream_frozen_snapshot = Some(Arc::new(self.stream_buffer.clone()));
+        self.needs_render = true; // Force render to update status indicator
+    }
+
+    /// Buffer currently shown in the logs view (frozen snapshot while paused)
+    fn display_buffer(&self) -> &VecDeque<LogLine> {
+        if self.stream_paused
+            && let Some(snapshot) = &self.stream_frozen_snapshot
+        {
+            snapshot.as_ref()
+        } else {
+            &self.stream_buffer
+        }
+    }
+
+    /// Selected log line and the visible (filtered) buffer text, for piping into actions.
+    /// Returns None when the current page isn't showing a stream.
+    fn log_selection_text(&self) -> Option<(String, String)> {
+        if !self.stream_active && self.stream_buffer.is_empty() {
+            return None;
+        }
+        let buffer = self.display_buffer();
+        let line = buffer
+            .get(self.selected_index)
+            .map(|log_line| log_line.raw.clone())
+            .unwrap_or_default();
+        let visible: Vec<&str> = buffer
+            .iter()
+            .filter(|log_line| self.logs_find || self.global_search.matches(&log_line.raw))
+            .map(|log_line| log_line.raw.as_str())
+            .collect();
+        Some((line, visible.join("\n")))
+    }
+
+    /// Write the logs buffer (or frozen snapshot) to the page's `save.path`, or
+    /// pipe it to `save.command`. While a search filters the view only the
+    /// matching lines are saved, unless `save.all` is set.
+    fn save_log_buffer(&mut self) {
+        let save = self
+            .ctx
+            .config
+            .pages
+            .get(&self.current_page)
+            .and_then(|page| match &page.view {
+                crate::config::View::Logs(logs) => logs.save.clone(),
+                _ => None,
+            })
+            .unwrap_or_default();
+        let filtering = self.global_search.filter_active && !self.logs_find && !save.all;
+        let lines: Vec<&str> = self
+            .display_buffer()
+            .iter()
+            .filter(|log_line| !filtering || self.global_search.matches(&log_line.raw))
+            .map(|log_line| log_line.raw.as_str())
+            .collect();
+        let count = lines.len();
+        let mut text = lines.join("\n");
+        text.push('\n');
+
+        let ctx = self
+            .create_template_context(None)
+            .with_page_context("page".to_string(), Value::String(self.current_page.clone()))
+            .with_page_context(
+                "time".to_string(),
+                Value::String(chrono::Local::now().format("%Y%m%d-%H%M%S").to_string()),
+            );
+        let engine = &self.ctx.template_engine;
+        let result = match &save.command {
+            // The command runs in the background and reports when it exits
+            Some(command) => match engine.render_string(command, &ctx) {
+                Ok(command) => {
+                    let exports = self.exports.0.clone();
+                    tokio::spawn(async move {
+                        let result = crate::util::export::pipe_to_command(&command, text).await;
+                        let _ = exports.send(match result {
+                            Ok(()) => {
+                                let message = format!("Piped {} lines to {}", count, command);
+                                (message, ToastKind::Success)
+                            }
+                            Err(e) => (format!("Save failed: {}", e), ToastKind::Error),
+                        });
+                    });
+                    self.needs_render = true;
+                    return;
+                }
+                Err(e) => Err(e.to_string()),
+            },
+            None => {
+                let template = save.path.as_deref().unwrap_or("{{ page }}-{{ time }}.log");
+                engine.render_string(template, &ctx).map_err(|e| e.to_string()).and_then(|path| {
+                    let path = crate::util::export::expand_home(path.trim());
+                    crate::util::export::write_file(&path, &text)
+                        .map(|()| format!("Saved {} lines to {}", count, path.display()))
+                        .map_err(|e| format!("{}: {}", path.display(), e))
+                })
+            }
+        };
+        let (message, kind) = match result {
+            Ok(message) => (message, ToastKind::Success),
+            Err(e) => (format!("Save failed: {}", e), ToastKind::Error),
+        };
+        self.notify(message, kind);
+        self.needs_render = true;
+    }
+
+    fn get_selected_row(&self) -> Option<&Value> {
+        self.filtered_indices
+            .get(self.selected_index)
+            .and_then(|&idx| self.current_data.get(idx))
+    }
+
+    fn create_template_context_map(&self) -> std::collections::HashMap<String, Value> {
+        self.create_template_context_map_for(self.get_selected_row())
+    }
+
+    /// Action context (globals, page contexts, flattened `row`) for a given row
+    fn create_template_context_map_for(
+        &self,
+        row: Option<&Value>,
+    ) -> std::collections::HashMap<String, Value> {
+        let mut context = std::collections::HashMap::new();
+
+        // Add globals
+        for (key, value) in &self.nav_context.globals {
+            context.insert(key.clone(), value.clone());
+        }
+
+        // Add page contexts
+        for (page, data) in &self.nav_context.page_contexts {
+            context.insert(page.clone(), data.clone());
+        }
+
+        // Add current row data
+        if let Some(row) = row {
+            context.insert("row".to_string(), row.clone());
+            context.insert("value".to_string(), row.clone());
+
+            // Flatten current object fields
+            if let Value::Object(map) = row {
+                for (key, value) in map {
+                    context.insert(key.clone(), value.clone());
+                }
+            }
+        }
+
+        // Add selected log line and visible buffer on stream pages
+        if let Some((line, buffer)) = self.log_selection_text() {
+            context.insert("line".to_string(), Value::String(line));
+            context.insert("buffer".to_string(), Value::String(buffer));
+        }
+
+        context
+    }
+
+    /// Update protected pages in NavigationContext based on current navigation stack
+    /// Make `context` what the current page was opened with, and store it.
+    /// It's protected first, so storing one key can't evict another.
+    fn set_opened_context(&mut self, context: HashMap<String, Value>) {
+        self.opened_context = context;
+        self.update_protected_pages();
+        for (key, value) in &self.opened_context {
+            self.nav_context.set_page_context(key.clone(), value.clone());
+        }
+    }
+
+    /// Protected pages won't be evicted from the LRU cache. Called on every push
+    /// and pop, before new context is stored, so storing it can't evict what
+    /// the pages on the way here were opened with.
+    fn update_protected_pages(&mut self) {
+        // Clear existing protections
+        self.nav_context.clear_protected();
+
+        // Protect all pages in the navigation stack (active navigation path):
+        // the rows stored under their IDs and the context they were opened with
+        for frame in self.nav_stack.frames() {
+            self.nav_context.protect_page(&frame.page_id);
+            for key in frame.context.keys() {
+                self.nav_context.protect_page(key);
+            }
+        }
+
+        // And the page under a modal one
+        if let Some(parent) = &self.modal {
+            self.nav_context.protect_page(&parent.page_id);
+            for key in parent.context.keys() {
+                self.nav_context.protect_page(key);
+            }
+        }
+
+        // Also protect the current page
+        self.nav_context.protect_page(&self.current_page);
+        for key in self.opened_context.keys() {
+            self.nav_context.protect_page(key);
+        }
+    }
+
+    async fn execute_action(&mut self, action: &crate::config::schema::Action) {
+        // Block concurrent actions
+        if self.activity.is_loading() {
+            return;
+        }
+
+        // Page navigation is instant — handle inline (no I/O)
+        if let Some(page) = &action.page
+            && !page.is_empty()
+        {
+            let page = page.clone();
+            let context_map = action.context.clone();
+            self.activity = ActivityState::Loading { message: format!("{}...", action.name) };
+            self.navigate_to_page(&page, context_map, action.modal).await;
+            return;
+        }
+
+        // Capture template context and context map NOW (before user scrolls away)
+        let selected_row = self.get_selected_row();
+        let mut template_ctx = self.create_template_context(selected_row);
+        if let Some((line, buffer)) = self.log_selection_text() {
+            template_ctx.add_page_context("line".to_string(), Value::String(line));
+            template_ctx.add_page_context("buffer".to_string(), Value::String(buffer));
+        }
+        // With rows marked, command and HTTP actions run once per marked row
+        let marked = if Self::is_bulk_action(action) {
+            self.bulk_rows().0
+        } else {
+            Vec::new()
+        };
+        let bulk = marked.len();
+        let contexts: Vec<_> = if marked.is_empty() {
+            vec![self.create_template_context_map()]
+        } else {
+            marked
+                .into_iter()
+                .map(|row| self.create_template_context_map_for(Some(row)))
+                .collect()
+        };
+
+        // Set up background execution state
+        self.activity = ActivityState::Loading { message: format!("Executing: {}...", action.name) };
+        self.spinner_frame = 0;
+        self.needs_render = true;
+
+        // Store pending info for result handling
+        self.pending_action_info = Some(PendingActionInfo {
+            action: action.clone(),
+            template_ctx,
+            bulk,
+        });
+
+        // Create channel for result
+        let (tx, rx) = mpsc::channel(1);
+        self.action_result_receiver = Some(rx);
+
+        // Clone what we need for the spawned task
+        let executor = self.action_executor.clone();
+        let action_owned = action.clone();
+
+        // Spawn background task
+        tokio::spawn(async move {
+            // Rows run one after another; the first failure stops the rest
+            let total = contexts.len();
+            let mut result = Err("nothing to run".to_string());
+            for (n, context) in contexts.iter().enumerate() {
+                result = match executor.execute(&action_owned, context).await {
+                    Ok(ActionResult::Error(e)) if total > 1 => Err(e),
+                    Ok(action_result) => Ok(action_result),
+                    Err(e) => Err(e.to_string()),
+                };
+                if let Err(e) = &result {
+                    if total > 1 {
+                        result = Err(format!("row {} of {}: {}", n + 1, total, e));
+                    }
+                    break;
+                }
+            }
+            let _ = tx.send(ActionResultMsg::Completed(result)).await;
         });
     }
 
+    /// Whether an action runs per marked row: commands and HTTP requests do,
+    /// navigation and built-ins act on the selected row only
+    fn is_bulk_action(action: &crate::config::schema::Action) -> bool {
+        action.page.as_deref().is_none_or(str::is_empty)
+            && action.builtin.is_none()
+            && (action.command.as_deref().is_some_and(|c| !c.is_empty()) || action.http.is_some())
+    }
+
     /// Process results from background action execution (called every event loop iteration)
     fn check_action_result(&mut self) -> Option<ActionResult> {
         let msg = {
@@ -1564,6 +4056,22 @@ impl App {
             ActionResultMsg::Completed(Ok(action_result)) => {
                 if let Some(info) = &pending {
                     self.process_action_result(&action_result, &info.action, &info.template_ctx);
+                    if !matches!(action_result, ActionResult::Error(_)) {
+                        let action = Value::String(info.action.name.clone());
+                        self.run_hooks(HookEvent::ActionSuccess, vec![("action", action)]);
+                    }
+                    // A bulk action reports the row count (its message is per row) and
+                    // clears the marks so it can't be re-applied by accident
+                    if info.bulk > 0
+                        && matches!(action_result, ActionResult::Success(_) | ActionResult::Refresh)
+                    {
+                        self.marked_rows.clear();
+                        self.notify(
+                            format!("{}: done for {} rows", info.action.name, info.bulk),
+                            ToastKind::Success,
+                        );
+                        self.needs_render = true;
+                    }
                 }
                 // Return Navigate/Refresh for async handling in event loop
                 match action_result {
@@ -1575,14 +4083,16 @@ impl App {
                 let message = if let Some(info) = &pending {
                     if let Some(notification) = &info.action.notification {
                         if let Some(custom_msg) = &notification.on_failure {
-                            globals::template_engine()
+                            self.ctx
+                                .template_engine
                                 .render_string(custom_msg, &info.template_ctx)
                                 .unwrap_or_else(|_| format!("Action failed: {}", e))
                         } else {
                             format!("Action failed: {}", e)
                         }
                     } else if let Some(error_msg) = &info.action.error_message {
-                        globals::template_engine()
+                        self.ctx
+                            .template_engine
                             .render_string(error_msg, &info.template_ctx)
                             .unwrap_or_else(|_| format!("Action failed: {}", e))
                     } else {
@@ -1592,11 +4102,7 @@ impl App {
                     format!("Action failed: {}", e)
                 };
 
-                self.activity = ActivityState::Result {
-                    message,
-                    kind: MessageType::Error,
-                    timestamp: std::time::Instant::now(),
-                };
+                self.notify(message, ToastKind::Error);
                 self.needs_render = true;
                 None
             }
@@ -1615,29 +4121,25 @@ impl App {
                 // Only show notification if explicitly configured
                 if let Some(notification) = &action.notification {
                     if let Some(custom_msg) = &notification.on_success {
-                        let message = globals::template_engine()
+                        let message = self
+                            .ctx
+                            .template_engine
                             .render_string(custom_msg, template_ctx)
                             .unwrap_or_else(|_| custom_msg.clone());
 
-                        self.activity = ActivityState::Result {
-                            message,
-                            kind: MessageType::Success,
-                            timestamp: std::time::Instant::now(),
-                        };
+                        self.notify(message, ToastKind::Success);
                         self.needs_render = true;
                     } else {
                         self.activity = ActivityState::Idle;
                     }
                 } else if let Some(success_msg) = &action.success_message {
-                    let message = globals::template_engine()
+                    let message = self
+                        .ctx
+                        .template_engine
                         .render_string(success_msg, template_ctx)
                         .unwrap_or_else(|_| success_msg.clone());
 
-                    self.activity = ActivityState::Result {
-                        message,
-                        kind: MessageType::Success,
-                        timestamp: std::time::Instant::now(),
-                    };
+                    self.notify(message, ToastKind::Success);
                     self.needs_render = true;
                 } else {
                     self.activity = ActivityState::Idle;
@@ -1646,54 +4148,48 @@ impl App {
             ActionResult::Error(msg) => {
                 let message = if let Some(notification) = &action.notification {
                     if let Some(custom_msg) = &notification.on_failure {
-                        globals::template_engine()
+                        self.ctx
+                            .template_engine
                             .render_string(custom_msg, template_ctx)
                             .unwrap_or_else(|_| custom_msg.clone())
                     } else {
                         msg.clone()
                     }
                 } else if let Some(error_msg) = &action.error_message {
-                    globals::template_engine()
+                    self.ctx
+                        .template_engine
                         .render_string(error_msg, template_ctx)
                         .unwrap_or_else(|_| error_msg.clone())
                 } else {
                     msg.clone()
                 };
 
-                self.activity = ActivityState::Result {
-                    message,
-                    kind: MessageType::Error,
-                    timestamp: std::time::Instant::now(),
-                };
+                self.notify(message, ToastKind::Error);
                 self.needs_render = true;
             }
             ActionResult::Refresh => {
                 // Show success notification if configured (reload handled by caller)
                 if let Some(notification) = &action.notification {
                     if let Some(custom_msg) = &notification.on_success {
-                        let message = globals::template_engine()
+                        let message = self
+                            .ctx
+                            .template_engine
                             .render_string(custom_msg, template_ctx)
                             .unwrap_or_else(|_| custom_msg.clone());
 
-                        self.activity = ActivityState::Result {
-                            message,
-                            kind: MessageType::Success,
-                            timestamp: std::time::Instant::now(),
-                        };
+                        self.notify(message, ToastKind::Success);
                         self.needs_render = true;
                     } else {
                         self.activity = ActivityState::Idle;
                     }
                 } else if let Some(success_msg) = &action.success_message {
-                    let message = globals::template_engine()
+                    let message = self
+                        .ctx
+                        .template_engine
                         .render_string(success_msg, template_ctx)
                         .unwrap_or_else(|_| success_msg.clone());
 
-                    self.activity = ActivityState::Result {
-                        message,
-                        kind: MessageType::Success,
-                        timestamp: std::time::Instant::now(),
-                    };
+                    self.notify(message, ToastKind::Success);
                     self.needs_render = true;
                 } else {
                     self.activity = ActivityState::Idle;
@@ -1710,22 +4206,34 @@ impl App {
         &mut self,
         target_page: &str,
         context_map: std::collections::HashMap<String, String>,
+        modal: bool,
     ) {
         // Get the current selected row
         let selected_row = self.get_selected_row().cloned();
 
+        let link = selected_row.as_ref().map(|_| RowLink {
+            page: target_page.to_string(),
+            context: context_map.clone(),
+            templates: true,
+            rows: self.filtered_indices.len(),
+        });
+
         // Render context values with template engine
         let mut rendered_context = std::collections::HashMap::new();
         if let Some(row) = &selected_row {
             let template_ctx = self.create_template_context(Some(row));
 
             for (key, template) in context_map {
-                match globals::template_engine().render_string(&template, &template_ctx) {
+                match self
+                    .ctx
+                    .template_engine
+                    .render_string(&template, &template_ctx)
+                {
                     Ok(rendered) => {
                         rendered_context.insert(key, serde_json::json!(rendered));
                     }
                     Err(e) => {
-                        self.error_message = Some(format!("Failed to render context: {}", e));
+                        self.page_error = Some(PageError::new("Failed to render context", e));
                         return;
                     }
                 }
@@ -1736,30 +4244,34 @@ impl App {
         let source_page_id = self.current_page.clone();
 
         // Save current state to navigation stack
-        let frame = NavigationFrame {
-            page_id: source_page_id.clone(),
-            context: HashMap::new(),
-            scroll_offset: self.scroll_offset,
-            selected_index: self.selected_index,
-        };
-        self.nav_stack.push(frame);
+        let mut frame = self.current_frame();
+        frame.link = link;
+        self.leave(frame, modal);
 
         // Update navigation context with new data
-        for (key, value) in rendered_context {
-            self.nav_context.page_contexts.insert(key, value);
-        }
+        self.opened_with = rendered_context.values().filter_map(context_label).collect();
+        self.opened_with.sort();
 
         // Also store the entire selected row under the current page name
         // This allows templates like "Pods - {{ namespaces.metadata.name }}" to work
         if let Some(row) = selected_row {
-            self.nav_context.set_page_context(source_page_id, row);
+            rendered_context.insert(source_page_id, row);
         }
+        self.set_opened_context(rendered_context);
 
         // Clear search when navigating to new page via action
         self.global_search.clear();
 
         // Navigate to new page
         self.current_page = target_page.to_string();
+        self.expanded_rows.clear();
+        self.marked_rows.clear();
+        self.detail_pane = DetailPane::default();
+        self.form = None;
+        self.logs_timestamps = None;
+        self.focused_column = 0;
+        self.column_offset = 0;
+        self.runtime_sort = None;
         self.selected_index = 0;
         self.scroll_offset = 0;
 
@@ -1772,7 +4284,7 @@ impl App {
 
     fn move_down(&mut self) {
         // Check if we're in a text view
-        if let Some(page) = globals::config().pages.get(&self.current_page)
+        if let Some(page) = self.ctx.config.pages.get(&self.current_page)
             && matches!(page.view, ConfigView::Text(_))
         {
             // Text view: scroll down by one line
@@ -1823,7 +4335,7 @@ impl App {
 
     fn move_up(&mut self) {
         // Check if we're in a text view
-        if let Some(page) = globals::config().pages.get(&self.current_page)
+        if let Some(page) = self.ctx.config.pages.get(&self.current_page)
             && matches!(page.view, ConfigView::Text(_))
         {
             // Text view: scroll up by one line
@@ -1850,38 +4362,155 @@ impl App {
         }
     }
 
-    fn move_top(&mut self) {
-        // Check if we're in a text view
-        if let Some(page) = globals::config().pages.get(&self.current_page)
+    /// Move several lines at once (paging), stopping at either end
+    fn move_lines(&mut self, lines: usize, down: bool) {
+        for _ in 0..lines {
+            if down {
+                self.move_down();
+            } else {
+                self.move_up();
+            }
+        }
+    }
+
+    /// Jump to row / line `n` (1-based), clamped to the data
+    fn jump_to_line(&mut self, n: usize) {
+        let target = n.saturating_sub(1);
+        self.needs_render = true;
+
+        if let Some(page) = self.ctx.config.pages.get(&self.current_page)
             && matches!(page.view, ConfigView::Text(_))
         {
-            // Text view: scroll to top
-            self.scroll_offset = 0;
-            self.needs_render = true;
+            // Text view: scroll so line n is at the top (clamped in render_text)
+            self.scroll_offset = target;
             return;
         }
 
-        // Logs view with filter: jump to first matching line
-        if let Some(filtered) = self.get_logs_filtered_indices() {
-            if let Some(&first_idx) = filtered.first() {
-                self.selected_index = first_idx;
-                self.needs_render = true;
+        if self.stream_active || !self.stream_buffer.is_empty() {
+            // Logs: stop following so the jump sticks
+            if !self.stream_paused {
+                self.pause_stream();
             }
+            let last = self.display_buffer().len().saturating_sub(1);
+            let target = target.min(last);
+            // With a filter, land on the first matching line at or after the target
+            self.selected_index = match self.get_logs_filtered_indices() {
+                Some(filtered) => filtered
+                    .iter()
+                    .find(|&&idx| idx >= target)
+                    .or(filtered.last())
+                    .copied()
+                    .unwrap_or(self.selected_index),
+                None => target,
+            };
             return;
         }
 
-        self.selected_index = 0;
-        // Always render cursor movement, even when paused
-        self.needs_render = true;
+        self.selected_index = target.min(self.filtered_indices.len().saturating_sub(1));
     }
 
-    fn move_bottom(&mut self) {
-        // Check if we're in a text view
-        if let Some(page) = globals::config().pages.get(&self.current_page)
-            && matches!(page.view, ConfigView::Text(_))
-        {
-            // Text view: scroll to bottom (will be clamped in render_text)
-            self.scroll_offset = usize::MAX;
+    /// Lines of the current text view document, as displayed (search filter applied)
+    fn text_view_lines(&self) -> Vec<String> {
+        let Some(item) = self.current_data.first() else {
+            return Vec::new();
+        };
+        let content = Self::text_content(item);
+        content
+            .lines()
+            .filter(|line| {
+                !self.global_search.filter_active
+                    || self.global_search.query.is_empty()
+                    || self.global_search.matches(line)
+            })
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Text view document for a data item: strings as-is (JSON strings re-indented),
+    /// other values pretty-printed as JSON
+    fn text_content(item: &Value) -> String {
+        if item.is_string() {
+            // Already a string - check if it's JSON and re-format for proper indentation
+            let raw = item.as_str().unwrap_or("");
+            if let Ok(json_val) = serde_json::from_str::<Value>(raw) {
+                // Re-parse and pretty-print JSON
+                serde_json::to_string_pretty(&json_val).unwrap_or_else(|_| raw.to_string())
+            } else {
+                raw.to_string()
+            }
+        } else {
+            // Convert JSON object to formatted string
+            serde_json::to_string_pretty(item).unwrap_or_else(|_| "Failed to serialize".to_string())
+        }
+    }
+
+    /// `{` / `}`: scroll a text view to the previous / next blank line
+    fn move_paragraph(&mut self, down: bool) {
+        let is_text = self
+            .ctx
+            .config
+            .pages
+            .get(&self.current_page)
+            .is_some_and(|p| matches!(p.view, ConfigView::Text(_)));
+        if !is_text {
+            return;
+        }
+
+        let lines = self.text_view_lines();
+        if lines.is_empty() {
+            return;
+        }
+        let blank = |idx: usize| lines[idx].trim().is_empty();
+        let current = self.scroll_offset.min(lines.len() - 1);
+
+        self.scroll_offset = if down {
+            // Skip the blank lines we're on, then find the next blank line
+            (current + 1..lines.len())
+                .skip_while(|&idx| blank(idx))
+                .find(|&idx| blank(idx))
+                .unwrap_or(lines.len() - 1)
+        } else {
+            (0..current)
+                .rev()
+                .skip_while(|&idx| blank(idx))
+                .find(|&idx| blank(idx))
+                .unwrap_or(0)
+        };
+        self.needs_render = true;
+    }
+
+    fn move_top(&mut self) {
+        // Check if we're in a text view
+        if let Some(page) = self.ctx.config.pages.get(&self.current_page)
+            && matches!(page.view, ConfigView::Text(_))
+        {
+            // Text view: scroll to top
+            self.scroll_offset = 0;
+            self.needs_render = true;
+            return;
+        }
+
+        // Logs view with filter: jump to first matching line
+        if let Some(filtered) = self.get_logs_filtered_indices() {
+            if let Some(&first_idx) = filtered.first() {
+                self.selected_index = first_idx;
+                self.needs_render = true;
+            }
+            return;
+        }
+
+        self.selected_index = 0;
+        // Always render cursor movement, even when paused
+        self.needs_render = true;
+    }
+
+    fn move_bottom(&mut self) {
+        // Check if we're in a text view
+        if let Some(page) = self.ctx.config.pages.get(&self.current_page)
+            && matches!(page.view, ConfigView::Text(_))
+        {
+            // Text view: scroll to bottom (will be clamped in render_text)
+            self.scroll_offset = usize::MAX;
             self.needs_render = true;
             return;
         }
@@ -1923,39 +4552,325 @@ impl App {
     }
 
     async fn go_back(&mut self) {
+        self.jump_back(self.nav_stack.len()).await;
+    }
+
+    /// Go back to the page at 1-based place `n` on the navigation stack (the
+    /// number shown before it in the breadcrumb). The pages left, the current
+    /// one included, can be gone forward to again.
+    async fn jump_back(&mut self, n: usize) {
+        self.promote_modal();
+        if n == 0 || n > self.nav_stack.len() {
+            return;
+        }
+        self.forward_stack.push(self.current_frame());
+        while self.nav_stack.len() > n {
+            if let Some(frame) = self.nav_stack.pop() {
+                self.forward_stack.push(frame);
+            }
+        }
         if let Some(frame) = self.nav_stack.pop() {
-            // Stop any active stream before navigating back
-            self.stop_stream();
+            self.restore_frame(frame).await;
+        }
+    }
 
-            // Clear search when navigating back
-            self.global_search.clear();
+    /// Open the current page for the previous or next row of the table it was
+    /// opened from (`[` / `]`), in its place
+    async fn open_sibling(&mut self, next: bool) {
+        let modal = self.modal.is_some();
+        let Some(parent) = self.modal.as_ref().or(self.nav_stack.current()) else {
+            return;
+        };
+        let Some(link) = parent.link.clone() else {
+            return;
+        };
+        let is_table = (self.ctx.config.pages.get(&parent.page_id))
+            .is_some_and(|page| page.view.table().is_some());
+        let Some(rows) = self.page_cache.get(&parent.page_id).filter(|_| is_table).cloned() else {
+            return;
+        };
+        let row = if next {
+            parent.selected_index + 1
+        } else {
+            parent.selected_index.wrapping_sub(1)
+        };
+        if row >= link.rows {
+            let edge = if next { "last" } else { "first" };
+            self.notify(format!("Already at the {} row", edge), ToastKind::Info);
+            return;
+        }
+        let Some(parent) = (if modal {
+            self.modal.take()
+        } else {
+            self.nav_stack.pop()
+        }) else {
+            return;
+        };
 
-            self.current_page = frame.page_id.clone();
-            self.selected_index = frame.selected_index;
-            self.scroll_offset = frame.scroll_offset;
+        // The table as it was left, with the row before or after selected,
+        // without fetching it again
+        self.leave_stream();
+        self.stop_page_refresh();
+        self.set_opened_context(parent.context);
+        self.opened_with = parent.opened_with;
+        self.current_page = parent.page_id;
+        self.expanded_rows.clear();
+        self.marked_rows.clear();
+        self.detail_pane = DetailPane::default();
+        self.restore_view(parent.view);
+        self.current_data = rows;
+        self.update_row_keys();
+        self.update_page_title();
+        self.apply_sort_and_filter();
+        self.selected_index = row.min(self.filtered_indices.len().saturating_sub(1));
+        self.scroll_offset = parent.scroll_offset;
+        self.update_protected_pages();
 
-            // Update protected pages in context cache (popped page is no longer protected)
-            self.update_protected_pages();
+        if link.templates {
+            self.navigate_to_page(&link.page, link.context.clone(), modal).await;
+        } else {
+            let rows = self.filtered_indices.len();
+            self.navigate_to_target(&link.page, &link.context, modal).await;
+            if let Some(frame) = self.parent_frame_mut() {
+                frame.link = Some(RowLink { rows, ..link });
+            }
+        }
+    }
 
-            // Check if we have cached data for this page
-            if let Some(cached_data) = self.page_cache.get(&frame.page_id) {
-                // Use cached data immediately for instant navigation
-                self.current_data = cached_data.clone();
-                self.apply_sort_and_filter();
-                self.activity = ActivityState::Idle;
-                self.needs_render = true;
+    /// Return to the page last gone back from
+    async fn go_forward(&mut self) {
+        let Some(frame) = self.forward_stack.pop() else {
+            return;
+        };
+        self.promote_modal();
+        let current = self.current_frame();
+        self.nav_stack.push(current);
+        self.update_protected_pages();
+        self.restore_frame(frame).await;
+    }
 
-                // Load fresh data in background with spinner
-                self.load_current_page_background();
-            } else {
-                // No cache, load with spinner
-                self.load_current_page().await;
-            }
+    /// Put the page being navigated away from on the stack, or aside while a
+    /// `modal` page is shown over it. Navigating on from a modal page turns it
+    /// into a regular one first.
+    fn leave(&mut self, frame: NavigationFrame, modal: bool) {
+        self.promote_modal();
+        if modal {
+            self.modal = Some(frame);
+        } else {
+            self.nav_stack.push(frame);
+            self.forward_stack.clear();
+        }
+        self.update_protected_pages();
+    }
+
+    /// Make the modal page shown a regular page, its parent the top of the stack
+    fn promote_modal(&mut self) {
+        if let Some(parent) = self.modal.take() {
+            self.nav_stack.push(parent);
+        }
+    }
+
+    /// Dismiss the modal page, back to the page under it as it was
+    async fn close_modal(&mut self) {
+        if let Some(parent) = self.modal.take() {
+            self.restore_frame(parent).await;
+        }
+    }
+
+    /// The page the current one was opened from: the one under a modal, else
+    /// the top of the stack
+    fn parent_frame_mut(&mut self) -> Option<&mut NavigationFrame> {
+        match &mut self.modal {
+            Some(parent) => Some(parent),
+            None => self.nav_stack.current_mut(),
+        }
+    }
+
+    /// The current page as a navigation frame, to come back to
+    fn current_frame(&self) -> NavigationFrame {
+        NavigationFrame {
+            page_id: self.current_page.clone(),
+            context: self.opened_context.clone(),
+            scroll_offset: self.scroll_offset,
+            selected_index: self.selected_index,
+            view: self.view_snapshot(),
+            title: self.rendered_page_title(),
+            opened_with: self.opened_with.clone(),
+            link: None,
+        }
+    }
+
+    /// Show the page of a frame as it was left, with the context it was opened with
+    async fn restore_frame(&mut self, frame: NavigationFrame) {
+        // Stop (or detach) any active stream or auto-refresh before navigating
+        self.leave_stream();
+        self.stop_page_refresh();
+
+        self.set_opened_context(frame.context);
+        self.current_page = frame.page_id.clone();
+        self.opened_with = frame.opened_with;
+        self.expanded_rows.clear();
+        self.marked_rows.clear();
+        self.detail_pane = DetailPane::default();
+        // Search, sort, column focus and log settings as the page was left
+        self.restore_view(frame.view);
+        self.selected_index = frame.selected_index;
+        self.scroll_offset = frame.scroll_offset;
+
+        // Update protected pages in context cache (popped page is no longer protected)
+        self.update_protected_pages();
+
+        // Check if we have cached data for this page
+        if let Some(cached_data) = self.page_cache.get(&frame.page_id) {
+            // Use cached data immediately for instant navigation
+            self.current_data = cached_data.clone();
+            self.update_row_keys();
+            self.update_page_title();
+            self.record_visit();
+            self.apply_sort_and_filter();
+            self.activity = ActivityState::Idle;
+            self.needs_render = true;
+
+            // Load fresh data in background with spinner
+            self.load_current_page_background();
+        } else {
+            // No cache, load with spinner
+            self.load_current_page().await;
+        }
+    }
+
+    /// Open a page again with the context it was opened with before (from the
+    /// history or a bookmark)
+    async fn reopen(
+        &mut self,
+        page_id: String,
+        context: HashMap<String, Value>,
+        opened_with: Vec<String>,
+    ) {
+        self.promote_modal();
+        let current = self.current_frame();
+        self.nav_stack.push(current);
+        self.forward_stack.clear();
+        self.update_protected_pages();
+
+        self.set_opened_context(context);
+        self.opened_with = opened_with;
+
+        self.global_search.clear();
+        self.current_page = page_id;
+        self.expanded_rows.clear();
+        self.marked_rows.clear();
+        self.detail_pane = DetailPane::default();
+        self.form = None;
+        self.logs_timestamps = None;
+        self.focused_column = 0;
+        self.column_offset = 0;
+        self.runtime_sort = None;
+        self.selected_index = 0;
+        self.scroll_offset = 0;
+
+        self.update_protected_pages();
+        self.load_current_page().await;
+    }
+
+    /// Bookmark the current page with the context it was opened with, or drop
+    /// its bookmark
+    fn toggle_bookmark(&mut self) {
+        let label = breadcrumb::label(&self.rendered_page_title(), &self.opened_with);
+        if let Some(index) = (self.bookmarks.iter())
+            .position(|b| b.is(&self.current_page, &self.opened_context))
+        {
+            self.bookmarks.remove(index);
+            self.notify(format!("Removed bookmark '{}'", label), ToastKind::Info);
+        } else {
+            self.bookmarks.push(Bookmark {
+                page_id: self.current_page.clone(),
+                label: label.clone(),
+                context: self.opened_context.clone(),
+                opened_with: self.opened_with.clone(),
+            });
+            self.notify(format!("Bookmarked '{}'", label), ToastKind::Success);
+        }
+        self.save_bookmarks();
+    }
+
+    /// Write the bookmarks to the state file, when configured
+    fn save_bookmarks(&mut self) {
+        if !self.ctx.config.app.save_bookmarks {
+            return;
+        }
+        let app = self.ctx.config.app.name.clone();
+        self.view_state.set_bookmarks(&app, self.bookmarks.clone());
+        if let Some(path) = &self.view_state_path
+            && let Err(e) = self.view_state.save(path)
+        {
+            self.notify(format!("Could not save bookmarks: {}", e), ToastKind::Warning);
+        }
+    }
+
+    /// Note the current page in the history (again, once its title is rendered)
+    fn record_visit(&mut self) {
+        // Modal pages are a peek, not a place visited
+        if self.modal.is_some() {
+            return;
+        }
+        let title = self.rendered_page_title();
+        self.visits.record(Visit {
+            page_id: self.current_page.clone(),
+            label: breadcrumb::label(&title, &self.opened_with),
+            context: self.opened_context.clone(),
+            opened_with: self.opened_with.clone(),
+            time: chrono::Local::now(),
+        });
+    }
+
+    /// The current page's view settings, saved with its navigation frame
+    fn view_snapshot(&self) -> ViewSnapshot {
+        ViewSnapshot {
+            search_query: self.global_search.query.clone(),
+            search_applied: self.global_search.filter_active,
+            search_case_sensitive: self.global_search.case_sensitive,
+            sort_override: self.runtime_sort.as_ref().map(|sort| match sort {
+                RuntimeSort::Unsorted => None,
+                RuntimeSort::By(sort) => Some(sort.clone()),
+            }),
+            focused_column: self.focused_column,
+            column_offset: self.column_offset,
+            logs_follow: self.logs_follow,
+            logs_wrap: self.logs_wrap,
+            logs_horizontal_scroll: self.logs_horizontal_scroll,
+            logs_timestamps: self.logs_timestamps,
+            form: self.form.clone(),
+        }
+    }
+
+    /// Put back view settings saved by [`Self::view_snapshot`]; the data is
+    /// filtered and sorted with them once it arrives
+    fn restore_view(&mut self, view: ViewSnapshot) {
+        self.global_search.clear();
+        self.global_search.case_sensitive = view.search_case_sensitive;
+        if view.search_applied {
+            self.global_search.query = view.search_query;
+            self.global_search.preview();
+            self.update_search_mode();
         }
+        self.runtime_sort = view.sort_override.map(|sort| match sort {
+            Some(sort) => RuntimeSort::By(sort),
+            None => RuntimeSort::Unsorted,
+        });
+        self.focused_column = view.focused_column;
+        self.column_offset = view.column_offset;
+        self.form = view.form;
+        self.logs_follow = view.logs_follow;
+        self.logs_wrap = view.logs_wrap;
+        self.logs_horizontal_scroll = view.logs_horizontal_scroll;
+        self.logs_timestamps = view.logs_timestamps;
+        self.logs_find = false;
     }
 
     async fn navigate_next(&mut self) {
-        let page = match globals::config().pages.get(&self.current_page) {
+        let ctx = self.ctx.clone();
+        let page = match ctx.config.pages.get(&self.current_page) {
             Some(p) => p,
             None => return,
         };
@@ -1965,9 +4880,17 @@ impl App {
             None => return,
         };
 
+        if let Some(code) = &page.lua.on_select {
+            let globals = &self.nav_context.globals;
+            let verdict = crate::lua::on_select(code, self.get_selected_row(), globals);
+            if !self.lua_allows("on_select", verdict) {
+                return;
+            }
+        }
+
         use crate::config::Navigation;
-        let (next_page, context_map) = match next_nav {
-            Navigation::Simple(simple) => (&simple.page, &simple.context),
+        let (next_page, context_map, modal) = match next_nav {
+            Navigation::Simple(simple) => (&simple.page, &simple.context, simple.modal),
             Navigation::Conditional(conditionals) => {
                 // Find first matching condition or default
                 let mut found = None;
@@ -1978,24 +4901,17 @@ impl App {
 
                 for cond in conditionals {
                     if cond.default {
-                        default_found = Some((&cond.page, &cond.context));
+                        default_found = Some((&cond.page, &cond.context, cond.modal));
                         continue;
                     }
 
                     // Evaluate condition if present
                     if let Some(condition) = &cond.condition
                         && let Some(row) = selected_row
+                        && self.condition_matches(condition, row)
                     {
-                        let ctx = self.create_template_context(Some(row));
-                        let matches = globals::template_engine()
-                            .render_string(condition, &ctx)
-                            .map(|result| result.trim() == "true")
-                            .unwrap_or(false);
-
-                        if matches {
-                            found = Some((&cond.page, &cond.context));
-                            break;
-                        }
+                        found = Some((&cond.page, &cond.context, cond.modal));
+                        break;
                     }
                 }
 
@@ -2007,32 +4923,96 @@ impl App {
             }
         };
 
+        let link = self.get_selected_row().map(|_| RowLink {
+            page: next_page.clone(),
+            context: context_map.clone(),
+            templates: false,
+            rows: self.filtered_indices.len(),
+        });
+        self.navigate_to_target(next_page, context_map, modal).await;
+        if let Some(frame) = self.parent_frame_mut() {
+            frame.link = link;
+        }
+    }
+
+    /// Whether to go on after a Lua `on_select` or `on_key` hook: a veto's
+    /// message is shown, and a hook that fails is reported without vetoing
+    fn lua_allows(
+        &mut self,
+        event: &str,
+        verdict: std::result::Result<crate::lua::Verdict, String>,
+    ) -> bool {
+        match verdict {
+            Ok(crate::lua::Verdict::Allow) => true,
+            Ok(crate::lua::Verdict::Veto(message)) => {
+                if let Some(message) = message {
+                    self.notify(message, ToastKind::Info);
+                }
+                false
+            }
+            Err(e) => {
+                self.notify(format!("Lua {} failed: {}", event, e), ToastKind::Warning);
+                true
+            }
+        }
+    }
+
+    /// Evaluate a navigation condition template against a row
+    fn condition_matches(&self, condition: &str, row: &Value) -> bool {
+        let ctx = self.create_template_context(Some(row));
+        self.ctx
+            .template_engine
+            .render_string(condition, &ctx)
+            .map(|result| result.trim() == "true")
+            .unwrap_or(false)
+    }
+
+    /// Push the current page and navigate to `next_page`, capturing `context_map`
+    /// (name -> JSONPath) from the selected row. A `modal` page is shown over the
+    /// current one, which is kept aside instead of pushed.
+    async fn navigate_to_target(
+        &mut self,
+        next_page: &str,
+        context_map: &HashMap<String, String>,
+        modal: bool,
+    ) {
         // Save current frame to navigation stack
-        let mut frame = NavigationFrame::new(self.current_page.clone());
-        frame.selected_index = self.selected_index;
-        frame.scroll_offset = self.scroll_offset;
-        self.nav_stack.push(frame);
+        let frame = self.current_frame();
+        self.leave(frame, modal);
+        self.opened_with.clear();
 
         // Capture context from selected row
+        let mut context = HashMap::new();
         if let Some(selected_row) = self.get_selected_row().cloned() {
             for (key, json_path) in context_map {
                 if let Ok(extractor) = JsonPathExtractor::new(json_path)
                     && let Ok(Some(value)) = extractor.extract_single(&selected_row)
                 {
-                    self.nav_context.set_page_context(key.clone(), value);
+                    self.opened_with.extend(context_label(&value));
+                    context.insert(key.clone(), value);
                 }
             }
 
+            self.opened_with.sort();
+
             // Also store the entire selected row under the current page name
-            self.nav_context
-                .set_page_context(self.current_page.clone(), selected_row);
+            context.insert(self.current_page.clone(), selected_row);
         }
+        self.set_opened_context(context);
 
         // Clear search when navigating to next page
         self.global_search.clear();
 
         // Navigate to next page
-        self.current_page = next_page.clone();
+        self.current_page = next_page.to_string();
+        self.expanded_rows.clear();
+        self.marked_rows.clear();
+        self.detail_pane = DetailPane::default();
+        self.form = None;
+        self.logs_timestamps = None;
+        self.focused_column = 0;
+        self.column_offset = 0;
+        self.runtime_sort = None;
 
         // Update protected pages in context cache (prevent eviction of active nav path)
         self.update_protected_pages();
@@ -2043,6 +5023,60 @@ impl App {
     fn render(&mut self, frame: &mut Frame) {
         let area = frame.area();
 
+        let toasts = if self.modal.is_some() {
+            self.render_modal(frame, area)
+        } else {
+            let toasts = self.render_page(frame, area);
+            self.last_screen = Some(frame.buffer_mut().clone());
+            toasts
+        };
+        self.toasts.render(frame, toasts, self.theme());
+
+        // Render action menu on top if active
+        if let Some(menu) = &self.context_menu {
+            self.render_context_menu(frame, area, menu);
+        }
+
+        if self.show_action_menu {
+            self.render_action_menu(frame, area);
+        }
+
+        if let Some(palette) = &self.command_palette {
+            self.render_command_palette(frame, area, palette);
+        }
+
+        if let Some(popup) = &self.value_popup {
+            self.render_value_popup(frame, area, popup);
+        }
+
+        if let Some(scroll) = self.notification_history {
+            self.render_notification_history(frame, area, scroll);
+        }
+
+        if let Some(selected) = self.history_picker {
+            self.render_history_picker(frame, area, selected);
+        }
+
+        if let Some(selected) = self.bookmark_picker {
+            self.render_bookmark_picker(frame, area, selected);
+        }
+
+        // Render action confirmation dialog on top if active
+        if let Some(confirm) = &self.action_confirm {
+            self.render_action_confirm(frame, area, confirm);
+        }
+
+        // Render quit confirmation dialog on top if active
+        if self.show_quit_confirm {
+            self.render_quit_confirm(frame, area);
+        }
+
+        self.caps.adapt(frame.buffer_mut(), self.theme().bg);
+    }
+
+    /// The current page: breadcrumb, content and status bar. Returns the area
+    /// toasts go in.
+    fn render_page(&mut self, frame: &mut Frame, area: Rect) -> Rect {
         // Dynamically adjust header size based on search state
         let header_height = if self.global_search.active {
             6 // Breadcrumb + search input
@@ -2057,24 +5091,88 @@ impl App {
         ])
         .split(area);
 
+        // A banner over the data naming the sources that failed to load
+        let (banner, content) = if self.failed_sources.is_empty() || self.page_error.is_some() {
+            (None, chunks[1])
+        } else {
+            let [banner, content] =
+                Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(chunks[1]);
+            (Some(banner), content)
+        };
+
+        // Content block borders plus the table header
+        self.page_height = (content.height.saturating_sub(3) as usize).max(1);
+
+        frame.render_widget(Block::default().style(self.theme().base()), area);
         self.render_header(frame, chunks[0]);
-        self.render_content(frame, chunks[1]);
+        if let Some(banner) = banner {
+            self.render_failed_sources(frame, banner);
+        }
+        self.render_content(frame, content);
         self.render_statusbar(frame, chunks[2]);
+        chunks[1]
+    }
 
-        // Render action menu on top if active
-        if self.show_action_menu {
-            self.render_action_menu(frame, area);
-        }
+    /// The current page for `--print`: breadcrumb and content
+    fn render_print(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+        let [header, content] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+        let (banner, content) = if self.failed_sources.is_empty() || self.page_error.is_some() {
+            (None, content)
+        } else {
+            let [banner, content] =
+                Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(content);
+            (Some(banner), content)
+        };
+        self.page_height = (content.height.saturating_sub(3) as usize).max(1);
 
-        // Render action confirmation dialog on top if active
-        if let Some(confirm) = &self.action_confirm {
-            self.render_action_confirm(frame, area, confirm);
+        frame.render_widget(Block::default().style(self.theme().base()), area);
+        self.render_header(frame, header);
+        if let Some(banner) = banner {
+            self.render_failed_sources(frame, banner);
         }
+        self.render_content(frame, content);
+        self.caps.adapt(frame.buffer_mut(), self.theme().bg);
+    }
 
-        // Render quit confirmation dialog on top if active
-        if self.show_quit_confirm {
-            self.render_quit_confirm(frame, area);
+    /// A modal page: centered over the page it was opened from, as last drawn.
+    /// Returns the area toasts go in.
+    fn render_modal(&mut self, frame: &mut Frame, area: Rect) -> Rect {
+        use ratatui::widgets::Clear;
+
+        match &self.last_screen {
+            Some(screen) if screen.area == area => {
+                let buffer = frame.buffer_mut();
+                buffer.merge(screen);
+                buffer.set_style(area, Style::default().add_modifier(Modifier::DIM));
+            }
+            _ => frame.render_widget(Block::default().style(self.theme().base()), area),
         }
+
+        let popup = Rect {
+            x: area.x + area.width / 10,
+            y: area.y + area.height / 10,
+            width: area.width - area.width / 5,
+            height: area.height - area.height / 5,
+        };
+        frame.render_widget(Clear, popup);
+        frame.render_widget(Block::default().style(self.theme().base()), popup);
+        self.page_height = (popup.height.saturating_sub(3) as usize).max(1);
+        self.render_content(frame, popup);
+
+        let hint = Line::from(Span::styled(
+            " Esc: close ",
+            Style::default().fg(self.theme().muted),
+        ));
+        let hint_area = Rect {
+            x: popup.x + 2,
+            y: popup.bottom().saturating_sub(1),
+            width: (hint.width() as u16).min(popup.width.saturating_sub(4)),
+            height: 1,
+        };
+        frame.render_widget(Paragraph::new(hint), hint_area);
+        popup
     }
 
     fn render_header(&self, frame: &mut Frame, area: Rect) {
@@ -2100,81 +5198,29 @@ impl App {
     }
 
     fn render_breadcrumb(&self, frame: &mut Frame, area: Rect) {
+        let theme = self.theme();
         use ratatui::layout::{Alignment, Constraint, Direction, Layout};
 
-        // Left side: breadcrumb navigation
-        let mut left_spans = vec![
-            Span::styled(
-                &globals::config().app.name,
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw(" | "),
-        ];
-
-        // Add pages from navigation stack (if any)
-        for (idx, nav_frame) in self.nav_stack.frames().iter().enumerate() {
-            if idx > 0 {
-                left_spans.push(Span::raw(" > "));
-            }
-            left_spans.push(Span::styled(
-                &nav_frame.page_id,
-                Style::default().fg(Color::White),
-            ));
-        }
-
-        // Add separator before current page if there are previous pages
-        if !self.nav_stack.frames().is_empty() {
-            left_spans.push(Span::raw(" > "));
-        }
-
-        // Add current page with distinct color
-        left_spans.push(Span::styled(
-            &self.current_page,
-            Style::default()
-                .fg(Color::Green)
-                .add_modifier(Modifier::BOLD),
-        ));
-
-        // Right side: unified activity indicator
-        let right_text = match &self.activity {
-            ActivityState::Loading { message } => {
-                let spinner_char = crate::ui::loading::get_spinner_char(self.spinner_frame);
-                format!(" {} {} ", spinner_char, message)
-            }
-            ActivityState::Result { message, kind, .. } => {
-                let icon = match kind {
-                    MessageType::Success => "\u{2713}",
-                    MessageType::Error => "\u{2717}",
-                    MessageType::Info => "\u{2139}",
-                    MessageType::Warning => "\u{26a0}",
-                };
-                format!(" {} {} ", icon, message)
+        // Right side: unified activity indicator
+        let right_text = match &self.activity {
+            ActivityState::Loading { message } => {
+                let spinner_char = crate::ui::loading::get_spinner_char(self.spinner_frame);
+                format!(" {} {} ", spinner_char, message)
             }
             ActivityState::Idle => String::new(),
         };
 
         // Cap right_text width to prevent overflow
         let max_right_width = 45_usize;
-        let right_text = if right_text.chars().count() > max_right_width {
-            let truncated: String = right_text.chars().take(max_right_width - 1).collect();
-            format!("{}\u{2026}", truncated)
-        } else {
-            right_text
-        };
+        let right_text = crate::util::text::truncate(&right_text, max_right_width);
 
         let right_style = match &self.activity {
-            ActivityState::Loading { .. } => Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
-            ActivityState::Result { kind: MessageType::Success, .. } => Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
-            ActivityState::Result { kind: MessageType::Error, .. } => Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
-            ActivityState::Result { kind: MessageType::Warning, .. } => Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
-            ActivityState::Result { kind: MessageType::Info, .. } => Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD),
+            ActivityState::Loading { .. } => Style::default().fg(theme.warning).add_modifier(Modifier::BOLD),
             ActivityState::Idle => Style::default(),
         };
 
         // Split the header area into left and right sections
-        let header_block = Block::default().borders(Borders::ALL);
+        let header_block = theme.block();
         let inner_area = header_block.inner(area);
 
         // Create layout for left-aligned breadcrumb and right-aligned activity
@@ -2186,223 +5232,2347 @@ impl App {
             ])
             .split(inner_area);
 
-        // Render the border block
-        frame.render_widget(header_block, area);
+        // Render the border block
+        frame.render_widget(header_block, area);
+
+        // Left side: the app name, then the pages on the stack and the current
+        // one, fitted to what the activity indicator leaves
+        let app_name = &self.ctx.config.app.name;
+        let mut left_spans = vec![
+            Span::styled(
+                app_name.as_str(),
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" | "),
+        ];
+        let frames: Vec<String> = self
+            .nav_stack
+            .frames()
+            .iter()
+            .map(|nav_frame| {
+                let title = match nav_frame.title.as_str() {
+                    "" => &nav_frame.page_id,
+                    title => title,
+                };
+                breadcrumb::label(title, &nav_frame.opened_with)
+            })
+            .collect();
+        let current = breadcrumb::label(&self.rendered_page_title(), &self.opened_with);
+        let width = (chunks[0].width as usize).saturating_sub(app_name.width() + 3);
+        for (idx, crumb) in breadcrumb::fit(&frames, &current, width).into_iter().enumerate() {
+            if idx > 0 {
+                left_spans.push(Span::raw(breadcrumb::SEPARATOR));
+            }
+            match crumb {
+                Crumb::Frame(n, title) => {
+                    left_spans.push(Span::styled(
+                        format!("{} ", n),
+                        Style::default().fg(theme.muted),
+                    ));
+                    left_spans.push(Span::styled(title, Style::default().fg(theme.fg)));
+                }
+                Crumb::Elided => {
+                    left_spans.push(Span::styled("…", Style::default().fg(theme.muted)));
+                }
+                Crumb::Current(title) => left_spans.push(Span::styled(
+                    title,
+                    Style::default()
+                        .fg(theme.success)
+                        .add_modifier(Modifier::BOLD),
+                )),
+            }
+        }
+
+        // Render left-aligned breadcrumb
+        let breadcrumb = Paragraph::new(Line::from(left_spans)).alignment(Alignment::Left);
+        frame.render_widget(breadcrumb, chunks[0]);
+
+        // Render right-aligned activity indicator
+        if !right_text.is_empty() {
+            let activity_widget = Paragraph::new(right_text)
+                .alignment(Alignment::Right)
+                .style(right_style);
+            frame.render_widget(activity_widget, chunks[1]);
+        }
+    }
+
+    /// Add the entered query to the search history, saving it when configured
+    fn remember_search(&mut self) {
+        let query = self.global_search.query.clone();
+        self.global_search.history.push(&query);
+        if !self.ctx.config.app.save_search_history {
+            return;
+        }
+        let entries = self.global_search.history.entries().to_vec();
+        self.view_state.set_search_history(entries);
+        if let Some(path) = &self.view_state_path
+            && let Err(e) = self.view_state.save(path)
+        {
+            self.notify(
+                format!("Could not save search history: {}", e),
+                ToastKind::Warning,
+            );
+        }
+    }
+
+    /// Re-filter after the search query or filter changed, from the first match
+    fn refilter_for_search(&mut self) {
+        // Stream views filter as they render
+        if !self.stream_active {
+            self.apply_sort_and_filter();
+        }
+        self.selected_index = 0;
+        self.needs_render = true;
+    }
+
+    /// Rows (or log lines) the live search filter currently shows
+    fn search_match_count(&self) -> Option<usize> {
+        if !self.global_search.filter_active {
+            return None;
+        }
+        if let Some(indices) = self.log_matches() {
+            return Some(indices.len());
+        }
+        self.current_table_view().map(|_| self.filtered_indices.len())
+    }
+
+    fn render_search_input(&self, frame: &mut Frame, area: Rect) {
+        let theme = self.theme();
+        // Only renders during active input
+        let search_text = format!("{}_", self.global_search.query);
+
+        let case_indicator = if self.global_search.case_sensitive {
+            " [Case-sensitive]"
+        } else {
+            ""
+        };
+
+        // Show column-specific or global search mode
+        let scope_indicator = match &self.global_search.mode {
+            SearchMode::Global => {
+                if self.global_search.query.starts_with('!') {
+                    " (All columns, Regex)".to_string()
+                } else {
+                    " (All columns)".to_string()
+                }
+            }
+            SearchMode::ColumnSpecific { column_display_name, search_term, .. } => {
+                if search_term.starts_with('!') {
+                    format!(" (Column: {}, Regex)", column_display_name)
+                } else {
+                    format!(" (Column: {})", column_display_name)
+                }
+            }
+            SearchMode::Filters { filters, .. } => {
+                let mut columns: Vec<&str> =
+                    filters.iter().map(|f| f.column_display_name.as_str()).collect();
+                columns.dedup();
+                format!(" (Filter: {})", columns.join(", "))
+            }
+        };
+
+        let match_count = match self.search_match_count() {
+            Some(1) => " · 1 match".to_string(),
+            Some(n) => format!(" · {} matches", n),
+            None => String::new(),
+        };
+
+        let title = format!(
+            "Search{}{}{} - Enter to apply, Esc to cancel",
+            scope_indicator, case_indicator, match_count
+        );
+
+        let search_input = Paragraph::new(search_text)
+            .style(
+                Style::default()
+                    .fg(theme.heading)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(title)
+                    .border_style(Style::default().fg(theme.heading)),
+            );
+
+        frame.render_widget(search_input, area);
+    }
+
+    fn render_content(&mut self, frame: &mut Frame, area: Rect) {
+        let ctx = self.ctx.clone();
+        let theme = ctx.themes.page(&self.current_page);
+        if let Some(error) = &self.page_error {
+            self.render_page_error(frame, area, error, theme);
+            return;
+        }
+
+        let page = match ctx.config.pages.get(&self.current_page) {
+            Some(p) => p,
+            None => return,
+        };
+
+        match &page.view {
+            ConfigView::Table(table_view) => {
+                let table_view = table_view.clone();
+                self.render_table(frame, area, &table_view);
+            }
+            ConfigView::Logs(logs_view) => {
+                let logs_view = logs_view.clone();
+                self.render_logs(frame, area, &logs_view);
+            }
+            ConfigView::Text(text_view) => {
+                self.render_text(frame, area, text_view);
+            }
+            ConfigView::Chart(chart_view) => {
+                let data = self.chart_data.get(&self.current_page);
+                Self::render_chart(
+                    frame,
+                    area,
+                    self.get_rendered_page_title(),
+                    chart_view,
+                    data,
+                    theme,
+                );
+            }
+            ConfigView::BarGauge(gauge_view) | ConfigView::Gauge(gauge_view) => {
+                let bars = matches!(page.view, ConfigView::BarGauge(_));
+                let rows = self
+                    .filtered_indices
+                    .iter()
+                    .filter_map(|&idx| self.current_data.get(idx));
+                let items = crate::view::gauge::items(&self.ctx.paths, gauge_view, rows);
+                let title = self.get_rendered_page_title();
+                Self::render_gauges(frame, area, title, gauge_view, &items, bars, theme);
+            }
+            ConfigView::Histogram(histogram_view) => {
+                let rows = self
+                    .filtered_indices
+                    .iter()
+                    .filter_map(|&idx| self.current_data.get(idx));
+                let title = self.get_rendered_page_title();
+                self.render_histogram(frame, area, title, histogram_view, rows, theme);
+            }
+            ConfigView::Heatmap(heatmap_view) => {
+                let rows = self
+                    .filtered_indices
+                    .iter()
+                    .filter_map(|&idx| self.current_data.get(idx));
+                let title = self.get_rendered_page_title();
+                self.render_heatmap(frame, area, title, heatmap_view, rows, theme);
+            }
+            ConfigView::Dashboard(dashboard) => self.render_dashboard(frame, area, dashboard),
+            ConfigView::Form(form) => self.render_form(frame, area, form),
+            ConfigView::Split(split_view) => {
+                let percent = split_view.ratio.clamp(10, 90);
+                let constraints = [Constraint::Percentage(percent), Constraint::Fill(1)];
+                let [table_area, detail_area] = match split_view.orientation {
+                    SplitOrientation::Horizontal => Layout::horizontal(constraints).areas(area),
+                    SplitOrientation::Vertical => Layout::vertical(constraints).areas(area),
+                };
+                self.render_table(frame, table_area, &split_view.table);
+                self.render_detail_pane(frame, detail_area, &split_view.detail);
+            }
+        }
+    }
+
+    /// Each dashboard panel in its grid cell, titled with when it last updated
+    fn render_dashboard(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        dashboard: &crate::config::DashboardView,
+    ) {
+        let rects = crate::view::dashboard::layout(dashboard, area);
+        let panels = dashboard.panels.iter().zip(&self.dashboard_panels);
+        for (idx, ((panel, state), rect)) in panels.zip(rects).enumerate() {
+            let theme = self.ctx.themes.panel(&self.current_page, idx);
+            let title = match state.updated {
+                Some(at) => format!("{} · {}", panel.title, at.format("%H:%M:%S")),
+                None => panel.title.clone(),
+            };
+            if let Some(error) = &state.error {
+                let block = Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(theme.error))
+                    .title(title);
+                let error = Paragraph::new(error.as_str())
+                    .style(Style::default().fg(theme.error))
+                    .wrap(ratatui::widgets::Wrap { trim: false })
+                    .block(block);
+                frame.render_widget(error, rect);
+                continue;
+            }
+            if state.updated.is_none() {
+                let loading = Paragraph::new("Loading...")
+                    .style(Style::default().fg(theme.muted))
+                    .block(theme.block().title(title));
+                frame.render_widget(loading, rect);
+                continue;
+            }
+            match &panel.view {
+                ConfigView::Table(table_view) => {
+                    self.render_panel_table(frame, rect, title, table_view, &state.rows, theme);
+                }
+                ConfigView::Text(text_view) => {
+                    let content = state.rows.first().map(Self::text_content).unwrap_or_default();
+                    let syntax = text_view
+                        .syntax
+                        .clone()
+                        .unwrap_or_else(|| self.detect_content_type(&content).to_string());
+                    let lines =
+                        self.highlight_text(&content, &syntax, text_view.line_numbers, theme);
+                    let mut text = Paragraph::new(lines)
+                        .block(theme.block().title(title));
+                    if text_view.wrap {
+                        text = text.wrap(ratatui::widgets::Wrap { trim: false });
+                    }
+                    frame.render_widget(text, rect);
+                }
+                ConfigView::Chart(chart_view) => {
+                    Self::render_chart(frame, rect, title, chart_view, Some(&state.chart), theme);
+                }
+                ConfigView::BarGauge(gauge_view) => {
+                    let items = crate::view::gauge::items(&self.ctx.paths, gauge_view, &state.rows);
+                    Self::render_gauges(frame, rect, title, gauge_view, &items, true, theme);
+                }
+                ConfigView::Gauge(gauge_view) => {
+                    let items = crate::view::gauge::items(&self.ctx.paths, gauge_view, &state.rows);
+                    Self::render_gauges(frame, rect, title, gauge_view, &items, false, theme);
+                }
+                ConfigView::Histogram(histogram_view) => {
+                    self.render_histogram(frame, rect, title, histogram_view, &state.rows, theme);
+                }
+                ConfigView::Heatmap(heatmap_view) => {
+                    self.render_heatmap(frame, rect, title, heatmap_view, &state.rows, theme);
+                }
+                // Other views aren't allowed in panels (see the validator)
+                _ => {}
+            }
+        }
+    }
+
+    /// The error chain, what was run and when, and the keys to deal with it
+    fn render_page_error(&self, frame: &mut Frame, area: Rect, error: &PageError, theme: &Theme) {
+        use ratatui::widgets::Wrap;
+
+        let label = |text: &'static str| Span::styled(text, Style::default().fg(theme.muted));
+        let mut lines = vec![
+            Line::from(Span::styled(
+                format!("\u{2717} {}", error.title),
+                Style::default()
+                    .fg(theme.error)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+        ];
+        for (depth, cause) in error.chain().into_iter().enumerate() {
+            let (indent, style) = match depth {
+                0 => (String::from("  "), Style::default().fg(theme.error)),
+                _ => (
+                    format!("{}\u{21b3} ", "  ".repeat(depth)),
+                    Style::default().fg(theme.fg),
+                ),
+            };
+            lines.push(Line::from(vec![
+                Span::styled(indent, Style::default().fg(theme.muted)),
+                Span::styled(cause.to_string(), style),
+            ]));
+        }
+        lines.push(Line::from(""));
+        if let Some(source) = &error.source {
+            lines.push(Line::from(vec![
+                label("  Source  "),
+                Span::styled(source.clone(), Style::default().fg(theme.accent)),
+            ]));
+        }
+        lines.push(Line::from(vec![
+            label("  Failed  "),
+            Span::styled(error.failed(), Style::default().fg(theme.fg)),
+        ]));
+        lines.push(Line::from(""));
+
+        let mut keys = vec![("r", "retry")];
+        if self.opened_from_form() {
+            keys.push(("e", "edit filters"));
+        }
+        keys.push(("y", "copy error"));
+        if !self.nav_stack.is_empty() {
+            keys.push(("Esc", "back"));
+        }
+        let mut hint = vec![Span::raw("  ")];
+        for (key, what) in keys {
+            hint.push(Span::styled(
+                key,
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            hint.push(Span::styled(format!(": {}   ", what), Style::default().fg(theme.muted)));
+        }
+        lines.push(Line::from(hint));
+
+        let widget = Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(theme.block().title("Error").border_style(Style::default().fg(theme.error)));
+        frame.render_widget(widget, area);
+    }
+
+    /// "⚠ Source 'usage' failed (showing the others): HTTP request failed ..."
+    fn render_failed_sources(&self, frame: &mut Frame, area: Rect) {
+        let theme = self.theme();
+        let ids: Vec<String> = (self.failed_sources.iter())
+            .map(|failure| format!("'{}'", failure.id))
+            .collect();
+        let text = format!(
+            " \u{26a0} {} {} failed (showing the rest, r retries): {}",
+            if ids.len() == 1 { "Source" } else { "Sources" },
+            ids.join(", "),
+            self.failed_sources[0].error
+        );
+        let banner = Paragraph::new(crate::util::text::truncate(&text, area.width as usize)).style(
+            Style::default()
+                .fg(theme.inverse)
+                .bg(theme.warning)
+                .add_modifier(Modifier::BOLD),
+        );
+        frame.render_widget(banner, area);
+    }
+
+    /// Whether the current page was opened from a form, whose fields fed it
+    fn opened_from_form(&self) -> bool {
+        self.nav_stack.frames().back().is_some_and(|frame| {
+            self.ctx
+                .config
+                .pages
+                .get(&frame.page_id)
+                .is_some_and(|page| matches!(page.view, ConfigView::Form(_)))
+        })
+    }
+
+    /// The command or URL behind a page's data, templates rendered, e.g.
+    /// `kubectl get pods -n prod -o json` or `GET https://api/repos`
+    fn data_source_line(&self, page: &crate::config::Page) -> Option<String> {
+        use crate::config::{DataSource, SingleOrStream};
+
+        let template_ctx = self.create_template_context(None);
+        let render = |s: &str| {
+            if crate::template::engine::TemplateEngine::is_template(s) {
+                (self.ctx.template_engine)
+                    .render_string(s, &template_ctx)
+                    .unwrap_or_else(|_| s.to_string())
+            } else {
+                s.to_string()
+            }
+        };
+        let (command, args): (String, Vec<String>) = match page.data.as_ref()? {
+            DataSource::SingleOrStream(SingleOrStream::Single(single)) => {
+                let field = |name: &str| single.config.get(name).and_then(|v| v.as_str());
+                if let Some(url) = field("url") {
+                    let method = field("method").unwrap_or("GET").to_uppercase();
+                    return Some(format!("{} {}", method, render(url)));
+                }
+                let args = (single.config.get("args").and_then(|v| v.as_array()))
+                    .map(|args| args.iter().filter_map(|v| v.as_str()).map(render).collect())
+                    .unwrap_or_default();
+                (render(field("command")?), args)
+            }
+            DataSource::SingleOrStream(SingleOrStream::Stream(stream)) => (
+                render(stream.command.as_deref()?),
+                stream.args.iter().map(|arg| render(arg)).collect(),
+            ),
+            DataSource::Multi(_) => return None,
+        };
+        Some(std::iter::once(command).chain(args).collect::<Vec<_>>().join(" "))
+    }
+
+    /// Keys of the error view: `e` back to the form the page came from, `y`
+    /// copies the error. False for keys it leaves to the keymap (`r` retries).
+    async fn handle_page_error_key(&mut self, key: KeyEvent) -> bool {
+        let Some(error) = &self.page_error else {
+            return false;
+        };
+        if !key.modifiers.is_empty() {
+            return false;
+        }
+        match key.code {
+            KeyCode::Char('e') if self.opened_from_form() => {
+                self.go_back().await;
+                true
+            }
+            KeyCode::Char('y') => {
+                match crate::util::clipboard::copy(&error.report()) {
+                    Ok(()) => self.notify("Copied the error", ToastKind::Success),
+                    Err(e) => self.notify(format!("Copy failed: {}", e), ToastKind::Error),
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// A form's fields, one per line with the focused one marked, then the
+    /// submit button and the reason the last submit was refused
+    fn render_form(&self, frame: &mut Frame, area: Rect, view: &crate::config::FormView) {
+        let theme = self.theme();
+        use crate::config::FieldKind;
+
+        let Some(state) = &self.form else {
+            return;
+        };
+        let label_width = view.fields.iter().map(|f| form::label(f).width()).max().unwrap_or(0);
+        let mut lines: Vec<Line> = Vec::new();
+        for (i, (field, value)) in view.fields.iter().zip(&state.values).enumerate() {
+            let focused = i == state.focused;
+            let label_style = match focused {
+                true => Style::default().fg(theme.heading).add_modifier(Modifier::BOLD),
+                false => Style::default().fg(theme.dim),
+            };
+            let required = if field.required { "*" } else { " " };
+            let mut spans = vec![
+                Span::styled(if focused { "▶ " } else { "  " }, label_style),
+                Span::styled(
+                    format!("{:>width$}{} ", form::label(field), required, width = label_width),
+                    label_style,
+                ),
+            ];
+            match value {
+                FieldValue::Text(text) if text.is_empty() && !focused => {
+                    let placeholder = field.placeholder.clone().unwrap_or_default();
+                    spans.push(Span::styled(placeholder, Style::default().fg(theme.muted)));
+                }
+                FieldValue::Text(text) => {
+                    spans.push(Span::raw(text.clone()));
+                    if focused {
+                        spans.push(Span::styled("█", Style::default().fg(theme.heading)));
+                    }
+                }
+                FieldValue::Select(idx) => {
+                    let option = field.options.get(*idx).cloned().unwrap_or_default();
+                    spans.push(match focused {
+                        true => Span::styled(
+                            format!("‹ {} ›", option),
+                            Style::default().fg(theme.accent),
+                        ),
+                        false => Span::raw(option),
+                    });
+                }
+                FieldValue::Checkbox(checked) => {
+                    let mark = if *checked { "[x]" } else { "[ ]" };
+                    let style = match focused {
+                        true => Style::default().fg(theme.accent),
+                        false => Style::default(),
+                    };
+                    spans.push(Span::styled(mark, style));
+                }
+            }
+            if field.kind == FieldKind::Select && field.options.is_empty() {
+                spans.push(Span::styled("(no options)", Style::default().fg(theme.muted)));
+            }
+            lines.push(Line::from(spans));
+        }
+
+        lines.push(Line::raw(""));
+        let submit = view.submit.as_deref().unwrap_or("Submit");
+        lines.push(Line::from(vec![
+            Span::raw(" ".repeat(label_width + 4)),
+            Span::styled(
+                format!("[ {} ]", submit),
+                Style::default().fg(theme.inverse).bg(theme.success),
+            ),
+            Span::styled("  Enter", Style::default().fg(theme.muted)),
+        ]));
+        if let Some(error) = &state.error {
+            lines.push(Line::raw(""));
+            lines.push(Line::styled(format!("  {}", error), Style::default().fg(theme.error)));
+        }
+
+        let title = self.get_rendered_page_title();
+        let form = Paragraph::new(lines).block(theme.block().title(title));
+        frame.render_widget(form, area);
+    }
+
+    /// A form built from its fields' defaults, text ones rendered as templates
+    fn new_form(&self, view: &crate::config::FormView) -> FormState {
+        let ctx = self.create_template_context(None);
+        let defaults = view
+            .fields
+            .iter()
+            .map(|field| match &field.default {
+                Some(Value::String(template)) => Some(Value::String(
+                    self.ctx
+                        .template_engine
+                        .render_string(template, &ctx)
+                        .unwrap_or_else(|_| template.clone()),
+                )),
+                other => other.clone(),
+            })
+            .collect();
+        FormState::new(&view.fields, defaults)
+    }
+
+    /// Edit the current form with a key; false for keys it leaves to the keymap
+    async fn handle_form_key(&mut self, key: KeyEvent) -> bool {
+        if key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+            || self.show_action_menu
+        {
+            return false;
+        }
+        let ctx = self.ctx.clone();
+        let Some(ConfigView::Form(view)) =
+            ctx.config.pages.get(&self.current_page).map(|p| &p.view)
+        else {
+            return false;
+        };
+        let Some(form) = &mut self.form else {
+            return false;
+        };
+        match key.code {
+            KeyCode::Tab | KeyCode::Down => form.focus_next(true),
+            KeyCode::BackTab | KeyCode::Up => form.focus_next(false),
+            KeyCode::Left => form.change(&view.fields, false),
+            KeyCode::Right => form.change(&view.fields, true),
+            KeyCode::Backspace => form.backspace(),
+            KeyCode::Char(c) => form.type_char(&view.fields, c),
+            KeyCode::Enter => self.submit_form(view).await,
+            _ => return false,
+        }
+        self.needs_render = true;
+        true
+    }
+
+    /// Make the form's values the page's row and run the submit action with
+    /// them (or go to the next page), unless a required field is empty
+    async fn submit_form(&mut self, view: &crate::config::FormView) {
+        let Some(form) = &mut self.form else {
+            return;
+        };
+        form.error = form.missing(&view.fields);
+        if form.error.is_some() {
+            return;
+        }
+        self.current_data = vec![form.to_row(&view.fields)];
+        self.update_row_keys();
+        self.apply_sort_and_filter();
+        self.selected_index = 0;
+
+        let Some(name) = &view.submit else {
+            self.navigate_next().await;
+            return;
+        };
+        if self.read_only {
+            self.notify("Read-only mode: actions are disabled", ToastKind::Info);
+            return;
+        }
+        if let Some(action) = self.page_actions().iter().find(|a| &a.name == name) {
+            self.trigger_action(action.clone()).await;
+        }
+    }
+
+    /// A dashboard panel's table: every row, styled and formatted like a
+    /// table page but without a cursor
+    fn render_panel_table(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        title: String,
+        table_view: &crate::config::TableView,
+        rows: &[Value],
+        theme: &Theme,
+    ) {
+        let alignments: Vec<_> = table_view.columns.iter().map(Self::column_alignment).collect();
+        let header = Row::new(table_view.columns.iter().zip(&alignments).map(|(col, &align)| {
+            Cell::from(Line::from(col.display.clone()).alignment(align))
+        }))
+        .style(Style::default().fg(theme.heading).add_modifier(Modifier::BOLD));
+        let body: Vec<Row> = rows
+            .iter()
+            .map(|row| {
+                let cells = table_view.columns.iter().zip(&alignments).map(|(col, &align)| {
+                    let (text, value) = self.cell_display(col, row);
+                    let style = self.apply_column_style(col, &value, row);
+                    let line = match (&col.progress, value.as_ref().and_then(Value::as_f64)) {
+                        (Some(progress), Some(percent)) => {
+                            Self::progress_line(progress, percent, text, style, theme)
+                        }
+                        _ => Line::styled(text, style),
+                    };
+                    Cell::from(line.alignment(align))
+                });
+                Row::new(cells).style(self.apply_row_style(table_view, row))
+            })
+            .collect();
+        let widths: Vec<Constraint> = table_view
+            .columns
+            .iter()
+            .map(|col| col.width.map_or(Constraint::Fill(1), Constraint::Length))
+            .collect();
+        let table = Table::new(body, widths)
+            .header(header)
+            .column_spacing(1)
+            .block(theme.block().title(title));
+        frame.render_widget(table, area);
+    }
+
+    /// A split view's pane showing the selected row, scrolled with the movement
+    /// keys while it has the focus
+    fn render_detail_pane(&mut self, frame: &mut Frame, area: Rect, detail: &SplitDetail) {
+        let ctx = self.ctx.clone();
+        let theme = ctx.themes.page(&self.current_page);
+        let row_idx = self.filtered_indices.get(self.selected_index).copied();
+        if row_idx != self.detail_pane.row {
+            // Start each row at the top
+            self.detail_pane.row = row_idx;
+            self.detail_pane.scroll = 0;
+        }
+        let lines: Vec<Line> = match self.get_selected_row() {
+            Some(row) => {
+                let (content, syntax) = self.detail_content(detail, row);
+                self.highlight_text(&content, syntax, false, theme)
+            }
+            None => vec![Line::styled("No row selected", Style::default().fg(theme.muted))],
+        };
+
+        let height = area.height.saturating_sub(2);
+        let last = (lines.len() as u16).saturating_sub(height);
+        self.detail_pane.height = height;
+        self.detail_pane.last = last;
+        self.detail_pane.scroll = self.detail_pane.scroll.min(last);
+
+        let border = if self.detail_pane.focused { theme.accent } else { theme.muted };
+        let title = match detail.kind {
+            DetailKind::Yaml => "Detail [yaml]",
+            DetailKind::Json => "Detail [json]",
+            DetailKind::Fields | DetailKind::Text => "Detail",
+        };
+        let paragraph = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(border))
+                    .title(title),
+            )
+            .scroll((self.detail_pane.scroll, 0));
+        frame.render_widget(paragraph, area);
+    }
+
+    /// Text of the detail pane for a row, and the syntax to highlight it as
+    fn detail_content(&self, detail: &SplitDetail, row: &Value) -> (String, &'static str) {
+        match detail.kind {
+            DetailKind::Yaml => {
+                let yaml = serde_yaml::to_string(row).unwrap_or_else(|e| e.to_string());
+                (yaml, "yaml")
+            }
+            DetailKind::Json => (Self::text_content(row), "json"),
+            DetailKind::Fields => (crate::view::fields::field_lines(row).join("\n"), "text"),
+            DetailKind::Text => {
+                let ctx = self
+                    .create_template_context(Some(row))
+                    .with_page_context("row".to_string(), row.clone());
+                let rendered = self
+                    .ctx
+                    .template_engine
+                    .render_cached(detail.template.as_deref().unwrap_or(""), &ctx)
+                    .unwrap_or_else(|e| e.to_string());
+                (rendered, "text")
+            }
+        }
+    }
+
+    /// Scroll the focused detail pane with a movement key; false for other keys
+    fn scroll_detail_pane(&mut self, key_action: KeyAction) -> bool {
+        let pane = &mut self.detail_pane;
+        let page = pane.height.max(1);
+        pane.scroll = match key_action {
+            KeyAction::Down => pane.scroll.saturating_add(1),
+            KeyAction::Up => pane.scroll.saturating_sub(1),
+            KeyAction::HalfPageDown => pane.scroll.saturating_add((page / 2).max(1)),
+            KeyAction::HalfPageUp => pane.scroll.saturating_sub((page / 2).max(1)),
+            KeyAction::PageDown => pane.scroll.saturating_add(page),
+            KeyAction::PageUp => pane.scroll.saturating_sub(page),
+            KeyAction::Top => 0,
+            KeyAction::Bottom => pane.last,
+            _ => return false,
+        }
+        .min(pane.last);
+        self.needs_render = true;
+        true
+    }
+
+    /// A bar (`bargauge`) or a gauge per item the filter shows, colored by
+    /// the view's thresholds
+    fn render_gauges(
+        frame: &mut Frame,
+        area: Rect,
+        title: String,
+        gauge_view: &crate::config::GaugeView,
+        items: &[crate::view::gauge::GaugeItem],
+        bars: bool,
+        theme: &Theme,
+    ) {
+        use crate::view::gauge;
+        use ratatui::widgets::{Bar, BarChart, BarGroup, Gauge};
+
+        let block = theme.block().title(title);
+        if items.is_empty() {
+            frame.render_widget(Paragraph::new("No data").block(block), area);
+            return;
+        }
+        let full = gauge::full_scale(gauge_view, items, bars);
+        let color = |value: f64| {
+            theme.color(gauge::color_for(gauge_view, value)).unwrap_or(theme.success)
+        };
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if bars {
+            // Bars are whole numbers, so scale to a fixed resolution; one row per
+            // bar with a gap between, as many as fit
+            const RESOLUTION: f64 = 1000.0;
+            let fit = (inner.height as usize).div_ceil(2);
+            let bars: Vec<Bar> = items
+                .iter()
+                .take(fit)
+                .map(|item| {
+                    let scaled = if full > 0.0 { item.value / full * RESOLUTION } else { 0.0 };
+                    Bar::default()
+                        .label(Line::from(item.label.clone()))
+                        .value(scaled.clamp(0.0, RESOLUTION) as u64)
+                        .text_value(gauge::format_value(gauge_view, item.value))
+                        .style(Style::default().fg(color(item.value)))
+                        .value_style(Style::default().fg(theme.inverse).bg(color(item.value)))
+                })
+                .collect();
+            let chart = BarChart::default()
+                .direction(ratatui::layout::Direction::Horizontal)
+                .bar_width(1)
+                .bar_gap(1)
+                .max(RESOLUTION as u64)
+                .data(BarGroup::default().bars(&bars));
+            frame.render_widget(chart, inner);
+            return;
+        }
+
+        // Gauges: a label line and a bar each, as many as fit
+        let fit = (inner.height / 2) as usize;
+        for (i, item) in items.iter().take(fit).enumerate() {
+            let y = inner.y + 2 * i as u16;
+            frame.render_widget(
+                Paragraph::new(item.label.as_str()),
+                Rect { y, height: 1, ..inner },
+            );
+            let ratio = if full > 0.0 { (item.value / full).clamp(0.0, 1.0) } else { 0.0 };
+            let gauge = Gauge::default()
+                .ratio(ratio)
+                .label(gauge::format_value(gauge_view, item.value))
+                .gauge_style(Style::default().fg(color(item.value)).bg(theme.muted));
+            frame.render_widget(gauge, Rect { y: y + 1, height: 1, ..inner });
+        }
+    }
+
+    /// A bar per bucket, labelled with its range and count
+    fn render_histogram<'a>(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        title: String,
+        histogram_view: &crate::config::HistogramView,
+        rows: impl IntoIterator<Item = &'a Value>,
+        theme: &Theme,
+    ) {
+        use crate::view::histogram;
+
+        let buckets = histogram::histogram(&self.ctx.paths, histogram_view, rows);
+        let block = theme.block().title(title);
+        if buckets.is_empty() {
+            frame.render_widget(Paragraph::new("No data").block(block), area);
+            return;
+        }
+        let unit = histogram_view.unit.as_deref();
+        let labels: Vec<String> =
+            buckets.iter().map(|b| histogram::bucket_label(b, unit)).collect();
+        let label_width = labels.iter().map(|l| l.width()).max().unwrap_or(0);
+        let peak = buckets.iter().map(|b| b.count).max().unwrap_or(0);
+        let count_width = peak.to_string().len();
+        let inner_width = block.inner(area).width as usize;
+        let bar_width = inner_width.saturating_sub(label_width + count_width + 3).max(1);
+        let color = histogram_view
+            .color
+            .as_deref()
+            .and_then(|color| theme.color(color))
+            .unwrap_or(theme.accent);
+
+        let lines: Vec<Line> = buckets
+            .iter()
+            .zip(&labels)
+            .map(|(bucket, label)| {
+                let fraction = if peak > 0 { bucket.count as f64 / peak as f64 } else { 0.0 };
+                let bar = histogram::bar(fraction, bar_width);
+                let padding = bar_width.saturating_sub(bar.width());
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:>width$} ", label, width = label_width),
+                        Style::default().fg(theme.dim),
+                    ),
+                    Span::styled(bar, Style::default().fg(color)),
+                    Span::raw(" ".repeat(padding + 1)),
+                    Span::raw(format!("{:>width$}", bucket.count, width = count_width)),
+                ])
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    /// Columns of time intervals, the latest on the right, with a row per
+    /// value band shaded by its count. Without a `value` each column is a bar
+    /// as tall as its count.
+    fn render_heatmap<'a>(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        title: String,
+        heatmap_view: &crate::config::HeatmapView,
+        rows: impl IntoIterator<Item = &'a Value>,
+        theme: &Theme,
+    ) {
+        use crate::view::histogram;
+
+        let interval = humantime::parse_duration(&heatmap_view.interval)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(60);
+        let map = histogram::heatmap(&self.ctx.paths, heatmap_view, rows, interval);
+        let block = theme.block().title(title);
+        if map.times.is_empty() {
+            frame.render_widget(Paragraph::new("No data").block(block), area);
+            return;
+        }
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        let color = heatmap_view
+            .color
+            .as_deref()
+            .and_then(|color| theme.color(color))
+            .unwrap_or(theme.warning);
+        let banded = heatmap_view.value.is_some();
+        let unit = heatmap_view.unit.as_deref();
+        let labels: Vec<String> = match banded {
+            true => map.bands.iter().map(|b| histogram::bucket_label(b, unit)).collect(),
+            false => Vec::new(),
+        };
+        let label_width = labels.iter().map(|l| l.width() + 1).max().unwrap_or(0);
+
+        // The latest intervals that fit, and a line below them for their times
+        let height = inner.height.saturating_sub(1) as usize;
+        let visible = (inner.width as usize).saturating_sub(label_width).min(map.times.len());
+        let skip = map.times.len() - visible;
+        let counts = &map.counts[skip..];
+        let peak = counts.iter().flatten().copied().max().unwrap_or(0).max(1) as f64;
+
+        let mut lines: Vec<Line> = Vec::with_capacity(height + 1);
+        if banded {
+            // Highest band on top; bands that don't fit are left off the top
+            for band in (0..map.bands.len()).rev().skip(map.bands.len().saturating_sub(height)) {
+                let cells: String = counts
+                    .iter()
+                    .map(|column| histogram::shade(column[band] as f64 / peak))
+                    .collect();
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!("{:>width$} ", labels[band], width = label_width - 1),
+                        Style::default().fg(theme.dim),
+                    ),
+                    Span::styled(cells, Style::default().fg(color)),
+                ]));
+            }
+        } else {
+            let bars: Vec<Vec<char>> = counts
+                .iter()
+                .map(|column| histogram::column(column[0] as f64 / peak, height))
+                .collect();
+            for row in (0..height).rev() {
+                let cells: String = bars.iter().map(|bar| bar[row]).collect();
+                lines.push(Line::styled(cells, Style::default().fg(color)));
+            }
+        }
+
+        // Time axis: the first and last visible interval, and the busiest cell
+        let format = if interval >= 86_400 { "%m-%d" } else { "%H:%M" };
+        let time = |t: i64| {
+            chrono::DateTime::from_timestamp(t, 0)
+                .map(|dt| dt.with_timezone(&chrono::Local).format(format).to_string())
+                .unwrap_or_default()
+        };
+        let first = time(map.times[skip]);
+        let last = time(map.times[map.times.len() - 1]);
+        let peak_label = format!("max {}", peak as usize);
+        let gap = visible.saturating_sub(first.width() + last.width()).max(1);
+        lines.push(Line::from(vec![
+            Span::raw(" ".repeat(label_width)),
+            Span::styled(
+                format!("{}{}{}  {}", first, " ".repeat(gap), last, peak_label),
+                Style::default().fg(theme.muted),
+            ),
+        ]));
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
+    /// Plot the fetched items of a chart page (appending them to its history
+    /// if it keeps one)
+    fn update_chart_data(&mut self) {
+        let Some(ConfigView::Chart(chart_view)) = self
+            .ctx
+            .config
+            .pages
+            .get(&self.current_page)
+            .map(|p| &p.view)
+        else {
+            return;
+        };
+        let now = chrono::Utc::now().timestamp_millis() as f64 / 1000.0;
+        let fetched = ChartData::from_rows(&self.ctx.paths, chart_view, &self.current_data, now);
+        match chart_view.history {
+            Some(limit) => {
+                let data = self.chart_data.entry(self.current_page.clone()).or_default();
+                data.append(fetched, limit);
+            }
+            None => {
+                self.chart_data.insert(self.current_page.clone(), fetched);
+            }
+        }
+    }
+
+    fn render_chart(
+        frame: &mut Frame,
+        area: Rect,
+        page_title: String,
+        chart_view: &crate::config::ChartView,
+        data: Option<&ChartData>,
+        theme: &Theme,
+    ) {
+        use ratatui::symbols::Marker;
+        use ratatui::widgets::{Axis, Chart, Dataset, GraphType, Sparkline};
+
+        let data = data.filter(|d| !d.is_empty());
+        let Some((data, (x_bounds, y_bounds))) =
+            data.and_then(|d| Some((d, d.bounds(chart_view.y_min, chart_view.y_max)?)))
+        else {
+            let empty = Paragraph::new("No data")
+                .block(theme.block().title(page_title));
+            frame.render_widget(empty, area);
+            return;
+        };
+
+        // Series without a color take one from the theme's, in turn
+        let palette =
+            [theme.accent, theme.warning, theme.success, theme.secondary, theme.error, theme.info];
+        let color = |i: usize| {
+            chart_view.series[i]
+                .color
+                .as_deref()
+                .and_then(|color| theme.color(color))
+                .unwrap_or(palette[i % palette.len()])
+        };
+
+        if chart_view.kind == crate::config::ChartKind::Sparkline {
+            let block = theme.block().title(page_title);
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+            let count = data.series.len();
+            let rows = Layout::default()
+                .direction(ratatui::layout::Direction::Vertical)
+                .constraints(vec![Constraint::Ratio(1, count as u32); count])
+                .split(inner);
+            for (i, (points, &row)) in data.series.iter().zip(rows.iter()).enumerate() {
+                // Sparklines plot whole numbers from zero, so shift and scale each
+                // series' own range into 0..=100
+                let own = ChartData { series: vec![points.clone()], x_is_time: false };
+                let Some((_, [lo, hi])) = own.bounds(chart_view.y_min, chart_view.y_max) else {
+                    continue;
+                };
+                let values: Vec<u64> = points
+                    .iter()
+                    .map(|&(_, y)| (((y - lo) / (hi - lo)).clamp(0.0, 1.0) * 100.0).round() as u64)
+                    .collect();
+                // Show the most recent points that fit
+                let values = &values[values.len().saturating_sub(row.width as usize)..];
+                let latest = points.last().map(|&(_, y)| chart::axis_label(y)).unwrap_or_default();
+                let title = format!("{} {}", chart_view.series[i].name, latest);
+                let sparkline = Sparkline::default()
+                    .block(Block::default().title(title))
+                    .data(values)
+                    .max(100)
+                    .style(Style::default().fg(color(i)));
+                frame.render_widget(sparkline, row);
+            }
+            return;
+        }
+
+        let datasets: Vec<Dataset> = data
+            .series
+            .iter()
+            .enumerate()
+            .map(|(i, points)| {
+                Dataset::default()
+                    .name(chart_view.series[i].name.clone())
+                    .marker(Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(color(i)))
+                    .data(points)
+            })
+            .collect();
+
+        let x_label = |x: f64| {
+            if data.x_is_time {
+                chrono::DateTime::from_timestamp(x as i64, 0)
+                    .map(|t| t.with_timezone(&chrono::Local).format("%H:%M:%S").to_string())
+                    .unwrap_or_default()
+            } else {
+                chart::axis_label(x)
+            }
+        };
+        let mid = |[lo, hi]: [f64; 2]| lo + (hi - lo) / 2.0;
+        let axis_style = Style::default().fg(theme.muted);
+        let x_axis = Axis::default()
+            .title(chart_view.x_label.clone().unwrap_or_default())
+            .style(axis_style)
+            .bounds(x_bounds)
+            .labels([x_bounds[0], mid(x_bounds), x_bounds[1]].map(x_label));
+        let y_axis = Axis::default()
+            .title(chart_view.y_label.clone().unwrap_or_default())
+            .style(axis_style)
+            .bounds(y_bounds)
+            .labels([y_bounds[0], mid(y_bounds), y_bounds[1]].map(chart::axis_label));
+
+        let chart = Chart::new(datasets)
+            .block(theme.block().title(page_title))
+            .x_axis(x_axis)
+            .y_axis(y_axis)
+            .hidden_legend_constraints((Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)));
+        frame.render_widget(chart, area);
+    }
+
+    fn render_table(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        table_config: &crate::config::TableView,
+    ) {
+        let ctx = self.ctx.clone();
+        let theme = ctx.themes.page(&self.current_page);
+        let stats = self.column_stats(table_config);
+        // Get the rendered page title, with the number of marked rows
+        let mut page_title = self.get_rendered_page_title();
+        if !self.marked_rows.is_empty() {
+            let (marked, hidden) = self.bulk_rows();
+            page_title = match hidden {
+                0 => format!("{} · {} selected", page_title, marked.len()),
+                _ => format!(
+                    "{} · {} selected ({} hidden by filter)",
+                    page_title,
+                    marked.len(),
+                    hidden
+                ),
+            };
+        }
+        let baseline = self.active_baseline();
+        if let Some(baseline) = baseline {
+            let paths: Vec<_> =
+                table_config.columns.iter().filter_map(|c| self.ctx.paths.get(&c.path)).collect();
+            let paths: Vec<&JsonPathExtractor> = paths.iter().map(Arc::as_ref).collect();
+            let diff = baseline.summary(&self.row_keys, &self.current_data, &paths);
+            page_title = format!(
+                "{} · Δ {}: {} changed, {} added, {} removed",
+                page_title, baseline.name, diff.changed, diff.added, diff.removed
+            );
+        }
+
+        if self.filtered_indices.is_empty() {
+            let empty = Paragraph::new("No data")
+                .block(theme.block().title(page_title));
+            frame.render_widget(empty, area);
+            return;
+        }
+
+        // Lay the columns out in the user's saved order
+        let order = self.column_order(table_config);
+        let ordered;
+        let table_config = if order.iter().enumerate().all(|(pos, &idx)| pos == idx) {
+            table_config
+        } else {
+            ordered = crate::config::TableView {
+                columns: order.iter().map(|&idx| table_config.columns[idx].clone()).collect(),
+                ..table_config.clone()
+            };
+            &ordered
+        };
+
+        // Expanded rows are taller; their extra lines are drawn over the table afterwards
+        let mut row_heights = vec![1u16; self.filtered_indices.len()];
+        let mut expansions: Vec<(usize, Vec<String>)> = Vec::new();
+        if !self.expanded_rows.is_empty() {
+            for (pos, &data_idx) in self.filtered_indices.iter().enumerate() {
+                let Some(item) = self.current_data.get(data_idx) else {
+                    continue;
+                };
+                let lines = self.expansion_lines(table_config, data_idx, item);
+                if !lines.is_empty() {
+                    row_heights[pos] += lines.len() as u16;
+                    expansions.push((pos, lines));
+                }
+            }
+        }
+
+        // Only the rows on screen are built: pick them the way the Table widget
+        // would, in the space between the header and the footer
+        let has_footer = table_config.columns.iter().any(|c| c.aggregate.is_some());
+        let body_area = Rect {
+            height: area.height.saturating_sub(has_footer as u16),
+            ..area
+        };
+        let viewport = Block::default().borders(Borders::ALL).inner(body_area).height;
+        // A row expanded past the screen is clipped to it, so it can still be selected
+        for height in &mut row_heights {
+            *height = (*height).min(viewport.saturating_sub(1).max(1));
+        }
+        let window = crate::view::window::visible_rows(
+            &row_heights,
+            self.table_state.selected(),
+            self.table_state.offset(),
+            viewport.saturating_sub(1),
+        );
+
+        // Build the visible rows with styling
+        // Display width of each linked cell, per visible row: (column index, width)
+        let mut link_widths: Vec<Vec<(usize, u16)>> = Vec::new();
+        // Column sizes over all the filtered rows, in display order
+        let column_count = table_config.columns.len();
+        let mut content_widths: Vec<u16> = order.iter().map(|&idx| stats.widths[idx]).collect();
+        let has_numeric: Vec<bool> = order.iter().map(|&idx| stats.has_numeric[idx]).collect();
+        let has_text: Vec<bool> = order.iter().map(|&idx| stats.has_text[idx]).collect();
+        // Cells that differ from the baseline while diffing, else from before the
+        // last refresh while that's highlighted
+        let recent = self.recent_changes();
+        let changes = baseline.or(recent.map(|c| &c.before));
+        // Compiled once per column rather than per cell
+        let column_paths: Vec<Option<Arc<JsonPathExtractor>>> = match changes {
+            Some(_) => table_config.columns.iter().map(|c| self.ctx.paths.get(&c.path)).collect(),
+            None => Vec::new(),
+        };
+        let mut rows: Vec<(Vec<Line>, Style, u16)> = self.filtered_indices[window.clone()]
+            .iter()
+            .zip(&row_heights[window.clone()])
+            .filter_map(|(&data_idx, &height)| {
+                self.current_data.get(data_idx).map(|item| (data_idx, item, height))
+            })
+            .map(|(data_idx, item, height)| {
+                let mut row_links = Vec::new();
+                let cells: Vec<Line> = table_config
+                    .columns
+                    .iter()
+                    .enumerate()
+                    .map(|(col_idx, col)| {
+                        // Extract value using JSONPath; computed columns render their
+                        // transform over the row and show a placeholder if that fails
+                        let (value_str, extracted_value) = self.cell_display(col, item);
+
+                        // Apply column styling
+                        let mut cell_style = self.apply_column_style(col, &extracted_value, item);
+                        if col.link.is_some() {
+                            cell_style = cell_style.add_modifier(Modifier::UNDERLINED);
+                            row_links.push((col_idx, value_str.width() as u16));
+                        }
+
+                        if let Some(changes) = changes
+                            && let Some(key) = self.row_keys.get(data_idx)
+                            && let Some(path) = &column_paths[col_idx]
+                        {
+                            match changes.cell_change(key, path, extracted_value.as_ref()) {
+                                Some(CellChange::Changed) => {
+                                    cell_style = cell_style.fg(theme.inverse).bg(theme.highlight);
+                                }
+                                Some(CellChange::Added) => {
+                                    cell_style = cell_style.fg(theme.success)
+                                }
+                                None => {}
+                            }
+                        }
+
+                        if let Some(progress) = &col.progress
+                            && let Some(percent) = extracted_value.as_ref().and_then(Value::as_f64)
+                        {
+                            return Self::progress_line(
+                                progress, percent, value_str, cell_style, theme,
+                            );
+                        }
+
+                        // Highlight search matches in cell text
+                        if self.global_search.filter_active {
+                            let should_highlight = match &self.global_search.mode {
+                                SearchMode::Global | SearchMode::Filters { .. } => true,
+                                SearchMode::ColumnSpecific { column_display_name, .. } => {
+                                    col.display == *column_display_name
+                                }
+                            };
+                            if should_highlight {
+                                let spans = vec![Span::styled(value_str, cell_style)];
+                                Line::from(
+                                    self.global_search.highlight_search_in_spans(spans, theme),
+                                )
+                            } else {
+                                Line::styled(value_str, cell_style)
+                            }
+                        } else {
+                            Line::styled(value_str, cell_style)
+                        }
+                    })
+                    .collect();
+
+                link_widths.push(row_links);
+
+                // Apply row-level styling
+                let row_style = self.apply_row_style(table_config, item);
+                (cells, row_style, height)
+            })
+            .collect();
+
+        // Rows the last refresh removed, struck through after the last row
+        // (not while a search filters the table)
+        if let Some(recent) = recent
+            && table_config.show_removed
+            && !self.global_search.filter_active
+            && window.end == self.filtered_indices.len()
+        {
+            let style = Style::default().fg(theme.muted).add_modifier(Modifier::CROSSED_OUT);
+            for row in &recent.removed {
+                let cells = table_config
+                    .columns
+                    .iter()
+                    .enumerate()
+                    .map(|(col_idx, col)| {
+                        let text = self
+                            .column_value(col, row)
+                            .map(|value| value_to_string(&value))
+                            .unwrap_or_default();
+                        content_widths[col_idx] = content_widths[col_idx].max(text.width() as u16);
+                        Line::styled(text, style)
+                    })
+                    .collect();
+                rows.push((cells, Style::default(), 1));
+            }
+        }
+
+        // Footer with each column's `aggregate` over the rows the filter shows
+        let footer_cells: Option<Vec<String>> = has_footer.then(|| {
+                table_config
+                    .columns
+                    .iter()
+                    .map(|col| {
+                        let Some(kind) = col.aggregate else {
+                            return String::new();
+                        };
+                        let values: Vec<Value> = self
+                            .filtered_indices
+                            .iter()
+                            .filter_map(|&idx| self.current_data.get(idx))
+                            .map(|row| self.column_value(col, row).unwrap_or(Value::Null))
+                            .collect();
+                        crate::view::column::aggregate(kind, col.value_type(), &values)
+                            .map(|v| format!("{} {}", kind.label(), v))
+                            .unwrap_or_default()
+                    })
+                    .collect()
+            });
+        for (width, cell) in content_widths.iter_mut().zip(footer_cells.iter().flatten()) {
+            *width = (*width).max(cell.width() as u16);
+        }
+
+        // Untyped columns without an explicit `align` right-align when all values are numeric
+        let alignments: Vec<ratatui::layout::Alignment> = table_config
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(col_idx, col)| {
+                if col.align.is_none()
+                    && col.value_type().is_none()
+                    && has_numeric[col_idx]
+                    && !has_text[col_idx]
+                {
+                    ratatui::layout::Alignment::Right
+                } else {
+                    Self::column_alignment(col)
+                }
+            })
+            .collect();
+
+        // Column widths: each column wants its fixed `width` or its content width
+        // (within `min_width`/`max_width`). Inside the borders there's a 3-wide
+        // selection column and 1-wide spacing between columns.
+        let inner_width = area.width.saturating_sub(2 + 3);
+        let wanted: Vec<u16> = table_config
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, col)| {
+                col.width.unwrap_or_else(|| {
+                    content_widths[i]
+                        .min(col.max_width.unwrap_or(u16::MAX))
+                        .max(col.min_width.unwrap_or(1))
+                })
+            })
+            .collect();
+
+        // When not even the headers fit, scroll sideways instead of squeezing
+        let header_floor: u32 = table_config
+            .columns
+            .iter()
+            .zip(&wanted)
+            .map(|(col, &want)| {
+                let floor = want.min(col.display.width() as u16).max(col.min_width.unwrap_or(1));
+                col.width.unwrap_or(floor) as u32 + 1
+            })
+            .sum();
+        let (visible, hidden_left, hidden_right) = if header_floor <= inner_width as u32 + 1 {
+            ((0..column_count).collect(), 0, 0)
+        } else {
+            self.scroll_window(&table_config.columns, &wanted, inner_width)
+        };
+
+        // Fixed columns are laid out first, then auto-sized ones share the rest
+        let columns: Vec<&crate::config::TableColumn> =
+            visible.iter().map(|&i| &table_config.columns[i]).collect();
+        let fixed: u16 = columns.iter().filter_map(|c| c.width).sum();
+        let available = inner_width
+            .saturating_sub(visible.len().saturating_sub(1) as u16)
+            .saturating_sub(fixed);
+        let auto_columns: Vec<usize> =
+            (0..visible.len()).filter(|&pos| columns[pos].width.is_none()).collect();
+        let auto_wanted: Vec<u16> = auto_columns.iter().map(|&pos| wanted[visible[pos]]).collect();
+        let fitted = crate::view::column::fit_widths(&auto_wanted, available);
+        let mut widths: Vec<Constraint> = columns
+            .iter()
+            .map(|col| Constraint::Length(col.width.unwrap_or(0)))
+            .collect();
+        for (&pos, &width) in auto_columns.iter().zip(&fitted) {
+            let min = columns[pos].min_width.unwrap_or(0);
+            widths[pos] = Constraint::Length(width.max(min));
+        }
+
+        // Build header, marking the focused column and the sort column
+        let sort = self.effective_sort(table_config);
+        let header_cells: Vec<Cell> = visible
+            .iter()
+            .map(|&col_idx| {
+                let col = &table_config.columns[col_idx];
+                let mut style = Style::default()
+                    .fg(theme.heading)
+                    .add_modifier(Modifier::BOLD);
+                if col_idx == self.focused_column && column_count > 1 {
+                    style = style.add_modifier(Modifier::REVERSED);
+                }
+                let label = match &sort {
+                    Some(s) if s.column == col.id() => match s.order {
+                        crate::config::SortOrder::Asc => format!("{} ▲", col.display),
+                        crate::config::SortOrder::Desc => format!("{} ▼", col.display),
+                    },
+                    _ => col.display.clone(),
+                };
+                Cell::from(Line::from(label).alignment(alignments[col_idx])).style(style)
+            })
+            .collect();
+        let header = Row::new(header_cells).height(1);
+        let footer = footer_cells.map(|cells| {
+            let cells = visible.iter().map(|&col_idx| {
+                Cell::from(Line::from(cells[col_idx].clone()).alignment(alignments[col_idx]))
+            });
+            Row::new(cells).style(Style::default().add_modifier(Modifier::BOLD)).height(1)
+        });
+
+        // Cells wider than their column end in "…" (`v` shows the full value)
+        let mut column_widths = vec![0; column_count];
+        for (&col_idx, width) in visible.iter().zip(&widths) {
+            if let Constraint::Length(w) = width {
+                column_widths[col_idx] = *w;
+            }
+        }
+        let rows: Vec<Row> = rows
+            .into_iter()
+            .map(|(cells, row_style, height)| {
+                let cells = cells
+                    .into_iter()
+                    .zip(&alignments)
+                    .enumerate()
+                    .filter(|(col_idx, _)| visible.contains(col_idx))
+                    .map(|(col_idx, (line, &alignment))| {
+                        let line = crate::view::column::truncate_line(line, column_widths[col_idx]);
+                        Cell::from(line.alignment(alignment))
+                    });
+                Row::new(cells).style(row_style).height(height)
+            })
+            .collect();
+
+        // Scrolled tables narrow down to the visible columns and say what's hidden
+        let visible_config;
+        let (table_config, alignments, link_widths, page_title) = if visible.len() < column_count {
+            visible_config = crate::config::TableView {
+                columns: columns.iter().map(|&col| col.clone()).collect(),
+                ..table_config.clone()
+            };
+            let alignments: Vec<_> = visible.iter().map(|&i| alignments[i]).collect();
+            let link_widths: Vec<Vec<(usize, u16)>> = link_widths
+                .into_iter()
+                .map(|row| {
+                    row.into_iter()
+                        .filter_map(|(i, w)| visible.iter().position(|&v| v == i).map(|p| (p, w)))
+                        .collect()
+                })
+                .collect();
+            let mut hidden = Vec::new();
+            if hidden_left > 0 {
+                hidden.push(format!("◀ {}", hidden_left));
+            }
+            if hidden_right > 0 {
+                hidden.push(format!("{} ▶", hidden_right));
+            }
+            let title = format!("{} [{}]", page_title, hidden.join(" "));
+            (&visible_config, alignments, link_widths, title)
+        } else {
+            (table_config, alignments, link_widths, page_title)
+        };
+
+        let mut table = Table::new(rows, widths.clone())
+            .header(header)
+            .block(theme.block().title(page_title))
+            .row_highlight_style(
+                Style::default()
+                    .bg(theme.selected)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol(">> ");
+        if let Some(footer) = footer {
+            table = table.footer(footer);
+        }
+
+        // The table only holds the visible rows, so it sees them from the top
+        *self.table_state.offset_mut() = window.start;
+        let mut window_state = ratatui::widgets::TableState::default()
+            .with_selected(self.table_state.selected().map(|s| s.saturating_sub(window.start)));
+        frame.render_stateful_widget(table, area, &mut window_state);
+
+        if !expansions.is_empty() {
+            self.render_row_expansions(frame, body_area, &row_heights, &expansions);
+        }
+
+        // Marked rows get a dot in the selection column
+        if !self.marked_rows.is_empty() {
+            let inner = Block::default().borders(Borders::ALL).inner(body_area);
+            let marker = Style::default().fg(theme.accent).add_modifier(Modifier::BOLD);
+            for (pos, y) in self.visible_row_positions(inner, &row_heights) {
+                let marked = self
+                    .filtered_indices
+                    .get(pos)
+                    .and_then(|&idx| self.row_keys.get(idx))
+                    .is_some_and(|key| self.marked_rows.contains(key));
+                if marked && inner.width > 2 {
+                    frame.buffer_mut().set_string(inner.x + 2, y, "●", marker);
+                }
+            }
+        }
+
+        if table_config.columns.iter().any(|c| c.link.is_some()) {
+            self.record_table_hyperlinks(
+                body_area,
+                table_config,
+                &widths,
+                &alignments,
+                &link_widths,
+                &row_heights,
+            );
+        }
+    }
+
+    /// Draw expanded rows' template output across the full table width, beneath the row
+    fn render_row_expansions(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        row_heights: &[u16],
+        expansions: &[(usize, Vec<String>)],
+    ) {
+        let theme = self.theme();
+        let inner = Block::default().borders(Borders::ALL).inner(area);
+        let positions = self.visible_row_positions(inner, row_heights);
+
+        for (row_pos, lines) in expansions {
+            let Some(&(_, y)) = positions.iter().find(|(pos, _)| pos == row_pos) else {
+                continue;
+            };
+            let top = y + 1;
+            if top >= inner.bottom() {
+                continue;
+            }
+            // Indent past the selection column
+            let rect = Rect::new(
+                inner.x + 3,
+                top,
+                inner.width.saturating_sub(3),
+                (lines.len() as u16).min(inner.bottom() - top),
+            );
+            let text: Vec<Line> = lines
+                .iter()
+                .map(|line| {
+                    Line::from(vec![
+                        Span::styled("│ ", Style::default().fg(theme.accent)),
+                        Span::styled(line.clone(), Style::default().fg(theme.dim)),
+                    ])
+                })
+                .collect();
+            frame.render_widget(ratatui::widgets::Clear, rect);
+            frame.render_widget(Paragraph::new(text), rect);
+        }
+    }
+
+    /// Effective cell alignment: explicit `align`, else the column type's default
+    fn column_alignment(col: &TableColumn) -> ratatui::layout::Alignment {
+        use crate::config::Alignment;
+
+        match col
+            .align
+            .or_else(|| col.value_type().map(crate::view::column::default_alignment))
+        {
+            Some(Alignment::Right) => ratatui::layout::Alignment::Right,
+            Some(Alignment::Center) => ratatui::layout::Alignment::Center,
+            Some(Alignment::Left) | None => ratatui::layout::Alignment::Left,
+        }
+    }
+
+    /// Record screen regions of linked cells in the visible rows, mirroring the
+    /// column layout ratatui's Table uses (3-wide ">> " selection column, spacing 1).
+    fn record_table_hyperlinks(
+        &mut self,
+        area: Rect,
+        table_config: &crate::config::TableView,
+        widths: &[Constraint],
+        alignments: &[ratatui::layout::Alignment],
+        link_widths: &[Vec<(usize, u16)>],
+        row_heights: &[u16],
+    ) {
+        let inner = Block::default().borders(Borders::ALL).inner(area);
+        let [_, columns_area] = Layout::horizontal([Constraint::Length(3), Constraint::Fill(0)])
+            .areas(Rect::new(0, 0, inner.width, 1));
+        let columns = Layout::horizontal(widths.to_vec())
+            .flex(Flex::Start)
+            .spacing(1)
+            .split(columns_area);
+
+        for (row_pos, y) in self.visible_row_positions(inner, row_heights) {
+            let Some(row_links) = row_pos
+                .checked_sub(self.table_state.offset())
+                .and_then(|visible_pos| link_widths.get(visible_pos))
+            else {
+                continue;
+            };
+            let Some(item) = self
+                .filtered_indices
+                .get(row_pos)
+                .and_then(|&idx| self.current_data.get(idx))
+            else {
+                continue;
+            };
+
+            for &(col_idx, text_width) in row_links {
+                let (Some(col), Some(col_rect)) =
+                    (table_config.columns.get(col_idx), columns.get(col_idx))
+                else {
+                    continue;
+                };
+                let width = text_width.min(col_rect.width);
+                if width == 0 {
+                    continue;
+                }
+                let align_offset = match alignments[col_idx] {
+                    ratatui::layout::Alignment::Left => 0,
+                    ratatui::layout::Alignment::Center => (col_rect.width - width) / 2,
+                    ratatui::layout::Alignment::Right => col_rect.width - width,
+                };
+                if let Some(url) = self.render_link(col, item) {
+                    let rect = Rect::new(inner.x + col_rect.x + align_offset, y, width, 1);
+                    self.hyperlinks.push((rect, url));
+                }
+            }
+        }
+    }
+
+    /// A cell's text and the value it shows: a computed column's rendered
+    /// transform, else the value at `path` through the column's transform or
+    /// its type and `format`
+    fn cell_display(&self, col: &TableColumn, item: &Value) -> (String, Option<Value>) {
+        if col.is_computed() {
+            return match self.render_computed(col, item) {
+                Ok(rendered) => (rendered.clone(), Some(Value::String(rendered))),
+                Err(_) => (COMPUTED_ERROR.to_string(), None),
+            };
+        }
+        if let Some(progress) = &col.progress {
+            // The bar is drawn from the percentage in front of this label
+            let percent =
+                crate::view::progress::percent(&self.ctx.paths, progress, &col.path, item);
+            return match percent {
+                Some(percent) => (
+                    crate::view::progress::label(percent),
+                    serde_json::Number::from_f64(percent).map(Value::Number),
+                ),
+                None => (String::new(), None),
+            };
+        }
+        let Some(value) = self.ctx.paths.extract(&col.path, item) else {
+            return (String::new(), None);
+        };
+
+        let display_str = if let Some(transform) = col.transform_template() {
+            // The extracted value as "value" for easy access in transforms, and
+            // the full row as "row" for conditions
+            let row_ctx = self
+                .create_template_context(Some(item))
+                .with_page_context("value".to_string(), value.clone())
+                .with_page_context("row".to_string(), item.clone());
+
+            // A transform that hits the render limits shows an error cell;
+            // other failures fall back to the raw value
+            match self.ctx.template_engine.render_cached(transform, &row_ctx) {
+                Ok(rendered) => rendered,
+                Err(crate::error::TermStackError::TemplateLimit(e)) => format!("⚠ {}", e),
+                Err(_) => value_to_string(&value),
+            }
+        } else {
+            let text = col
+                .value_type()
+                .and_then(|ty| crate::view::column::format(ty, &value))
+                .unwrap_or_else(|| value_to_string(&value));
+            match &col.format {
+                Some(format) => {
+                    crate::view::column::apply_format(format, col.value_type(), &value, text)
+                }
+                None => text,
+            }
+        };
+        (display_str, Some(value))
+    }
+
+    /// `rows` as the current page's table shows them, in its sort order: the
+    /// column headers and each row's cell text. None if it isn't a table.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn table_text(
+        &mut self,
+        rows: Vec<Value>,
+    ) -> Option<(Vec<String>, Vec<Vec<String>>)> {
+        let table_view = self.current_table_view()?.clone();
+        self.current_data = rows;
+        self.update_row_keys();
+        self.apply_sort_and_filter();
+
+        let headers = table_view.columns.iter().map(|col| col.display.clone()).collect();
+        let cells = self
+            .filtered_indices
+            .iter()
+            .map(|&idx| {
+                let row = &self.current_data[idx];
+                table_view
+                    .columns
+                    .iter()
+                    .map(|col| self.cell_display(col, row).0)
+                    .collect()
+            })
+            .collect();
+        Some((headers, cells))
+    }
+
+    /// A progress column's cell: the bar in its threshold color, the empty
+    /// track, then the percentage
+    fn progress_line(
+        progress: &crate::config::ProgressColumn,
+        percent: f64,
+        label: String,
+        style: Style,
+        theme: &Theme,
+    ) -> Line<'static> {
+        use crate::view::{gauge, progress as bar};
+
+        let (filled, track) = bar::segments(percent, progress.width);
+        let color =
+            gauge::threshold_color(&progress.thresholds, progress.color.as_deref(), percent);
+        let color = theme.color(color).unwrap_or(theme.success);
+        Line::from(vec![
+            Span::styled(filled, style.fg(color)),
+            Span::styled(track, Style::default().fg(theme.muted)),
+            Span::styled(format!(" {}", label), style),
+        ])
+    }
+
+    /// A column's value for a row: the value at `path`, the rendered
+    /// `transform` of a computed column (None if it fails to render), or the
+    /// percentage of a progress column
+    fn column_value(&self, col: &TableColumn, row: &Value) -> Option<Value> {
+        if col.is_computed() {
+            return self.render_computed(col, row).ok().map(Value::String);
+        }
+        if let Some(progress) = &col.progress {
+            let percent =
+                crate::view::progress::percent(&self.ctx.paths, progress, &col.path, row)?;
+            return serde_json::Number::from_f64(percent).map(Value::Number);
+        }
+        self.ctx.paths.extract(&col.path, row)
+    }
+
+    /// Render a computed column's `transform` (template or bare expression) with `row`
+    fn render_computed(&self, col: &TableColumn, row: &Value) -> Result<String> {
+        let template =
+            crate::view::style::condition_template(col.transform_template().unwrap_or(""));
+        let ctx = self
+            .create_template_context(Some(row))
+            .with_page_context("row".to_string(), row.clone());
+        self.ctx.template_engine.render_cached(&template, &ctx)
+    }
+
+    /// Render a column's `link` template for a row, with `value` and `row` in context;
+    /// `None` unless it renders to a valid URL (see `sanitize_url`)
+    fn render_link(&self, col: &TableColumn, row: &Value) -> Option<String> {
+        let link = col.link.as_ref()?;
+        let mut ctx = self.create_template_context(Some(row));
+        if let Some(value) = self.ctx.paths.extract(&col.path, row) {
+            ctx = ctx.with_page_context("value".to_string(), value);
+        }
+        ctx = ctx.with_page_context("row".to_string(), row.clone());
+
+        self.ctx
+            .template_engine
+            .render_cached(link, &ctx)
+            .ok()
+            .and_then(|url| crate::util::open::sanitize_url(&url))
+    }
+
+    /// Expand or collapse the selected table row (needs `expand` or `expandable`)
+    fn toggle_row_expansion(&mut self) {
+        let Some(table_view) = self.current_table_view() else {
+            return;
+        };
+        if table_view.expand.is_none() && !table_view.expandable {
+            self.notify("Rows on this page aren't expandable", ToastKind::Info);
+            self.needs_render = true;
+            return;
+        }
+        let Some(key) = self.selected_row_key() else {
+            return;
+        };
+
+        if !self.expanded_rows.remove(&key) {
+            self.expanded_rows.insert(key);
+        }
+        self.needs_render = true;
+    }
+
+    /// The current page's table view, if it is a table (or a split view's table)
+    fn current_table_view(&self) -> Option<&crate::config::TableView> {
+        self.ctx
+            .config
+            .pages
+            .get(&self.current_page)
+            .and_then(|p| p.view.table())
+    }
+
+    /// Whether the current page is a split view
+    fn current_split_view(&self) -> bool {
+        self.ctx
+            .config
+            .pages
+            .get(&self.current_page)
+            .is_some_and(|p| matches!(p.view, ConfigView::Split(_)))
+    }
+
+    /// Key the current page's saved view state is stored under
+    fn view_state_key(&self) -> String {
+        format!("{}/{}", self.ctx.config.app.name, self.current_page)
+    }
+
+    /// The current page's column sizes (see [`ColumnStats`]), measured again
+    /// only when the filtered rows have changed since
+    fn column_stats(&mut self, table_config: &crate::config::TableView) -> ColumnStats {
+        if let Some(stats) = &self.column_stats
+            && stats.page == self.current_page
+            && stats.generation == self.data_generation
+            && stats.widths.len() == table_config.columns.len()
+        {
+            return stats.clone();
+        }
+
+        let column_count = table_config.columns.len();
+        let mut widths: Vec<u16> =
+            table_config.columns.iter().map(|c| c.display.width() as u16 + 2).collect();
+        let mut has_numeric = vec![false; column_count];
+        let mut has_text = vec![false; column_count];
+        let rows = self.filtered_indices.iter().filter_map(|&idx| self.current_data.get(idx));
+        for item in rows {
+            for (col_idx, col) in table_config.columns.iter().enumerate() {
+                let (value_str, extracted_value) = self.cell_display(col, item);
+                let bar_width = col.progress.as_ref().map_or(0, |p| p.width + 1);
+                widths[col_idx] = widths[col_idx].max(value_str.width() as u16 + bar_width);
+                // Formatted numbers ("$1,234.00", "12 ms") align by their raw value
+                let numeric = match (&col.format, &extracted_value) {
+                    (Some(_), Some(raw)) if col.transform_template().is_none() => {
+                        crate::view::column::looks_numeric(&value_to_string(raw))
+                    }
+                    _ => crate::view::column::looks_numeric(&value_str),
+                };
+                if numeric {
+                    has_numeric[col_idx] = true;
+                } else if !value_str.trim().is_empty() {
+                    has_text[col_idx] = true;
+                }
+            }
+        }
+
+        let stats = ColumnStats {
+            page: self.current_page.clone(),
+            generation: self.data_generation,
+            widths,
+            has_numeric,
+            has_text,
+        };
+        self.column_stats = Some(stats.clone());
+        stats
+    }
+
+    /// Display order of a table's columns (indices into `columns`), honoring saved
+    /// reordering. Pinned columns come first.
+    fn column_order(&self, table_view: &crate::config::TableView) -> Vec<usize> {
+        let names: Vec<&str> = table_view.columns.iter().map(|c| c.display.as_str()).collect();
+        let mut order = self.view_state.column_order(&self.view_state_key(), &names);
+        order.sort_by_key(|&idx| !table_view.columns[idx].pinned);
+        order
+    }
+
+    /// Columns to draw when the table is too wide for the screen: the pinned
+    /// columns plus as many of the others as fit, starting at `column_offset`
+    /// and scrolled so the focused column is in view. Takes the columns in
+    /// display order and each one's width; returns the visible positions and
+    /// how many columns are hidden to the left and right.
+    fn scroll_window(
+        &mut self,
+        columns: &[crate::config::TableColumn],
+        widths: &[u16],
+        available: u16,
+    ) -> (Vec<usize>, usize, usize) {
+        let pinned: Vec<usize> = (0..columns.len()).filter(|&i| columns[i].pinned).collect();
+        let scrollable: Vec<usize> = (0..columns.len()).filter(|&i| !columns[i].pinned).collect();
+        // Every column takes one cell of spacing, except that the last needs none
+        let room = (available as u32 + 1)
+            .saturating_sub(pinned.iter().map(|&i| widths[i] as u32 + 1).sum::<u32>());
+        let fitting = |offset: usize| {
+            let mut used = 0;
+            let mut count = 0;
+            for &i in &scrollable[offset..] {
+                used += widths[i] as u32 + 1;
+                if count > 0 && used > room {
+                    break;
+                }
+                count += 1;
+            }
+            count
+        };
+
+        let mut offset = self.column_offset.min(scrollable.len().saturating_sub(1));
+        if let Some(pos) = scrollable.iter().position(|&i| i == self.focused_column) {
+            offset = offset.min(pos);
+            while pos >= offset + fitting(offset) {
+                offset += 1;
+            }
+        }
+        self.column_offset = offset;
+
+        let count = fitting(offset);
+        let visible = pinned
+            .into_iter()
+            .chain(scrollable[offset..offset + count].iter().copied())
+            .collect();
+        (visible, offset, scrollable.len() - offset - count)
+    }
+
+    /// Move the column focus one column left or right
+    fn move_column_focus(&mut self, right: bool) {
+        let Some(table_view) = self.current_table_view() else {
+            return;
+        };
+        let last = table_view.columns.len().saturating_sub(1);
+        self.focused_column = if right {
+            (self.focused_column + 1).min(last)
+        } else {
+            self.focused_column.saturating_sub(1).min(last)
+        };
+        self.needs_render = true;
+    }
+
+    /// Swap the focused column with its neighbour and save the new order
+    fn move_focused_column(&mut self, right: bool) {
+        let ctx = self.ctx.clone();
+        let Some(table_view) = ctx
+            .config
+            .pages
+            .get(&self.current_page)
+            .and_then(|p| p.view.table())
+        else {
+            return;
+        };
+        let mut order = self.column_order(table_view);
+        let from = self.focused_column.min(order.len().saturating_sub(1));
+        let to = if right { from + 1 } else { from.wrapping_sub(1) };
+        // Columns don't move across the pinned / scrolling boundary
+        if to >= order.len()
+            || table_view.columns[order[from]].pinned != table_view.columns[order[to]].pinned
+        {
+            return;
+        }
+        order.swap(from, to);
+        self.focused_column = to;
+
+        let names = order
+            .iter()
+            .map(|&idx| table_view.columns[idx].display.clone())
+            .collect();
+        let key = self.view_state_key();
+        self.view_state.set_column_order(&key, names);
+        if let Some(path) = &self.view_state_path
+            && let Err(e) = self.view_state.save(path)
+        {
+            self.notify(
+                format!("Could not save column order: {}", e),
+                ToastKind::Warning,
+            );
+        }
+        self.needs_render = true;
+    }
+
+    /// The table's sort: the one picked at runtime, else the configured `sort`
+    fn effective_sort(
+        &self,
+        table_view: &crate::config::TableView,
+    ) -> Option<crate::config::TableSort> {
+        match &self.runtime_sort {
+            Some(RuntimeSort::Unsorted) => None,
+            Some(RuntimeSort::By(sort)) => Some(sort.clone()),
+            None => table_view.sort.clone(),
+        }
+    }
+
+    /// Recompute each row's identity after the data changed, forgetting marks
+    /// and expansions of rows that are gone
+    fn update_row_keys(&mut self) {
+        let row_key = self.current_table_view().and_then(|t| t.row_key.clone());
+        self.row_keys = self
+            .current_data
+            .iter()
+            .map(|row| selection::row_key(row_key.as_deref(), row))
+            .collect();
+        let present: HashSet<&str> = self.row_keys.iter().map(String::as_str).collect();
+        self.marked_rows.retain_present(&present);
+        self.expanded_rows.retain(|key| present.contains(key.as_str()));
+    }
 
-        // Render left-aligned breadcrumb
-        let breadcrumb = Paragraph::new(Line::from(left_spans)).alignment(Alignment::Left);
-        frame.render_widget(breadcrumb, chunks[0]);
+    fn selected_row_key(&self) -> Option<String> {
+        let &idx = self.filtered_indices.get(self.selected_index)?;
+        self.row_keys.get(idx).cloned()
+    }
 
-        // Render right-aligned activity indicator
-        if !right_text.is_empty() {
-            let activity_widget = Paragraph::new(right_text)
-                .alignment(Alignment::Right)
-                .style(right_style);
-            frame.render_widget(activity_widget, chunks[1]);
+    fn select_row_by_key(&mut self, key: &str) {
+        if let Some(pos) = self
+            .filtered_indices
+            .iter()
+            .position(|&idx| self.row_keys.get(idx).is_some_and(|k| k == key))
+        {
+            self.selected_index = pos;
+        } else {
+            // The row is gone: stay at the same position, within the new rows
+            self.selected_index =
+                self.selected_index.min(self.filtered_indices.len().saturating_sub(1));
         }
     }
 
-    fn render_search_input(&self, frame: &mut Frame, area: Rect) {
-        // Only renders during active input
-        let search_text = format!("{}_", self.global_search.query);
+    /// Mark or unmark the selected row (tables with `multi_select`)
+    fn toggle_mark(&mut self) {
+        if !self.current_table_view().is_some_and(|t| t.multi_select) {
+            return;
+        }
+        if let Some(key) = self.selected_row_key() {
+            self.marked_rows.toggle(&key);
+            // Move on, so Space can be held down to mark a run of rows
+            if self.selected_index + 1 < self.filtered_indices.len() {
+                self.selected_index += 1;
+            }
+            self.needs_render = true;
+        }
+    }
 
-        let case_indicator = if self.global_search.case_sensitive {
-            " [Case-sensitive]"
+    /// Mark every row the filter shows, or unmark them if they're all marked
+    fn mark_all(&mut self) {
+        if !self.current_table_view().is_some_and(|t| t.multi_select) {
+            return;
+        }
+        let shown: Vec<String> = self
+            .filtered_indices
+            .iter()
+            .filter_map(|&idx| self.row_keys.get(idx).cloned())
+            .collect();
+        if shown.iter().all(|key| self.marked_rows.contains(key)) {
+            shown.iter().for_each(|key| self.marked_rows.remove(key));
         } else {
-            ""
-        };
+            shown.iter().for_each(|key| self.marked_rows.insert(key));
+        }
+        self.needs_render = true;
+    }
 
-        // Show column-specific or global search mode
-        let scope_indicator = match &self.global_search.mode {
-            SearchMode::Global => {
-                if self.global_search.query.starts_with('!') {
-                    " (All columns, Regex)".to_string()
-                } else {
-                    " (All columns)".to_string()
-                }
-            }
-            SearchMode::ColumnSpecific { column_display_name, search_term, .. } => {
-                if search_term.starts_with('!') {
-                    format!(" (Column: {}, Regex)", column_display_name)
-                } else {
-                    format!(" (Column: {})", column_display_name)
+    /// Marked rows in data order, and how many of them the filter hides
+    fn bulk_rows(&self) -> (Vec<&Value>, usize) {
+        if self.marked_rows.is_empty() {
+            return (Vec::new(), 0);
+        }
+        let shown: HashSet<usize> = self.filtered_indices.iter().copied().collect();
+        let mut hidden = 0;
+        let rows = (0..self.current_data.len())
+            .filter(|&idx| self.row_keys.get(idx).is_some_and(|k| self.marked_rows.contains(k)))
+            .inspect(|idx| {
+                if !shown.contains(idx) {
+                    hidden += 1;
                 }
-            }
-        };
+            })
+            .map(|idx| &self.current_data[idx])
+            .collect();
+        (rows, hidden)
+    }
 
-        let title = format!(
-            "Search{}{} - Enter to apply, Esc to cancel",
-            scope_indicator, case_indicator
+    /// Snapshot the current table's rows as the page's diff baseline
+    fn capture_baseline(&mut self, name: String) {
+        if self.current_table_view().is_none() || self.current_data.is_empty() {
+            return;
+        }
+        let baseline = Baseline::capture(name, &self.row_keys, &self.current_data);
+        let message = format!(
+            "Baseline '{}' captured ({} rows, d to compare)",
+            baseline.name,
+            baseline.len()
         );
+        self.notify(message, ToastKind::Success);
+        self.baselines.insert(self.current_page.clone(), baseline);
+        self.needs_render = true;
+    }
 
-        let search_input = Paragraph::new(search_text)
-            .style(
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
-            )
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title(title)
-                    .border_style(Style::default().fg(Color::Yellow)),
+    fn toggle_diff(&mut self) {
+        if self.baselines.contains_key(&self.current_page) {
+            self.show_diff = !self.show_diff;
+        } else {
+            self.notify(
+                "No baseline for this page (b captures one)",
+                ToastKind::Info,
             );
-
-        frame.render_widget(search_input, area);
+        }
+        self.needs_render = true;
     }
 
-    fn render_content(&mut self, frame: &mut Frame, area: Rect) {
-        if let Some(error) = &self.error_message {
-            let error_widget = Paragraph::new(error.as_str())
-                .style(Style::default().fg(Color::Red))
-                .block(Block::default().borders(Borders::ALL).title("Error"));
-            frame.render_widget(error_widget, area);
+    /// Stop or restart the current page's auto-refresh. Stays paused across
+    /// pages until resumed; `r` still refreshes once.
+    fn toggle_refresh_pause(&mut self) {
+        if self.refresh_interval.is_none() && !self.refresh_paused {
+            self.notify("This page doesn't auto-refresh", ToastKind::Info);
+            self.needs_render = true;
             return;
         }
-
-        let page = match globals::config().pages.get(&self.current_page) {
-            Some(p) => p,
-            None => return,
-        };
-
-        match &page.view {
-            ConfigView::Table(table_view) => {
-                let table_view = table_view.clone();
-                self.render_table(frame, area, &table_view);
-            }
-            ConfigView::Logs(logs_view) => {
-                let logs_view = logs_view.clone();
-                self.render_logs(frame, area, &logs_view);
-            }
-            ConfigView::Text(text_view) => {
-                self.render_text(frame, area, text_view);
+        self.refresh_paused = !self.refresh_paused;
+        if self.refresh_paused {
+            // Cancel the watcher, even mid-fetch, but not a one-off load
+            if self.next_refresh.take().is_some()
+                && let Some(watcher) = self.refresh_task.take()
+            {
+                watcher.abort();
+                if self.activity.is_loading() {
+                    self.activity = ActivityState::Idle;
+                }
             }
+        } else if let Some(page) = self.ctx.config.pages.get(&self.current_page).cloned() {
+            self.spawn_refresh_watcher(self.current_page.clone(), page);
         }
+        let message = if self.refresh_paused {
+            "Auto-refresh paused (p resumes)"
+        } else {
+            "Auto-refresh resumed"
+        };
+        self.notify(message, ToastKind::Info);
+        self.needs_render = true;
     }
 
-    fn render_table(
-        &mut self,
-        frame: &mut Frame,
-        area: Rect,
-        table_config: &crate::config::TableView,
-    ) {
-        // Get the rendered page title
-        let page_title = self.get_rendered_page_title();
+    /// Status bar note on the current page's auto-refresh, e.g.
+    /// "⟳ every 30s · next in 12s · last 14:02:11"
+    fn refresh_status(&self) -> Option<String> {
+        let interval = self.refresh_interval?;
+        let last = chrono::Duration::from_std(self.last_refresh.elapsed())
+            .ok()
+            .map(|ago| (chrono::Local::now() - ago).format("%H:%M:%S").to_string())?;
+        let interval = humantime::format_duration(interval);
+        Some(match self.seconds_to_refresh() {
+            _ if self.refresh_paused => format!("⟳ every {} · paused · last {}", interval, last),
+            Some(left) => format!("⟳ every {} · next in {}s · last {}", interval, left, last),
+            None => format!("⟳ every {} · last {}", interval, last),
+        })
+    }
 
-        if self.filtered_indices.is_empty() {
-            let empty = Paragraph::new("No data")
-                .block(Block::default().borders(Borders::ALL).title(page_title));
-            frame.render_widget(empty, area);
+    /// Whole seconds until the watcher refreshes the page, rounded up
+    fn seconds_to_refresh(&self) -> Option<u64> {
+        let left = self.next_refresh?.saturating_duration_since(std::time::Instant::now());
+        Some(left.as_secs_f64().ceil() as u64)
+    }
+
+    /// Remember what a refresh of the current page changed, if its table
+    /// highlights changes
+    fn note_refresh_changes(&mut self, before_keys: Vec<String>, before: Vec<Value>) {
+        if before.is_empty() || !self.current_table_view().is_some_and(|t| t.highlight_changes) {
             return;
         }
+        let removed = diff::removed_rows(&before_keys, &before, &self.row_keys);
+        // Replaces the previous refresh's changes, so highlights last one cycle
+        self.recent_changes = Some(RecentChanges {
+            page: self.current_page.clone(),
+            before: Baseline::capture("refresh", &before_keys, &before),
+            removed,
+            at: std::time::Instant::now(),
+        });
+    }
 
-        // Build header
-        let header_cells: Vec<Cell> = table_config
-            .columns
-            .iter()
-            .map(|col| {
-                Cell::from(col.display.clone()).style(
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD),
-                )
-            })
-            .collect();
-        let header = Row::new(header_cells).height(1);
+    /// What the last refresh of the current page changed, while it's highlighted
+    fn recent_changes(&self) -> Option<&RecentChanges> {
+        self.recent_changes.as_ref().filter(|c| c.page == self.current_page)
+    }
 
-        // Build rows with styling (optimized - using indices)
-        let _ctx = self.create_template_context(None);
-        let rows: Vec<Row> = self
-            .filtered_indices
-            .iter()
-            .filter_map(|&data_idx| self.current_data.get(data_idx))
-            .map(|item| {
-                let cells: Vec<Cell> = table_config
-                    .columns
-                    .iter()
-                    .map(|col| {
-                        // Extract value using JSONPath
-                        let (value_str, extracted_value) =
-                            if let Ok(extractor) = JsonPathExtractor::new(&col.path) {
-                                if let Ok(Some(value)) = extractor.extract_single(item) {
-                                    // Apply transform if present
-                                    let display_str = if let Some(transform) = &col.transform {
-                                        // Create context with full row for transform
-                                        let mut row_ctx = self.create_template_context(Some(item));
-                                        // Add the extracted value as "value" page context for easy access in transforms
-                                        row_ctx = row_ctx
-                                            .with_page_context("value".to_string(), value.clone());
-                                        // Also add the full row as "row" for conditions
-                                        row_ctx = row_ctx
-                                            .with_page_context("row".to_string(), item.clone());
-
-                                        globals::template_engine()
-                                            .render_string(transform, &row_ctx)
-                                            .unwrap_or_else(|_| value_to_string(&value))
-                                    } else {
-                                        value_to_string(&value)
-                                    };
-                                    (display_str, Some(value))
-                                } else {
-                                    ("".to_string(), None)
-                                }
-                            } else {
-                                ("".to_string(), None)
-                            };
+    /// The page's baseline, while diffing is on
+    fn active_baseline(&self) -> Option<&Baseline> {
+        self.baselines.get(&self.current_page).filter(|_| self.show_diff)
+    }
 
-                        // Apply column styling
-                        let cell_style = self.apply_column_style(col, &extracted_value, item);
+    /// Sort by the focused column, cycling ascending -> descending -> unsorted.
+    /// The selected row stays selected.
+    fn cycle_sort(&mut self) {
+        use crate::config::{SortOrder, TableSort};
 
-                        // Highlight search matches in cell text
-                        if self.global_search.filter_active {
-                            let should_highlight = match &self.global_search.mode {
-                                SearchMode::Global => true,
-                                SearchMode::ColumnSpecific { column_path, .. } => col.path == *column_path,
-                            };
-                            if should_highlight {
-                                let spans = vec![Span::styled(value_str, cell_style)];
-                                let highlighted = self.global_search.highlight_search_in_spans(spans);
-                                Cell::from(Line::from(highlighted))
-                            } else {
-                                Cell::from(value_str).style(cell_style)
-                            }
-                        } else {
-                            Cell::from(value_str).style(cell_style)
-                        }
-                    })
-                    .collect();
+        let Some(table_view) = self.current_table_view() else {
+            return;
+        };
+        let order = self.column_order(table_view);
+        let Some(col) = order
+            .get(self.focused_column)
+            .and_then(|&idx| table_view.columns.get(idx))
+        else {
+            return;
+        };
 
-                // Apply row-level styling
-                let row_style = self.apply_row_style(table_config, item);
-                Row::new(cells).style(row_style)
-            })
-            .collect();
+        let current = self.effective_sort(table_view).filter(|s| s.column == col.id());
+        self.runtime_sort = Some(match current.map(|s| s.order) {
+            None => RuntimeSort::By(TableSort {
+                column: col.id().to_string(),
+                order: SortOrder::Asc,
+            }),
+            Some(SortOrder::Asc) => RuntimeSort::By(TableSort {
+                column: col.id().to_string(),
+                order: SortOrder::Desc,
+            }),
+            Some(SortOrder::Desc) => RuntimeSort::Unsorted,
+        });
 
-        // Calculate column widths
-        let widths: Vec<Constraint> = table_config
+        let selected = self.filtered_indices.get(self.selected_index).copied();
+        self.apply_sort_and_filter();
+        if let Some(pos) =
+            selected.and_then(|idx| self.filtered_indices.iter().position(|&i| i == idx))
+        {
+            self.selected_index = pos;
+        }
+        self.needs_render = true;
+    }
+
+    /// Lines of a row's `expand` template (or its fields, for `expandable`
+    /// tables without one), empty when the row is collapsed
+    fn expansion_lines(
+        &self,
+        table_config: &crate::config::TableView,
+        data_idx: usize,
+        row: &Value,
+    ) -> Vec<String> {
+        if !self
+            .row_keys
+            .get(data_idx)
+            .is_some_and(|key| self.expanded_rows.contains(key))
+        {
+            return Vec::new();
+        }
+        let Some(template) = &table_config.expand else {
+            if table_config.expandable {
+                return crate::view::fields::field_lines(row);
+            }
+            return Vec::new();
+        };
+
+        let ctx = self
+            .create_template_context(Some(row))
+            .with_page_context("row".to_string(), row.clone());
+        let rendered = self
+            .ctx
+            .template_engine
+            .render_cached(template, &ctx)
+            .unwrap_or_else(|e| e.to_string());
+        let lines: Vec<String> = rendered.trim_end().lines().map(str::to_string).collect();
+        if lines.is_empty() {
+            vec!["(empty)".to_string()]
+        } else {
+            lines
+        }
+    }
+
+    /// Screen y of each visible row given the per-row heights, as (row position, y).
+    /// Mirrors ratatui's Table: rows start below the one-line header at the state offset.
+    fn visible_row_positions(&self, inner: Rect, heights: &[u16]) -> Vec<(usize, u16)> {
+        let mut positions = Vec::new();
+        let mut y = inner.y + 1;
+        for (row_pos, &height) in heights.iter().enumerate().skip(self.table_state.offset()) {
+            if y >= inner.bottom() {
+                break;
+            }
+            positions.push((row_pos, y));
+            y = y.saturating_add(height);
+        }
+        positions
+    }
+
+    /// The first link of the selected row that renders to a valid URL
+    fn selected_link(&self) -> Option<String> {
+        let table_view = self.current_table_view()?;
+        let row = self.get_selected_row()?;
+        table_view
             .columns
             .iter()
-            .map(|col| {
-                if let Some(width) = col.width {
-                    Constraint::Length(width)
-                } else {
-                    Constraint::Percentage((100 / table_config.columns.len()) as u16)
-                }
-            })
-            .collect();
+            .find_map(|col| self.render_link(col, row))
+    }
 
-        let table = Table::new(rows, widths)
-            .header(header)
-            .block(Block::default().borders(Borders::ALL).title(page_title))
-            .row_highlight_style(
-                Style::default()
-                    .bg(Color::DarkGray)
-                    .add_modifier(Modifier::BOLD),
-            )
-            .highlight_symbol(">> ");
+    /// Open the first linked column of the selected row in the system browser
+    fn open_selected_link(&mut self) {
+        let Some(table_view) = self.current_table_view() else {
+            return;
+        };
+        if !table_view.columns.iter().any(|col| col.link.is_some()) {
+            return;
+        }
 
-        // Use stateful rendering for efficient highlight updates
-        frame.render_stateful_widget(table, area, &mut self.table_state);
+        match self.selected_link() {
+            Some(url) => match crate::util::open::open_url(&url) {
+                Ok(()) => self.notify(format!("Opened {}", url), ToastKind::Info),
+                Err(e) => self.notify(format!("Failed to open {}: {}", url, e), ToastKind::Error),
+            },
+            None => self.notify("No valid link on this row", ToastKind::Info),
+        }
+        self.needs_render = true;
     }
 
     /// Apply column-level conditional styling
@@ -2412,114 +7582,82 @@ impl App {
         value: &Option<Value>,
         row: &Value,
     ) -> Style {
-        let mut style = Style::default();
-
-        // Find the first matching style rule
-        for style_rule in &col.style {
-            let matches = if let Some(condition) = &style_rule.condition {
-                // Evaluate condition template
-                let mut ctx = self.create_template_context(Some(row));
-                if let Some(val) = value {
-                    ctx = ctx.with_page_context("value".to_string(), val.clone());
-                }
-                ctx = ctx.with_page_context("row".to_string(), row.clone());
-
-                globals::template_engine()
-                    .render_string(condition, &ctx)
-                    .map(|result| result.trim() == "true")
-                    .unwrap_or(false)
-            } else {
-                style_rule.default
-            };
+        if col.style.is_empty() {
+            return Style::default();
+        }
+        let mut ctx = self.create_template_context(Some(row));
+        if let Some(val) = value {
+            ctx = ctx.with_page_context("value".to_string(), val.clone());
+        }
+        self.matching_style(&col.style, &ctx)
+    }
 
-            if matches {
-                // Apply this style
-                if let Some(color_str) = &style_rule.color
-                    && let Some(color) = Self::parse_color(color_str)
-                {
-                    style = style.fg(color);
-                }
-                if let Some(bg_str) = &style_rule.bg
-                    && let Some(bg_color) = Self::parse_color(bg_str)
-                {
-                    style = style.bg(bg_color);
-                }
-                if style_rule.bold {
-                    style = style.add_modifier(Modifier::BOLD);
+    /// Compile the templates a table renders for every cell (transforms, links,
+    /// style conditions, `expand`) up front, so the first frame doesn't pay for
+    /// it. Syntax errors still surface where the template is rendered.
+    fn precompile_table_templates(&self, table_view: &crate::config::TableView) {
+        use crate::view::style::condition_template;
+
+        let engine = &self.ctx.template_engine;
+        let conditions = table_view
+            .columns
+            .iter()
+            .flat_map(|col| &col.style)
+            .chain(&table_view.row_style)
+            .filter_map(|rule| rule.condition.as_deref());
+        for condition in conditions {
+            let _ = engine.precompile(&condition_template(condition));
+        }
+        for col in &table_view.columns {
+            match col.transform_template() {
+                Some(transform) if col.is_computed() => {
+                    let _ = engine.precompile(&condition_template(transform));
                 }
-                if style_rule.dim {
-                    style = style.add_modifier(Modifier::DIM);
+                Some(transform) => {
+                    let _ = engine.precompile(transform);
                 }
-                break; // Use first matching rule
+                None => {}
+            }
+            if let Some(link) = &col.link {
+                let _ = engine.precompile(link);
             }
         }
-
-        style
+        if let Some(expand) = &table_view.expand {
+            let _ = engine.precompile(expand);
+        }
     }
 
     /// Apply row-level conditional styling
     fn apply_row_style(&self, table_config: &crate::config::TableView, row: &Value) -> Style {
-        let mut style = Style::default();
-
-        // Find the first matching row style rule
-        for style_rule in &table_config.row_style {
-            let matches = if let Some(condition) = &style_rule.condition {
-                // Evaluate condition template
-                let ctx = self.create_template_context(Some(row));
-                globals::template_engine()
-                    .render_string(condition, &ctx)
-                    .map(|result| result.trim() == "true")
-                    .unwrap_or(false)
-            } else {
-                style_rule.default
-            };
-
-            if matches {
-                // Apply this style
-                if let Some(color_str) = &style_rule.color
-                    && let Some(color) = Self::parse_color(color_str)
-                {
-                    style = style.fg(color);
-                }
-                if let Some(bg_str) = &style_rule.bg
-                    && let Some(bg_color) = Self::parse_color(bg_str)
-                {
-                    style = style.bg(bg_color);
-                }
-                if style_rule.bold {
-                    style = style.add_modifier(Modifier::BOLD);
-                }
-                if style_rule.dim {
-                    style = style.add_modifier(Modifier::DIM);
-                }
-                break; // Use first matching rule
-            }
+        if table_config.row_style.is_empty() {
+            return Style::default();
         }
-
-        style
+        let ctx = self.create_template_context(Some(row));
+        self.matching_style(&table_config.row_style, &ctx)
     }
 
-    /// Parse color string to ratatui Color
-    fn parse_color(color_str: &str) -> Option<Color> {
-        match color_str.to_lowercase().as_str() {
-            "black" => Some(Color::Black),
-            "red" => Some(Color::Red),
-            "green" => Some(Color::Green),
-            "yellow" => Some(Color::Yellow),
-            "blue" => Some(Color::Blue),
-            "magenta" => Some(Color::Magenta),
-            "cyan" => Some(Color::Cyan),
-            "gray" | "grey" => Some(Color::Gray),
-            "darkgray" | "darkgrey" => Some(Color::DarkGray),
-            "lightred" => Some(Color::LightRed),
-            "lightgreen" => Some(Color::LightGreen),
-            "lightyellow" => Some(Color::LightYellow),
-            "lightblue" => Some(Color::LightBlue),
-            "lightmagenta" => Some(Color::LightMagenta),
-            "lightcyan" => Some(Color::LightCyan),
-            "white" => Some(Color::White),
-            _ => None,
-        }
+    /// Style of the first rule whose condition holds (or the `default` rule).
+    /// Conditions are compiled once and cached by the template engine.
+    fn matching_style(
+        &self,
+        rules: &[crate::config::ConditionalStyle],
+        ctx: &TemplateContext,
+    ) -> Style {
+        use crate::view::style;
+
+        rules
+            .iter()
+            .find(|rule| match &rule.condition {
+                Some(condition) => self
+                    .ctx
+                    .template_engine
+                    .render_cached(&style::condition_template(condition), ctx)
+                    .map(|result| style::is_truthy(&result))
+                    .unwrap_or(false),
+                None => rule.default,
+            })
+            .map(|rule| style::rule_style(rule, self.theme()))
+            .unwrap_or_default()
     }
 
     fn render_text(
@@ -2528,11 +7666,13 @@ impl App {
         area: Rect,
         text_config: &crate::config::schema::TextView,
     ) {
+        let ctx = self.ctx.clone();
+        let theme = ctx.themes.page(&self.current_page);
         let page_title = self.get_rendered_page_title();
 
         if self.current_data.is_empty() {
             let msg = Paragraph::new("No data")
-                .block(Block::default().borders(Borders::ALL).title(page_title));
+                .block(theme.block().title(page_title));
             frame.render_widget(msg, area);
             return;
         }
@@ -2541,19 +7681,7 @@ impl App {
         let item = &self.current_data[0];
 
         // Convert to string representation
-        let content_str = if item.is_string() {
-            // Already a string - check if it's JSON and re-format for proper indentation
-            let raw = item.as_str().unwrap_or("");
-            if let Ok(json_val) = serde_json::from_str::<Value>(raw) {
-                // Re-parse and pretty-print JSON
-                serde_json::to_string_pretty(&json_val).unwrap_or_else(|_| raw.to_string())
-            } else {
-                raw.to_string()
-            }
-        } else {
-            // Convert JSON object to formatted string
-            serde_json::to_string_pretty(item).unwrap_or_else(|_| "Failed to serialize".to_string())
-        };
+        let content_str = Self::text_content(item);
 
         // Auto-detect content type if not specified
         let detected_syntax: String = text_config
@@ -2564,7 +7692,7 @@ impl App {
 
         // Apply syntax highlighting
         let mut lines =
-            self.highlight_text(&content_str, &detected_syntax, text_config.line_numbers);
+            self.highlight_text(&content_str, &detected_syntax, text_config.line_numbers, theme);
 
         // Apply search filter if active
         if self.global_search.filter_active && !self.global_search.query.is_empty() {
@@ -2597,7 +7725,7 @@ impl App {
             .collect();
 
         let mut paragraph = Paragraph::new(visible_lines).block(
-            Block::default().borders(Borders::ALL).title(format!(
+            theme.block().title(format!(
                 "{} [{}] ({}/{})",
                 page_title,
                 detected_syntax,
@@ -2650,36 +7778,38 @@ impl App {
         content: &str,
         syntax: &str,
         line_numbers: bool,
+        theme: &Theme,
     ) -> Vec<Line<'static>> {
         let lines: Vec<&str> = content.lines().collect();
         let line_count = lines.len();
         let line_num_width = line_count.to_string().len();
 
-        lines
-            .iter()
-            .enumerate()
-            .map(|(idx, line)| {
-                let mut spans = Vec::new();
-
-                // Add line numbers if enabled
-                if line_numbers {
-                    spans.push(Span::styled(
-                        format!("{:>width$} │ ", idx + 1, width = line_num_width),
-                        Style::default().fg(Color::DarkGray),
-                    ));
-                }
-
+        lines
+            .iter()
+            .enumerate()
+            .map(|(idx, line)| {
                 // Apply syntax-specific highlighting
-                match syntax {
-                    "yaml" => spans.extend(self.highlight_yaml_line(line)),
-                    "json" => spans.extend(self.highlight_json_line(line)),
-                    "xml" => spans.extend(self.highlight_xml_line(line)),
-                    _ => spans.push(Span::raw(line.to_string())),
-                }
+                let mut spans = match syntax {
+                    "yaml" => Self::highlight_yaml_line(line, theme),
+                    "json" => Self::highlight_json_line(line, theme),
+                    "xml" => Self::highlight_xml_line(line, theme),
+                    _ => vec![Span::raw(line.to_string())],
+                };
 
-                // Highlight search matches over syntax colors
+                // Highlight search matches over syntax colors (not in the gutter)
                 if self.global_search.filter_active {
-                    spans = self.global_search.highlight_search_in_spans(spans);
+                    spans = self.global_search.highlight_search_in_spans(spans, theme);
+                }
+
+                // Add line numbers if enabled
+                if line_numbers {
+                    spans.insert(
+                        0,
+                        Span::styled(
+                            format!("{:>width$} │ ", idx + 1, width = line_num_width),
+                            Style::default().fg(theme.muted),
+                        ),
+                    );
                 }
 
                 Line::from(spans)
@@ -2688,14 +7818,14 @@ impl App {
     }
 
     /// Simple YAML syntax highlighting
-    fn highlight_yaml_line(&self, line: &str) -> Vec<Span<'static>> {
+    fn highlight_yaml_line(line: &str, theme: &Theme) -> Vec<Span<'static>> {
         let trimmed = line.trim_start();
 
         // Comments
         if trimmed.starts_with('#') {
             return vec![Span::styled(
                 line.to_string(),
-                Style::default().fg(Color::Green),
+                Style::default().fg(theme.success),
             )];
         }
 
@@ -2703,7 +7833,7 @@ impl App {
         if trimmed.starts_with("---") || trimmed.starts_with("...") {
             return vec![Span::styled(
                 line.to_string(),
-                Style::default().fg(Color::Magenta),
+                Style::default().fg(theme.secondary),
             )];
         }
 
@@ -2716,10 +7846,10 @@ impl App {
                 Span::styled(
                     key.to_string(),
                     Style::default()
-                        .fg(Color::Cyan)
+                        .fg(theme.accent)
                         .add_modifier(Modifier::BOLD),
                 ),
-                Span::styled(rest.to_string(), Style::default().fg(Color::White)),
+                Span::styled(rest.to_string(), Style::default().fg(theme.fg)),
             ]
         } else {
             vec![Span::raw(line.to_string())]
@@ -2727,7 +7857,7 @@ impl App {
     }
 
     /// Simple JSON syntax highlighting
-    fn highlight_json_line(&self, line: &str) -> Vec<Span<'static>> {
+    fn highlight_json_line(line: &str, theme: &Theme) -> Vec<Span<'static>> {
         let trimmed = line.trim();
 
         // Keys (quoted strings followed by colon)
@@ -2750,7 +7880,7 @@ impl App {
                             spans.push(Span::styled(
                                 line[idx..=close_pos].to_string(),
                                 Style::default()
-                                    .fg(Color::Cyan)
+                                    .fg(theme.accent)
                                     .add_modifier(Modifier::BOLD),
                             ));
                             current_pos = close_pos + 1;
@@ -2770,11 +7900,11 @@ impl App {
     }
 
     /// Simple XML syntax highlighting
-    fn highlight_xml_line(&self, line: &str) -> Vec<Span<'static>> {
+    fn highlight_xml_line(line: &str, theme: &Theme) -> Vec<Span<'static>> {
         if line.trim().starts_with('<') {
             vec![Span::styled(
                 line.to_string(),
-                Style::default().fg(Color::Magenta),
+                Style::default().fg(theme.secondary),
             )]
         } else {
             vec![Span::raw(line.to_string())]
@@ -2787,32 +7917,34 @@ impl App {
         area: Rect,
         _logs_config: &crate::config::schema::LogsView,
     ) {
+        let ctx = self.ctx.clone();
+        let theme = ctx.themes.page(&self.current_page);
         // Get the rendered page title
         let page_title = self.get_rendered_page_title();
 
         // For streaming logs, render from stream buffer
         if self.stream_active || !self.stream_buffer.is_empty() {
             // Use frozen snapshot when paused, otherwise use live buffer
-            let display_buffer: &VecDeque<LogLine> = if self.stream_paused {
-                if let Some(ref snapshot) = self.stream_frozen_snapshot {
-                    snapshot.as_ref()
-                } else {
-                    &self.stream_buffer
-                }
-            } else {
-                &self.stream_buffer
-            };
+            let display_buffer = self.display_buffer();
+            let buffer_len = display_buffer.len();
 
             if display_buffer.is_empty() {
-                let empty = Paragraph::new("Waiting for data...")
-                    .style(Style::default().fg(Color::Yellow))
-                    .block(Block::default().borders(Borders::ALL).title(page_title));
+                let waiting = match &self.stream_status {
+                    StreamStatus::Reconnecting { attempt, .. } => {
+                        format!("Reconnecting (attempt {})...", attempt)
+                    }
+                    _ => "Waiting for data...".to_string(),
+                };
+                let empty = Paragraph::new(waiting)
+                    .style(Style::default().fg(theme.warning))
+                    .block(theme.block().title(page_title));
                 frame.render_widget(empty, area);
                 return;
             }
 
-            // Filter logs using global search if active
-            let filtered_indices: Vec<usize> = if self.global_search.filter_active {
+            // Filter logs using global search if active (unless jumping between matches)
+            let filtering = self.global_search.filter_active && !self.logs_find;
+            let filtered_indices: Vec<usize> = if filtering {
                 display_buffer
                     .iter()
                     .enumerate()
@@ -2821,24 +7953,25 @@ impl App {
                     .collect()
             } else {
                 // No filter, use all indices
-                (0..display_buffer.len()).collect()
+                (0..buffer_len).collect()
             };
 
             // Calculate visible area
             let visible_height = area.height.saturating_sub(2) as usize; // Account for borders
 
             // When follow is enabled, snap to last filtered line (or last buffer line if no filter)
-            if self.logs_follow && !self.stream_paused {
-                if let Some(&last_idx) = filtered_indices.last() {
-                    self.selected_index = last_idx;
-                }
+            if self.logs_follow
+                && !self.stream_paused
+                && let Some(&last_idx) = filtered_indices.last()
+            {
+                self.selected_index = last_idx;
             }
 
             // Ensure selected_index is within bounds and lands on a filtered line
             if !filtered_indices.is_empty() {
                 // Clamp to buffer bounds first
-                if !display_buffer.is_empty() {
-                    self.selected_index = self.selected_index.min(display_buffer.len() - 1);
+                if buffer_len > 0 {
+                    self.selected_index = self.selected_index.min(buffer_len - 1);
                 }
                 // Snap to nearest filtered line if current index isn't in the filtered set
                 if !filtered_indices.contains(&self.selected_index) {
@@ -2848,8 +7981,8 @@ impl App {
                         .min_by_key(|&&idx| (idx as isize - self.selected_index as isize).unsigned_abs())
                         .unwrap();
                 }
-            } else if !display_buffer.is_empty() {
-                self.selected_index = self.selected_index.min(display_buffer.len() - 1);
+            } else if buffer_len > 0 {
+                self.selected_index = self.selected_index.min(buffer_len - 1);
             }
 
             // Find the position of selected_index in the filtered list
@@ -2870,8 +8003,11 @@ impl App {
             let _end_line = (start_line + visible_height).min(total_lines);
 
             // Build visible lines with optional timestamps and wrapping
+            let timestamps = self.log_timestamps();
+            let now = chrono::Local::now();
             let content_width = area.width.saturating_sub(4) as usize; // Account for borders and padding
             let mut lines: Vec<Line> = Vec::new();
+            let display_buffer = self.display_buffer();
 
             for &actual_idx in filtered_indices
                 .iter()
@@ -2890,13 +8026,22 @@ impl App {
 
                 // Highlight search matches in log line
                 if self.global_search.filter_active {
-                    parsed_line = Line::from(self.global_search.highlight_search_in_spans(parsed_line.spans));
+                    parsed_line = Line::from(
+                        self.global_search
+                            .highlight_search_in_spans(parsed_line.spans, theme),
+                    );
+                }
+
+                let prefix = timestamps.prefix(log_line.received, now);
+                if !prefix.is_empty() {
+                    let prefix = Span::styled(prefix, Style::default().fg(theme.muted));
+                    parsed_line.spans.insert(0, prefix);
                 }
 
                 // Apply selection highlighting if this is the selected line
                 if actual_idx == self.selected_index {
                     for span in &mut parsed_line.spans {
-                        span.style = span.style.bg(Color::DarkGray).add_modifier(Modifier::BOLD);
+                        span.style = span.style.bg(theme.selected).add_modifier(Modifier::BOLD);
                     }
                 }
 
@@ -2918,7 +8063,7 @@ impl App {
                         let mut result_spans: Vec<Span> = Vec::new();
 
                         if has_left {
-                            result_spans.push(Span::styled("< ", Style::default().fg(Color::DarkGray)));
+                            result_spans.push(Span::styled("< ", Style::default().fg(theme.muted)));
                         }
 
                         let truncated = Self::format_log_line(&parsed_line, scroll, available);
@@ -2926,7 +8071,7 @@ impl App {
                         result_spans.extend(truncated.spans);
 
                         if scroll + cols_taken < visual_width {
-                            result_spans.push(Span::styled(" >", Style::default().fg(Color::DarkGray)));
+                            result_spans.push(Span::styled(" >", Style::default().fg(theme.muted)));
                         }
 
                         lines.push(Line::from(result_spans));
@@ -2951,6 +8096,13 @@ impl App {
                     title_parts.push(format!(" ✗ ERROR: {}", err));
                     ""
                 }
+                StreamStatus::Reconnecting { attempt, reason, .. } => {
+                    title_parts.push(format!(" ↻ RECONNECTING (attempt {})", attempt));
+                    if let Some(reason) = reason {
+                        title_parts.push(format!(": {}", reason));
+                    }
+                    ""
+                }
                 _ => "",
             };
             if !status_str.is_empty() {
@@ -2969,8 +8121,15 @@ impl App {
                 title_parts.push(format!(" [{}]", settings.join("")));
             }
 
-            // Add filter count if search is active
-            if self.global_search.filter_active {
+            // Add filter count if search is active, or the match position when jumping
+            if self.logs_find {
+                let matches = self.log_matches().unwrap_or_default();
+                let position = matches
+                    .iter()
+                    .position(|&idx| idx == self.selected_index)
+                    .map_or("-".to_string(), |p| (p + 1).to_string());
+                title_parts.push(format!(" (match {}/{})", position, matches.len()));
+            } else if self.global_search.filter_active {
                 title_parts.push(format!(
                     " ({}/{})",
                     filtered_indices.len(),
@@ -2981,8 +8140,8 @@ impl App {
             let title_with_status = title_parts.join("");
 
             let mut logs = Paragraph::new(lines).block(
-                Block::default()
-                    .borders(Borders::ALL)
+                theme
+                    .block()
                     .title(title_with_status),
             );
 
@@ -2995,32 +8154,83 @@ impl App {
         } else {
             // Non-streaming logs view (not implemented yet)
             let msg = Paragraph::new("Non-streaming logs not yet implemented")
-                .style(Style::default().fg(Color::Yellow))
-                .block(Block::default().borders(Borders::ALL).title(page_title));
+                .style(Style::default().fg(theme.warning))
+                .block(theme.block().title(page_title));
             frame.render_widget(msg, area);
         }
     }
 
-    fn get_rendered_page_title(&self) -> String {
+    /// Render the page title against the current data: `items` plus the page's `summary`
+    /// values. Runs once per fetch rather than on every frame.
+    fn update_page_title(&mut self) {
+        self.page_title = None;
+        let Some(page) = self.ctx.config.pages.get(&self.current_page) else {
+            return;
+        };
+        // Titles that don't use templates render the same way every frame
+        if !crate::template::engine::TemplateEngine::is_template(&page.title) {
+            return;
+        }
+
+        let ctx = self.ctx.clone();
+        let engine = &ctx.template_engine;
+        let base = self
+            .create_template_context(None)
+            .with_page_context("items".to_string(), Value::Array(self.current_data.clone()));
+
+        let mut ctx = base.clone();
+        for (name, template) in &page.summary {
+            let rendered = engine
+                .render_string(template, &base)
+                .map(|s| s.trim().to_string())
+                .unwrap_or_else(|_| "?".to_string());
+            // Numeric results stay numbers so titles can compare them
+            let value = serde_json::from_str::<serde_json::Number>(&rendered)
+                .map(Value::Number)
+                .unwrap_or(Value::String(rendered));
+            ctx = ctx.with_page_context(name.clone(), value);
+        }
+
+        self.page_title = engine.render_string(&page.title, &ctx).ok();
+    }
+
+    /// The theme the current page is drawn with
+    fn theme(&self) -> &Theme {
+        self.ctx.themes.page(&self.current_page)
+    }
+
+    /// Show a toast (and record it in the history). Like the header message
+    /// it replaces, it ends any loading indicator.
+    fn notify(&mut self, message: impl Into<String>, kind: ToastKind) {
+        self.activity = ActivityState::Idle;
+        self.toasts.push(message, kind);
+        self.needs_render = true;
+    }
+
+    /// The current page's title with its templates rendered
+    fn rendered_page_title(&self) -> String {
         // Get current page config
-        let page = match globals::config().pages.get(&self.current_page) {
+        let page = match self.ctx.config.pages.get(&self.current_page) {
             Some(p) => p,
             None => return self.current_page.clone(), // Fallback to page ID
         };
 
-        // Render the page title with template context
-        let ctx = self.create_template_context(None);
-        let mut title = globals::template_engine()
-            .render_string(&page.title, &ctx)
-            .unwrap_or_else(|_| page.title.clone());
+        // Use the title rendered after the last fetch, else render with template context
+        self.page_title.clone().unwrap_or_else(|| {
+            let ctx = self.create_template_context(None);
+            self.ctx
+                .template_engine
+                .render_string(&page.title, &ctx)
+                .unwrap_or_else(|_| page.title.clone())
+        })
+    }
+
+    fn get_rendered_page_title(&self) -> String {
+        let mut title = self.rendered_page_title();
 
         // Add search filter tag if active (but not during input)
         if self.global_search.filter_active && !self.global_search.active {
-            let filter_display = if self.global_search.query.len() > 25 {
-                format!("{}...", &self.global_search.query[..22])
-            } else {
-                self.global_search.query.clone()
-            };
+            let filter_display = crate::util::text::truncate(&self.global_search.query, 25);
 
             let mode_indicator = if self.global_search.query.starts_with('!') {
                 "~/" // regex
@@ -3035,27 +8245,46 @@ impl App {
     }
 
     fn render_statusbar(&self, frame: &mut Frame, area: Rect) {
+        let theme = self.theme();
         // Build navigation shortcuts based on view type
-        let view_kind = globals::config()
+        let view_kind = self
+            .ctx
+            .config
             .pages
             .get(&self.current_page)
             .map(|p| match &p.view {
                 ConfigView::Table(_) => "table",
                 ConfigView::Logs(_) => "logs",
                 ConfigView::Text(_) => "text",
+                ConfigView::Chart(_) => "chart",
+                ConfigView::BarGauge(_) | ConfigView::Gauge(_) => "gauge",
+                ConfigView::Histogram(_) | ConfigView::Heatmap(_) => "gauge",
+                ConfigView::Split(_) => "split",
+                ConfigView::Dashboard(_) => "dashboard",
+                ConfigView::Form(_) => "form",
             });
 
         let nav_shortcuts = match view_kind.unwrap_or("table") {
             "logs" => {
                 let has_buffer = self.stream_active || !self.stream_buffer.is_empty();
                 if has_buffer && !self.logs_wrap {
-                    "j/k: Scroll  |  h/l: Side-scroll  |  g/G: Top/Bottom  |  /: Search  |  f: LIVE/Pause  |  w: Wrap  |  r: Restart  |  ESC: Back  |  q: Quit"
+                    "j/k: Scroll  |  h/l: Side-scroll  |  g/G: Top/Bottom  |  /: Search  |  f: LIVE/Pause  |  w: Wrap  |  t: Time  |  S: Save  |  r: Restart  |  ESC: Back  |  q: Quit"
                 } else if has_buffer {
-                    "j/k: Scroll  |  g/G: Top/Bottom  |  /: Search  |  f: LIVE/Pause  |  w: Wrap  |  r: Restart  |  ESC: Back  |  q: Quit"
+                    "j/k: Scroll  |  g/G: Top/Bottom  |  /: Search  |  f: LIVE/Pause  |  w: Wrap  |  t: Time  |  S: Save  |  r: Restart  |  ESC: Back  |  q: Quit"
                 } else {
                     "q/ESC: Quit  |  r: Refresh"
                 }
             }
+            "chart" => "r: Refresh  |  ESC: Back  |  q: Quit",
+            "gauge" => "/: Search  |  r: Refresh  |  ESC: Back  |  q: Quit",
+            "dashboard" => "r: Refresh all panels  |  ESC: Back  |  q: Quit",
+            "form" => "Tab/↑↓: Field  |  ←/→/Space: Change  |  Enter: Submit  |  ESC: Back  |  Ctrl+C: Quit",
+            "split" if self.detail_pane.focused => {
+                "j/k: Scroll  |  g/G: Top/Bottom  |  Shift+Tab: Table  |  ESC: Back  |  q: Quit"
+            }
+            "split" if !self.current_data.is_empty() => {
+                "j/k: Move  |  Enter: Select  |  Shift+Tab: Detail  |  /: Search  |  ESC: Back  |  r: Refresh  |  q: Quit"
+            }
             "text" => {
                 if self.current_data.is_empty() {
                     "q/ESC: Quit  |  r: Refresh"
@@ -3068,12 +8297,27 @@ impl App {
                 if self.current_data.is_empty() {
                     "q/ESC: Quit  |  r: Refresh"
                 } else {
-                    "j/k: Move  |  g/G: Top/Bottom  |  Enter: Select  |  /: Search (%col% term)  |  ESC: Back  |  r: Refresh  |  q: Quit"
+                    "j/k: Move  |  g/G: Top/Bottom  |  Enter: Select  |  m: Menu  |  /: Search (%col% term)  |  ESC: Back  |  r: Refresh  |  q: Quit"
                 }
             }
         };
 
-        let row_info = if (self.stream_active || !self.stream_buffer.is_empty())
+        let row_info = if view_kind == Some("form") {
+            let fields = self.form.as_ref().map_or(0, |f| f.values.len());
+            let focused = self.form.as_ref().map_or(0, |f| f.focused + 1);
+            format!("Field {}/{}", focused, fields)
+        } else if view_kind == Some("dashboard") {
+            let failed = self.dashboard_panels.iter().filter(|p| p.error.is_some()).count();
+            match failed {
+                0 => format!("Panels: {}", self.dashboard_panels.len()),
+                _ => format!("Panels: {} ({} failed)", self.dashboard_panels.len(), failed),
+            }
+        } else if view_kind == Some("chart") {
+            let points = self.chart_data.get(&self.current_page).map_or(0, |data| {
+                data.series.iter().map(Vec::len).sum::<usize>()
+            });
+            format!("Points: {}", points)
+        } else if (self.stream_active || !self.stream_buffer.is_empty())
             && self.global_search.filter_active
         {
             // Logs view with filter: show filtered count
@@ -3087,7 +8331,21 @@ impl App {
             } else {
                 self.stream_buffer.len()
             };
-            if let Some(filtered) = self.get_logs_filtered_indices() {
+            if self.logs_find
+                && let Some(matches) = self.log_matches()
+            {
+                let match_pos = matches
+                    .iter()
+                    .position(|&idx| idx == self.selected_index)
+                    .map_or("-".to_string(), |p| (p + 1).to_string());
+                format!(
+                    "Match {}/{} | Line {}/{}",
+                    match_pos,
+                    matches.len(),
+                    self.selected_index + 1,
+                    buffer_len
+                )
+            } else if let Some(filtered) = self.get_logs_filtered_indices() {
                 let filter_pos = filtered
                     .iter()
                     .position(|&idx| idx == self.selected_index)
@@ -3137,19 +8395,37 @@ impl App {
             )
         };
 
-        let nav_line = Line::from(vec![
+        let mut nav_spans = Vec::new();
+        if self.pending_chord.is_some() || self.count_prefix.is_some() {
+            // Pending count / chord indicator, e.g. "5 g …" while waiting for the next key
+            let mut pending: Vec<String> =
+                self.count_prefix.iter().map(|n| n.to_string()).collect();
+            if let Some(chord) = &self.pending_chord {
+                pending.push(crate::input::keymap::display_sequence(&chord.keys));
+            }
+            nav_spans.push(Span::styled(
+                format!(" {} … ", pending.join(" ")),
+                Style::default()
+                    .fg(theme.inverse)
+                    .bg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            nav_spans.push(Span::raw(" "));
+        }
+        nav_spans.extend([
             Span::styled(
                 row_info,
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(theme.accent)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::raw(" | "),
-            Span::styled(nav_shortcuts, Style::default().fg(Color::White)),
+            Span::styled(nav_shortcuts, Style::default().fg(theme.fg)),
         ]);
+        let nav_line = Line::from(nav_spans);
 
         // Build hints line (next page indicator + action hint)
-        let action_line = if let Some(page) = globals::config().pages.get(&self.current_page) {
+        let action_line = if let Some(page) = self.ctx.config.pages.get(&self.current_page) {
             use crate::config::Navigation;
             let mut hint_spans: Vec<Span> = Vec::new();
 
@@ -3171,32 +8447,51 @@ impl App {
                     hint_spans.push(Span::styled(
                         "Enter",
                         Style::default()
-                            .fg(Color::Cyan)
+                            .fg(theme.accent)
                             .add_modifier(Modifier::BOLD),
                     ));
                     hint_spans.push(Span::styled(
                         format!(" → {}", next_label),
-                        Style::default().fg(Color::DarkGray),
+                        Style::default().fg(theme.muted),
                     ));
                 }
             }
 
+            // Link hint
+            if let Some(table_view) = page.view.table()
+                && table_view.columns.iter().any(|c| c.link.is_some())
+            {
+                if !hint_spans.is_empty() {
+                    hint_spans.push(Span::styled(
+                        "  |  ",
+                        Style::default().fg(theme.muted),
+                    ));
+                }
+                hint_spans.push(Span::styled(
+                    "o",
+                    Style::default()
+                        .fg(theme.accent)
+                        .add_modifier(Modifier::BOLD),
+                ));
+                hint_spans.push(Span::styled(" → open link", Style::default().fg(theme.muted)));
+            }
+
             // Action hint
-            if page.actions.as_ref().map(|a| !a.is_empty()).unwrap_or(false) {
+            if !self.page_actions().is_empty() {
                 if !hint_spans.is_empty() {
                     hint_spans.push(Span::styled(
                         "  |  ",
-                        Style::default().fg(Color::DarkGray),
+                        Style::default().fg(theme.muted),
                     ));
                 }
-                hint_spans.push(Span::styled("Press ", Style::default().fg(Color::DarkGray)));
+                hint_spans.push(Span::styled("Press ", Style::default().fg(theme.muted)));
                 hint_spans.push(Span::styled(
                     "Shift+A",
                     Style::default()
-                        .fg(Color::Cyan)
+                        .fg(theme.accent)
                         .add_modifier(Modifier::BOLD),
                 ));
-                hint_spans.push(Span::styled(" for actions", Style::default().fg(Color::DarkGray)));
+                hint_spans.push(Span::styled(" for actions", Style::default().fg(theme.muted)));
             }
 
             Line::from(hint_spans)
@@ -3205,30 +8500,125 @@ impl App {
         };
 
         let status = Paragraph::new(vec![nav_line, action_line])
-            .style(Style::default().fg(Color::White))
-            .block(Block::default().borders(Borders::ALL).title("Status"));
+            .style(Style::default().fg(theme.fg))
+            .block(
+                theme
+                    .block()
+                    .title(match (self.safe_mode, self.read_only) {
+                        (false, false) => "Status",
+                        (true, false) => "Status [SAFE MODE]",
+                        (false, true) => "Status [READ-ONLY]",
+                        (true, true) => "Status [SAFE MODE] [READ-ONLY]",
+                    })
+                    .title(
+                        Line::from(self.refresh_status().unwrap_or_default())
+                            .right_aligned(),
+                    ),
+            );
 
         frame.render_widget(status, area);
     }
 
 
     fn render_action_menu(&self, frame: &mut Frame, area: Rect) {
+        let theme = self.theme();
         use ratatui::layout::Alignment;
         use ratatui::widgets::Clear;
 
         // Get actions for current page
-        let page = match globals::config().pages.get(&self.current_page) {
-            Some(p) => p,
-            None => return,
+        let actions = self.page_actions();
+        if actions.is_empty() {
+            return;
+        }
+
+        // Get selected row to show resource context in title
+        let resource_name = self.selected_resource_name();
+
+        // Calculate popup size based on number of actions
+        let num_actions = actions.len();
+        let popup_height = (num_actions + 5).min(area.height.saturating_sub(4) as usize) as u16;
+        let popup_width = 70.min(area.width.saturating_sub(4));
+        let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+        let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+        let popup_area = Rect {
+            x: popup_x,
+            y: popup_y,
+            width: popup_width,
+            height: popup_height,
         };
 
-        let actions = match &page.actions {
-            Some(a) if !a.is_empty() => a,
-            _ => return,
+        // Clear the background area to hide content behind
+        frame.render_widget(Clear, popup_area);
+
+        // Build the menu lines
+        let mut menu_lines = vec![Line::from("")];
+
+        for (idx, action) in actions.iter().enumerate() {
+            // Parse the key to display it properly
+            let key_display = action.parse_key()
+                .map(|k| k.display())
+                .unwrap_or_else(|_| action.key.clone());
+
+            let description = action.description.as_deref().unwrap_or(&action.name);
+            let line_text = format!("  {} - {}", key_display, description);
+
+            // Highlight selected action
+            let line = if idx == self.action_menu_selected {
+                Line::from(Span::styled(
+                    format!("> {}", line_text.trim_start()),
+                    Style::default()
+                        .fg(theme.inverse)
+                        .bg(theme.accent)
+                        .add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(Span::styled(
+                    line_text,
+                    Style::default().fg(theme.fg),
+                ))
+            };
+
+            menu_lines.push(line);
+        }
+
+        // Add navigation instructions
+        menu_lines.push(Line::from(""));
+        menu_lines.push(Line::from(Span::styled(
+            "↑↓/jk: Navigate | Enter/Ctrl+Key: Execute | Esc: Cancel",
+            Style::default()
+                .fg(theme.muted)
+                .add_modifier(Modifier::ITALIC),
+        )));
+
+        // Build title with resource context if available
+        let title = if let Some(name) = resource_name {
+            format!(" Actions for: {} ", name)
+        } else {
+            " Actions ".to_string()
         };
 
-        // Get selected row to show resource context in title
-        let resource_name = self.get_selected_row().and_then(|row| {
+        let menu = Paragraph::new(menu_lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(theme.accent))
+                    .style(Style::default().bg(theme.bg))
+                    .title(Span::styled(
+                        title,
+                        Style::default()
+                            .fg(theme.accent)
+                            .add_modifier(Modifier::BOLD),
+                    )),
+            )
+            .alignment(Alignment::Left);
+
+        frame.render_widget(menu, popup_area);
+    }
+
+    /// Best-effort display name for the selected row (name, metadata.name, id or title)
+    fn selected_resource_name(&self) -> Option<String> {
+        self.get_selected_row().and_then(|row| {
             // Try common name fields in order of preference
             row.get("name")
                 .or_else(|| row.pointer("/metadata/name"))
@@ -3236,91 +8626,353 @@ impl App {
                 .or_else(|| row.get("title"))
                 .and_then(|v| v.as_str())
                 .map(|s| s.to_string())
-        });
+        })
+    }
+
+    fn render_context_menu(&self, frame: &mut Frame, area: Rect, menu: &ContextMenu) {
+        let theme = self.theme();
+        use ratatui::layout::Alignment;
+        use ratatui::widgets::Clear;
+
+        let popup_height =
+            (menu.entries.len() + 5).min(area.height.saturating_sub(4) as usize) as u16;
+        let popup_width = 70.min(area.width.saturating_sub(4));
+        let popup_area = Rect {
+            x: (area.width.saturating_sub(popup_width)) / 2,
+            y: (area.height.saturating_sub(popup_height)) / 2,
+            width: popup_width,
+            height: popup_height,
+        };
+
+        frame.render_widget(Clear, popup_area);
+
+        let mut menu_lines = vec![Line::from("")];
+
+        for (idx, entry) in menu.entries.iter().enumerate() {
+            let line_text = match entry {
+                ContextMenuEntry::Navigate { page, .. } => {
+                    let title = self
+                        .ctx
+                        .config
+                        .pages
+                        .get(page)
+                        .map(|p| p.title.as_str())
+                        .unwrap_or(page);
+                    format!("  → {} ({})", title, page)
+                }
+                ContextMenuEntry::Action(action) => {
+                    let key_display = action
+                        .parse_key()
+                        .map(|k| k.display())
+                        .unwrap_or_else(|_| action.key.clone());
+                    let description = action.description.as_deref().unwrap_or(&action.name);
+                    format!("  {} - {}", key_display, description)
+                }
+            };
+
+            let line = if idx == menu.selected {
+                Line::from(Span::styled(
+                    format!("> {}", line_text.trim_start()),
+                    Style::default()
+                        .fg(theme.inverse)
+                        .bg(theme.accent)
+                        .add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(Span::styled(line_text, Style::default().fg(theme.fg)))
+            };
+
+            menu_lines.push(line);
+        }
+
+        menu_lines.push(Line::from(""));
+        menu_lines.push(Line::from(Span::styled(
+            "↑↓/jk: Navigate | Enter: Select | Esc/m: Close",
+            Style::default()
+                .fg(theme.muted)
+                .add_modifier(Modifier::ITALIC),
+        )));
+
+        let title = match self.selected_resource_name() {
+            Some(name) => format!(" {} ", name),
+            None => " Menu ".to_string(),
+        };
+
+        let widget = Paragraph::new(menu_lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(theme.accent))
+                    .style(Style::default().bg(theme.bg))
+                    .title(Span::styled(
+                        title,
+                        Style::default()
+                            .fg(theme.accent)
+                            .add_modifier(Modifier::BOLD),
+                    )),
+            )
+            .alignment(Alignment::Left);
+
+        frame.render_widget(widget, popup_area);
+    }
+
+    fn render_value_popup(&self, frame: &mut Frame, area: Rect, popup: &ValuePopup) {
+        let theme = self.theme();
+        use ratatui::widgets::{Clear, Wrap};
+
+        let popup_area = crate::ui::layout::centered_rect(area);
+        frame.render_widget(Clear, popup_area);
+
+        let hint = Line::from(Span::styled(
+            " y: copy  j/k: scroll  Esc: close ",
+            Style::default().fg(theme.muted),
+        ));
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.accent))
+            .title(format!(" {} ", popup.title))
+            .title_bottom(hint.right_aligned());
+        let text = if popup.text.is_empty() {
+            ratatui::text::Text::from(Span::styled("(empty)", Style::default().fg(theme.muted)))
+        } else {
+            ratatui::text::Text::from(popup.text.as_str())
+        };
+        let paragraph = Paragraph::new(text)
+            .block(block)
+            .wrap(Wrap { trim: false })
+            .scroll((popup.scroll, 0));
+        frame.render_widget(paragraph, popup_area);
+    }
+
+    fn render_notification_history(&self, frame: &mut Frame, area: Rect, scroll: u16) {
+        let theme = self.theme();
+        use ratatui::widgets::Clear;
+
+        let popup_area = crate::ui::layout::centered_rect(area);
+        frame.render_widget(Clear, popup_area);
+
+        let hint = Line::from(Span::styled(
+            " j/k: scroll  Esc: close ",
+            Style::default().fg(theme.muted),
+        ));
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.accent))
+            .title(format!(" Notifications ({}) ", self.toasts.history_len()))
+            .title_bottom(hint.right_aligned());
+        let lines = self.toasts.history_lines(theme);
+        let text = if lines.is_empty() {
+            ratatui::text::Text::from(Span::styled(
+                "No notifications yet",
+                Style::default().fg(theme.muted),
+            ))
+        } else {
+            ratatui::text::Text::from(lines)
+        };
+        let paragraph = Paragraph::new(text).block(block).scroll((scroll, 0));
+        frame.render_widget(paragraph, popup_area);
+    }
+
+    fn render_history_picker(&self, frame: &mut Frame, area: Rect, selected: usize) {
+        let theme = self.theme();
+        use ratatui::widgets::{Clear, List, ListItem, ListState};
+
+        let popup_area = crate::ui::layout::centered_rect(area);
+        frame.render_widget(Clear, popup_area);
+
+        let hint = Line::from(Span::styled(
+            " j/k: select  Enter: open  Esc: close ",
+            Style::default().fg(theme.muted),
+        ));
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.accent))
+            .style(theme.base())
+            .title(format!(" Visited pages ({}) ", self.visits.len()))
+            .title_bottom(hint.right_aligned());
+        let items: Vec<ListItem> = self
+            .visits
+            .visits()
+            .map(|visit| {
+                let mut spans = vec![
+                    Span::styled(
+                        format!("{} ", visit.time.format("%H:%M:%S")),
+                        Style::default().fg(theme.muted),
+                    ),
+                    Span::styled(visit.label.clone(), Style::default().fg(theme.fg)),
+                ];
+                if visit.label != visit.page_id {
+                    spans.push(Span::styled(
+                        format!("  {}", visit.page_id),
+                        Style::default().fg(theme.muted),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(
+                Style::default()
+                    .bg(theme.selected)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("> ");
+        let mut state = ListState::default().with_selected(Some(selected));
+        frame.render_stateful_widget(list, popup_area, &mut state);
+    }
+
+    fn render_bookmark_picker(&self, frame: &mut Frame, area: Rect, selected: usize) {
+        let theme = self.theme();
+        use ratatui::widgets::{Clear, List, ListItem, ListState};
+
+        let popup_area = crate::ui::layout::centered_rect(area);
+        frame.render_widget(Clear, popup_area);
+
+        let hint = Line::from(Span::styled(
+            " j/k: select  Enter: open  d: delete  Esc: close ",
+            Style::default().fg(theme.muted),
+        ));
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.accent))
+            .style(theme.base())
+            .title(format!(" Bookmarks ({}) ", self.bookmarks.len()))
+            .title_bottom(hint.right_aligned());
+        if self.bookmarks.is_empty() {
+            let empty = Paragraph::new(Span::styled(
+                "No bookmarks yet (B bookmarks the current page)",
+                Style::default().fg(theme.muted),
+            ))
+            .block(block);
+            frame.render_widget(empty, popup_area);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .bookmarks
+            .iter()
+            .map(|bookmark| {
+                let mut spans = vec![Span::styled(
+                    bookmark.label.clone(),
+                    Style::default().fg(theme.fg),
+                )];
+                if bookmark.label != bookmark.page_id {
+                    spans.push(Span::styled(
+                        format!("  {}", bookmark.page_id),
+                        Style::default().fg(theme.muted),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(
+                Style::default()
+                    .bg(theme.selected)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("> ");
+        let mut state = ListState::default().with_selected(Some(selected));
+        frame.render_stateful_widget(list, popup_area, &mut state);
+    }
 
-        // Calculate popup size based on number of actions
-        let num_actions = actions.len();
-        let popup_height = (num_actions + 5).min(area.height.saturating_sub(4) as usize) as u16;
-        let popup_width = 70.min(area.width.saturating_sub(4));
-        let popup_x = (area.width.saturating_sub(popup_width)) / 2;
-        let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    fn render_command_palette(&self, frame: &mut Frame, area: Rect, palette: &CommandPalette) {
+        let theme = self.theme();
+        use ratatui::widgets::Clear;
+
+        const MAX_VISIBLE: usize = 12;
+
+        let entries = self.palette_entries(&palette.query);
+        let selected = palette.selected.min(entries.len().saturating_sub(1));
+        let visible = entries.len().clamp(1, MAX_VISIBLE);
 
+        let popup_width = 70.min(area.width.saturating_sub(4));
+        let popup_height = (visible as u16 + 4).min(area.height.saturating_sub(2));
         let popup_area = Rect {
-            x: popup_x,
-            y: popup_y,
+            x: (area.width.saturating_sub(popup_width)) / 2,
+            y: area.height / 6,
             width: popup_width,
             height: popup_height,
         };
 
-        // Clear the background area to hide content behind
         frame.render_widget(Clear, popup_area);
 
-        // Build the menu lines
-        let mut menu_lines = vec![Line::from("")];
-
-        for (idx, action) in actions.iter().enumerate() {
-            // Parse the key to display it properly
-            let key_display = action.parse_key()
-                .map(|k| k.display())
-                .unwrap_or_else(|_| action.key.clone());
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled(
+                    ": ",
+                    Style::default()
+                        .fg(theme.accent)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(palette.query.clone(), Style::default().fg(theme.fg)),
+                Span::styled("█", Style::default().fg(theme.accent)),
+            ]),
+            Line::from(""),
+        ];
 
-            let description = action.description.as_deref().unwrap_or(&action.name);
-            let line_text = format!("  {} - {}", key_display, description);
+        if entries.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  No matches",
+                Style::default()
+                    .fg(theme.muted)
+                    .add_modifier(Modifier::ITALIC),
+            )));
+        }
 
-            // Highlight selected action
-            let line = if idx == self.action_menu_selected {
-                Line::from(Span::styled(
-                    format!("> {}", line_text.trim_start()),
+        // Keep the selection in view
+        let start = (selected + 1).saturating_sub(visible);
+        for (idx, entry) in entries.iter().enumerate().skip(start).take(visible) {
+            let kind_color = match entry.kind {
+                "page" => theme.success,
+                "action" => theme.secondary,
+                "jump" => theme.warning,
+                _ => theme.info,
+            };
+            let line = Line::from(vec![
+                Span::styled(format!(" {:<7}", entry.kind), Style::default().fg(kind_color)),
+                Span::raw(entry.label.clone()),
+                Span::styled(
+                    format!("  {}", entry.hint),
+                    Style::default().fg(theme.muted),
+                ),
+            ]);
+            lines.push(if idx == selected {
+                line.style(
                     Style::default()
-                        .fg(Color::Black)
-                        .bg(Color::Cyan)
+                        .bg(theme.accent)
+                        .fg(theme.inverse)
                         .add_modifier(Modifier::BOLD),
-                ))
+                )
             } else {
-                Line::from(Span::styled(
-                    line_text,
-                    Style::default().fg(Color::White),
-                ))
-            };
-
-            menu_lines.push(line);
+                line
+            });
         }
 
-        // Add navigation instructions
-        menu_lines.push(Line::from(""));
-        menu_lines.push(Line::from(Span::styled(
-            "↑↓/jk: Navigate | Enter/Ctrl+Key: Execute | Esc: Cancel",
-            Style::default()
-                .fg(Color::DarkGray)
-                .add_modifier(Modifier::ITALIC),
-        )));
-
-        // Build title with resource context if available
-        let title = if let Some(name) = resource_name {
-            format!(" Actions for: {} ", name)
-        } else {
-            " Actions ".to_string()
-        };
-
-        let menu = Paragraph::new(menu_lines)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_style(Style::default().fg(Color::Cyan))
-                    .style(Style::default().bg(Color::Black))
-                    .title(Span::styled(
-                        title,
-                        Style::default()
-                            .fg(Color::Cyan)
-                            .add_modifier(Modifier::BOLD),
-                    )),
-            )
-            .alignment(Alignment::Left);
+        let widget = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .style(Style::default().bg(theme.bg))
+                .title(Span::styled(
+                    " Command Palette ",
+                    Style::default()
+                        .fg(theme.accent)
+                        .add_modifier(Modifier::BOLD),
+                ))
+                .title_bottom(Line::from(Span::styled(
+                    " ↑↓/Tab: Navigate | Enter: Run | Esc: Close ",
+                    Style::default().fg(theme.muted),
+                ))),
+        );
 
-        frame.render_widget(menu, popup_area);
+        frame.render_widget(widget, popup_area);
     }
 
     fn render_action_confirm(&self, frame: &mut Frame, area: Rect, confirm: &ActionConfirm) {
+        let theme = self.theme();
         use ratatui::layout::Alignment;
         use ratatui::widgets::Clear;
 
@@ -3352,13 +9004,13 @@ impl App {
                 Line::from(Span::styled(
                     format!("{} {}", spinner_char, action_name),
                     Style::default()
-                        .fg(Color::Yellow)
+                        .fg(theme.warning)
                         .add_modifier(Modifier::BOLD),
                 )),
                 Line::from(""),
                 Line::from(Span::styled(
                     "Please wait...",
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme.muted),
                 )),
                 Line::from(""),
             ];
@@ -3367,8 +9019,8 @@ impl App {
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
-                        .border_style(Style::default().fg(Color::Yellow))
-                        .style(Style::default().bg(Color::Black))
+                        .border_style(Style::default().fg(theme.warning))
+                        .style(Style::default().bg(theme.bg))
                         .title("Executing Action"),
                 )
                 .alignment(Alignment::Center);
@@ -3381,13 +9033,13 @@ impl App {
                 Line::from(Span::styled(
                     &confirm.message,
                     Style::default()
-                        .fg(Color::Yellow)
+                        .fg(theme.warning)
                         .add_modifier(Modifier::BOLD),
                 )),
                 Line::from(""),
                 Line::from(Span::styled(
                     format!("Action: {}", confirm.action.name),
-                    Style::default().fg(Color::Cyan),
+                    Style::default().fg(theme.accent),
                 )),
                 Line::from(""),
                 Line::from(Span::raw("Press 'y' to confirm, 'n' or ESC to cancel")),
@@ -3398,8 +9050,8 @@ impl App {
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
-                        .border_style(Style::default().fg(Color::Yellow))
-                        .style(Style::default().bg(Color::Black))
+                        .border_style(Style::default().fg(theme.warning))
+                        .style(Style::default().bg(theme.bg))
                         .title("Confirm Action"),
                 )
                 .alignment(Alignment::Center);
@@ -3409,6 +9061,7 @@ impl App {
     }
 
     fn render_quit_confirm(&self, frame: &mut Frame, area: Rect) {
+        let theme = self.theme();
         use ratatui::layout::Alignment;
         use ratatui::widgets::Clear;
 
@@ -3434,7 +9087,7 @@ impl App {
             Line::from(Span::styled(
                 "Quit TermStack?",
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(theme.warning)
                     .add_modifier(Modifier::BOLD),
             )),
             Line::from(""),
@@ -3446,8 +9099,8 @@ impl App {
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(Color::Yellow))
-                    .style(Style::default().bg(Color::Black))
+                    .border_style(Style::default().fg(theme.warning))
+                    .style(Style::default().bg(theme.bg))
                     .title("Confirm"),
             )
             .alignment(Alignment::Center);
@@ -3457,11 +9110,11 @@ impl App {
 
     /// Update search mode based on current query and table columns (live as user types)
     fn update_search_mode(&mut self) {
-        if let Some(page) = globals::config().pages.get(&self.current_page) {
-            if let ConfigView::Table(table_view) = &page.view {
-                self.global_search.mode = self.global_search.parse_mode(&table_view.columns);
-                return;
-            }
+        if let Some(page) = self.ctx.config.pages.get(&self.current_page)
+            && let Some(table_view) = page.view.table()
+        {
+            self.global_search.mode = self.global_search.parse_mode(&table_view.columns);
+            return;
         }
         self.global_search.mode = SearchMode::Global;
     }
@@ -3473,15 +9126,7 @@ impl App {
         // Apply global search filter if active
         if self.global_search.filter_active {
             // Get table columns if in table view
-            let table_columns = if let Some(page) = globals::config().pages.get(&self.current_page) {
-                if let ConfigView::Table(table_view) = &page.view {
-                    Some(&table_view.columns)
-                } else {
-                    None
-                }
-            } else {
-                None
-            };
+            let table_columns = self.current_table_view().map(|table_view| &table_view.columns);
 
             // Parse search mode with column context
             if let Some(columns) = table_columns {
@@ -3494,15 +9139,16 @@ impl App {
             indices = self.filter_data_indices(&indices);
         }
 
-        // Apply sorting if configured
-        if let Some(page) = globals::config().pages.get(&self.current_page)
-            && let ConfigView::Table(table_view) = &page.view
-            && let Some(sort_config) = &table_view.sort
+        // Apply sorting if configured (or picked at runtime)
+        if let Some(table_view) = self.current_table_view()
+            && let Some(sort_config) = self.effective_sort(table_view)
         {
-            self.sort_data_indices(&mut indices, sort_config);
+            let column = table_view.columns.iter().find(|c| c.id() == sort_config.column);
+            self.sort_data_indices(&mut indices, &sort_config, column);
         }
 
         self.filtered_indices = indices;
+        self.data_generation += 1;
     }
 
     fn filter_data_indices(&self, indices: &[usize]) -> Vec<usize> {
@@ -3516,9 +9162,24 @@ impl App {
                             let item_text = self.item_to_searchable_text(item);
                             self.global_search.matches(&item_text)
                         }
-                        SearchMode::ColumnSpecific { column_path, search_term, .. } => {
+                        SearchMode::ColumnSpecific { column_display_name, search_term, .. } => {
                             // New: search specific column only
-                            self.matches_column_value(item, column_path, search_term)
+                            let value = self.current_table_view().and_then(|table| {
+                                let col = table
+                                    .columns
+                                    .iter()
+                                    .find(|c| c.display == *column_display_name)?;
+                                self.column_value(col, item)
+                            });
+                            self.matches_column_value(value, search_term)
+                        }
+                        SearchMode::Filters { filters, text } => {
+                            filters.iter().all(|f| self.matches_filter(f, item))
+                                && (text.is_empty()
+                                    || self.matches_column_value(
+                                        Some(Value::String(self.item_to_searchable_text(item))),
+                                        text,
+                                    ))
                         }
                     }
                 } else {
@@ -3572,33 +9233,50 @@ impl App {
         buffer
     }
 
+    /// Whether a row passes a `column<op>value` filter. Rows without a value in
+    /// the column only pass `!=`.
+    fn matches_filter(&self, column_filter: &ColumnFilter, item: &Value) -> bool {
+        let cell = self.current_table_view().and_then(|table| {
+            let col = table
+                .columns
+                .iter()
+                .find(|c| c.display == column_filter.column_display_name)?;
+            self.column_value(col, item)
+        });
+        match cell {
+            Some(value) if !value.is_null() => column_filter.op.matches(
+                &value_to_string(&value),
+                &column_filter.value,
+                self.global_search.case_sensitive,
+                column_filter.regex.as_ref(),
+            ),
+            _ => column_filter.op == FilterOp::NotEquals,
+        }
+    }
+
     /// Match a specific column value against a search term
-    fn matches_column_value(&self, item: &Value, column_path: &str, search_term: &str) -> bool {
-        // Extract column value using JSONPath
-        if let Ok(extractor) = JsonPathExtractor::new(column_path) {
-            if let Ok(Some(value)) = extractor.extract_single(item) {
-                // Convert value to string
-                let value_str = match value {
-                    Value::String(s) => s.to_string(),
-                    Value::Number(n) => n.to_string(),
-                    Value::Bool(b) => b.to_string(),
-                    _ => return false,
-                };
+    fn matches_column_value(&self, value: Option<Value>, search_term: &str) -> bool {
+        if let Some(value) = value {
+            // Convert value to string
+            let value_str = match value {
+                Value::String(s) => s.to_string(),
+                Value::Number(n) => n.to_string(),
+                Value::Bool(b) => b.to_string(),
+                _ => return false,
+            };
 
-                // Check if search term starts with '!' for regex mode
-                if search_term.starts_with('!') {
-                    // Regex matching
-                    let pattern = &search_term[1..];
-                    if let Ok(regex) = Regex::new(pattern) {
-                        return regex.is_match(&value_str);
-                    }
+            // Check if search term starts with '!' for regex mode
+            if let Some(pattern) = search_term.strip_prefix('!') {
+                // Regex matching
+                if let Ok(regex) = Regex::new(pattern) {
+                    return regex.is_match(&value_str);
+                }
+            } else {
+                // Literal string matching (case-insensitive by default)
+                if self.global_search.case_sensitive {
+                    return value_str.contains(search_term);
                 } else {
-                    // Literal string matching (case-insensitive by default)
-                    if self.global_search.case_sensitive {
-                        return value_str.contains(search_term);
-                    } else {
-                        return value_str.to_lowercase().contains(&search_term.to_lowercase());
-                    }
+                    return value_str.to_lowercase().contains(&search_term.to_lowercase());
                 }
             }
         }
@@ -3609,60 +9287,53 @@ impl App {
         &self,
         indices: &mut [usize],
         sort_config: &crate::config::schema::TableSort,
+        column: Option<&TableColumn>,
     ) {
+        use crate::config::SortAs;
         use crate::config::schema::SortOrder;
         use crate::data::JsonPathExtractor;
 
-        // Create extractor once for efficiency
-        let extractor = match JsonPathExtractor::new(&sort_config.column) {
-            Ok(ext) => ext,
-            Err(_) => return, // Return unsorted if path is invalid
+        let extractor = JsonPathExtractor::new(&sort_config.column).ok();
+        let value_of = |row: &Value| match column {
+            Some(col) if col.is_computed() || col.progress.is_some() => {
+                self.column_value(col, row)
+            }
+            _ => extractor.as_ref()?.extract_single(row).ok().flatten(),
         };
 
-        indices.sort_by(|&a, &b| {
-            let a_item = self.current_data.get(a);
-            let b_item = self.current_data.get(b);
-
-            let cmp = match (a_item, b_item) {
-                (Some(a_data), Some(b_data)) => {
-                    let a_val = extractor.extract_single(a_data);
-                    let b_val = extractor.extract_single(b_data);
-
-                    match (&a_val, &b_val) {
-                        (Ok(Some(av)), Ok(Some(bv))) => Self::compare_values(av, bv),
-                        (Ok(Some(_)), Ok(None)) => std::cmp::Ordering::Less,
-                        (Ok(None), Ok(Some(_))) => std::cmp::Ordering::Greater,
-                        _ => std::cmp::Ordering::Equal,
-                    }
-                }
-                _ => std::cmp::Ordering::Equal,
-            };
-
-            match sort_config.order {
-                SortOrder::Asc => cmp,
-                SortOrder::Desc => cmp.reverse(),
+        // Extract (or render) each row's value once rather than per comparison.
+        // An untyped column sorts by the kind all its values parse as.
+        let mut values: HashMap<usize, Option<Value>> = indices
+            .iter()
+            .map(|&idx| (idx, self.current_data.get(idx).and_then(value_of)))
+            .collect();
+        let sort_as = match column.map_or(SortAs::Auto, |c| c.sort_kind()) {
+            SortAs::Auto => crate::view::column::auto_sort_kind(values.values().flatten()),
+            kind => kind,
+        };
+        // Missing values, nulls and ones that don't parse as the sort kind go
+        // last in either order
+        for value in values.values_mut() {
+            if value
+                .as_ref()
+                .is_some_and(|v| v.is_null() || crate::view::column::unsortable(sort_as, v))
+            {
+                *value = None;
             }
-        });
-    }
-
-    fn compare_values(a: &Value, b: &Value) -> std::cmp::Ordering {
-        use std::cmp::Ordering;
+        }
 
-        match (a, b) {
-            (Value::String(a), Value::String(b)) => a.cmp(b),
-            (Value::Number(a), Value::Number(b)) => {
-                if let (Some(a_f), Some(b_f)) = (a.as_f64(), b.as_f64()) {
-                    a_f.partial_cmp(&b_f).unwrap_or(Ordering::Equal)
-                } else {
-                    Ordering::Equal
+        indices.sort_by(|a, b| match (&values[a], &values[b]) {
+            (Some(av), Some(bv)) => {
+                let cmp = crate::view::column::sort_compare(sort_as, av, bv);
+                match sort_config.order {
+                    SortOrder::Asc => cmp,
+                    SortOrder::Desc => cmp.reverse(),
                 }
             }
-            (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
-            (Value::Null, Value::Null) => Ordering::Equal,
-            (Value::Null, _) => Ordering::Less,
-            (_, Value::Null) => Ordering::Greater,
-            _ => value_to_string(a).cmp(&value_to_string(b)),
-        }
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        });
     }
 }
 
@@ -3676,3 +9347,1378 @@ fn value_to_string(value: &Value) -> String {
         Value::Object(_) => "{...}".to_string(),
     }
 }
+
+/// A context value as shown next to a page's title in the breadcrumb; None for
+/// values that don't read as a name (objects, lists, null)
+fn context_label(value: &Value) -> Option<String> {
+    match value {
+        Value::String(_) | Value::Number(_) | Value::Bool(_) => Some(value_to_string(value)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{Harness, Session};
+    use serde_json::json;
+
+    /// `yaml` started with each source answered from `data`
+    async fn start(yaml: &str, data: &[(&str, Value)]) -> Session {
+        let mut harness = Harness::from_yaml(yaml).unwrap().with_size(100, 30);
+        for (key, value) in data {
+            harness = harness.with_data(key, value.clone());
+        }
+        harness.start().await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_context_menu_entries() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view: {type: table, columns: [{path: "$.name", display: Name}]}
+    next:
+      - {condition: "{{ row.status == 'Failed' }}", page: logs}
+      - {condition: "{{ row.status == 'Pending' }}", page: events}
+      - {default: true, page: detail}
+    actions:
+      - {key: "ctrl+r", name: Restart, command: "true"}
+  logs: {title: Logs, data: {adapter: cli, command: echo}, view: {type: text}}
+  events: {title: Events, data: {adapter: cli, command: echo}, view: {type: text}}
+  detail: {title: Detail, data: {adapter: cli, command: echo}, view: {type: text}}
+"#;
+        let rows = json!([{"name": "api-1", "status": "Failed"}]);
+        let mut session = start(yaml, &[("pods", rows)]).await;
+
+        session.press("m").await.unwrap();
+        let menu = session.app().context_menu.as_ref().unwrap();
+        let entries: Vec<&str> = menu
+            .entries
+            .iter()
+            .map(|entry| match entry {
+                ContextMenuEntry::Navigate { page, .. } => page.as_str(),
+                ContextMenuEntry::Action(action) => action.name.as_str(),
+            })
+            .collect();
+        assert_eq!(entries, ["logs", "detail", "Restart"]);
+
+        session.press("j enter").await.unwrap();
+        assert_eq!(session.page(), "detail");
+        assert!(session.app().context_menu.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_links() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: repos
+pages:
+  repos:
+    title: Repos
+    data: {adapter: cli, command: gh, items: "$[*]"}
+    view:
+      type: table
+      columns:
+        - {path: "$.name", display: Name, link: "{{ row.url }}"}
+"#;
+        let rows = json!([
+            {"name": "evil", "url": "https://x.io/a\x1b]8;;https://y.io\x07b"},
+            {"name": "plain", "url": "api-1"},
+        ]);
+        let mut session = start(yaml, &[("repos", rows)]).await;
+
+        let url = session.app().selected_link().unwrap();
+        assert_eq!(url, "https://x.io/a%1B]8;;https://y.io%07b");
+
+        // Only the sanitized URL reaches the terminal
+        session.screen().unwrap();
+        let mut out = Vec::new();
+        let buffer = Buffer::empty(Rect::new(0, 0, 100, 30));
+        let links = session.app().hyperlink_cells(&buffer);
+        let mut backend = ratatui::backend::CrosstermBackend::new(&mut out);
+        App::emit_hyperlinks(&mut backend, &links).unwrap();
+        let out = String::from_utf8_lossy(&out).to_string();
+        assert!(out.contains(&format!("\x1b]8;;{}\x1b\\", url)), "{:?}", out);
+        assert!(!out.contains("y.io\x07"), "{:?}", out);
+
+        // A link that isn't a URL is neither linked nor opened
+        session.press("j").await.unwrap();
+        assert_eq!(session.app().selected_link(), None);
+        session.press("o").await.unwrap();
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("No valid link on this row"), "{}", screen);
+        assert_eq!(session.app().hyperlinks.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unsortable_values_sort_last() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: jobs
+pages:
+  jobs:
+    title: Jobs
+    data: {adapter: cli, command: jobs, items: "$[*]"}
+    view:
+      type: table
+      sort: {column: "$.took", order: desc}
+      columns:
+        - {path: "$.name", display: Name}
+        - {path: "$.took", display: Took, type: duration}
+"#;
+        let rows = json!([
+            {"name": "a", "took": "soon"},
+            {"name": "b", "took": "90s"},
+            {"name": "c"},
+            {"name": "d", "took": "2h"},
+        ]);
+        let mut session = start(yaml, &[("jobs", rows)]).await;
+        let app = session.app();
+        let names: Vec<&str> = app
+            .filtered_indices
+            .iter()
+            .map(|&i| app.current_data[i]["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, ["d", "b", "a", "c"]);
+    }
+
+    #[tokio::test]
+    async fn test_cycle_sort() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view:
+      type: table
+      columns: [{path: "$.name", display: Name}, {path: "$.cpu", display: CPU, type: number}]
+"#;
+        let rows = json!([
+            {"name": "b", "cpu": 2},
+            {"name": "c", "cpu": 3},
+            {"name": "a", "cpu": 1},
+        ]);
+        let mut session = start(yaml, &[("pods", rows)]).await;
+        let names = |app: &App| -> Vec<String> {
+            (app.filtered_indices.iter())
+                .map(|&i| app.current_data[i]["name"].as_str().unwrap().to_string())
+                .collect()
+        };
+
+        // Sort on the focused column, keeping the cursor on its row
+        session.press("j l s").await.unwrap();
+        assert_eq!(names(session.app()), ["a", "b", "c"]);
+        assert_eq!(session.app().selected_index, 2);
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("CPU ▲"), "{}", screen);
+
+        session.press("s").await.unwrap();
+        assert_eq!(names(session.app()), ["c", "b", "a"]);
+        assert_eq!(session.app().selected_index, 0);
+        assert!(session.screen().unwrap().contains("CPU ▼"));
+
+        // Then back to the order fetched
+        session.press("s").await.unwrap();
+        assert_eq!(names(session.app()), ["b", "c", "a"]);
+        assert_eq!(session.app().selected_index, 1);
+        let screen = session.screen().unwrap();
+        assert!(!screen.contains('▲') && !screen.contains('▼'), "{}", screen);
+    }
+
+    #[tokio::test]
+    async fn test_broken_chord_runs_fallback() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+keybindings:
+  custom: {"g n": nodes}
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view: {type: table, columns: [{path: "$.name", display: Name}]}
+  nodes: {title: Nodes, data: {adapter: cli, command: echo}, view: {type: text}}
+"#;
+        let rows = json!([{"name": "a"}, {"name": "b"}, {"name": "c"}, {"name": "d"}]);
+        let mut session = start(yaml, &[("pods", rows)]).await;
+
+        session.press("G").await.unwrap();
+        assert_eq!(session.app().selected_index, 3);
+        // `g` alone goes to the top, then `j` moves down as usual
+        session.press("g j").await.unwrap();
+        assert_eq!(session.app().selected_index, 1);
+        assert!(session.app().pending_chord.is_none());
+
+        session.press("g n").await.unwrap();
+        assert_eq!(session.page(), "nodes");
+    }
+
+    #[tokio::test]
+    async fn test_expanded_rows_follow_refresh() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view:
+      type: table
+      expand: "node {{ row.node }}"
+      columns: [{path: "$.id", display: Id}]
+"#;
+        let rows = json!([{"id": "a", "node": "n1"}, {"id": "b", "node": "n2"}]);
+        let mut session = start(yaml, &[("pods", rows)]).await;
+        session.press("j x").await.unwrap();
+        assert!(session.screen().unwrap().contains("node n2"));
+
+        // `b` moves up and a new row takes its place: the expansion stays with `b`
+        let rows = json!([{"id": "b", "node": "n2"}, {"id": "c", "node": "n3"}]);
+        session.set_data("pods", rows);
+        session.press("r").await.unwrap();
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("node n2"), "{}", screen);
+        assert!(!screen.contains("node n3"), "{}", screen);
+
+        // ...and is forgotten once `b` is gone
+        session.set_data("pods", json!([{"id": "c", "node": "n3"}]));
+        session.press("r").await.unwrap();
+        assert!(session.app().expanded_rows.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cursor_follows_row_across_refresh() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view:
+      type: table
+      key: "$.name"
+      columns: [{path: "$.name", display: Name}]
+"#;
+        let rows = json!([{"name": "a"}, {"name": "b"}, {"name": "c"}, {"name": "d"}]);
+        let mut session = start(yaml, &[("pods", rows)]).await;
+        session.press("j j").await.unwrap();
+        let selected = |app: &mut App| app.get_selected_row().map(|row| row["name"].clone());
+        assert_eq!(selected(session.app()), Some(json!("c")));
+
+        // `c` moves to the top: the cursor goes with it
+        let rows = json!([{"name": "c"}, {"name": "d"}, {"name": "a"}, {"name": "b"}]);
+        session.set_data("pods", rows);
+        session.press("r").await.unwrap();
+        assert_eq!(session.app().selected_index, 0);
+        assert_eq!(selected(session.app()), Some(json!("c")));
+
+        // Once it's gone the cursor stays where it was, within the rows left
+        session.press("G").await.unwrap();
+        session.set_data("pods", json!([{"name": "d"}, {"name": "a"}]));
+        session.press("r").await.unwrap();
+        assert_eq!(session.app().selected_index, 1);
+        assert_eq!(selected(session.app()), Some(json!("a")));
+    }
+
+    #[tokio::test]
+    async fn test_count_prefix_and_paging() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view: {type: table, columns: [{path: "$.id", display: Id}]}
+  readme:
+    title: Readme
+    data: {adapter: cli, command: cat}
+    view: {type: text}
+"#;
+        let rows: Vec<Value> = (0..100).map(|id| json!({"id": id})).collect();
+        let text: Vec<String> = (0..100).map(|n| format!("line {}", n)).collect();
+        let mut session =
+            start(yaml, &[("pods", json!(rows)), ("readme", json!(text.join("\n")))]).await;
+        session.screen().unwrap();
+        let page = session.app().page_height;
+        assert!(page > 2);
+
+        session.press("5 j").await.unwrap();
+        assert_eq!(session.app().selected_index, 5);
+        session.press("1 2 k").await.unwrap();
+        assert_eq!(session.app().selected_index, 0);
+        // An unbound key drops the count
+        session.press("3 z j").await.unwrap();
+        assert_eq!(session.app().selected_index, 1);
+        assert_eq!(session.app().count_prefix, None);
+
+        session.press("ctrl+d").await.unwrap();
+        assert_eq!(session.app().selected_index, 1 + page / 2);
+        session.press("ctrl+u").await.unwrap();
+        assert_eq!(session.app().selected_index, 1);
+        session.press("2 pagedown").await.unwrap();
+        assert_eq!(session.app().selected_index, 1 + 2 * page);
+        session.press("pageup").await.unwrap();
+        assert_eq!(session.app().selected_index, 1 + page);
+        session.press("4 0 G").await.unwrap();
+        assert_eq!(session.app().selected_index, 39);
+        session.press("G pagedown").await.unwrap();
+        assert_eq!(session.app().selected_index, 99);
+
+        // Text views scroll by the same amounts
+        let mut session = Harness::from_yaml(yaml)
+            .unwrap()
+            .with_data("readme", json!(text.join("\n")))
+            .with_page("readme")
+            .start()
+            .await
+            .unwrap();
+        session.screen().unwrap();
+        let page = session.app().page_height;
+        session.press("5 j").await.unwrap();
+        assert_eq!(session.app().scroll_offset, 5);
+        session.press("ctrl+d").await.unwrap();
+        assert_eq!(session.app().scroll_offset, 5 + page / 2);
+        session.press("pageup").await.unwrap();
+        assert_eq!(session.app().scroll_offset, (5 + page / 2).saturating_sub(page));
+    }
+
+    #[tokio::test]
+    async fn test_search_filters_after_a_pause() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view: {type: table, columns: [{path: "$.name", display: Name}]}
+"#;
+        let rows = json!([{"name": "api-1"}, {"name": "web-1"}, {"name": "api-2"}]);
+        let mut session = start(yaml, &[("pods", rows)]).await;
+        session.press("/").await.unwrap();
+        session.type_text("api").await;
+
+        // Still typing: nothing is filtered yet
+        assert_eq!(session.app().filtered_indices.len(), 3);
+        tokio::time::sleep(SEARCH_DEBOUNCE).await;
+        session.app().tick().await;
+        assert_eq!(session.app().filtered_indices, [0, 2]);
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("· 2 matches"), "{}", screen);
+        assert!(!screen.contains("web-1"), "{}", screen);
+
+        // Esc drops the query and its filter
+        session.press("esc").await.unwrap();
+        assert_eq!(session.app().filtered_indices.len(), 3);
+        assert!(session.screen().unwrap().contains("web-1"));
+    }
+
+    #[tokio::test]
+    async fn test_going_back_restores_view_settings() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view:
+      type: table
+      columns: [{path: "$.name", display: Name}, {path: "$.node", display: Node}]
+    next: {page: logs}
+  logs:
+    title: Logs
+    data: {type: stream, command: "echo started", shell: true}
+    view: {type: logs}
+    actions:
+      - {key: "ctrl+o", name: Readme, page: readme}
+  readme: {title: Readme, data: {adapter: cli, command: cat}, view: {type: text}}
+"#;
+        let rows = json!([
+            {"name": "api-2", "node": "n1"},
+            {"name": "web-1", "node": "n2"},
+            {"name": "api-1", "node": "n3"},
+        ]);
+        let mut session = start(yaml, &[("pods", rows), ("readme", json!("hello"))]).await;
+        session.press("/").await.unwrap();
+        session.type_text("api").await;
+        session.press("enter s s l").await.unwrap();
+        let sorted = format!("{:?}", session.app().runtime_sort);
+        assert!(sorted.contains("Desc"), "{}", sorted);
+
+        session.press("enter").await.unwrap();
+        assert_eq!(session.page(), "logs");
+        assert!(session.app().logs_follow && session.app().logs_wrap);
+        session.press("f w ctrl+o").await.unwrap();
+        assert_eq!(session.page(), "readme");
+
+        // Each page comes back as it was left
+        session.press("esc").await.unwrap();
+        assert_eq!(session.page(), "logs");
+        assert!(!session.app().logs_follow);
+        assert!(!session.app().logs_wrap);
+
+        session.press("esc").await.unwrap();
+        assert_eq!(session.page(), "pods");
+        let app = session.app();
+        assert_eq!(app.global_search.query, "api");
+        assert!(app.global_search.filter_active);
+        assert_eq!(app.filtered_indices.len(), 2);
+        assert_eq!(format!("{:?}", app.runtime_sort), sorted);
+        assert_eq!(app.focused_column, 1);
+    }
+
+    #[tokio::test]
+    async fn test_page_title_from_data() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+pages:
+  pods:
+    title: "Pods ({{ items | length }} total, {{ failing }} failing){% if failing > 0 %} !{% endif %}"
+    summary:
+      failing: "{{ items | filter(attribute='phase', value='Failed') | length }}"
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view: {type: table, columns: [{path: "$.name", display: Name}]}
+"#;
+        let rows = json!([
+            {"name": "a", "phase": "Running"},
+            {"name": "b", "phase": "Failed"},
+            {"name": "c", "phase": "Running"},
+        ]);
+        let mut session = start(yaml, &[("pods", rows)]).await;
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("Pods (3 total, 1 failing) !"), "{}", screen);
+
+        // Re-rendered with each fetch
+        session.set_data("pods", json!([{"name": "a", "phase": "Running"}]));
+        session.press("r").await.unwrap();
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("Pods (1 total, 0 failing)"), "{}", screen);
+        assert!(!screen.contains("failing) !"), "{}", screen);
+    }
+
+    #[tokio::test]
+    async fn test_auto_sort_mixed_units() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view:
+      type: table
+      sort: {column: "$.mem"}
+      columns: [{path: "$.name", display: Name}, {path: "$.mem", display: Mem}]
+"#;
+        let names = |session: &mut Session| -> Vec<String> {
+            let app = session.app();
+            app.filtered_indices
+                .iter()
+                .map(|&i| app.current_data[i]["name"].as_str().unwrap().to_string())
+                .collect()
+        };
+
+        // All sizes: compared as sizes, whatever else some of them parse as
+        let rows = json!([
+            {"name": "a", "mem": "1Gi"},
+            {"name": "b", "mem": "10"},
+            {"name": "c"},
+            {"name": "d", "mem": "5m"},
+            {"name": "e", "mem": "900Mi"},
+        ]);
+        let mut session = start(yaml, &[("pods", rows)]).await;
+        assert_eq!(names(&mut session), ["b", "d", "e", "a", "c"]);
+
+        // No kind fits every value: natural text
+        let rows = json!([
+            {"name": "a", "mem": "abc"},
+            {"name": "b", "mem": "10"},
+            {"name": "c", "mem": "1h"},
+            {"name": "d", "mem": "5m"},
+        ]);
+        let mut session = start(yaml, &[("pods", rows)]).await;
+        assert_eq!(names(&mut session), ["c", "d", "b", "a"]);
+    }
+
+    #[tokio::test]
+    async fn test_horizontal_scroll_and_pinned_columns() {
+        let mut columns = vec![r#"{path: "$.name", display: Name, pinned: true}"#.to_string()];
+        columns.extend((1..=12).map(|n| format!(r#"{{path: "$.c{n}", display: Column{n}}}"#)));
+        let yaml = format!(
+            r#"
+version: v1
+app: {{name: test}}
+start: wide
+pages:
+  wide:
+    title: Wide
+    data: {{adapter: cli, command: wide, items: "$[*]"}}
+    view: {{type: table, columns: [{}]}}
+"#,
+            columns.join(", ")
+        );
+        let mut row = serde_json::Map::new();
+        row.insert("name".to_string(), json!("api-1"));
+        for n in 1..=12 {
+            row.insert(format!("c{n}"), json!(format!("value{n}")));
+        }
+        let mut session = Harness::from_yaml(&yaml)
+            .unwrap()
+            .with_data("wide", json!([row]))
+            .with_size(60, 12)
+            .start()
+            .await
+            .unwrap();
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("Wide [8 ▶]"), "{}", screen);
+        assert!(screen.contains("Name   Column1   Column2   Column3   Column4"), "{}", screen);
+        assert!(screen.contains("api-1  value1    value2"), "{}", screen);
+
+        // The view follows the focus; the pinned column stays put
+        session.press("7 l").await.unwrap();
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("Wide [◀ 3 5 ▶]"), "{}", screen);
+        assert!(screen.contains("Name   Column4   Column5   Column6   Column7"), "{}", screen);
+        assert!(screen.contains("api-1  value4    value5"), "{}", screen);
+
+        session.press("4 h").await.unwrap();
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("Wide [◀ 2 6 ▶]"), "{}", screen);
+        assert!(screen.contains("Name   Column3   Column4"), "{}", screen);
+
+        // Pinned columns don't trade places with scrolling ones
+        for _ in 0..3 {
+            session.press("h").await.unwrap();
+            session.screen().unwrap();
+        }
+        session.press(">").await.unwrap();
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("Wide [8 ▶]"), "{}", screen);
+        assert!(screen.contains("Name   Column1"), "{}", screen);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_countdown_after_error() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, items: "$[*]", refresh_interval: 30s}
+    view: {type: table, columns: [{path: "$.name", display: Name}]}
+"#;
+        let mut session = start(yaml, &[("pods", json!([{"name": "api-1"}]))]).await;
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("next in 30s"), "{}", screen);
+
+        // The watcher's fetch failed just as the countdown ran out
+        let app = session.app();
+        app.next_refresh = Some(std::time::Instant::now());
+        let (tx, rx) = mpsc::channel(1);
+        app.refresh_receiver = Some(rx);
+        let error = RefreshMessage::Error {
+            page_name: "pods".to_string(),
+            error: "connection refused".to_string(),
+            elapsed: std::time::Duration::from_millis(20),
+        };
+        tx.send(error).await.unwrap();
+        app.check_refresh_updates();
+
+        assert_eq!(app.seconds_to_refresh(), Some(30));
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("connection refused"), "{}", screen);
+        assert!(screen.contains("next in 30s"), "{}", screen);
+    }
+
+    #[tokio::test]
+    async fn test_row_select_hooks_wait_for_the_cursor_to_rest() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view: {type: table, columns: [{path: "$.name", display: Name}]}
+    hooks:
+      on_row_select:
+        - set: "pod={{ row.name }}"
+"#;
+        let rows = json!([{"name": "a"}, {"name": "b"}, {"name": "c"}]);
+        let mut session = start(yaml, &[("pods", rows)]).await;
+        session.press("j j").await.unwrap();
+        assert_eq!(session.app().nav_context.globals.get("pod"), None);
+
+        tokio::time::sleep(ROW_SELECT_DEBOUNCE).await;
+        session.app().tick().await;
+        assert_eq!(session.app().nav_context.globals.get("pod"), Some(&json!("c")));
+    }
+
+    #[tokio::test]
+    async fn test_lua_hooks() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+globals: {env: prod}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view:
+      type: table
+      columns: [{path: "$.name", display: Name}, {path: "$.flaky", display: Flaky}]
+    next: {page: detail, context: {pod: "$.name"}}
+    lua:
+      on_data: |
+        for _, row in ipairs(rows) do row.flaky = row.restarts > 3 end
+      on_select: |
+        if row and row.protected then return false, row.name .. " is protected" end
+      on_key: |
+        if key == "r" and globals.env == "prod" then return false, "Not refreshing prod" end
+  detail: {title: Detail, data: {adapter: cli, command: echo}, view: {type: text}}
+"#;
+        let rows = json!([
+            {"name": "db-0", "restarts": 9, "protected": true},
+            {"name": "web-0", "restarts": 0},
+        ]);
+        let mut session = start(yaml, &[("pods", rows)]).await;
+        let screen = session.screen().unwrap();
+        let flaky = |name: &str| screen.lines().find(|l| l.contains(name)).unwrap().to_string();
+        assert!(flaky("db-0").contains("true"), "{}", screen);
+        assert!(flaky("web-0").contains("false"), "{}", screen);
+
+        // on_select vetoes opening the protected row, on_key swallows `r`
+        session.press("enter").await.unwrap();
+        assert_eq!(session.page(), "pods");
+        assert!(session.screen().unwrap().contains("db-0 is protected"));
+        session.press("r").await.unwrap();
+        assert!(session.screen().unwrap().contains("Not refreshing prod"));
+
+        session.press("j enter").await.unwrap();
+        assert_eq!(session.page(), "detail");
+    }
+
+    #[tokio::test]
+    async fn test_column_widths_cover_all_rows() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view:
+      type: table
+      columns: [{path: "$.name", display: Name}, {path: "$.cpu", display: CPU}]
+"#;
+        let mut rows: Vec<Value> =
+            (0..40).map(|i| json!({"name": format!("pod-{}", i), "cpu": i})).collect();
+        rows[35]["name"] = json!("a-much-longer-pod-name");
+        rows[36]["cpu"] = json!("n/a");
+        let mut session = start(yaml, &[("pods", json!(rows))]).await;
+        let cpu_column = |screen: &str| {
+            let header = screen.lines().find(|line| line.contains("CPU")).unwrap();
+            let name = header.find("Name").unwrap();
+            (header.find("CPU").unwrap() - name, name)
+        };
+        let top = session.screen().unwrap();
+        let (cpu, name) = cpu_column(&top);
+        assert!(cpu > "a-much-longer-pod-name".len(), "{}", top);
+        // Left-aligned, as one of its cells (off screen) isn't a number
+        let line = top.lines().find(|line| line.contains("pod-1 ")).unwrap();
+        assert_eq!(&line[name + cpu..name + cpu + 1], "1", "{}", top);
+
+        // The same layout once scrolled to the bottom
+        session.press("G").await.unwrap();
+        let bottom = session.screen().unwrap();
+        assert_eq!(cpu_column(&bottom), (cpu, name), "{}", bottom);
+    }
+
+    #[tokio::test]
+    async fn test_read_only_blocks_actions() {
+        let marker =
+            std::env::temp_dir().join(format!("termstack-read-only-{}", std::process::id()));
+        let yaml = format!(
+            r#"
+version: v1
+app: {{name: test}}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {{adapter: cli, command: kubectl, items: "$[*]"}}
+    view: {{type: table, columns: [{{path: "$.name", display: Name}}]}}
+    actions:
+      - {{key: "ctrl+r", name: Restart, command: touch, args: ["{}"]}}
+"#,
+            marker.display()
+        );
+        let rows = json!([{"name": "api-1"}, {"name": "api-2"}]);
+        let mut session = start(&yaml, &[("pods", rows)]).await;
+        session.app().read_only = true;
+
+        // Neither the action's key, on one row or on marked rows, nor the menu
+        session.press("ctrl+r space j space ctrl+r").await.unwrap();
+        session.press("A").await.unwrap();
+        assert!(!session.app().show_action_menu);
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("Read-only mode: actions are disabled"), "{}", screen);
+        assert!(screen.contains("Status [READ-ONLY]"), "{}", screen);
+        assert!(!marker.exists());
+
+        session.app().read_only = false;
+        session.press("ctrl+r").await.unwrap();
+        assert!(marker.exists());
+        std::fs::remove_file(&marker).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_leaving_a_page_cancels_its_refresh() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, items: "$[*]", refresh_interval: 1s}
+    view: {type: table, columns: [{path: "$.name", display: Name}]}
+    next: {page: detail}
+  detail: {title: Detail, data: {adapter: cli, command: echo}, view: {type: text}}
+"#;
+        let rows = json!([{"name": "api-1"}]);
+        let mut session = start(yaml, &[("pods", rows), ("detail", json!("api-1"))]).await;
+        let watcher = session.app().refresh_task.clone().unwrap();
+        assert!(!watcher.is_finished());
+
+        session.press("enter").await.unwrap();
+        assert_eq!(session.page(), "detail");
+        tokio::task::yield_now().await;
+        assert!(watcher.is_finished());
+
+        // Coming back starts a new one, and reloading replaces it
+        session.press("esc").await.unwrap();
+        let watcher = session.app().refresh_task.clone().unwrap();
+        session.press("r").await.unwrap();
+        tokio::task::yield_now().await;
+        assert!(watcher.is_finished());
+        assert!(!session.app().refresh_task.as_ref().unwrap().is_finished());
+    }
+
+    #[test]
+    fn test_log_highlights() {
+        let theme = Theme::default();
+        let view: LogsView = serde_yaml::from_str(
+            r#"
+type: logs
+parse: json
+highlights:
+  - {pattern: "FATAL|panic", color: red, bold: true}
+  - {pattern: "\\d+ms", bg: blue}
+"#,
+        )
+        .unwrap();
+        let highlights: Vec<(Regex, Style)> = view
+            .highlights
+            .iter()
+            .map(|rule| {
+                let style = crate::view::style::highlight_style(rule, &theme);
+                (Regex::new(&rule.pattern).unwrap(), style)
+            })
+            .collect();
+        let red = Style::default().fg(ratatui::style::Color::Red).add_modifier(Modifier::BOLD);
+        let blue = Style::default().bg(ratatui::style::Color::Blue);
+        let styled = |line: &LogLine| -> Vec<(String, Style)> {
+            line.parsed
+                .spans
+                .iter()
+                .filter(|span| span.style != Style::default())
+                .map(|span| (span.content.to_string(), span.style))
+                .collect()
+        };
+
+        // Plain lines keep all their text, with each match restyled
+        let line = App::parse_and_store_line("FATAL: took 250ms", None, &highlights, &theme);
+        assert_eq!(line.raw, "FATAL: took 250ms");
+        assert_eq!(
+            styled(&line),
+            [("FATAL".to_string(), red), ("250ms".to_string(), blue)]
+        );
+
+        // So do JSON lines shown as fields
+        let raw = r#"{"level": "error", "message": "panic in handler"}"#;
+        let line = App::parse_and_store_line(raw, Some(&view), &highlights, &theme);
+        assert!(line.raw.contains("panic in handler"), "{}", line.raw);
+        assert!(
+            line.parsed
+                .spans
+                .iter()
+                .any(|span| span.content == "panic" && span.style.patch(red) == span.style),
+            "{:?}",
+            line.parsed
+        );
+
+        // No highlights leaves a line as it was
+        let line = App::parse_and_store_line("FATAL", None, &[], &theme);
+        assert!(styled(&line).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_jump_between_log_matches() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: logs
+pages:
+  logs:
+    title: Logs
+    data: {type: stream, command: "printf 'a\nerr 1\nb\nerr 2\nc\n'", shell: true}
+    view: {type: logs}
+"#;
+        let mut session = start(yaml, &[]).await;
+        while session.app().stream_buffer.len() < 5 {
+            session.app().tick().await;
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        session.press("/").await.unwrap();
+        session.type_text("err").await;
+        session.press("enter").await.unwrap();
+
+        // From the last line, `n` wraps round to the first match
+        session.press("n").await.unwrap();
+        assert_eq!(session.app().selected_index, 1);
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("(match 1/2)"), "{}", screen);
+        // Every line shows while jumping
+        assert!(screen.contains("b"), "{}", screen);
+        session.press("n").await.unwrap();
+        assert_eq!(session.app().selected_index, 3);
+        assert!(session.screen().unwrap().contains("(match 2/2)"));
+        session.press("n").await.unwrap();
+        assert_eq!(session.app().selected_index, 1);
+        session.press("N").await.unwrap();
+        assert_eq!(session.app().selected_index, 3);
+
+        // Paused, lines arriving meanwhile aren't among the matches shown
+        session.press("f").await.unwrap();
+        let theme = Theme::default();
+        let line = App::parse_and_store_line("err 3", None, &[], &theme);
+        session.app().stream_buffer.push_back(line);
+        session.press("n").await.unwrap();
+        assert_eq!(session.app().selected_index, 1);
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("(match 1/2)"), "{}", screen);
+        assert!(!screen.contains("err 3"), "{}", screen);
+    }
+
+    #[tokio::test]
+    async fn test_stream_buffer_time() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: logs
+pages:
+  logs:
+    title: Logs
+    data: {type: stream, command: "echo one; echo two; sleep 5", shell: true, buffer_time: 500ms}
+    view: {type: logs}
+"#;
+        let mut session = start(yaml, &[]).await;
+        let window = std::time::Duration::from_millis(500);
+        assert_eq!(session.app().stream_buffer_time, Some(window));
+        while session.app().stream_buffer.len() < 2 {
+            session.app().tick().await;
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        let since = session.app().stream_unrendered_since.unwrap();
+
+        // Both lines wait for the window to end to be drawn
+        session.app().needs_render = false;
+        session.app().tick().await;
+        assert!(since.elapsed() < window);
+        assert!(!session.app().needs_render);
+
+        tokio::time::sleep(window.saturating_sub(since.elapsed())).await;
+        session.app().tick().await;
+        assert!(session.app().needs_render);
+        assert!(session.app().stream_unrendered_since.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_backoff() {
+        let secs: Vec<u64> = (1..=8).map(|n| App::reconnect_delay(n).as_secs()).collect();
+        assert_eq!(secs, [1, 2, 4, 8, 16, 30, 30, 30]);
+
+        // Each failure waits longer, with the reason kept for the title
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view: {type: table, columns: [{path: "$.name", display: Name}]}
+"#;
+        let mut session = start(yaml, &[("pods", json!([]))]).await;
+        let app = session.app();
+        for (attempt, wait) in [(1, 1), (2, 2), (3, 4)] {
+            let before = std::time::Instant::now();
+            app.schedule_reconnect(Some("exited".to_string()));
+            let StreamStatus::Reconnecting { attempt: n, at, reason } = &app.stream_status else {
+                panic!("not reconnecting");
+            };
+            assert_eq!(*n, attempt);
+            assert_eq!(reason.as_deref(), Some("exited"));
+            let delay = *at - before;
+            assert!(delay >= std::time::Duration::from_secs(wait), "{:?}", delay);
+            assert!(delay < std::time::Duration::from_secs(wait + 1), "{:?}", delay);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_detached_stream_keeps_its_buffer() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view: {type: table, columns: [{path: "$.name", display: Name}]}
+    next: {page: logs}
+  logs:
+    title: Logs
+    data:
+      type: stream
+      command: "echo one; sleep 0.3; echo two; sleep 5"
+      shell: true
+      detach: true
+    view: {type: logs}
+"#;
+        let mut session = start(yaml, &[("pods", json!([{"name": "api-1"}]))]).await;
+        let lines = |app: &App| -> Vec<String> {
+            app.stream_buffer.iter().map(|line| line.raw.clone()).collect()
+        };
+        session.press("enter").await.unwrap();
+        assert_eq!(session.page(), "logs");
+        while lines(session.app()).is_empty() {
+            session.app().tick().await;
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert_eq!(lines(session.app()), ["one"]);
+
+        // Away from the page the command keeps running, and its lines are kept
+        session.press("esc").await.unwrap();
+        assert_eq!(session.page(), "pods");
+        let buffered = |app: &App| app.detached_streams.get("logs").map(|d| d.buffer.len());
+        assert_eq!(buffered(session.app()), Some(1));
+        while buffered(session.app()) == Some(1) {
+            session.app().tick().await;
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+
+        // Coming back shows them all, without starting the command again
+        session.press("enter").await.unwrap();
+        assert_eq!(lines(session.app()), ["one", "two"]);
+        assert!(session.app().detached_streams.is_empty());
+        assert!(session.app().stream_active);
+    }
+
+    #[test]
+    fn test_page_error_report() {
+        let mut error = PageError::new("Failed to load data", "kubectl failed: exit status 1: ");
+        assert_eq!(error.chain(), ["kubectl failed", "exit status 1"]);
+        error.source = Some("kubectl get pods".to_string());
+        error.elapsed = Some(std::time::Duration::from_millis(1250));
+        let report = error.report();
+        let time = error.at.format("%H:%M:%S");
+        assert_eq!(
+            report,
+            format!(
+                "Failed to load data\n  kubectl failed\n    exit status 1\n\
+                 Source: kubectl get pods\nFailed: after 1.2s at {}",
+                time
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_page_error_retry() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, args: [get, pods], items: "$[*]"}
+    view: {type: table, columns: [{path: "$.name", display: Name}]}
+"#;
+        let mut session = start(yaml, &[]).await;
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("✗ Failed to load data"), "{}", screen);
+        assert!(screen.contains("No test data for 'pods'"), "{}", screen);
+        assert!(screen.contains("Source  kubectl get pods"), "{}", screen);
+        assert!(screen.contains("Failed  after"), "{}", screen);
+        assert!(screen.contains("r: retry"), "{}", screen);
+        assert!(screen.contains("y: copy error"), "{}", screen);
+        assert!(!screen.contains("Esc: back"), "{}", screen);
+
+        // Retrying once the source works shows the page
+        session.set_data("pods", json!([{"name": "api-1"}]));
+        session.press("r").await.unwrap();
+        assert!(session.app().page_error.is_none());
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("api-1"), "{}", screen);
+        assert!(!screen.contains("Failed to load data"), "{}", screen);
+    }
+
+    #[tokio::test]
+    async fn test_multi_source_partial_failure() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: jobs
+pages:
+  jobs:
+    title: Jobs
+    data:
+      merge: true
+      sources:
+        - {id: eu, adapter: cli, command: jobs, items: "$[*]"}
+        - {id: us, adapter: cli, command: jobs, items: "$[*]"}
+        - {id: staging, adapter: cli, command: jobs, items: "$[*]", optional: true}
+    view: {type: table, columns: [{path: "$.name", display: Name}]}
+"#;
+        // A required source failing leaves the others' rows, under a banner
+        let mut session = start(yaml, &[("jobs.eu", json!([{"name": "eu-1"}]))]).await;
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("⚠ Source 'us' failed"), "{}", screen);
+        assert!(screen.contains("No test data for 'jobs.us'"), "{}", screen);
+        assert!(!screen.contains("staging"), "{}", screen);
+        assert!(screen.contains("eu-1"), "{}", screen);
+
+        // Once it loads the banner goes
+        session.set_data("jobs.us", json!([{"name": "us-1"}]));
+        session.press("r").await.unwrap();
+        let screen = session.screen().unwrap();
+        assert!(!screen.contains("⚠"), "{}", screen);
+        assert!(screen.contains("eu-1") && screen.contains("us-1"), "{}", screen);
+
+        // Only when nothing loads does the page fail
+        let mut session = start(yaml, &[]).await;
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("✗ Failed to load data"), "{}", screen);
+        assert!(screen.contains("Source 'eu' failed"), "{}", screen);
+        assert!(!screen.contains("⚠"), "{}", screen);
+    }
+
+    #[tokio::test]
+    async fn test_multi_source_without_merge() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: jobs
+pages:
+  jobs:
+    title: Jobs
+    data:
+      sources:
+        - {id: eu, adapter: cli, command: jobs, items: "$[*]"}
+        - {id: us, adapter: cli, command: jobs, items: "$[*]", optional: true}
+    view: {type: text}
+"#;
+        let mut session = start(yaml, &[("jobs.eu", json!([{"name": "eu-1"}]))]).await;
+        assert_eq!(session.app().current_data, [json!({"eu": [{"name": "eu-1"}]})]);
+        assert!(session.app().failed_sources.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_bookmarks_saved() {
+        let yaml = r#"
+version: v1
+app: {name: test, save_bookmarks: true}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view: {type: table, columns: [{path: "$.name", display: Name}]}
+    next: {page: logs, context: {pod: "$.name"}}
+  logs: {title: Logs, data: {adapter: cli, command: logs}, view: {type: text}}
+"#;
+        let data = [
+            ("pods", json!([{"name": "api-1"}, {"name": "web-1"}])),
+            ("logs", json!("started")),
+        ];
+        let dir = std::env::temp_dir().join(format!("termstack-bookmarks-{}", std::process::id()));
+        let path = dir.join("view-state.json");
+
+        let mut session = start(yaml, &data).await;
+        session.app().view_state_path = Some(path.clone());
+        session.press("j enter B").await.unwrap();
+        let saved = ViewState::load(&path);
+        let [bookmark] = saved.bookmarks("test") else {
+            panic!("{:?}", saved.bookmarks("test"));
+        };
+        assert_eq!(bookmark.page_id, "logs");
+        assert_eq!(bookmark.context.get("pod"), Some(&json!("web-1")));
+
+        // A later session opens it with its context
+        let mut session = start(yaml, &data).await;
+        session.app().bookmarks = saved.bookmarks("test").to_vec();
+        session.app().view_state = saved;
+        session.app().view_state_path = Some(path.clone());
+        session.press("' enter").await.unwrap();
+        assert_eq!(session.page(), "logs");
+        assert_eq!(session.app().opened_context.get("pod"), Some(&json!("web-1")));
+
+        // Deleting it saves too
+        session.press("' d esc").await.unwrap();
+        assert!(session.app().bookmarks.is_empty());
+        assert!(ViewState::load(&path).bookmarks("test").is_empty());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_start_with_context() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view: {type: table, columns: [{path: "$.name", display: Name}]}
+    next: {page: logs, context: {pod: "$.name"}}
+  logs:
+    title: "Logs of {{ pod }} in {{ cluster.name }}"
+    params: [pod]
+    data: {adapter: cli, command: logs}
+    view: {type: text}
+"#;
+        let mut context = HashMap::new();
+        for assignment in ["pod=api-7f9c", "cluster.name=prod"] {
+            crate::config::assign_value(&mut context, assignment).unwrap();
+        }
+        let mut harness = Harness::from_yaml(yaml)
+            .unwrap()
+            .with_data("logs", json!("started"))
+            .with_page("logs")
+            .with_size(100, 30);
+        for (key, value) in context {
+            harness = harness.with_context(&key, value);
+        }
+        let mut session = harness.start().await.unwrap();
+        assert_eq!(session.page(), "logs");
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("Logs of api-7f9c in prod"), "{}", screen);
+        assert!(screen.contains("started"), "{}", screen);
+        assert_eq!(session.app().opened_context["pod"], json!("api-7f9c"));
+
+        // Without the context it needs, the page says so
+        let mut session = Harness::from_yaml(yaml)
+            .unwrap()
+            .with_page("logs")
+            .with_size(100, 30)
+            .start()
+            .await
+            .unwrap();
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("needs parameter 'pod'"), "{}", screen);
+    }
+
+    #[tokio::test]
+    async fn test_navigation_path_context_kept() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: namespaces
+pages:
+  namespaces:
+    title: Namespaces
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view: {type: table, columns: [{path: "$.name", display: Name}]}
+    next: {page: pods, context: {namespace: "$.name"}}
+  pods:
+    title: "Pods in {{ namespace }}"
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view: {type: table, columns: [{path: "$.name", display: Name}]}
+    next: {page: containers, context: {pod: "$.name"}}
+  containers:
+    title: "Containers of {{ pod }}"
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view: {type: table, columns: [{path: "$.name", display: Name}]}
+    next: {page: logs, context: {container: "$.name"}}
+  logs:
+    title: "{{ container }} of {{ pod }} in {{ namespace }}"
+    data: {adapter: cli, command: logs}
+    view: {type: text}
+"#;
+        let data = [
+            ("namespaces", json!([{"name": "payments"}])),
+            ("pods", json!([{"name": "api-1"}])),
+            ("containers", json!([{"name": "app"}])),
+            ("logs", json!("started")),
+        ];
+        let mut session = start(yaml, &data).await;
+        // Room for fewer contexts than the path down to the logs sets
+        let globals = session.app().nav_context.globals.clone();
+        session.app().nav_context = NavigationContext::with_capacity(2).with_globals(globals);
+
+        session.press("enter enter enter").await.unwrap();
+        assert_eq!(session.page(), "logs");
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("app of api-1 in payments"), "{}", screen);
+
+        // Going back, each page still has what it was opened with
+        session.press("esc").await.unwrap();
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("Containers of api-1"), "{}", screen);
+        session.press("esc").await.unwrap();
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("Pods in payments"), "{}", screen);
+    }
+
+    #[tokio::test]
+    async fn test_step_through_parent_rows() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view: {type: table, columns: [{path: "$.name", display: Name}]}
+    next: {page: logs, context: {pod: "$.name"}}
+  logs:
+    title: "Logs of {{ pod }}"
+    data: {adapter: cli, command: logs}
+    view: {type: text}
+"#;
+        let data = [
+            ("pods", json!([{"name": "api-1"}, {"name": "api-2"}, {"name": "api-3"}])),
+            ("logs", json!("started")),
+        ];
+        let mut session = start(yaml, &data).await;
+        session.press("j enter").await.unwrap();
+        assert!(session.screen().unwrap().contains("Logs of api-2"));
+
+        // Each step replaces the page rather than stacking another
+        session.press("]").await.unwrap();
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("Logs of api-3"), "{}", screen);
+        assert_eq!(session.app().nav_stack.frames().len(), 1);
+
+        session.press("]").await.unwrap();
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("Already at the last row"), "{}", screen);
+        assert!(screen.contains("Logs of api-3"), "{}", screen);
+
+        session.press("[ [").await.unwrap();
+        assert!(session.screen().unwrap().contains("Logs of api-1"));
+        assert_eq!(session.app().opened_context["pod"], json!("api-1"));
+
+        // Back on the table, the cursor is on the row last shown
+        session.press("esc").await.unwrap();
+        assert_eq!(session.page(), "pods");
+        assert_eq!(session.app().selected_index, 0);
+
+        // A page that wasn't opened from a row has nothing to step through
+        session.press("[").await.unwrap();
+        assert_eq!(session.page(), "pods");
+    }
+
+    #[tokio::test]
+    async fn test_modal_target() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view: {type: table, columns: [{path: "$.name", display: Name}]}
+    next: {page: containers, modal: true, context: {pod: "$.name"}}
+  containers:
+    title: "Containers of {{ pod }}"
+    data: {adapter: cli, command: kubectl, items: "$[*]"}
+    view: {type: table, columns: [{path: "$.name", display: Container}]}
+    next: {page: logs, context: {container: "$.name"}}
+  logs:
+    title: "Logs of {{ container }}"
+    data: {adapter: cli, command: logs}
+    view: {type: text}
+"#;
+        let data = [
+            ("pods", json!([{"name": "api-1"}, {"name": "api-2"}])),
+            ("containers", json!([{"name": "sidecar"}])),
+            ("logs", json!("started")),
+        ];
+        let mut session = start(yaml, &data).await;
+
+        // Shown over the table as last drawn, which stays in view behind it
+        session.press("j").await.unwrap();
+        session.screen().unwrap();
+        session.press("enter").await.unwrap();
+        assert_eq!(session.page(), "containers");
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("Containers of api-2"), "{}", screen);
+        assert!(screen.contains("sidecar"), "{}", screen);
+        assert!(screen.contains("test | Pods"), "{}", screen);
+        assert!(session.app().nav_stack.frames().is_empty());
+
+        // Closing it leaves the table as it was, with nothing to go back to
+        session.press("esc").await.unwrap();
+        assert_eq!(session.page(), "pods");
+        assert!(session.app().modal.is_none());
+        assert!(session.app().nav_stack.frames().is_empty());
+        assert_eq!(session.app().selected_index, 1);
+        assert!(!session.screen().unwrap().contains("sidecar"));
+
+        // Navigating on from it keeps it as a regular page on the way back
+        session.press("enter enter").await.unwrap();
+        assert_eq!(session.page(), "logs");
+        assert!(session.app().modal.is_none());
+        assert_eq!(session.app().nav_stack.frames().len(), 2);
+        session.press("esc").await.unwrap();
+        assert_eq!(session.page(), "containers");
+        assert!(session.app().modal.is_none());
+        session.press("esc").await.unwrap();
+        assert_eq!(session.page(), "pods");
+    }
+}
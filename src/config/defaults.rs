@@ -1,49 +1,169 @@
 use std::collections::HashMap;
 
-/// Default keybindings for the application
+/// Default keybindings for the application (key -> built-in action name).
+/// Config `keybindings.global` entries are applied on top of these.
 pub fn default_keybindings() -> HashMap<String, String> {
     let mut bindings = HashMap::new();
 
     // Global
     bindings.insert("q".to_string(), "quit".to_string());
-    bindings.insert("?".to_string(), "help".to_string());
-    bindings.insert("Esc".to_string(), "back".to_string());
     bindings.insert("Ctrl+c".to_string(), "quit".to_string());
+    bindings.insert("Esc".to_string(), "back".to_string());
+    bindings.insert("Alt+Left".to_string(), "back".to_string());
+    bindings.insert("Alt+Right".to_string(), "forward".to_string());
+    bindings.insert("H".to_string(), "history".to_string());
+    bindings.insert("B".to_string(), "bookmark".to_string());
+    bindings.insert("'".to_string(), "bookmarks".to_string());
+    bindings.insert("[".to_string(), "prev_row".to_string());
+    bindings.insert("]".to_string(), "next_row".to_string());
 
     // Navigation
     bindings.insert("j".to_string(), "down".to_string());
+    bindings.insert("Down".to_string(), "down".to_string());
     bindings.insert("k".to_string(), "up".to_string());
+    bindings.insert("Up".to_string(), "up".to_string());
     bindings.insert("g".to_string(), "top".to_string());
     bindings.insert("G".to_string(), "bottom".to_string());
+    bindings.insert("Ctrl+d".to_string(), "half_page_down".to_string());
+    bindings.insert("Ctrl+u".to_string(), "half_page_up".to_string());
+    bindings.insert("PageDown".to_string(), "page_down".to_string());
+    bindings.insert("PageUp".to_string(), "page_up".to_string());
+    bindings.insert("}".to_string(), "paragraph_down".to_string());
+    bindings.insert("{".to_string(), "paragraph_up".to_string());
     bindings.insert("Enter".to_string(), "select".to_string());
-    bindings.insert("h".to_string(), "back".to_string());
-    bindings.insert("l".to_string(), "forward".to_string());
 
     // Actions
     bindings.insert("r".to_string(), "refresh".to_string());
+    bindings.insert("p".to_string(), "pause_refresh".to_string());
     bindings.insert("/".to_string(), "search".to_string());
-    bindings.insert(":".to_string(), "command".to_string());
-    bindings.insert("y".to_string(), "yaml_view".to_string());
+    bindings.insert("A".to_string(), "action_menu".to_string());
+    bindings.insert("m".to_string(), "row_menu".to_string());
+    bindings.insert("o".to_string(), "open_link".to_string());
+    bindings.insert(":".to_string(), "command_palette".to_string());
+    bindings.insert("x".to_string(), "expand".to_string());
+    bindings.insert("tab".to_string(), "expand".to_string());
+    bindings.insert("v".to_string(), "view_value".to_string());
+    bindings.insert("BackTab".to_string(), "switch_focus".to_string());
+    bindings.insert("M".to_string(), "notifications".to_string());
 
-    // Table specific
-    bindings.insert("Space".to_string(), "toggle_select".to_string());
-    bindings.insert("a".to_string(), "select_all".to_string());
+    // Table columns (h/l move the column focus; sort applies to the focused column)
+    bindings.insert("<".to_string(), "move_column_left".to_string());
+    bindings.insert(">".to_string(), "move_column_right".to_string());
     bindings.insert("s".to_string(), "sort".to_string());
-    bindings.insert("S".to_string(), "sort_desc".to_string());
 
-    // Detail view scrolling
-    bindings.insert("Ctrl+d".to_string(), "page_down".to_string());
-    bindings.insert("Ctrl+u".to_string(), "page_up".to_string());
+    // Multi-select (tables with `multi_select: true`)
+    bindings.insert("Space".to_string(), "toggle_mark".to_string());
+    bindings.insert("Ctrl+a".to_string(), "mark_all".to_string());
+    bindings.insert("u".to_string(), "clear_marks".to_string());
+
+    // Diff against a baseline snapshot (tables)
+    bindings.insert("b".to_string(), "baseline".to_string());
+    bindings.insert("d".to_string(), "toggle_diff".to_string());
+
+    // Logs view (h/l scroll horizontally)
+    bindings.insert("f".to_string(), "follow".to_string());
+    bindings.insert("w".to_string(), "wrap".to_string());
+    bindings.insert("t".to_string(), "timestamps".to_string());
+    bindings.insert("n".to_string(), "next_match".to_string());
+    bindings.insert("N".to_string(), "prev_match".to_string());
+    bindings.insert("S".to_string(), "save_buffer".to_string());
+    bindings.insert("h".to_string(), "scroll_left".to_string());
+    bindings.insert("Left".to_string(), "scroll_left".to_string());
+    bindings.insert("l".to_string(), "scroll_right".to_string());
+    bindings.insert("Right".to_string(), "scroll_right".to_string());
 
     bindings
 }
 
-/// Default theme configuration
+/// Names of the built-in themes, for `app.theme` and as a custom theme's `base`
+pub const BUILTIN_THEMES: &[&str] = &["default", "dark", "light", "solarized"];
+
+/// Default theme configuration: the terminal's own foreground and background
+/// with the 16 basic colors, so it suits dark and light terminals alike
 pub fn default_theme() -> ThemeConfig {
-    ThemeConfig {
-        name: "default".to_string(),
-        colors: default_colors(),
-    }
+    builtin_theme("default").unwrap()
+}
+
+/// A built-in theme by name
+pub fn builtin_theme(name: &str) -> Option<ThemeConfig> {
+    let colors: &[(&str, &str)] = match name {
+        "default" => &[
+            ("fg", "reset"),
+            ("bg", "reset"),
+            ("dim", "gray"),
+            ("muted", "darkgray"),
+            ("border", "reset"),
+            ("accent", "cyan"),
+            ("secondary", "magenta"),
+            ("heading", "yellow"),
+            ("selected", "darkgray"),
+            ("highlight", "yellow"),
+            ("inverse", "black"),
+            ("success", "green"),
+            ("warning", "yellow"),
+            ("error", "red"),
+            ("info", "blue"),
+        ],
+        "dark" => &[
+            ("fg", "#e4e4e4"),
+            ("bg", "#1c1c1c"),
+            ("dim", "#b2b2b2"),
+            ("muted", "#6c6c6c"),
+            ("border", "#4e4e4e"),
+            ("accent", "#5fafff"),
+            ("secondary", "#d787ff"),
+            ("heading", "#ffd75f"),
+            ("selected", "#3a3a3a"),
+            ("highlight", "#ffd75f"),
+            ("inverse", "#1c1c1c"),
+            ("success", "#87d787"),
+            ("warning", "#ffaf5f"),
+            ("error", "#ff5f5f"),
+            ("info", "#5fafd7"),
+        ],
+        "light" => &[
+            ("fg", "#1c1c1c"),
+            ("bg", "#ffffff"),
+            ("dim", "#4e4e4e"),
+            ("muted", "#8a8a8a"),
+            ("border", "#bcbcbc"),
+            ("accent", "#005fd7"),
+            ("secondary", "#8700af"),
+            ("heading", "#875f00"),
+            ("selected", "#dadada"),
+            ("highlight", "#ffd700"),
+            ("inverse", "#ffffff"),
+            ("success", "#008700"),
+            ("warning", "#af5f00"),
+            ("error", "#d70000"),
+            ("info", "#0087af"),
+        ],
+        "solarized" => &[
+            ("fg", "#93a1a1"),
+            ("bg", "#002b36"),
+            ("dim", "#839496"),
+            ("muted", "#586e75"),
+            ("border", "#586e75"),
+            ("accent", "#2aa198"),
+            ("secondary", "#d33682"),
+            ("heading", "#b58900"),
+            ("selected", "#073642"),
+            ("highlight", "#b58900"),
+            ("inverse", "#002b36"),
+            ("success", "#859900"),
+            ("warning", "#cb4b16"),
+            ("error", "#dc322f"),
+            ("info", "#268bd2"),
+        ],
+        _ => return None,
+    };
+    Some(ThemeConfig {
+        name: name.to_string(),
+        colors: colors
+            .iter()
+            .map(|(role, color)| (role.to_string(), color.to_string()))
+            .collect(),
+    })
 }
 
 #[derive(Debug, Clone)]
@@ -51,18 +171,3 @@ pub struct ThemeConfig {
     pub name: String,
     pub colors: HashMap<String, String>,
 }
-
-fn default_colors() -> HashMap<String, String> {
-    let mut colors = HashMap::new();
-
-    colors.insert("fg".to_string(), "white".to_string());
-    colors.insert("bg".to_string(), "black".to_string());
-    colors.insert("border".to_string(), "gray".to_string());
-    colors.insert("selected".to_string(), "blue".to_string());
-    colors.insert("success".to_string(), "green".to_string());
-    colors.insert("error".to_string(), "red".to_string());
-    colors.insert("warning".to_string(), "yellow".to_string());
-    colors.insert("info".to_string(), "cyan".to_string());
-
-    colors
-}
@@ -1,21 +1,87 @@
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, anyhow};
 use std::path::Path;
+use std::str::FromStr;
 
 use super::schema::Config;
 
+/// Syntax a config file is written in. All three describe the same config;
+/// JSON and TOML are for teams that generate configs with other tooling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConfigFormat {
+    #[default]
+    Yaml,
+    Json,
+    Toml,
+}
+
+impl ConfigFormat {
+    /// The format a file's extension names; YAML for anything else
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Self::Json,
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => Self::Toml,
+            _ => Self::Yaml,
+        }
+    }
+}
+
+impl FromStr for ConfigFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "yaml" | "yml" => Ok(Self::Yaml),
+            "json" => Ok(Self::Json),
+            "toml" => Ok(Self::Toml),
+            other => Err(format!("unknown config format '{}' (yaml, json or toml)", other)),
+        }
+    }
+}
+
 pub struct ConfigLoader;
 
 impl ConfigLoader {
+    /// Load a config, its format detected from the file extension
     pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Config> {
+        let format = ConfigFormat::from_path(path.as_ref());
+        Self::load_from_file_as(path, format)
+    }
+
+    pub fn load_from_file_as<P: AsRef<Path>>(path: P, format: ConfigFormat) -> Result<Config> {
         let content = std::fs::read_to_string(path.as_ref())
             .with_context(|| format!("Failed to read config file: {:?}", path.as_ref()))?;
 
-        Self::load_from_string(&content)
+        Self::load_from_str_as(&content, format)
     }
 
     pub fn load_from_string(content: &str) -> Result<Config> {
+        Self::load_from_str_as(content, ConfigFormat::Yaml)
+    }
+
+    pub fn load_from_str_as(content: &str, format: ConfigFormat) -> Result<Config> {
+        // Everything goes through a YAML value, which page templates and
+        // resource pages expand before the config is parsed
+        let mut value: serde_yaml::Value = match format {
+            ConfigFormat::Yaml => {
+                serde_yaml::from_str(content).context("Failed to parse YAML config")?
+            }
+            ConfigFormat::Json => {
+                let json: serde_json::Value =
+                    serde_json::from_str(content).context("Failed to parse JSON config")?;
+                serde_yaml::to_value(json)?
+            }
+            ConfigFormat::Toml => {
+                let table: toml::Table = toml::from_str(content)
+                    .map_err(|e| anyhow!("{}", e.to_string().trim_end()))
+                    .context("Failed to parse TOML config")?;
+                serde_yaml::to_value(table)?
+            }
+        };
+        super::page_template::expand_templates(&mut value)?;
+        super::resource::expand_resources(&mut value)?;
+
         let config: Config =
-            serde_yaml::from_str(content).context("Failed to parse YAML config")?;
+            serde_yaml::from_value(value).context("Failed to parse config")?;
 
         Ok(config)
     }
@@ -55,4 +121,103 @@ pages:
         assert_eq!(config.start, "main");
         assert!(config.pages.contains_key("main"));
     }
+
+    #[test]
+    fn test_refresh_interval() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+  refresh_interval: "1m"
+start: api
+pages:
+  api:
+    title: "API"
+    data:
+      adapter: http
+      url: "https://example.com/items"
+      refresh_interval: "5s"
+    view:
+      type: text
+  script:
+    title: "Script"
+    data:
+      adapter: script
+      script: "echo '[]'"
+    view:
+      type: text
+"#;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        let interval = |page: &str| config.refresh_interval(&config.pages[page]);
+        assert_eq!(interval("api"), Some(std::time::Duration::from_secs(5)));
+        // Pages without their own interval use the app's
+        assert_eq!(interval("script"), Some(std::time::Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn test_set_global() {
+        let yaml = "version: v1\napp: {name: Test}\nstart: main\n\
+                    globals: {namespace: default, cluster: {name: dev, region: eu}}\n\
+                    pages: {main: {title: Main, view: {type: text}}}";
+        let mut config = ConfigLoader::load_from_string(yaml).unwrap();
+        config.set_global("namespace=kube-system").unwrap();
+        config.set_global("replicas=3").unwrap();
+        config.set_global("cluster.name=prod").unwrap();
+        config.set_global("url=http://host/?a=b").unwrap();
+        assert_eq!(config.globals["namespace"], "kube-system");
+        assert_eq!(config.globals["replicas"], 3);
+        assert_eq!(config.globals["cluster"]["name"], "prod");
+        assert_eq!(config.globals["cluster"]["region"], "eu");
+        assert_eq!(config.globals["url"], "http://host/?a=b");
+        assert!(config.set_global("namespace").is_err());
+        assert!(config.set_global("=x").is_err());
+    }
+
+    #[test]
+    fn test_json_and_toml() {
+        let json = r#"{
+  "version": "v1",
+  "app": { "name": "Test App" },
+  "start": "main",
+  "pages": {
+    "main": {
+      "title": "Main Page",
+      "data": { "adapter": "http", "url": "https://example.com/items", "timeout": "5s" },
+      "view": { "type": "table", "columns": [{ "path": "$.name", "display": "Name" }] }
+    }
+  }
+}"#;
+        let toml = r#"
+version = "v1"
+start = "main"
+
+[app]
+name = "Test App"
+
+[pages.main]
+title = "Main Page"
+
+[pages.main.data]
+adapter = "http"
+url = "https://example.com/items"
+timeout = "5s"
+
+[pages.main.view]
+type = "table"
+columns = [{ path = "$.name", display = "Name" }]
+"#;
+        for (content, format) in [(json, ConfigFormat::Json), (toml, ConfigFormat::Toml)] {
+            let config = ConfigLoader::load_from_str_as(content, format).unwrap();
+            crate::config::ConfigValidator::validate(&config).unwrap();
+            assert_eq!(config.app.name, "Test App");
+            assert!(matches!(&config.pages["main"].view, crate::config::View::Table(_)));
+        }
+
+        let err = ConfigLoader::load_from_str_as("version = ", ConfigFormat::Toml).unwrap_err();
+        assert!(format!("{:#}", err).contains("Failed to parse TOML config"));
+        assert_eq!(ConfigFormat::from_path(Path::new("a/b.JSON")), ConfigFormat::Json);
+        assert_eq!(ConfigFormat::from_path(Path::new("dash.yml")), ConfigFormat::Yaml);
+        assert_eq!("toml".parse::<ConfigFormat>(), Ok(ConfigFormat::Toml));
+    }
 }
@@ -0,0 +1,203 @@
+// Where in the config text a setting is, for errors and lints that point at it
+use std::fmt;
+
+/// One step of a path into the config: `pages`, `pods`, `actions`, 2, `key`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+pub fn key(name: &str) -> Segment {
+    Segment::Key(name.to_string())
+}
+
+/// Error context naming where in the config the error is. Added with
+/// [`AtPath::at`], which prefixes the path of any `At` already on the error, so
+/// the outermost one holds the whole path.
+#[derive(Debug)]
+pub struct At {
+    pub path: Vec<Segment>,
+    message: String,
+}
+
+impl At {
+    /// An error raised at `path` itself
+    pub fn error(path: Vec<Segment>, message: impl fmt::Display) -> anyhow::Error {
+        anyhow::Error::new(At {
+            path,
+            message: message.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for At {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for At {}
+
+pub trait AtPath<T> {
+    /// Like `context(message)`, recording that the error is at `path`
+    /// (relative to the enclosing `at`)
+    fn at(self, path: Vec<Segment>, message: impl fmt::Display) -> anyhow::Result<T>;
+}
+
+impl<T, E: Into<anyhow::Error>> AtPath<T> for Result<T, E> {
+    fn at(self, mut path: Vec<Segment>, message: impl fmt::Display) -> anyhow::Result<T> {
+        self.map_err(|e| {
+            let e = e.into();
+            if let Some(inner) = e.downcast_ref::<At>() {
+                path.extend(inner.path.iter().cloned());
+            }
+            e.context(At {
+                path,
+                message: message.to_string(),
+            })
+        })
+    }
+}
+
+/// The path an error was raised at, if the validator recorded one
+pub fn error_path(error: &anyhow::Error) -> Option<&[Segment]> {
+    error.downcast_ref::<At>().map(|at| at.path.as_slice())
+}
+
+/// The 1-based line and column of `path` in YAML (or pretty-printed JSON)
+/// `source`, or of the deepest part of it found: keys inside flow collections
+/// (`{ }`, `[ ]`) and pages generated from templates or resources aren't in
+/// the text.
+pub fn locate(source: &str, path: &[Segment]) -> Option<(usize, usize)> {
+    let lines: Vec<&str> = source.lines().collect();
+    let skip = |line: &str| {
+        let content = line.trim();
+        content.is_empty() || content.starts_with('#')
+    };
+
+    let mut found = None;
+    let mut from = 0;
+    let mut parent: Option<usize> = None;
+    let mut in_item = false;
+    for segment in path {
+        let mut block_indent = None;
+        let mut hit = None;
+        for (i, line) in lines.iter().enumerate().skip(from) {
+            if skip(line) {
+                continue;
+            }
+            let (indent, content) = split(line, in_item && i == from);
+            if parent.is_some_and(|p| indent <= p) && !(in_item && i == from) {
+                // A sequence may sit at its key's indentation
+                let is_item = matches!(segment, Segment::Index(_)) && content.starts_with("- ");
+                if !(is_item && Some(indent) == parent) {
+                    break;
+                }
+            }
+            let level = *block_indent.get_or_insert(indent);
+            if indent != level {
+                continue;
+            }
+            match segment {
+                Segment::Key(key) => {
+                    let key = key.trim();
+                    let matched = [
+                        format!("{}:", key),
+                        format!("\"{}\":", key),
+                        format!("'{}':", key),
+                    ]
+                    .iter()
+                    .any(|k| content.starts_with(k.as_str()));
+                    if matched {
+                        hit = Some((i, indent, false));
+                        break;
+                    }
+                }
+                Segment::Index(n) => {
+                    if !content.starts_with("- ") && content != "-" {
+                        break;
+                    }
+                    let count = lines[from..i]
+                        .iter()
+                        .filter(|l| !skip(l) && split(l, false).0 == indent)
+                        .count();
+                    if count == *n {
+                        hit = Some((i, indent, true));
+                        break;
+                    }
+                }
+            }
+        }
+        let Some((i, indent, item)) = hit else {
+            break;
+        };
+        found = Some((i + 1, indent + 1));
+        parent = Some(indent);
+        in_item = item;
+        from = if item { i } else { i + 1 };
+    }
+    found
+}
+
+/// Indentation and content of a line, the `- ` of a sequence item counted as
+/// indentation when the item is what we're inside
+fn split(line: &str, in_item: bool) -> (usize, &str) {
+    let content = line.trim_start();
+    let indent = line.len() - content.len();
+    match content.strip_prefix("- ").filter(|_| in_item) {
+        Some(rest) => (indent + 2, rest.trim_start()),
+        None => (indent, content),
+    }
+}
+
+/// `line` of `source` with a caret under `column`, compiler style
+pub fn snippet(source: &str, line: usize, column: usize) -> String {
+    let text = source.lines().nth(line.saturating_sub(1)).unwrap_or_default();
+    let gutter = " ".repeat(line.to_string().len());
+    format!(
+        "{} |\n{} | {}\n{} | {}^",
+        gutter,
+        line,
+        text,
+        gutter,
+        " ".repeat(column.saturating_sub(1))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Segment::Index;
+    use anyhow::anyhow;
+
+    #[test]
+    fn test_locate() {
+        let yaml = "pages:\n  main:\n    actions:\n    - key: a\n      name: A\n    - key: b\n\
+                    \n    view: {type: text}\n";
+        let at = [key("pages"), key("main"), key("actions"), Index(1)];
+        assert_eq!(locate(yaml, &at), Some((6, 5)));
+        let at = [key("pages"), key("main"), key("actions"), Index(0), key("name")];
+        assert_eq!(locate(yaml, &at), Some((5, 7)));
+        // Flow mappings: the deepest key found
+        let at = [key("pages"), key("main"), key("view"), key("type")];
+        assert_eq!(locate(yaml, &at), Some((8, 5)));
+        assert_eq!(locate(yaml, &[key("globals")]), None);
+
+        assert_eq!(snippet(yaml, 5, 7), "  |\n5 |       name: A\n  |       ^");
+    }
+
+    #[test]
+    fn test_error_path() {
+        let error = Err::<(), _>(anyhow!("Action page 'x' not found"))
+            .at(vec![key("actions"), Index(2)], "Invalid action at index 2")
+            .at(vec![key("pages"), key("main")], "Invalid page: main")
+            .unwrap_err();
+        let path = [key("pages"), key("main"), key("actions"), Index(2)];
+        assert_eq!(error_path(&error), Some(&path[..]));
+        assert_eq!(
+            format!("{:#}", error),
+            "Invalid page: main: Invalid action at index 2: Action page 'x' not found"
+        );
+    }
+}
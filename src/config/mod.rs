@@ -1,9 +1,12 @@
 pub mod defaults;
 pub mod loader;
+pub mod location;
+pub mod page_template;
+pub mod resource;
 pub mod schema;
 pub mod validator;
 
 pub use defaults::*;
-pub use loader::ConfigLoader;
+pub use loader::{ConfigFormat, ConfigLoader};
 pub use schema::*;
 pub use validator::ConfigValidator;
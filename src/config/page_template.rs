@@ -0,0 +1,224 @@
+// `page_templates:` — parameterized page definitions instantiated by pages
+use anyhow::{Context, Result, anyhow};
+use serde_yaml::{Mapping, Value};
+
+/// Replace every page that has a `template:` key with an instance of that
+/// template, its parameters filled in from the page's `with:`.
+///
+/// A template is `params` (names with defaults; `~` for required ones) and a
+/// `page` whose strings may use `${name}`. A string that is only `${name}`
+/// takes the parameter's value as is (a number stays a number); elsewhere it
+/// is spliced into the text (`$${` for a literal `${`). `{{ }}` templates are
+/// left for runtime. Other keys on the instantiating page override the
+/// template's. Works on the raw YAML before it is parsed into `Config`, and
+/// before `resource:` pages expand.
+pub fn expand_templates(root: &mut Value) -> Result<()> {
+    let templates = match root.as_mapping_mut().and_then(|r| r.remove("page_templates")) {
+        Some(Value::Mapping(templates)) => templates,
+        Some(Value::Null) | None => return Ok(()),
+        Some(_) => return Err(anyhow!("page_templates must be a mapping of template names")),
+    };
+    let Some(pages) = root.get_mut("pages").and_then(|p| p.as_mapping_mut()) else {
+        return Ok(());
+    };
+
+    for (id, page) in pages.iter_mut() {
+        let Some(mut instance) = page.as_mapping().filter(|p| p.contains_key("template")).cloned()
+        else {
+            continue;
+        };
+        let page_id = id.as_str().unwrap_or_default();
+        let name = match instance.remove("template") {
+            Some(Value::String(name)) => name,
+            _ => return Err(anyhow!("Page '{}': template must be a template name", page_id)),
+        };
+        let template = templates.get(name.as_str()).ok_or_else(|| {
+            anyhow!("Page '{}' uses template '{}', which isn't defined", page_id, name)
+        })?;
+        let args = match instance.remove("with") {
+            Some(Value::Mapping(args)) => args,
+            Some(Value::Null) | None => Mapping::new(),
+            Some(_) => return Err(anyhow!("Page '{}': with must be a mapping", page_id)),
+        };
+        let mut expanded = instantiate(template, &args)
+            .with_context(|| format!("Invalid template '{}' for page '{}'", name, page_id))?;
+        for (key, value) in instance {
+            expanded.insert(key, value);
+        }
+        *page = Value::Mapping(expanded);
+    }
+
+    Ok(())
+}
+
+/// The template's page with `args` (over the defaults) substituted
+fn instantiate(template: &Value, args: &Mapping) -> Result<Mapping> {
+    let mut params = match template.get("params") {
+        Some(Value::Mapping(params)) => params.clone(),
+        Some(Value::Null) | None => Mapping::new(),
+        Some(_) => return Err(anyhow!("params must map names to defaults")),
+    };
+    for (name, value) in args {
+        if !params.contains_key(name) {
+            return Err(anyhow!("Unknown parameter '{}'", name.as_str().unwrap_or_default()));
+        }
+        params.insert(name.clone(), value.clone());
+    }
+    if let Some((name, _)) = params.iter().find(|(_, value)| value.is_null()) {
+        return Err(anyhow!("Missing parameter '{}'", name.as_str().unwrap_or_default()));
+    }
+
+    let mut page = template.get("page").cloned().ok_or_else(|| anyhow!("Missing page"))?;
+    substitute(&mut page, &params)?;
+    match page {
+        Value::Mapping(page) => Ok(page),
+        _ => Err(anyhow!("page must be a mapping")),
+    }
+}
+
+fn substitute(value: &mut Value, params: &Mapping) -> Result<()> {
+    match value {
+        Value::String(text) => {
+            if let Some(name) = text.strip_prefix("${").and_then(|t| t.strip_suffix('}'))
+                && !name.contains("${")
+            {
+                *value = param(params, name)?.clone();
+                return Ok(());
+            }
+            *text = splice(text, params)?;
+        }
+        Value::Sequence(items) => {
+            for item in items {
+                substitute(item, params)?;
+            }
+        }
+        Value::Mapping(mapping) => {
+            let entries = std::mem::take(mapping);
+            for (mut key, mut value) in entries {
+                substitute(&mut key, params)?;
+                substitute(&mut value, params)?;
+                mapping.insert(key, value);
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// `text` with each `${name}` replaced by the parameter as text
+fn splice(text: &str, params: &Mapping) -> Result<String> {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("${") {
+        // `$${` is a literal `${`, e.g. for shell variables
+        if rest[..start].ends_with('$') {
+            out.push_str(&rest[..start - 1]);
+            out.push_str("${");
+            rest = &rest[start + 2..];
+            continue;
+        }
+        let end = rest[start..]
+            .find('}')
+            .ok_or_else(|| anyhow!("Unclosed '${{' in '{}'", text))?;
+        out.push_str(&rest[..start]);
+        out.push_str(&match param(params, &rest[start + 2..start + end])? {
+            Value::String(s) => s.clone(),
+            Value::Number(n) => n.to_string(),
+            Value::Bool(b) => b.to_string(),
+            other => serde_yaml::to_string(other)?.trim_end().to_string(),
+        });
+        rest = &rest[start + end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+fn param<'a>(params: &'a Mapping, name: &str) -> Result<&'a Value> {
+    params
+        .get(name.trim())
+        .ok_or_else(|| anyhow!("Unknown parameter '{}' in ${{{}}}", name.trim(), name))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{ConfigLoader, ConfigValidator};
+
+    const TEMPLATES: &str = r#"
+version: v1
+app:
+  name: "Test App"
+start: api
+page_templates:
+  service:
+    params:
+      service: ~
+      port: 8080
+    page:
+      title: "${service} on :${port}"
+      data:
+        adapter: http
+        url: "http://${service}.internal:${port}/status"
+        headers:
+          X-Shell: "$${HOME}"
+        timeout: "${timeout}"
+      view:
+        type: table
+        columns:
+          - path: "$.name"
+            display: "{{ globals.label }}"
+"#;
+
+    #[test]
+    fn test_expand_templates() {
+        let yaml = format!(
+            "{}{}",
+            TEMPLATES.replace("    params:\n", "    params:\n      timeout: 5s\n"),
+            r#"pages:
+  api:
+    template: service
+    with: { service: api }
+  db:
+    template: service
+    with: { service: db, port: 5432 }
+    title: "Database"
+"#
+        );
+        let config = ConfigLoader::load_from_string(&yaml).unwrap();
+        ConfigValidator::validate(&config).unwrap();
+
+        let api = &config.pages["api"];
+        assert_eq!(api.title, "api on :8080");
+        let Some(crate::config::DataSource::SingleOrStream(
+            crate::config::SingleOrStream::Single(data),
+        )) = &api.data
+        else {
+            panic!("expected a single source");
+        };
+        assert_eq!(data.config["url"], "http://api.internal:8080/status");
+        assert_eq!(data.timeout.as_deref(), Some("5s"));
+        assert_eq!(data.config["headers"]["X-Shell"], "${HOME}");
+        // Runtime templates are left alone
+        assert!(matches!(
+            &api.view,
+            crate::config::View::Table(t) if t.columns[0].display == "{{ globals.label }}"
+        ));
+        // Keys on the page override the template's
+        assert_eq!(config.pages["db"].title, "Database");
+    }
+
+    #[test]
+    fn test_template_errors() {
+        let load = |pages: &str| {
+            let yaml = format!("{}pages:\n{}", TEMPLATES, pages);
+            format!("{:#}", ConfigLoader::load_from_string(&yaml).unwrap_err())
+        };
+        assert!(load("  api:\n    template: service\n").contains("Missing parameter 'service'"));
+        assert!(load("  api:\n    template: other\n").contains("isn't defined"));
+        let message =
+            load("  api:\n    template: service\n    with: { service: a, colour: red }\n");
+        assert!(message.contains("Unknown parameter 'colour'"));
+        // `timeout` is used by the page but not declared
+        let message = load("  api:\n    template: service\n    with: { service: a }\n");
+        assert!(message.contains("Unknown parameter 'timeout'"));
+    }
+}
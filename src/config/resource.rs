@@ -0,0 +1,277 @@
+// `resource:` page shorthand for CRUD-style REST APIs
+use anyhow::{Context, Result, anyhow};
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::HashMap;
+
+use super::schema::TableColumn;
+
+/// A REST collection described in a few lines. Expands into a list page (the
+/// page itself), a `<page>_detail` page for one item, and a Delete action.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResourceSpec {
+    /// Collection URL; an item lives at `<url>/<id>`
+    pub url: String,
+    /// JSONPath to the items in the list response
+    #[serde(default = "default_items")]
+    pub items: String,
+    /// Field holding an item's id (dotted for nested fields, e.g. `metadata.name`)
+    #[serde(default = "default_id")]
+    pub id: String,
+    /// JSONPath to the item in the detail response
+    #[serde(default = "default_detail_items")]
+    pub detail_items: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    /// List columns (default: just the id)
+    #[serde(default)]
+    pub columns: Vec<TableColumn>,
+    /// Sent with every request (list, detail and delete)
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    #[serde(default)]
+    pub refresh_interval: Option<String>,
+    /// Add a Delete action (`Delete` key, asks for confirmation)
+    #[serde(default = "default_true")]
+    pub delete: bool,
+}
+
+fn default_items() -> String {
+    "$[*]".to_string()
+}
+
+fn default_id() -> String {
+    "id".to_string()
+}
+
+fn default_detail_items() -> String {
+    "$".to_string()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Replace every page that has a `resource:` key with its expanded pages.
+///
+/// Works on the raw YAML before it is parsed into `Config`. Other keys on a
+/// resource page override the generated ones, except `actions`, which are
+/// added after the generated Delete action.
+pub fn expand_resources(root: &mut serde_yaml::Value) -> Result<()> {
+    let Some(pages) = root.get_mut("pages").and_then(|p| p.as_mapping_mut()) else {
+        return Ok(());
+    };
+
+    let resource_pages: Vec<String> = pages
+        .iter()
+        .filter(|(_, page)| page.get("resource").is_some())
+        .filter_map(|(id, _)| id.as_str().map(str::to_string))
+        .collect();
+
+    for page_id in resource_pages {
+        let mut page = pages
+            .remove(page_id.as_str())
+            .and_then(|p| match p {
+                serde_yaml::Value::Mapping(m) => Some(m),
+                _ => None,
+            })
+            .unwrap_or_default();
+        let spec_value = page.remove("resource").unwrap_or_default();
+        let spec: ResourceSpec = serde_yaml::from_value(spec_value)
+            .with_context(|| format!("Invalid resource on page '{}'", page_id))?;
+
+        let detail_id = format!("{}_detail", page_id);
+        if pages.contains_key(detail_id.as_str()) {
+            return Err(anyhow!(
+                "Resource page '{}' generates page '{}', which already exists",
+                page_id,
+                detail_id
+            ));
+        }
+
+        let (list, detail) = expand(&page_id, &spec);
+        let mut list: serde_yaml::Mapping = serde_yaml::to_value(list)
+            .ok()
+            .and_then(|v| v.as_mapping().cloned())
+            .unwrap_or_default();
+        for (key, value) in page {
+            if key.as_str() == Some("actions")
+                && let (Some(extra), Some(serde_yaml::Value::Sequence(generated))) =
+                    (value.as_sequence(), list.get_mut("actions"))
+            {
+                generated.extend(extra.iter().cloned());
+                continue;
+            }
+            list.insert(key, value);
+        }
+
+        pages.insert(page_id.into(), serde_yaml::Value::Mapping(list));
+        pages.insert(detail_id.into(), serde_yaml::to_value(detail)?);
+    }
+
+    Ok(())
+}
+
+/// The list and detail pages for a resource, as config values
+fn expand(page_id: &str, spec: &ResourceSpec) -> (serde_json::Value, serde_json::Value) {
+    // Context variable carrying the selected id to the detail page
+    let id_var: String = format!("{}_id", page_id)
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    let url = spec.url.trim_end_matches('/');
+    // Ids go into the URL as a single, percent-encoded path segment
+    let segment = |var: &str| format!("{{{{ {} | as_str | urlencode_strict }}}}", var);
+    let title = spec.title.clone().unwrap_or_else(|| page_id.to_string());
+    let columns = if spec.columns.is_empty() {
+        json!([{ "path": format!("$.{}", spec.id), "display": "ID" }])
+    } else {
+        serde_json::to_value(&spec.columns).unwrap_or_default()
+    };
+
+    let mut actions = Vec::new();
+    if spec.delete {
+        actions.push(json!({
+            "key": "Delete",
+            "name": "Delete",
+            "description": format!("Delete the selected {}", title),
+            "confirm": format!("Delete {{{{ row.{} }}}}?", spec.id),
+            "http": {
+                "method": "DELETE",
+                "url": format!("{}/{}", url, segment(&format!("row.{}", spec.id))),
+                "headers": spec.headers,
+            },
+            "success_message": format!("Deleted {{{{ row.{} }}}}", spec.id),
+            "refresh": true,
+        }));
+    }
+
+    let list = json!({
+        "title": title,
+        "data": {
+            "adapter": "http",
+            "url": url,
+            "headers": spec.headers,
+            "items": spec.items,
+            "refresh_interval": spec.refresh_interval,
+        },
+        "view": {
+            "type": "table",
+            "columns": columns,
+            "row_key": format!("$.{}", spec.id),
+        },
+        "next": {
+            "page": format!("{}_detail", page_id),
+            "context": { id_var.as_str(): format!("$.{}", spec.id) },
+        },
+        "actions": actions,
+    });
+
+    let detail = json!({
+        "title": format!("{} {{{{ {} }}}}", title, id_var),
+        "data": {
+            "adapter": "http",
+            "url": format!("{}/{}", url, segment(&id_var)),
+            "headers": spec.headers,
+            "items": spec.detail_items,
+        },
+        "view": { "type": "text", "syntax": "json" },
+    });
+
+    (list, detail)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{
+        ConfigLoader, ConfigValidator, DataSource, Navigation, SingleOrStream, View,
+    };
+    use crate::template::engine::{TemplateContext, TemplateEngine};
+
+    #[test]
+    fn test_expand_resource() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+start: users
+pages:
+  users:
+    resource:
+      url: "https://api.example.com/users/"
+      items: "$.data[*]"
+      title: "Users"
+      headers:
+        Accept: "application/json"
+      columns:
+        - path: "$.name"
+          display: "Name"
+    actions:
+      - key: "ctrl+o"
+        name: "Open"
+        command: "open"
+"#;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        ConfigValidator::validate(&config).unwrap();
+
+        let list = &config.pages["users"];
+        assert_eq!(list.title, "Users");
+        assert!(matches!(&list.view, View::Table(t) if t.columns[0].display == "Name"));
+        let Some(Navigation::Simple(next)) = &list.next else {
+            panic!("expected simple navigation");
+        };
+        assert_eq!(next.page, "users_detail");
+        assert_eq!(next.context["users_id"], "$.id");
+
+        let actions = list.actions.as_ref().unwrap();
+        assert_eq!(actions.len(), 2);
+        let delete = actions[0].http.as_ref().unwrap();
+        assert_eq!(
+            delete.url,
+            "https://api.example.com/users/{{ row.id | as_str | urlencode_strict }}"
+        );
+        assert!(actions[0].refresh);
+
+        let detail = &config.pages["users_detail"];
+        assert!(matches!(&detail.view, View::Text(_)));
+        let Some(DataSource::SingleOrStream(SingleOrStream::Single(source))) = &detail.data else {
+            panic!("expected a single source");
+        };
+        let url = source.config["url"].as_str().unwrap();
+        let engine = TemplateEngine::new().unwrap();
+        let ctx = TemplateContext::new()
+            .with_page_context("users_id".to_string(), serde_json::json!("a/b c?d"));
+        assert_eq!(
+            engine.render_string(url, &ctx).unwrap(),
+            "https://api.example.com/users/a%2Fb%20c%3Fd"
+        );
+        let ctx = TemplateContext::new().with_page_context("users_id".to_string(), 42.into());
+        assert_eq!(engine.render_string(url, &ctx).unwrap(), "https://api.example.com/users/42");
+    }
+
+    #[test]
+    fn test_resource_detail_page_clash() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+start: users
+pages:
+  users:
+    resource:
+      url: "https://api.example.com/users"
+  users_detail:
+    title: "Taken"
+    data:
+      type: cli
+      command: "echo"
+    view:
+      type: table
+      columns: []
+"#;
+
+        let err = ConfigLoader::load_from_string(yaml).unwrap_err();
+        assert!(format!("{:#}", err).contains("already exists"));
+    }
+}
@@ -9,10 +9,74 @@ pub struct Config {
     pub globals: HashMap<String, serde_json::Value>,
     #[serde(default)]
     pub keybindings: Option<Keybindings>,
+    /// Custom color palettes by name, selected with `app.theme`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub themes: HashMap<String, ThemePalette>,
     pub start: String,
     pub pages: HashMap<String, Page>,
 }
 
+impl Config {
+    /// How often `page` re-fetches on its own: its data source's
+    /// `refresh_interval`, else `app.refresh_interval`. Applies to every
+    /// adapter; stream pages update as lines arrive instead.
+    pub fn refresh_interval(&self, page: &Page) -> Option<std::time::Duration> {
+        match page.data.as_ref()? {
+            DataSource::SingleOrStream(SingleOrStream::Single(single)) => {
+                self.source_refresh_interval(single)
+            }
+            DataSource::SingleOrStream(SingleOrStream::Stream(_)) | DataSource::Multi(_) => None,
+        }
+    }
+
+    /// How often a single data source (a page's, or a dashboard panel's)
+    /// re-fetches: its `refresh_interval`, else `app.refresh_interval`
+    pub fn source_refresh_interval(
+        &self,
+        source: &SingleDataSource,
+    ) -> Option<std::time::Duration> {
+        let interval = source.refresh_interval.as_deref();
+        humantime::parse_duration(interval.or(self.app.refresh_interval.as_deref())?).ok()
+    }
+
+    /// Apply a `--set key=value` override to `globals` (see [`assign_value`])
+    pub fn set_global(&mut self, assignment: &str) -> Result<(), String> {
+        assign_value(&mut self.globals, assignment)
+    }
+}
+
+/// Apply a `key=value` assignment (from `--set` or `--context`) to `values`.
+/// The value is read as a YAML scalar (`3` is a number, `true` a bool, anything
+/// else a string) and a dotted key sets a field of an object (`cluster.name=prod`).
+pub fn assign_value(
+    values: &mut HashMap<String, serde_json::Value>,
+    assignment: &str,
+) -> Result<(), String> {
+    let (key, value) = assignment
+        .split_once('=')
+        .ok_or_else(|| format!("Expected key=value, got '{}'", assignment))?;
+    let value = serde_yaml::from_str::<serde_json::Value>(value)
+        .ok()
+        .filter(|v| !v.is_null() && !v.is_object() && !v.is_array())
+        .unwrap_or_else(|| serde_json::Value::String(value.to_string()));
+
+    let mut path = key.trim().split('.');
+    let first = path.next().filter(|k| !k.is_empty());
+    let first = first.ok_or_else(|| format!("Missing key in '{}'", assignment))?;
+    let mut slot = values.entry(first.to_string()).or_default();
+    for field in path {
+        if !slot.is_object() {
+            *slot = serde_json::Value::Object(Default::default());
+        }
+        let serde_json::Value::Object(object) = slot else {
+            unreachable!()
+        };
+        slot = object.entry(field).or_insert(serde_json::Value::Null);
+    }
+    *slot = value;
+    Ok(())
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct AppConfig {
     pub name: String,
@@ -24,6 +88,128 @@ pub struct AppConfig {
     pub refresh_interval: Option<String>,
     #[serde(default = "default_history_size")]
     pub history_size: usize,
+    /// Keep search queries (recalled with Up/Down in the search input) across
+    /// sessions, in the state directory. Without it history lasts one session.
+    #[serde(default)]
+    pub save_search_history: bool,
+    /// Keep bookmarked pages (`B`) across sessions, in the state directory.
+    /// Without it bookmarks last one session.
+    #[serde(default)]
+    pub save_bookmarks: bool,
+    /// Checks run before the TUI starts (e.g. "is kubectl context set", "is VPN up")
+    #[serde(default)]
+    pub preflight: Vec<PreflightCheck>,
+    /// Options for the HTTP client shared by http sources, actions and checks
+    #[serde(default)]
+    pub http: Option<HttpClientOptions>,
+    /// Where action results and other messages pop up, how many stack and for
+    /// how long, and how many the history (`M`) keeps
+    #[serde(default)]
+    pub notifications: Option<NotificationOptions>,
+    /// WASM modules loaded at startup, adding data source adapters and
+    /// template filters (see [`crate::wasm`])
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub plugins: Vec<WasmPlugin>,
+}
+
+/// A WASM plugin module (`app.plugins`)
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WasmPlugin {
+    /// Path to the module, binary `.wasm` or `.wat` text
+    pub path: String,
+    /// Instructions (wasmtime fuel) a single call may run; defaults to 100 million
+    #[serde(default)]
+    pub fuel: Option<u64>,
+    /// Most memory the module may grow to, in MiB; defaults to 64
+    #[serde(default)]
+    pub max_memory_mb: Option<usize>,
+}
+
+/// Options for toast notifications (`app.notifications`)
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct NotificationOptions {
+    /// Corner the toasts stack in; defaults to top_right
+    #[serde(default)]
+    pub position: ToastPosition,
+    /// Most toasts shown at once, the oldest giving way; defaults to 3
+    #[serde(default)]
+    pub max_visible: Option<usize>,
+    /// How long each kind of toast stays (humantime); defaults to 3s for
+    /// success and info, 5s for warning and 8s for error
+    #[serde(default)]
+    pub timeouts: ToastTimeouts,
+    /// Notifications kept for the history; defaults to 100
+    #[serde(default)]
+    pub history: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToastPosition {
+    #[default]
+    TopRight,
+    TopLeft,
+    BottomRight,
+    BottomLeft,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToastTimeouts {
+    #[serde(default)]
+    pub success: Option<String>,
+    #[serde(default)]
+    pub info: Option<String>,
+    #[serde(default)]
+    pub warning: Option<String>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// TLS, proxy, redirect and timeout settings for HTTP requests. Set under
+/// `app.http` for every request, or as `client` on an http source or action,
+/// where they override the app's one by one.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct HttpClientOptions {
+    /// PEM file of extra root certificates to trust (e.g. a corporate CA)
+    #[serde(default)]
+    pub ca_cert: Option<String>,
+    /// Accept any certificate; for dev servers with self-signed ones only
+    #[serde(default)]
+    pub insecure: Option<bool>,
+    /// Proxy for all requests, e.g. "http://proxy:3128"; without one the
+    /// HTTP(S)_PROXY environment variables apply
+    #[serde(default)]
+    pub proxy: Option<String>,
+    /// Comma-separated hosts the proxy is bypassed for
+    #[serde(default)]
+    pub no_proxy: Option<String>,
+    /// Most redirects followed (0 follows none); defaults to 10
+    #[serde(default)]
+    pub redirects: Option<usize>,
+    /// Limit on establishing a connection (humantime, e.g. "5s")
+    #[serde(default)]
+    pub connect_timeout: Option<String>,
+}
+
+/// A startup check: either a shell `command` (passes on exit 0) or an `http`
+/// URL (passes on a 2xx response). Both are templates with access to `globals`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PreflightCheck {
+    pub name: String,
+    #[serde(default)]
+    pub command: Option<String>,
+    #[serde(default)]
+    pub http: Option<String>,
+    /// Shown under the check when it fails, e.g. how to fix it
+    #[serde(default)]
+    pub hint: Option<String>,
+    /// Timeout (humantime, e.g. "5s"); defaults to 5 seconds
+    #[serde(default)]
+    pub timeout: Option<String>,
 }
 
 fn default_theme() -> String {
@@ -34,6 +220,19 @@ fn default_history_size() -> usize {
     50
 }
 
+/// A custom theme: a built-in (or another custom theme) with some of its
+/// colors replaced, by role (`accent`, `border`, `error`, ...). Also how a
+/// page or dashboard panel overrides the theme it is drawn with.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ThemePalette {
+    /// Theme whose colors the others are taken from; defaults to `default`
+    #[serde(default)]
+    pub base: Option<String>,
+    #[serde(default)]
+    pub colors: HashMap<String, String>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Keybindings {
     #[serde(default)]
@@ -47,14 +246,136 @@ pub struct Page {
     pub title: String,
     #[serde(default)]
     pub description: Option<String>,
-    pub data: DataSource,
+    /// Named values computed from the fetched data (`items`) after every fetch,
+    /// available to the title template
+    #[serde(default)]
+    pub summary: HashMap<String, String>,
+    /// Where the page's items come from; only dashboards, whose panels fetch
+    /// their own, and forms go without
+    #[serde(default)]
+    pub data: Option<DataSource>,
     pub view: View,
+    /// Context the page needs, by name: opening it without one set (by the
+    /// navigation to it, `--context` or a global) is an error instead of a
+    /// page of empty templates
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<String>,
     #[serde(default)]
     pub next: Option<Navigation>,
     #[serde(default)]
     pub actions: Option<Vec<Action>>,
+    /// Conditions checked against the rows after every fetch
+    #[serde(default)]
+    pub alerts: Vec<Alert>,
+    /// Side effects run on the page's events
+    #[serde(default, skip_serializing_if = "PageHooks::is_empty")]
+    pub hooks: PageHooks,
+    /// Lua run on the page's data, Enter and keys (see [`crate::lua`])
+    #[serde(default, skip_serializing_if = "LuaHooks::is_empty")]
+    pub lua: LuaHooks,
+    /// Colors replaced on this page; `base` defaults to the app's theme
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<ThemePalette>,
+}
+
+/// What to do on a page's events, each a list of hooks run in order
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct PageHooks {
+    /// After every successful fetch, refreshes included; `count` is the number
+    /// of rows
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub on_load: Vec<Hook>,
+    /// When the page fails to load; `error` is the message
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub on_error: Vec<Hook>,
+    /// When one of the page's actions succeeds; `action` is its name
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub on_action_success: Vec<Hook>,
+    /// When the cursor lands on another row, with the row as `row`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub on_row_select: Vec<Hook>,
+}
+
+impl PageHooks {
+    pub fn is_empty(&self) -> bool {
+        self.on_load.is_empty()
+            && self.on_error.is_empty()
+            && self.on_action_success.is_empty()
+            && self.on_row_select.is_empty()
+    }
+}
+
+/// Lua chunks run on a page's events, with `globals` set. Each runs in a fresh
+/// state without io, os or require, stopped after a short time.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct LuaHooks {
+    /// After every fetch, with the rows as `rows`: the list it returns, else
+    /// `rows` as it left them, is shown (derived fields, filtering, reshaping)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_data: Option<String>,
+    /// On Enter, with the selected `row`; returning `false` (and optionally a
+    /// message) keeps the page from opening its `next` page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_select: Option<String>,
+    /// On every key the page gets, as `key` (`d`, `Ctrl+D`) with the selected
+    /// `row`; returning `false` (and optionally a message) swallows the key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_key: Option<String>,
+}
+
+impl LuaHooks {
+    pub fn is_empty(&self) -> bool {
+        self.on_data.is_none() && self.on_select.is_none() && self.on_key.is_none()
+    }
+
+    /// The hooks that are set, by their key under `lua`
+    pub fn chunks(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        [
+            ("on_data", &self.on_data),
+            ("on_select", &self.on_select),
+            ("on_key", &self.on_key),
+        ]
+        .into_iter()
+        .filter_map(|(name, code)| Some((name, code.as_deref()?)))
+    }
+}
+
+/// One side effect of a page event; any of the three, all templates
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct Hook {
+    /// Shell command run in the background, with `TERMSTACK_HOOK_PAGE` and
+    /// `TERMSTACK_HOOK_EVENT` set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    /// Message shown as a notification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notify: Option<String>,
+    /// `key=value` global to set, as with `--set`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub set: Option<String>,
 }
 
+/// A per-row condition watched on every fetch. The TUI shows a notification
+/// when a row starts matching; `termstack watch` prints every transition and
+/// runs `command` for it.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Alert {
+    pub name: String,
+    /// Template or bare expression with access to `row`, like style conditions
+    pub condition: String,
+    /// Template describing the firing row (default: the alert name). Rows with
+    /// the same message count as one alert.
+    #[serde(default)]
+    pub message: Option<String>,
+    /// Shell command run by `termstack watch` on each transition, with
+    /// `TERMSTACK_ALERT_*` environment variables describing it
+    #[serde(default)]
+    pub command: Option<String>,
+}
+
+// Parsed once at startup; boxing the stream variant isn't worth the churn
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum DataSource {
@@ -63,6 +384,7 @@ pub enum DataSource {
     SingleOrStream(SingleOrStream),
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum SingleOrStream {
@@ -203,6 +525,24 @@ pub struct StreamDataSource {
     pub buffer_time: Option<String>,
     #[serde(default = "default_true")]
     pub follow: bool,
+    /// Restart the command when it exits or fails, backing off exponentially
+    #[serde(default)]
+    pub reconnect: bool,
+    /// Keep the command running, and its buffer, while other pages are shown
+    #[serde(default)]
+    pub detach: bool,
+    /// How lines the command writes to stderr show in the buffer
+    #[serde(default)]
+    pub stderr: StreamStderr,
+    /// Parse each line into a row of a table view instead of a log buffer
+    #[serde(default)]
+    pub rows: Option<StreamRows>,
+
+    // Source-side line filters, applied before lines enter the buffer
+    #[serde(default)]
+    pub grep: Option<String>,
+    #[serde(default)]
+    pub exclude: Option<String>,
 
     // Common fields
     #[serde(default)]
@@ -213,6 +553,53 @@ fn default_buffer_size() -> usize {
     100
 }
 
+/// Streamed lines as table rows: JSON objects, or a regex's named groups
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StreamRows {
+    #[serde(default)]
+    pub parse: RowFormat,
+    /// Regex with named groups, one per field (`parse: regex`)
+    #[serde(default)]
+    pub pattern: Option<String>,
+    /// JSONPath into the row; a row with a key already shown replaces that row
+    #[serde(default)]
+    pub key: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RowFormat {
+    #[default]
+    Json,
+    Regex,
+}
+
+/// Stderr lines are interleaved with stdout, tagged with `prefix` in `color`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StreamStderr {
+    #[serde(default = "default_stderr_prefix")]
+    pub prefix: String,
+    #[serde(default = "default_stderr_color")]
+    pub color: Option<String>,
+    /// Discard stderr instead
+    #[serde(default)]
+    pub hide: bool,
+}
+
+impl Default for StreamStderr {
+    fn default() -> Self {
+        Self { prefix: default_stderr_prefix(), color: default_stderr_color(), hide: false }
+    }
+}
+
+fn default_stderr_prefix() -> String {
+    "[stderr] ".to_string()
+}
+
+fn default_stderr_color() -> Option<String> {
+    Some("red".to_string())
+}
+
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum DataSourceType {
@@ -240,6 +627,32 @@ pub enum View {
     Table(TableView),
     Logs(LogsView),
     Text(TextView),
+    Chart(ChartView),
+    /// A horizontal bar per item
+    BarGauge(GaugeView),
+    /// A filled gauge per item, for percentages
+    Gauge(GaugeView),
+    /// Counts of a numeric field's values per bucket
+    Histogram(HistogramView),
+    /// Counts per time interval, banded by a numeric field
+    Heatmap(HeatmapView),
+    /// A table beside a pane showing its selected row
+    Split(SplitView),
+    /// A grid of panels, each with its own data source
+    Dashboard(DashboardView),
+    /// Input fields whose values are submitted to an action or the next page
+    Form(FormView),
+}
+
+impl View {
+    /// The table of a `table` or `split` view
+    pub fn table(&self) -> Option<&TableView> {
+        match self {
+            View::Table(table) => Some(table),
+            View::Split(split) => Some(&split.table),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -251,10 +664,31 @@ pub struct TableView {
     pub group_by: Option<String>,
     #[serde(default = "default_true")]
     pub selectable: bool,
+    /// Mark rows with Space; actions then run once per marked row
     #[serde(default)]
     pub multi_select: bool,
+    /// JSONPath identifying a row across refreshes and re-sorting, used to
+    /// keep the cursor and marks on it (default: `metadata.uid`, `uid` or
+    /// `id`, whichever the rows have)
+    #[serde(default, alias = "key")]
+    pub row_key: Option<String>,
     #[serde(default)]
     pub row_style: Vec<ConditionalStyle>,
+    /// Template rendered beneath a row (one line per line of output) when it is
+    /// expanded with `x`. Has access to `row`.
+    #[serde(default)]
+    pub expand: Option<String>,
+    /// Expand rows with Tab/`x` even without an `expand` template, showing the
+    /// row's fields as indented key/value lines
+    #[serde(default)]
+    pub expandable: bool,
+    /// After a refresh, briefly highlight added rows and changed cells
+    #[serde(default)]
+    pub highlight_changes: bool,
+    /// With `highlight_changes`, also show rows that disappeared, struck
+    /// through, until the highlight fades
+    #[serde(default)]
+    pub show_removed: bool,
 }
 
 fn default_true() -> bool {
@@ -263,16 +697,230 @@ fn default_true() -> bool {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct TableColumn {
+    /// JSONPath of the cell value. Computed columns leave it out and build the
+    /// cell from `transform` instead (a template or bare expression over `row`).
+    #[serde(default)]
     pub path: String,
     pub display: String,
+    /// Fixed width. Without it the column is sized to its content, within
+    /// `min_width`/`max_width`, sharing the space left by fixed columns.
     #[serde(default)]
     pub width: Option<u16>,
     #[serde(default)]
+    pub min_width: Option<u16>,
+    #[serde(default)]
+    pub max_width: Option<u16>,
+    /// Keep the column at the left while the others scroll sideways
+    #[serde(default)]
+    pub pinned: bool,
+    /// Explicit alignment; otherwise from `type`, or right when every value is numeric
+    #[serde(default)]
     pub align: Option<Alignment>,
     #[serde(default)]
     pub transform: Option<String>,
     #[serde(default)]
     pub style: Vec<ConditionalStyle>,
+    /// Value type hint: picks the default alignment, sort comparator and formatting
+    #[serde(default, rename = "type")]
+    pub column_type: Option<ColumnType>,
+    /// URL template for the cell (has access to `value` and `row`).
+    /// Linked cells are underlined, emitted as OSC 8 hyperlinks and opened with `o`.
+    #[serde(default)]
+    pub link: Option<String>,
+    /// Named formatting preset, expanded to a transform (an explicit `transform` wins)
+    #[serde(default)]
+    pub preset: Option<ColumnPreset>,
+    /// How values compare when sorting (default: from `type`, else detected)
+    #[serde(default)]
+    pub sort_as: Option<SortAs>,
+    /// Summary of the column over the shown rows, in a footer row under the table
+    #[serde(default)]
+    pub aggregate: Option<Aggregate>,
+    /// Number, date and boolean formatting applied to the value (ignored with a `transform`)
+    #[serde(default)]
+    pub format: Option<ColumnFormat>,
+    /// Show the value against a maximum as an inline bar with its percentage
+    #[serde(default)]
+    pub progress: Option<ProgressColumn>,
+}
+
+impl TableColumn {
+    /// Whether the column has no `path` and renders its `transform` over the row
+    pub fn is_computed(&self) -> bool {
+        self.path.is_empty() && self.progress.is_none()
+    }
+
+    /// What `sort.column` refers to the column by: its path, or its display
+    /// name for computed columns
+    pub fn id(&self) -> &str {
+        if self.path.is_empty() {
+            &self.display
+        } else {
+            &self.path
+        }
+    }
+
+    /// The transform template for this column: `transform`, or the preset's filter chain
+    pub fn transform_template(&self) -> Option<&str> {
+        self.transform
+            .as_deref()
+            .or_else(|| self.preset.map(ColumnPreset::transform))
+    }
+
+    /// The value type: `type`, or the one implied by the preset
+    pub fn value_type(&self) -> Option<ColumnType> {
+        self.column_type
+            .or_else(|| self.preset.and_then(ColumnPreset::column_type))
+    }
+
+    /// The sort comparison: `sort_as`, or the one implied by the value type
+    pub fn sort_kind(&self) -> SortAs {
+        self.sort_as.unwrap_or(match self.value_type() {
+            Some(ColumnType::String) => SortAs::Natural,
+            Some(ColumnType::Number) => SortAs::Number,
+            Some(ColumnType::Date) => SortAs::Date,
+            Some(ColumnType::Duration) => SortAs::Duration,
+            Some(ColumnType::Size) => SortAs::Size,
+            None => SortAs::Auto,
+        })
+    }
+}
+
+/// Common cell formats that would otherwise need a hand-written transform
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ColumnPreset {
+    /// Timestamp shown as its age ("5m", "3d")
+    Age,
+    /// Byte count shown as "1.50 KiB"
+    Size,
+    /// Fraction shown as a percentage (0.425 → "42.5%")
+    Percent,
+    /// Truthy/falsy value shown as ✓ / ✗
+    BooleanIcon,
+}
+
+impl ColumnPreset {
+    pub fn transform(self) -> &'static str {
+        match self {
+            ColumnPreset::Age => "{{ value | timeago }}",
+            ColumnPreset::Size => "{{ value | filesizeformat }}",
+            ColumnPreset::Percent => "{{ value | percent }}",
+            ColumnPreset::BooleanIcon => "{{ value | boolean_icon }}",
+        }
+    }
+
+    /// Type used for alignment and sorting of the raw value
+    pub fn column_type(self) -> Option<ColumnType> {
+        match self {
+            ColumnPreset::Age => Some(ColumnType::Date),
+            ColumnPreset::Size => Some(ColumnType::Size),
+            ColumnPreset::Percent => Some(ColumnType::Number),
+            ColumnPreset::BooleanIcon => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ColumnType {
+    String,
+    Number,
+    Date,
+    Duration,
+    Size,
+}
+
+/// Sort comparison for a column
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SortAs {
+    /// By what all the column's values parse as: numbers, dates, sizes, durations,
+    /// else natural text
+    Auto,
+    /// Text with digit runs compared as numbers ("pod-2" before "pod-10")
+    Natural,
+    /// Plain text comparison
+    Text,
+    Number,
+    Date,
+    Duration,
+    /// Byte sizes with units ("900Mi" before "1.5Gi")
+    Size,
+}
+
+/// A column drawn as a bar filled to `value / max`, e.g. memory used of the
+/// limit, colored by the percentage's thresholds
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProgressColumn {
+    /// JSONPath to the current value (default: the column's `path`)
+    #[serde(default)]
+    pub value_path: Option<String>,
+    /// JSONPath to each row's maximum
+    #[serde(default)]
+    pub max_path: Option<String>,
+    /// Maximum for rows without `max_path` (default 100)
+    #[serde(default)]
+    pub max: Option<f64>,
+    /// Cells the bar takes, besides the percentage
+    #[serde(default = "default_progress_width")]
+    pub width: u16,
+    /// Color below the first threshold (default: green)
+    #[serde(default)]
+    pub color: Option<String>,
+    /// Colors from each percentage up
+    #[serde(default)]
+    pub thresholds: Vec<Threshold>,
+}
+
+fn default_progress_width() -> u16 {
+    10
+}
+
+/// Display formatting for common cases that don't need a template
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ColumnFormat {
+    /// Decimal places for numbers
+    #[serde(default)]
+    pub precision: Option<usize>,
+    /// Group thousands with commas (1,234,567)
+    #[serde(default)]
+    pub thousands: bool,
+    /// Text before / after the value ("$", " ms", "%")
+    #[serde(default)]
+    pub prefix: Option<String>,
+    #[serde(default)]
+    pub suffix: Option<String>,
+    /// strftime-style format for dates, in local time ("%Y-%m-%d %H:%M")
+    #[serde(default)]
+    pub date: Option<String>,
+    /// Text for true and false, e.g. `["✓", "✗"]`
+    #[serde(default)]
+    pub bool: Option<[String; 2]>,
+}
+
+/// Footer summary of a column
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Aggregate {
+    Sum,
+    /// Number of rows with a value
+    Count,
+    Avg,
+    Min,
+    Max,
+}
+
+impl Aggregate {
+    pub fn label(self) -> &'static str {
+        match self {
+            Aggregate::Sum => "sum",
+            Aggregate::Count => "count",
+            Aggregate::Avg => "avg",
+            Aggregate::Min => "min",
+            Aggregate::Max => "max",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
@@ -330,6 +978,65 @@ pub struct LogsView {
     pub syntax: Option<String>,
     #[serde(default)]
     pub filters: Vec<LogFilter>,
+    /// Patterns styled wherever they appear in a line, without hiding any
+    #[serde(default)]
+    pub highlights: Vec<LogHighlight>,
+    /// `json`: show each line's `fields` as aligned columns; lines that don't
+    /// parse are shown as they are
+    #[serde(default)]
+    pub parse: Option<LogFormat>,
+    /// Columns of parsed lines (default: timestamp, level and message, from
+    /// their usual key names)
+    #[serde(default)]
+    pub fields: Vec<LogField>,
+    /// Where `S` saves the buffer (default: `<page>-<time>.log` in the
+    /// working directory)
+    #[serde(default)]
+    pub save: Option<LogSave>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct LogSave {
+    /// File to write, a template with `page` and `time`; `~/` is the home
+    /// directory
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Shell command the lines are piped into instead of writing a file
+    #[serde(default)]
+    pub command: Option<String>,
+    /// Save every line, even while a search filters them
+    #[serde(default)]
+    pub all: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LogHighlight {
+    /// Regex whose matches are styled
+    pub pattern: String,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub bg: Option<String>,
+    #[serde(default)]
+    pub bold: bool,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    Json,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LogField {
+    /// JSONPath into the parsed line
+    pub path: String,
+    /// Padded (or cut) to this width; the last field never is
+    #[serde(default)]
+    pub width: Option<u16>,
+    /// Color the value by log level (error red, warn yellow, ...)
+    #[serde(default)]
+    pub level: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -355,6 +1062,264 @@ pub struct TextView {
     pub wrap: bool,
 }
 
+/// Plots numeric series taken from the page's items
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ChartView {
+    pub series: Vec<ChartSeries>,
+    #[serde(default)]
+    pub kind: ChartKind,
+    /// JSONPath to each item's x value (a number or a date). Without it points
+    /// are numbered, or placed at the fetch time when keeping `history`.
+    #[serde(default)]
+    pub x: Option<String>,
+    /// Append every refresh's points instead of replacing them, keeping the
+    /// last this many per series (for live metrics)
+    #[serde(default)]
+    pub history: Option<usize>,
+    #[serde(default)]
+    pub x_label: Option<String>,
+    #[serde(default)]
+    pub y_label: Option<String>,
+    /// Fixed y bounds; each one left out is scaled to the data
+    #[serde(default)]
+    pub y_min: Option<f64>,
+    #[serde(default)]
+    pub y_max: Option<f64>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ChartSeries {
+    /// Shown in the legend
+    pub name: String,
+    /// JSONPath to the value plotted for each item; items without a number
+    /// there are skipped
+    pub y: String,
+    #[serde(default)]
+    pub color: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChartKind {
+    /// Lines on shared axes, with a legend
+    #[default]
+    Line,
+    /// One compact sparkline per series, with its latest value
+    Sparkline,
+}
+
+/// One bar or gauge per item, e.g. disk usage per mount
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GaugeView {
+    /// JSONPath to the number shown for each item
+    pub value: String,
+    /// JSONPath to each item's label
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Value of a full bar. Defaults to 100 for gauges; bar gauges without it
+    /// scale to the largest value.
+    #[serde(default)]
+    pub max: Option<f64>,
+    /// Appended to values, e.g. "%" or " GB"
+    #[serde(default)]
+    pub unit: Option<String>,
+    /// Color below the first threshold (default: green)
+    #[serde(default)]
+    pub color: Option<String>,
+    /// Colors for values at or above each threshold
+    #[serde(default)]
+    pub thresholds: Vec<Threshold>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Threshold {
+    pub value: f64,
+    pub color: String,
+}
+
+/// A bar per bucket counting the items whose value falls in it, e.g. request
+/// latencies
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HistogramView {
+    /// JSONPath to the number bucketed for each item
+    pub value: String,
+    #[serde(default = "default_buckets")]
+    pub buckets: usize,
+    /// Fixed range of the buckets; each one left out is the data's
+    #[serde(default)]
+    pub min: Option<f64>,
+    #[serde(default)]
+    pub max: Option<f64>,
+    /// Appended to bucket bounds, e.g. "ms"
+    #[serde(default)]
+    pub unit: Option<String>,
+    #[serde(default)]
+    pub color: Option<String>,
+}
+
+fn default_buckets() -> usize {
+    10
+}
+
+/// A grid of time intervals (columns) by value bands (rows), each cell shaded
+/// by how many items fall in it, e.g. log volume per minute
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HeatmapView {
+    /// JSONPath to each item's time (a date or Unix timestamp)
+    pub time: String,
+    /// Width of a column (humantime, e.g. "1m")
+    #[serde(default = "default_heatmap_interval")]
+    pub interval: String,
+    /// JSONPath to the number banded into rows; without it there is a single
+    /// row counting the items
+    #[serde(default)]
+    pub value: Option<String>,
+    #[serde(default = "default_buckets")]
+    pub buckets: usize,
+    #[serde(default)]
+    pub min: Option<f64>,
+    #[serde(default)]
+    pub max: Option<f64>,
+    #[serde(default)]
+    pub unit: Option<String>,
+    #[serde(default)]
+    pub color: Option<String>,
+}
+
+fn default_heatmap_interval() -> String {
+    "1m".to_string()
+}
+
+/// A table (configured as for `type: table`) with a detail pane that follows
+/// the selected row
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SplitView {
+    #[serde(flatten)]
+    pub table: TableView,
+    #[serde(default)]
+    pub detail: SplitDetail,
+    /// Percentage of the space given to the table
+    #[serde(default = "default_split_ratio")]
+    pub ratio: u16,
+    /// `horizontal`: table on the left, pane on the right; `vertical`: table on top
+    #[serde(default)]
+    pub orientation: SplitOrientation,
+}
+
+fn default_split_ratio() -> u16 {
+    50
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SplitDetail {
+    #[serde(default, rename = "type")]
+    pub kind: DetailKind,
+    /// Template rendered with `row` for `type: text`
+    #[serde(default)]
+    pub template: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DetailKind {
+    /// The row as YAML
+    #[default]
+    Yaml,
+    /// The row as pretty-printed JSON
+    Json,
+    /// The row's fields as indented key/value lines
+    Fields,
+    /// The row rendered through `template`
+    Text,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SplitOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+/// Fields filled in by the user. On submit the values become the page's row
+/// (`row.<name>`, or `<name>` in actions) for the `submit` action, or for
+/// navigating to `next` when there's no `submit`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FormView {
+    pub fields: Vec<FormField>,
+    /// Name of the page action run on submit
+    #[serde(default)]
+    pub submit: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FormField {
+    /// Key of the value in the submitted row
+    pub name: String,
+    /// Shown beside the field (default: `name`)
+    #[serde(default)]
+    pub label: Option<String>,
+    #[serde(default, rename = "type")]
+    pub kind: FieldKind,
+    /// Choices of a `select` field
+    #[serde(default)]
+    pub options: Vec<String>,
+    /// Initial value: text (a template with access to globals and page
+    /// contexts), an option of a `select`, or true/false for a `checkbox`
+    #[serde(default)]
+    pub default: Option<serde_json::Value>,
+    /// Text fields can't be submitted empty
+    #[serde(default)]
+    pub required: bool,
+    /// Shown in an empty text field
+    #[serde(default)]
+    pub placeholder: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldKind {
+    #[default]
+    Text,
+    Select,
+    Checkbox,
+}
+
+/// Panels in a grid, filled left to right and top to bottom
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DashboardView {
+    /// Grid columns
+    #[serde(default = "default_dashboard_columns")]
+    pub columns: u16,
+    pub panels: Vec<DashboardPanel>,
+}
+
+fn default_dashboard_columns() -> u16 {
+    2
+}
+
+/// A dashboard cell: a data source fetched and refreshed on its own, shown
+/// as a table, text, chart, gauge or bar gauge
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DashboardPanel {
+    pub title: String,
+    pub data: SingleDataSource,
+    pub view: View,
+    /// Grid columns the panel covers
+    #[serde(default = "default_panel_span")]
+    pub span: u16,
+    /// Height in lines; rows whose panels don't set one share the space left
+    #[serde(default)]
+    pub height: Option<u16>,
+    /// Colors replaced in this panel; `base` defaults to the page's theme
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<ThemePalette>,
+}
+
+fn default_panel_span() -> u16 {
+    1
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum Navigation {
@@ -367,6 +1332,9 @@ pub struct SimpleNavigation {
     pub page: String,
     #[serde(default)]
     pub context: HashMap<String, String>,
+    /// Show the page as a popup over this one, dismissed with Esc
+    #[serde(default)]
+    pub modal: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -378,6 +1346,8 @@ pub struct ConditionalNavigation {
     pub context: HashMap<String, String>,
     #[serde(default)]
     pub default: bool,
+    #[serde(default)]
+    pub modal: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -398,12 +1368,19 @@ pub struct Action {
     pub command: Option<String>,
     #[serde(default)]
     pub args: Vec<String>,
+    /// Template rendered and piped to the command's stdin (command actions only).
+    /// Has access to `row`, plus `line` and `buffer` on logs pages.
+    #[serde(default)]
+    pub stdin: Option<String>,
     #[serde(default)]
     pub http: Option<HttpAction>,
     #[serde(default)]
     pub script: Option<String>,
     #[serde(default)]
     pub page: Option<String>,
+    /// With `page`, show it as a popup over the current page
+    #[serde(default)]
+    pub modal: bool,
     #[serde(default)]
     pub builtin: Option<String>,
 
@@ -443,4 +1420,50 @@ pub struct HttpAction {
     pub headers: HashMap<String, String>,
     #[serde(default)]
     pub body: Option<String>,
+    #[serde(default)]
+    pub auth: Option<HttpAuth>,
+    #[serde(default)]
+    pub client: Option<HttpClientOptions>,
+}
+
+/// Credentials for http sources and actions, one of the kinds; values are
+/// templates
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct HttpAuth {
+    #[serde(default)]
+    pub basic: Option<BasicAuth>,
+    #[serde(default)]
+    pub bearer: Option<BearerAuth>,
+    #[serde(default)]
+    pub oauth2: Option<OAuth2Auth>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BasicAuth {
+    pub username: String,
+    #[serde(default)]
+    pub password: Option<String>,
+}
+
+/// A bearer token given as is, read from an environment variable, or printed
+/// by a command (run once and reused until the server answers 401)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BearerAuth {
+    #[serde(default)]
+    pub token: Option<String>,
+    #[serde(default)]
+    pub env: Option<String>,
+    #[serde(default)]
+    pub command: Option<String>,
+}
+
+/// OAuth2 client-credentials grant; the token is reused until it expires
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OAuth2Auth {
+    pub token_url: String,
+    pub client_id: String,
+    pub client_secret: String,
+    #[serde(default)]
+    pub scope: Option<String>,
 }
@@ -1,7 +1,11 @@
 use anyhow::{anyhow, Context, Result};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
+use super::location::{At, AtPath, Segment, key};
 use super::schema::{Config, DataSource, DataSourceType, SingleDataSource};
+use crate::data::JsonPathExtractor;
+use crate::input::keymap::Keymap;
+use crate::ui::theme::{Theme, is_color};
 
 pub struct ConfigValidator;
 
@@ -9,15 +13,15 @@ impl ConfigValidator {
     pub fn validate(config: &Config) -> Result<()> {
         // Validate version
         if config.version != "v1" {
-            return Err(anyhow!(
-                "Unsupported config version: {}. Expected: v1",
-                config.version
+            return Err(At::error(
+                vec![key("version")],
+                format!("Unsupported config version: {}. Expected: v1", config.version),
             ));
         }
 
         // Validate app name
         if config.app.name.trim().is_empty() {
-            return Err(anyhow!("App name cannot be empty"));
+            return Err(At::error(vec![key("app"), key("name")], "App name cannot be empty"));
         }
 
         // Validate pages exist
@@ -27,44 +31,417 @@ impl ConfigValidator {
 
         // Validate start page exists
         if !config.pages.contains_key(&config.start) {
-            return Err(anyhow!("Start page '{}' not found in pages", config.start));
+            return Err(At::error(
+                vec![key("start")],
+                format!("Start page '{}' not found in pages", config.start),
+            ));
+        }
+
+        if let Some(interval) = &config.app.refresh_interval {
+            humantime::parse_duration(interval).at(
+                vec![key("app"), key("refresh_interval")],
+                format!("Invalid app refresh_interval format: {}", interval),
+            )?;
+        }
+
+        if let Some(options) = &config.app.http {
+            Self::validate_http_client(options)
+                .at(vec![key("app"), key("http")], "Invalid app http options")?;
+        }
+
+        if let Some(notifications) = &config.app.notifications {
+            let timeouts = &notifications.timeouts;
+            for (kind, timeout) in [
+                ("success", &timeouts.success),
+                ("info", &timeouts.info),
+                ("warning", &timeouts.warning),
+                ("error", &timeouts.error),
+            ] {
+                if let Some(timeout) = timeout {
+                    humantime::parse_duration(timeout).at(
+                        vec![key("app"), key("notifications"), key("timeouts"), key(kind)],
+                        format!("Invalid {} notification timeout '{}'", kind, timeout),
+                    )?;
+                }
+            }
+        }
+
+        let theme =
+            Theme::from_config(config).at(vec![key("app"), key("theme")], "Invalid theme")?;
+        for (page_id, page) in &config.pages {
+            let at = || vec![key("pages"), key(page_id)];
+            let page_theme = match &page.theme {
+                Some(overrides) => theme
+                    .with_overrides(overrides, &config.themes)
+                    .at(vec![key("theme")], "Invalid theme")
+                    .at(at(), format!("Invalid page: {}", page_id))?,
+                None => theme.clone(),
+            };
+            let super::schema::View::Dashboard(dashboard) = &page.view else {
+                continue;
+            };
+            for (idx, panel) in dashboard.panels.iter().enumerate() {
+                if let Some(overrides) = &panel.theme {
+                    let path = vec![
+                        key("view"),
+                        key("panels"),
+                        Segment::Index(idx),
+                        key("theme"),
+                    ];
+                    page_theme
+                        .with_overrides(overrides, &config.themes)
+                        .at(path, format!("Invalid theme on panel '{}'", panel.title))
+                        .at(at(), format!("Invalid page: {}", page_id))?;
+                }
+            }
+        }
+
+        // Validate preflight checks
+        for check in &config.app.preflight {
+            if check.name.trim().is_empty() {
+                return Err(anyhow!("Preflight check name cannot be empty"));
+            }
+            if check.command.is_some() == check.http.is_some() {
+                return Err(anyhow!(
+                    "Preflight check '{}' must define exactly one of: command, http",
+                    check.name
+                ));
+            }
+            if let Some(timeout) = &check.timeout
+                && let Err(e) = humantime::parse_duration(timeout)
+            {
+                return Err(anyhow!(
+                    "Preflight check '{}' has invalid timeout '{}': {}",
+                    check.name,
+                    timeout,
+                    e
+                ));
+            }
         }
 
+        // Validate keybindings (defaults + overrides must resolve)
+        let keymap = Keymap::from_config(config.keybindings.as_ref())
+            .map_err(|e| anyhow!(e))
+            .at(vec![key("keybindings")], "Invalid keybindings")?;
+
         // Collect all page IDs for reference validation
         let page_ids: HashSet<_> = config.pages.keys().cloned().collect();
 
+        // Validate go-to-page shortcuts
+        for (key, page) in config.keybindings.iter().flat_map(|kb| &kb.custom) {
+            if !page_ids.contains(page.trim()) {
+                return Err(anyhow!(
+                    "Keybinding '{}' goes to unknown page '{}'",
+                    key,
+                    page
+                ));
+            }
+        }
+
         // Validate each page
         for (page_id, page) in &config.pages {
-            Self::validate_page(page_id, page, &page_ids)
-                .with_context(|| format!("Invalid page: {}", page_id))?;
+            Self::validate_page(page_id, page, &page_ids, &keymap)
+                .at(vec![key("pages"), key(page_id)], format!("Invalid page: {}", page_id))?;
         }
 
         Ok(())
     }
 
+    /// Problems `--validate --strict` reports on a config that passed
+    /// [`validate`](Self::validate): pages nothing leads to from `start`,
+    /// JSONPaths (items, columns, `next` context) that don't parse, and
+    /// templates, action contexts included, that fail a dry run against the
+    /// globals, and plugins that don't load
+    pub fn strict_warnings(config: &Config) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        let reachable = Self::reachable_pages(config);
+        for page_id in config
+            .pages
+            .keys()
+            .filter(|id| !reachable.contains(id.as_str()))
+        {
+            warnings.push(format!(
+                "Page '{}' can't be reached from '{}' (no next, action or shortcut leads to it)",
+                page_id, config.start
+            ));
+        }
+
+        let supplied = Self::context_names(config);
+        for (page_id, page) in &config.pages {
+            for param in page
+                .params
+                .iter()
+                .filter(|p| !supplied.contains(p.as_str()))
+            {
+                warnings.push(format!(
+                    "Page '{}': param '{}' is set by no navigation context or global \
+                     (only --context)",
+                    page_id, param
+                ));
+            }
+        }
+
+        for (page_id, page) in &config.pages {
+            for (what, path) in Self::json_paths(page) {
+                if let Err(e) = JsonPathExtractor::new(path) {
+                    warnings.push(format!("Page '{}': {} '{}': {}", page_id, what, path, e));
+                }
+            }
+        }
+
+        // Filters plugins register are known to the dry run; a plugin that
+        // doesn't load is a warning of its own
+        let plugins = crate::wasm::WasmPlugins::load(&config.app.plugins).unwrap_or_else(|e| {
+            warnings.push(format!("app.plugins: {:#}", e));
+            Default::default()
+        });
+        let engine = crate::template::TemplateEngine::with_filters(plugins.filters());
+        let context = crate::template::engine::TemplateContext::new()
+            .with_globals(config.globals.clone());
+        let mut templates = Vec::new();
+        if let Ok(root) = serde_json::to_value(config) {
+            Self::collect_templates(&root, String::new(), &mut templates);
+        }
+        for (location, template) in templates {
+            if let Err(e) = engine.dry_run(&template, &context) {
+                warnings.push(format!("{}: {}", location, e));
+            }
+        }
+
+        warnings.sort();
+        warnings
+    }
+
+    /// Pages reachable from `start` or a go-to shortcut through `next` and
+    /// page actions
+    fn reachable_pages(config: &Config) -> HashSet<&str> {
+        let shortcuts = config.keybindings.iter().flat_map(|kb| kb.custom.values());
+        let mut pending: Vec<&str> = std::iter::once(config.start.as_str())
+            .chain(shortcuts.map(|page| page.trim()))
+            .collect();
+        let mut reachable = HashSet::new();
+        while let Some(page_id) = pending.pop() {
+            let Some(page) = config.pages.get(page_id) else {
+                continue;
+            };
+            if !reachable.insert(page_id) {
+                continue;
+            }
+            match &page.next {
+                Some(super::schema::Navigation::Simple(simple)) => pending.push(&simple.page),
+                Some(super::schema::Navigation::Conditional(routes)) => {
+                    pending.extend(routes.iter().map(|route| route.page.as_str()));
+                }
+                None => {}
+            }
+            let actions = page.actions.iter().flatten();
+            pending.extend(actions.filter_map(|action| action.page.as_deref()));
+        }
+        reachable
+    }
+
+    /// Names navigation sets context under, anywhere in the config, and the
+    /// globals
+    fn context_names(config: &Config) -> HashSet<&str> {
+        let mut names: HashSet<&str> = config.globals.keys().map(String::as_str).collect();
+        for page in config.pages.values() {
+            for context in Self::navigation_contexts(page) {
+                names.extend(context.keys().map(String::as_str));
+            }
+        }
+        names
+    }
+
+    /// Every JSONPath on `page`, with what it is
+    fn json_paths(page: &super::schema::Page) -> Vec<(&'static str, &str)> {
+        let mut sources: Vec<&SingleDataSource> = match &page.data {
+            Some(DataSource::SingleOrStream(super::schema::SingleOrStream::Single(single))) => {
+                vec![single]
+            }
+            Some(DataSource::Multi(multi)) => multi.sources.iter().map(|s| &s.source).collect(),
+            _ => Vec::new(),
+        };
+        let mut views = vec![&page.view];
+        if let super::schema::View::Dashboard(dashboard) = &page.view {
+            sources.extend(dashboard.panels.iter().map(|panel| &panel.data));
+            views.extend(dashboard.panels.iter().map(|panel| &panel.view));
+        }
+
+        let mut paths: Vec<(&'static str, &str)> = sources
+            .iter()
+            .filter_map(|source| source.items.as_deref())
+            .map(|items| ("items", items))
+            .collect();
+        for table in views.iter().filter_map(|view| view.table()) {
+            let columns = table.columns.iter().filter(|col| !col.is_computed());
+            paths.extend(columns.map(|col| ("column path", col.path.as_str())));
+        }
+        // Action contexts are templates, rendered against the row, so only
+        // `next` reads its context through JSONPaths
+        for context in Self::next_contexts(page) {
+            paths.extend(context.values().map(|path| ("context path", path.as_str())));
+        }
+        paths
+    }
+
+    /// The `context` maps of `page`'s `next` targets
+    fn next_contexts(page: &super::schema::Page) -> Vec<&HashMap<String, String>> {
+        match &page.next {
+            Some(super::schema::Navigation::Simple(simple)) => vec![&simple.context],
+            Some(super::schema::Navigation::Conditional(routes)) => {
+                routes.iter().map(|route| &route.context).collect()
+            }
+            None => Vec::new(),
+        }
+    }
+
+    /// The `context` maps of `page`'s navigation targets and actions
+    fn navigation_contexts(page: &super::schema::Page) -> Vec<&HashMap<String, String>> {
+        let mut contexts = Self::next_contexts(page);
+        contexts.extend(page.actions.iter().flatten().map(|action| &action.context));
+        contexts
+    }
+
+    /// Strings with `{{ }}` or `{% %}` anywhere in the config, by where they are
+    /// (`pages.pods.data.args[0]`)
+    fn collect_templates(value: &serde_json::Value, at: String, out: &mut Vec<(String, String)>) {
+        match value {
+            serde_json::Value::String(s) if s.contains("{{") || s.contains("{%") => {
+                out.push((at, s.clone()));
+            }
+            serde_json::Value::Array(items) => {
+                for (idx, item) in items.iter().enumerate() {
+                    Self::collect_templates(item, format!("{}[{}]", at, idx), out);
+                }
+            }
+            serde_json::Value::Object(fields) => {
+                for (key, field) in fields {
+                    let at = if at.is_empty() { key.clone() } else { format!("{}.{}", at, key) };
+                    Self::collect_templates(field, at, out);
+                }
+            }
+            _ => {}
+        }
+    }
+
     fn validate_page(
         _page_id: &str,
         page: &super::schema::Page,
         page_ids: &HashSet<String>,
+        keymap: &Keymap,
     ) -> Result<()> {
         // Validate title
         if page.title.trim().is_empty() {
             return Err(anyhow!("Page title cannot be empty"));
         }
 
-        // Validate data source
-        Self::validate_data_source(&page.data).context("Invalid data source")?;
+        // Validate data source (dashboard panels each have their own instead)
+        match (&page.data, &page.view) {
+            (Some(_), super::schema::View::Dashboard(_)) => {
+                return Err(anyhow!("Dashboard pages take a data source per panel, not 'data'"));
+            }
+            (Some(_), super::schema::View::Form(_)) => {
+                return Err(anyhow!("Form pages take their row from the fields, not 'data'"));
+            }
+            (
+                Some(super::schema::DataSource::SingleOrStream(
+                    super::schema::SingleOrStream::Stream(stream),
+                )),
+                view,
+            ) if stream.rows.is_some() && view.table().is_none() => {
+                return Err(anyhow!("Streams with 'rows' need a table view"));
+            }
+            (Some(data), _) => {
+                Self::validate_data_source(data).at(vec![key("data")], "Invalid data source")?;
+            }
+            (None, super::schema::View::Dashboard(_) | super::schema::View::Form(_)) => {}
+            (None, _) => return Err(anyhow!("Page must have a 'data' source")),
+        }
+
+        Self::validate_view(&page.view).at(vec![key("view")], "Invalid view")?;
+        if let super::schema::View::Form(form) = &page.view {
+            Self::validate_form(form, page).at(vec![key("view")], "Invalid form")?;
+        }
+
+        // Validate alerts
+        let mut alert_names = HashSet::new();
+        for (idx, alert) in page.alerts.iter().enumerate() {
+            let at = || vec![key("alerts"), Segment::Index(idx)];
+            if !alert_names.insert(alert.name.as_str()) {
+                return Err(At::error(at(), format!("Duplicate alert name '{}'", alert.name)));
+            }
+            Self::validate_alert(alert).at(at(), format!("Invalid alert '{}'", alert.name))?;
+        }
+
+        // Validate hooks
+        for (event, hooks) in [
+            ("on_load", &page.hooks.on_load),
+            ("on_error", &page.hooks.on_error),
+            ("on_action_success", &page.hooks.on_action_success),
+            ("on_row_select", &page.hooks.on_row_select),
+        ] {
+            for (idx, hook) in hooks.iter().enumerate() {
+                let at = || vec![key("hooks"), key(event), Segment::Index(idx)];
+                if hook.command.is_none() && hook.notify.is_none() && hook.set.is_none() {
+                    return Err(At::error(at(), "Hook needs a 'command', 'notify' or 'set'"));
+                }
+                if let Some(set) = &hook.set
+                    && set.split_once('=').is_none_or(|(name, _)| name.trim().is_empty())
+                {
+                    return Err(At::error(
+                        [at(), vec![key("set")]].concat(),
+                        format!("Expected key=value, got '{}'", set),
+                    ));
+                }
+            }
+        }
+
+        // Lua hooks must at least compile
+        for (event, code) in page.lua.chunks() {
+            crate::lua::check(code, event)
+                .map_err(|e| anyhow!(e))
+                .at(vec![key("lua"), key(event)], "Invalid Lua hook")?;
+        }
+
+        // Validate params
+        let mut params = HashSet::new();
+        for (idx, param) in page.params.iter().enumerate() {
+            let at = || vec![key("params"), Segment::Index(idx)];
+            if param.trim().is_empty() {
+                return Err(At::error(at(), "Param name cannot be empty"));
+            }
+            if !params.insert(param.as_str()) {
+                return Err(At::error(at(), format!("Duplicate param '{}'", param)));
+            }
+        }
 
         // Validate navigation references
         if let Some(nav) = &page.next {
-            Self::validate_navigation(nav, page_ids).context("Invalid navigation")?;
+            Self::validate_navigation(nav, page_ids).at(vec![key("next")], "Invalid navigation")?;
         }
 
         // Validate actions
         if let Some(actions) = &page.actions {
+            let mut seen_keys = HashSet::new();
             for (idx, action) in actions.iter().enumerate() {
-                Self::validate_action(action, page_ids)
-                    .with_context(|| format!("Invalid action at index {}", idx))?;
+                let at = || vec![key("actions"), Segment::Index(idx)];
+                Self::validate_action(action, page_ids, keymap)
+                    .at(at(), format!("Invalid action at index {}", idx))?;
+
+                // Two actions on the same page can't share a hotkey
+                if let Ok(action_key) = action.parse_key()
+                    && !seen_keys.insert(action_key.clone())
+                {
+                    return Err(At::error(
+                        [at(), vec![key("key")]].concat(),
+                        format!(
+                            "Duplicate action key '{}' on action '{}'",
+                            action_key.display(),
+                            action.name
+                        ),
+                    ));
+                }
             }
         }
 
@@ -84,14 +461,50 @@ impl ConfigValidator {
                     return Err(anyhow!("Multi data source must have at least one source"));
                 }
                 for (idx, named_source) in multi.sources.iter().enumerate() {
-                    Self::validate_single_data_source(&named_source.source)
-                        .with_context(|| format!("Invalid source at index {}", idx))?;
+                    Self::validate_single_data_source(&named_source.source).at(
+                        vec![key("sources"), Segment::Index(idx)],
+                        format!("Invalid source at index {}", idx),
+                    )?;
                 }
                 Ok(())
             }
         }
     }
 
+    fn validate_auth(auth: &super::schema::HttpAuth) -> Result<()> {
+        let kinds = [auth.basic.is_some(), auth.bearer.is_some(), auth.oauth2.is_some()];
+        if kinds.iter().filter(|&&given| given).count() != 1 {
+            return Err(anyhow!("auth takes exactly one of: basic, bearer, oauth2"));
+        }
+        if let Some(basic) = &auth.basic
+            && basic.username.trim().is_empty()
+        {
+            return Err(anyhow!("Basic auth needs a username"));
+        }
+        if let Some(bearer) = &auth.bearer {
+            let given = [&bearer.token, &bearer.env, &bearer.command]
+                .iter()
+                .filter(|source| source.is_some())
+                .count();
+            if given != 1 {
+                return Err(anyhow!("Bearer auth takes exactly one of: token, env, command"));
+            }
+        }
+        if let Some(oauth) = &auth.oauth2
+            && oauth.token_url.trim().is_empty()
+        {
+            return Err(anyhow!("OAuth2 auth needs a token_url"));
+        }
+        Ok(())
+    }
+
+    /// Options are checked by building a client with them: the CA file must
+    /// hold certificates, and the proxy and timeout must parse
+    fn validate_http_client(options: &super::schema::HttpClientOptions) -> Result<()> {
+        crate::data::client::build(options)?;
+        Ok(())
+    }
+
     fn validate_single_data_source(source: &SingleDataSource) -> Result<()> {
         // Get adapter name (either from adapter field or legacy source_type)
         let adapter_name = source
@@ -100,20 +513,17 @@ impl ConfigValidator {
 
         // Validate adapter-specific requirements
         match adapter_name.as_str() {
-            "cli" => {
-                if !source.config.contains_key("command") {
-                    return Err(anyhow!("CLI data source must have 'command' field"));
-                }
+            "cli" if !source.config.contains_key("command") => {
+                return Err(anyhow!("CLI data source must have 'command' field"));
             }
-            "http" => {
-                if !source.config.contains_key("url") {
-                    return Err(anyhow!("HTTP data source must have 'url' field"));
-                }
+            "http" if !source.config.contains_key("url") => {
+                return Err(anyhow!("HTTP data source must have 'url' field"));
             }
-            "script" => {
-                if !source.config.contains_key("script") {
-                    return Err(anyhow!("Script data source must have 'script' field"));
-                }
+            "script" if !source.config.contains_key("script") => {
+                return Err(anyhow!("Script data source must have 'script' field"));
+            }
+            "plugin" if !source.config.contains_key("plugin") => {
+                return Err(anyhow!("Plugin data source must have 'plugin' field"));
             }
             "stream" => {
                 return Err(anyhow!(
@@ -125,10 +535,29 @@ impl ConfigValidator {
             }
         }
 
+        if adapter_name == "http" {
+            if let Some(auth) = source.config.get("auth") {
+                let auth =
+                    serde_json::from_value(auth.clone()).at(vec![key("auth")], "Invalid auth")?;
+                Self::validate_auth(&auth).at(vec![key("auth")], "Invalid auth")?;
+            }
+            if let Some(client) = source.config.get("client") {
+                let client = serde_json::from_value(client.clone())
+                    .at(vec![key("client")], "Invalid client")?;
+                Self::validate_http_client(&client).at(vec![key("client")], "Invalid client")?;
+            }
+        }
+
         // Validate timeout format if present
         if let Some(timeout) = &source.timeout {
             humantime::parse_duration(timeout)
-                .with_context(|| format!("Invalid timeout format: {}", timeout))?;
+                .at(vec![key("timeout")], format!("Invalid timeout format: {}", timeout))?;
+        }
+        if let Some(interval) = &source.refresh_interval {
+            humantime::parse_duration(interval).at(
+                vec![key("refresh_interval")],
+                format!("Invalid refresh_interval format: {}", interval),
+            )?;
         }
 
         Ok(())
@@ -141,14 +570,20 @@ impl ConfigValidator {
         match nav {
             super::schema::Navigation::Simple(simple) => {
                 if !page_ids.contains(&simple.page) {
-                    return Err(anyhow!("Navigation page '{}' not found", simple.page));
+                    return Err(At::error(
+                        vec![key("page")],
+                        format!("Navigation page '{}' not found", simple.page),
+                    ));
                 }
             }
             super::schema::Navigation::Conditional(conditionals) => {
                 let mut has_default = false;
-                for cond in conditionals {
+                for (idx, cond) in conditionals.iter().enumerate() {
                     if !page_ids.contains(&cond.page) {
-                        return Err(anyhow!("Navigation page '{}' not found", cond.page));
+                        return Err(At::error(
+                            vec![Segment::Index(idx), key("page")],
+                            format!("Navigation page '{}' not found", cond.page),
+                        ));
                     }
                     if cond.default {
                         if has_default {
@@ -165,7 +600,11 @@ impl ConfigValidator {
         Ok(())
     }
 
-    fn validate_action(action: &super::schema::Action, page_ids: &HashSet<String>) -> Result<()> {
+    fn validate_action(
+        action: &super::schema::Action,
+        page_ids: &HashSet<String>,
+        keymap: &Keymap,
+    ) -> Result<()> {
         // Validate key format
         if action.key.is_empty() {
             return Err(anyhow!("Action key cannot be empty"));
@@ -184,6 +623,44 @@ impl ConfigValidator {
             );
         }
 
+        // Warn when the key collides with a built-in binding. Direct keys (Ctrl, Alt,
+        // special keys) take precedence over the built-in; plain keys never trigger
+        // actions directly.
+        let keys = parsed_key.to_keys();
+        if let Some(builtin) = keymap.action_for(&keys) {
+            if parsed_key.is_direct() {
+                eprintln!(
+                    "Warning: Action '{}' key '{}' overrides the built-in '{}' binding on this page.",
+                    action.name,
+                    parsed_key.display(),
+                    builtin.name()
+                );
+            } else {
+                eprintln!(
+                    "Warning: Action '{}' key '{}' conflicts with the built-in '{}' binding. \
+                    It will only be reachable from the action menu.",
+                    action.name,
+                    parsed_key.display(),
+                    builtin.name()
+                );
+            }
+        }
+
+        // A sequence starting with a built-in key delays that built-in until the
+        // chord times out
+        if keys.len() > 1
+            && let Some(builtin) = keymap.action_for(&keys[..1])
+        {
+            eprintln!(
+                "Warning: Action '{}' key '{}' starts with '{}', so the built-in '{}' binding \
+                waits for the next key on this page.",
+                action.name,
+                parsed_key.display(),
+                keys[0].display(),
+                builtin.name()
+            );
+        }
+
         // Warn about problematic Ctrl combinations that may conflict with terminal
         if let crate::input::ActionKey::Ctrl(ch) = parsed_key {
             match ch {
@@ -229,11 +706,36 @@ impl ConfigValidator {
             ));
         }
 
+        if let Some(auth) = action.http.as_ref().and_then(|http| http.auth.as_ref()) {
+            Self::validate_auth(auth).at(
+                vec![key("http"), key("auth")],
+                format!("Action '{}' has invalid auth", action.name),
+            )?;
+        }
+        if let Some(client) = action.http.as_ref().and_then(|http| http.client.as_ref()) {
+            Self::validate_http_client(client).at(
+                vec![key("http"), key("client")],
+                format!("Action '{}' has invalid client", action.name),
+            )?;
+        }
+
+        // stdin can only be piped into a command
+        if action.stdin.is_some() && !has_command {
+            return Err(anyhow!(
+                "Action '{}' uses 'stdin' but is not a command action",
+                action.name
+            ));
+        }
+
         // Validate page reference if present
         if let Some(page) = &action.page
-            && !page_ids.contains(page) {
-                return Err(anyhow!("Action page '{}' not found", page));
-            }
+            && !page_ids.contains(page)
+        {
+            return Err(At::error(
+                vec![key("page")],
+                format!("Action page '{}' not found", page),
+            ));
+        }
 
         // Validate builtin actions
         if let Some(builtin) = &action.builtin {
@@ -250,81 +752,440 @@ impl ConfigValidator {
         Ok(())
     }
 
-    fn validate_stream_data_source(source: &super::schema::StreamDataSource) -> Result<()> {
-        match source.source_type {
-            DataSourceType::Stream => {
-                // Validate that at least one source is specified
-                if source.command.is_none() && source.websocket.is_none() && source.file.is_none() {
+    fn validate_styles(rules: &[super::schema::ConditionalStyle]) -> Result<()> {
+        use crate::view::style::condition_template;
+
+        for rule in rules {
+            if let Some(condition) = &rule.condition {
+                tera::Tera::default()
+                    .add_raw_template("condition", &condition_template(condition))
+                    .map_err(|e| anyhow!("Invalid condition '{}': {}", condition, e))?;
+            }
+            for color in [&rule.color, &rule.bg].into_iter().flatten() {
+                if !is_color(color) {
                     return Err(anyhow!(
-                        "Stream data source must have 'command', 'websocket', or 'file' field"
+                        "Unknown color '{}' (use a name like 'red', '#rrggbb', 0-255 or a theme \
+                         color like 'success')",
+                        color
                     ));
                 }
+            }
+        }
+        Ok(())
+    }
 
-                // Validate CLI streaming
-                if source.command.is_some() && source.command.as_ref().unwrap().is_empty() {
-                    return Err(anyhow!("Stream command cannot be empty"));
-                }
+    fn validate_computed_column(col: &super::schema::TableColumn) -> Result<()> {
+        let Some(transform) = col.transform_template() else {
+            return Err(anyhow!("Needs a 'path', or a 'transform' to compute it from the row"));
+        };
+        tera::Tera::default()
+            .add_raw_template("transform", &crate::view::style::condition_template(transform))
+            .map_err(|e| anyhow!("Invalid transform '{}': {}", transform, e))?;
+        Ok(())
+    }
 
-                // Validate buffer_size
-                if source.buffer_size == 0 {
-                    return Err(anyhow!("Stream buffer_size must be greater than 0"));
-                }
+    fn validate_progress(
+        col: &super::schema::TableColumn,
+        progress: &super::schema::ProgressColumn,
+    ) -> Result<()> {
+        let paths = [Some(col.path.as_str()), progress.value_path.as_deref()];
+        if paths.iter().flatten().all(|path| path.is_empty()) {
+            return Err(anyhow!("progress needs a 'value_path' or the column's 'path'"));
+        }
+        for path in paths
+            .into_iter()
+            .chain([progress.max_path.as_deref()])
+            .flatten()
+        {
+            if !path.is_empty() {
+                JsonPathExtractor::new(path)
+                    .with_context(|| format!("Invalid progress path '{}'", path))?;
+            }
+        }
+        if progress.max == Some(0.0) {
+            return Err(anyhow!("progress max cannot be 0"));
+        }
+        if progress.width == 0 {
+            return Err(anyhow!("progress width must be at least 1"));
+        }
+        Ok(())
+    }
 
-                // Validate buffer_time format if present
-                if let Some(buffer_time) = &source.buffer_time {
-                    humantime::parse_duration(buffer_time)
-                        .with_context(|| format!("Invalid buffer_time format: {}", buffer_time))?;
+    fn validate_chart(chart: &super::schema::ChartView) -> Result<()> {
+        if chart.series.is_empty() {
+            return Err(anyhow!("Chart must have at least one series"));
+        }
+        if let Some(x) = &chart.x {
+            JsonPathExtractor::new(x).with_context(|| format!("Invalid x path '{}'", x))?;
+        }
+        for series in &chart.series {
+            JsonPathExtractor::new(&series.y)
+                .with_context(|| format!("Invalid y path on series '{}'", series.name))?;
+            if let Some(color) = &series.color
+                && !is_color(color)
+            {
+                return Err(anyhow!("Invalid color '{}' on series '{}'", color, series.name));
+            }
+        }
+        if chart.history == Some(0) {
+            return Err(anyhow!("history must be at least 1"));
+        }
+        if let (Some(min), Some(max)) = (chart.y_min, chart.y_max)
+            && min >= max
+        {
+            return Err(anyhow!("y_min ({}) must be less than y_max ({})", min, max));
+        }
+        Ok(())
+    }
+
+    fn validate_gauge(gauge: &super::schema::GaugeView) -> Result<()> {
+        JsonPathExtractor::new(&gauge.value)
+            .with_context(|| format!("Invalid value path '{}'", gauge.value))?;
+        if let Some(label) = &gauge.label {
+            JsonPathExtractor::new(label)
+                .with_context(|| format!("Invalid label path '{}'", label))?;
+        }
+        if gauge.max.is_some_and(|max| max <= 0.0) {
+            return Err(anyhow!("max must be greater than 0"));
+        }
+        let colors = gauge.color.iter().chain(gauge.thresholds.iter().map(|t| &t.color));
+        for color in colors {
+            if !is_color(color) {
+                return Err(anyhow!("Invalid color '{}'", color));
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_view(view: &super::schema::View) -> Result<()> {
+        // Validate table styling
+        if let Some(table) = view.table() {
+            Self::validate_styles(&table.row_style)
+                .at(vec![key("row_style")], "Invalid row_style")?;
+            for (idx, col) in table.columns.iter().enumerate() {
+                let at = || vec![key("columns"), Segment::Index(idx)];
+                Self::validate_styles(&col.style)
+                    .at(at(), format!("Invalid style on column '{}'", col.display))?;
+                if let Some(pattern) = col.format.as_ref().and_then(|f| f.date.as_deref())
+                    && !crate::view::column::is_valid_date_format(pattern)
+                {
+                    return Err(At::error(
+                        at(),
+                        format!("Invalid date format '{}' on column '{}'", pattern, col.display),
+                    ));
+                }
+                if col.is_computed() {
+                    Self::validate_computed_column(col)
+                        .at(at(), format!("Invalid column '{}'", col.display))?;
                 }
+                if let Some(progress) = &col.progress {
+                    Self::validate_progress(col, progress)
+                        .at(at(), format!("Invalid column '{}'", col.display))?;
+                }
+            }
+        }
 
-                // Validate timeout format if present
-                if let Some(timeout) = &source.timeout {
-                    humantime::parse_duration(timeout)
-                        .with_context(|| format!("Invalid timeout format: {}", timeout))?;
+        match view {
+            super::schema::View::Chart(chart) => {
+                Self::validate_chart(chart).context("Invalid chart")?;
+            }
+            super::schema::View::BarGauge(gauge) | super::schema::View::Gauge(gauge) => {
+                Self::validate_gauge(gauge).context("Invalid gauge")?;
+            }
+            super::schema::View::Logs(logs) => {
+                if !logs.fields.is_empty() && logs.parse.is_none() {
+                    return Err(anyhow!("Log fields need 'parse: json'"));
+                }
+                for field in &logs.fields {
+                    JsonPathExtractor::new(&field.path)
+                        .with_context(|| format!("Invalid log field path '{}'", field.path))?;
+                }
+                for rule in &logs.highlights {
+                    regex::Regex::new(&rule.pattern)
+                        .with_context(|| format!("Invalid highlight pattern '{}'", rule.pattern))?;
+                }
+                if let Some(save) = &logs.save
+                    && save.path.is_some()
+                    && save.command.is_some()
+                {
+                    return Err(anyhow!("Log save takes either 'path' or 'command', not both"));
                 }
             }
-            _ => {
-                return Err(anyhow!("Stream data source must have type 'stream'"));
+            super::schema::View::Histogram(histogram) => {
+                Self::validate_buckets(histogram.buckets, histogram.min, histogram.max)
+                    .context("Invalid histogram")?;
+            }
+            super::schema::View::Heatmap(heatmap) => {
+                Self::validate_buckets(heatmap.buckets, heatmap.min, heatmap.max)
+                    .context("Invalid heatmap")?;
+                match humantime::parse_duration(&heatmap.interval) {
+                    Ok(interval) if interval.as_secs() >= 1 => {}
+                    Ok(_) => return Err(anyhow!("Invalid heatmap: interval must be at least 1s")),
+                    Err(e) => {
+                        return Err(anyhow!(
+                            "Invalid heatmap: interval '{}': {}",
+                            heatmap.interval,
+                            e
+                        ));
+                    }
+                }
+            }
+            super::schema::View::Split(split) => {
+                Self::validate_split(split).context("Invalid split view")?;
             }
+            super::schema::View::Dashboard(dashboard) => {
+                Self::validate_dashboard(dashboard).context("Invalid dashboard")?;
+            }
+            _ => {}
         }
-
         Ok(())
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::config::loader::ConfigLoader;
-
-    #[test]
-    fn test_validate_valid_config() {
-        let yaml = r#"
-version: v1
-app:
-  name: "Test App"
-start: main
-pages:
-  main:
-    title: "Main Page"
-    data:
-      type: cli
-      command: "echo"
-      args: ["hello"]
-    view:
-      type: table
-      columns:
-        - path: "$.name"
-          display: "Name"
-"#;
 
-        let config = ConfigLoader::load_from_string(yaml).unwrap();
-        let result = ConfigValidator::validate(&config);
-        assert!(result.is_ok());
+    fn validate_buckets(buckets: usize, min: Option<f64>, max: Option<f64>) -> Result<()> {
+        if buckets == 0 {
+            return Err(anyhow!("buckets must be at least 1"));
+        }
+        if let (Some(min), Some(max)) = (min, max)
+            && min >= max
+        {
+            return Err(anyhow!("min ({}) must be below max ({})", min, max));
+        }
+        Ok(())
     }
 
-    #[test]
-    fn test_validate_invalid_version() {
-        let yaml = r#"
+    fn validate_dashboard(dashboard: &super::schema::DashboardView) -> Result<()> {
+        use super::schema::View;
+
+        if dashboard.columns == 0 {
+            return Err(anyhow!("columns must be at least 1"));
+        }
+        if dashboard.panels.is_empty() {
+            return Err(anyhow!("Dashboard must have at least one panel"));
+        }
+        for (idx, panel) in dashboard.panels.iter().enumerate() {
+            let at = || vec![key("panels"), Segment::Index(idx)];
+            let context = || format!("Invalid panel '{}'", panel.title);
+            if panel.span == 0 || panel.span > dashboard.columns {
+                return Err(anyhow!(
+                    "span must be between 1 and the dashboard's {} columns",
+                    dashboard.columns
+                ))
+                .at([at(), vec![key("span")]].concat(), context());
+            }
+            if !matches!(
+                panel.view,
+                View::Table(_)
+                    | View::Text(_)
+                    | View::Chart(_)
+                    | View::BarGauge(_)
+                    | View::Gauge(_)
+                    | View::Histogram(_)
+                    | View::Heatmap(_)
+            ) {
+                return Err(anyhow!(
+                    "Panels show a table, text, chart, bargauge, gauge, histogram or heatmap"
+                ))
+                .at([at(), vec![key("view")]].concat(), context());
+            }
+            Self::validate_single_data_source(&panel.data)
+                .at(vec![key("data")], "Invalid data source")
+                .at(at(), context())?;
+            Self::validate_view(&panel.view).at([at(), vec![key("view")]].concat(), context())?;
+        }
+        Ok(())
+    }
+
+    fn validate_form(form: &super::schema::FormView, page: &super::schema::Page) -> Result<()> {
+        use super::schema::FieldKind;
+
+        if form.fields.is_empty() {
+            return Err(anyhow!("Form must have at least one field"));
+        }
+        let mut names = HashSet::new();
+        for (idx, field) in form.fields.iter().enumerate() {
+            let at = || vec![key("fields"), Segment::Index(idx)];
+            if !names.insert(field.name.as_str()) {
+                return Err(At::error(at(), format!("Duplicate field name '{}'", field.name)));
+            }
+            let context = || format!("Invalid field '{}'", field.name);
+            match (&field.kind, &field.default) {
+                (FieldKind::Select, _) if field.options.is_empty() => {
+                    return Err(anyhow!("select fields need options")).at(at(), context());
+                }
+                (FieldKind::Select, Some(default))
+                    if !field.options.iter().any(|o| default.as_str() == Some(o)) =>
+                {
+                    return Err(anyhow!("default {} is not one of the options", default))
+                        .at(at(), context());
+                }
+                (FieldKind::Checkbox, Some(default)) if !default.is_boolean() => {
+                    return Err(anyhow!("checkbox default must be true or false"))
+                        .at(at(), context());
+                }
+                _ => {}
+            }
+        }
+
+        match &form.submit {
+            Some(name) => {
+                let actions = page.actions.as_deref().unwrap_or_default();
+                if !actions.iter().any(|a| &a.name == name) {
+                    return Err(anyhow!("submit names unknown action '{}'", name));
+                }
+            }
+            None if page.next.is_none() => {
+                return Err(anyhow!("Form needs a 'submit' action or a 'next' page"));
+            }
+            None => {}
+        }
+        Ok(())
+    }
+
+    fn validate_split(split: &super::schema::SplitView) -> Result<()> {
+        if !(10..=90).contains(&split.ratio) {
+            return Err(anyhow!("ratio must be between 10 and 90, got {}", split.ratio));
+        }
+        match (&split.detail.kind, &split.detail.template) {
+            (super::schema::DetailKind::Text, None) => {
+                Err(anyhow!("detail type 'text' needs a template"))
+            }
+            (_, Some(template)) => tera::Tera::default()
+                .add_raw_template("detail", template)
+                .map(|_| ())
+                .map_err(|e| anyhow!("Invalid detail template: {}", e)),
+            _ => Ok(()),
+        }
+    }
+
+    fn validate_alert(alert: &super::schema::Alert) -> Result<()> {
+        let condition = crate::view::style::condition_template(&alert.condition);
+        tera::Tera::default()
+            .add_raw_template("condition", &condition)
+            .map_err(|e| anyhow!("Invalid condition '{}': {}", alert.condition, e))?;
+        if let Some(message) = &alert.message {
+            tera::Tera::default()
+                .add_raw_template("message", message)
+                .map_err(|e| anyhow!("Invalid message template: {}", e))?;
+        }
+        if alert.command.as_deref().is_some_and(|c| c.trim().is_empty()) {
+            return Err(anyhow!("Alert command cannot be empty"));
+        }
+        Ok(())
+    }
+
+    fn validate_stream_rows(rows: &super::schema::StreamRows) -> Result<()> {
+        use super::schema::RowFormat;
+
+        match (rows.parse, &rows.pattern) {
+            (RowFormat::Json, Some(_)) => {
+                return Err(anyhow!("'pattern' is for 'parse: regex'"));
+            }
+            (RowFormat::Regex, Some(pattern)) => {
+                let regex = regex::Regex::new(pattern)
+                    .with_context(|| format!("Invalid pattern '{}'", pattern))?;
+                if regex.capture_names().flatten().next().is_none() {
+                    return Err(anyhow!(
+                        "Pattern '{}' needs named groups, e.g. (?P<name>\\S+)",
+                        pattern
+                    ));
+                }
+            }
+            (RowFormat::Regex, None) => return Err(anyhow!("'parse: regex' needs a 'pattern'")),
+            (RowFormat::Json, None) => {}
+        }
+        if let Some(key) = &rows.key {
+            JsonPathExtractor::new(key).with_context(|| format!("Invalid key path '{}'", key))?;
+        }
+        Ok(())
+    }
+
+    fn validate_stream_data_source(source: &super::schema::StreamDataSource) -> Result<()> {
+        match source.source_type {
+            DataSourceType::Stream => {
+                // Validate that at least one source is specified
+                if source.command.is_none() && source.websocket.is_none() && source.file.is_none() {
+                    return Err(anyhow!(
+                        "Stream data source must have 'command', 'websocket', or 'file' field"
+                    ));
+                }
+
+                // Validate CLI streaming
+                if source.command.is_some() && source.command.as_ref().unwrap().is_empty() {
+                    return Err(anyhow!("Stream command cannot be empty"));
+                }
+
+                // Validate buffer_size
+                if source.buffer_size == 0 {
+                    return Err(anyhow!("Stream buffer_size must be greater than 0"));
+                }
+
+                // Validate source-side line filters
+                for (field, pattern) in [("grep", &source.grep), ("exclude", &source.exclude)] {
+                    if let Some(pattern) = pattern {
+                        regex::Regex::new(pattern)
+                            .with_context(|| format!("Invalid stream {} regex: {}", field, pattern))?;
+                    }
+                }
+
+                if let Some(rows) = &source.rows {
+                    Self::validate_stream_rows(rows).context("Invalid stream rows")?;
+                }
+
+                // Validate buffer_time format if present
+                if let Some(buffer_time) = &source.buffer_time {
+                    humantime::parse_duration(buffer_time)
+                        .with_context(|| format!("Invalid buffer_time format: {}", buffer_time))?;
+                }
+
+                // Validate timeout format if present
+                if let Some(timeout) = &source.timeout {
+                    humantime::parse_duration(timeout)
+                        .with_context(|| format!("Invalid timeout format: {}", timeout))?;
+                }
+            }
+            _ => {
+                return Err(anyhow!("Stream data source must have type 'stream'"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::loader::ConfigLoader;
+
+    #[test]
+    fn test_validate_valid_config() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+start: main
+pages:
+  main:
+    title: "Main Page"
+    data:
+      type: cli
+      command: "echo"
+      args: ["hello"]
+    view:
+      type: table
+      columns:
+        - path: "$.name"
+          display: "Name"
+"#;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        let result = ConfigValidator::validate(&config);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_validate_invalid_version() {
+        let yaml = r#"
 version: v2
 app:
   name: "Test App"
@@ -369,4 +1230,841 @@ pages:
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Start page"));
     }
+
+    #[test]
+    fn test_validate_page_shortcut_target() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+start: main
+keybindings:
+  custom:
+    "g m": main
+    "g x": missing
+pages:
+  main:
+    title: "Main Page"
+    data:
+      type: cli
+      command: "echo"
+    view:
+      type: table
+      columns: []
+"#;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        let result = ConfigValidator::validate(&config);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("unknown page 'missing'"));
+    }
+
+    #[test]
+    fn test_validate_stdin_requires_command() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+start: main
+pages:
+  main:
+    title: "Main Page"
+    data:
+      type: cli
+      command: "echo"
+    view:
+      type: table
+      columns: []
+    actions:
+      - key: "ctrl+y"
+        name: "Copy"
+        page: "main"
+        stdin: "{{ row.name }}"
+"#;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        let result = ConfigValidator::validate(&config);
+        assert!(result.is_err());
+        assert!(format!("{:#}", result.unwrap_err()).contains("stdin"));
+    }
+
+    #[test]
+    fn test_validate_duplicate_action_keys() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+start: main
+pages:
+  main:
+    title: "Main Page"
+    data:
+      type: cli
+      command: "echo"
+    view:
+      type: table
+      columns: []
+    actions:
+      - key: "ctrl+d"
+        name: "Describe"
+        command: "echo"
+      - key: "CTRL+D"
+        name: "Delete"
+        command: "echo"
+"#;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        let result = ConfigValidator::validate(&config);
+        assert!(result.is_err());
+        assert!(format!("{:#}", result.unwrap_err()).contains("Duplicate action key"));
+    }
+
+    #[test]
+    fn test_validate_stream_filter_regex() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+start: main
+pages:
+  main:
+    title: "Logs"
+    data:
+      type: stream
+      command: "tail"
+      args: ["-f", "app.log"]
+      grep: "ERROR|WARN"
+      exclude: "(unclosed"
+    view:
+      type: logs
+"#;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        let result = ConfigValidator::validate(&config);
+        assert!(result.is_err());
+        assert!(format!("{:#}", result.unwrap_err()).contains("Invalid stream exclude regex"));
+    }
+
+    #[test]
+    fn test_validate_styles() {
+        let yaml = r##"
+version: v1
+app:
+  name: "Test App"
+start: main
+pages:
+  main:
+    title: "Pods"
+    data:
+      adapter: cli
+      command: "kubectl"
+      items: "$.items[*]"
+    view:
+      type: table
+      columns:
+        - path: "$.status"
+          display: "Status"
+          style:
+            - condition: "value == 'Failed'"
+              color: "#ff0000"
+            - default: true
+              color: greenish
+"##;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        let result = ConfigValidator::validate(&config);
+        assert!(result.is_err());
+        let message = format!("{:#}", result.unwrap_err());
+        assert!(message.contains("Invalid style on column 'Status'"));
+        assert!(message.contains("Unknown color 'greenish'"));
+
+        // Theme roles work as colors; a page's theme overrides are checked
+        let yaml = yaml.replace("color: greenish", "color: success");
+        let config = ConfigLoader::load_from_string(&yaml).unwrap();
+        ConfigValidator::validate(&config).unwrap();
+        let yaml = format!("{}    theme:\n      colors: {{border: blu}}\n", yaml);
+        let config = ConfigLoader::load_from_string(&yaml).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(
+            message.contains("Invalid page: main: Invalid theme"),
+            "{}",
+            message
+        );
+        assert!(message.contains("Invalid color 'blu' for 'border'"));
+    }
+
+    #[test]
+    fn test_validate_alerts() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+start: main
+pages:
+  main:
+    title: "Pods"
+    data:
+      adapter: cli
+      command: "kubectl"
+    view:
+      type: table
+      columns: []
+    alerts:
+      - name: "Failing"
+        condition: "row.status == 'Failed'"
+      - name: "Broken"
+        condition: "{{ row.status == }}"
+"#;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("Invalid alert 'Broken'"));
+    }
+
+    #[test]
+    fn test_validate_computed_columns() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+start: main
+pages:
+  main:
+    title: "Disks"
+    data:
+      adapter: cli
+      command: "df"
+    view:
+      type: table
+      columns:
+        - display: "Used %"
+          transform: "{{ row.used / row.total * 100 | round }}%"
+        - display: "Free"
+          transform: "row.total - row.used"
+        - display: "Empty"
+"#;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("Invalid column 'Empty'"));
+        assert!(message.contains("Needs a 'path'"));
+    }
+
+    #[test]
+    fn test_validate_chart() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+start: main
+pages:
+  main:
+    title: "Load"
+    data:
+      adapter: cli
+      command: "uptime"
+    view:
+      type: chart
+      history: 60
+      series:
+        - name: "1m"
+          y: "$.load1"
+          color: "not-a-color"
+"#;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("Invalid chart"));
+        assert!(message.contains("Invalid color 'not-a-color'"));
+    }
+
+    #[test]
+    fn test_validate_split() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+start: main
+pages:
+  main:
+    title: "Pods"
+    data:
+      adapter: cli
+      command: "kubectl"
+    view:
+      type: split
+      ratio: 40
+      columns:
+        - path: "$.name"
+          display: "Name"
+      detail:
+        type: text
+"#;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        let split = match &config.pages["main"].view {
+            crate::config::View::Split(split) => split,
+            other => panic!("expected a split view, got {:?}", other),
+        };
+        assert_eq!(split.table.columns[0].display, "Name");
+        assert_eq!(config.pages["main"].view.table().map(|t| t.columns.len()), Some(1));
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("detail type 'text' needs a template"));
+    }
+
+    #[test]
+    fn test_validate_dashboard() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+start: main
+pages:
+  main:
+    title: "Overview"
+    view:
+      type: dashboard
+      columns: 2
+      panels:
+        - title: "Nodes"
+          data:
+            adapter: cli
+            command: "kubectl"
+          view:
+            type: text
+        - title: "Disk"
+          span: 3
+          data:
+            adapter: cli
+            command: "df"
+          view:
+            type: gauge
+            value: "$.used"
+"#;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        assert!(config.pages["main"].data.is_none());
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("Invalid panel 'Disk'"));
+        assert!(message.contains("span must be between 1 and the dashboard's 2 columns"));
+
+        let yaml = yaml.replace("span: 3", "span: 2");
+        let config = ConfigLoader::load_from_string(&yaml).unwrap();
+        assert!(ConfigValidator::validate(&config).is_ok());
+
+        let yaml = yaml.replace("type: dashboard", "type: text");
+        let config = ConfigLoader::load_from_string(&yaml).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("Page must have a 'data' source"));
+    }
+
+    #[test]
+    fn test_validate_form() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+start: main
+pages:
+  main:
+    title: "New user"
+    view:
+      type: form
+      submit: "Create"
+      fields:
+        - name: user
+          required: true
+        - name: role
+          type: select
+          options: [admin, viewer]
+          default: owner
+    actions:
+      - key: "c"
+        name: "Create"
+        command: "useradd {{ row.user }}"
+"#;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("Invalid field 'role'"));
+        assert!(message.contains("default \"owner\" is not one of the options"));
+
+        let yaml = yaml.replace("default: owner", "default: viewer");
+        let config = ConfigLoader::load_from_string(&yaml).unwrap();
+        assert!(ConfigValidator::validate(&config).is_ok());
+
+        let yaml = yaml.replace("submit: \"Create\"", "submit: \"Delete\"");
+        let config = ConfigLoader::load_from_string(&yaml).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("submit names unknown action 'Delete'"));
+    }
+
+    #[test]
+    fn test_validate_histogram() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+start: main
+pages:
+  main:
+    title: "Latency"
+    data:
+      adapter: cli
+      command: "cat"
+    view:
+      type: heatmap
+      time: "$.at"
+      value: "$.ms"
+      interval: "soon"
+"#;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("Invalid heatmap: interval 'soon'"));
+
+        let yaml = yaml.replace("\"soon\"", "\"5m\"");
+        let config = ConfigLoader::load_from_string(&yaml).unwrap();
+        assert!(ConfigValidator::validate(&config).is_ok());
+
+        let yaml = yaml.replace("type: heatmap", "type: histogram\n      min: 10\n      max: 5");
+        let config = ConfigLoader::load_from_string(&yaml).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("min (10) must be below max (5)"));
+    }
+
+    #[test]
+    fn test_validate_progress() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+start: main
+pages:
+  main:
+    title: "Pods"
+    data:
+      adapter: cli
+      command: "kubectl"
+    view:
+      type: table
+      columns:
+        - display: "Memory"
+          progress:
+            max_path: "$.limit"
+"#;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("Invalid column 'Memory'"));
+        assert!(message.contains("progress needs a 'value_path'"));
+
+        let yaml = yaml.replace("max_path:", "value_path: \"$.used\"\n            max_path:");
+        let config = ConfigLoader::load_from_string(&yaml).unwrap();
+        assert!(ConfigValidator::validate(&config).is_ok());
+        assert!(!config.pages["main"].view.table().unwrap().columns[0].is_computed());
+    }
+
+    #[test]
+    fn test_validate_http_auth() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+start: main
+pages:
+  main:
+    title: "Repos"
+    data:
+      type: http
+      url: "https://api.example.com/repos"
+      auth:
+        bearer:
+          env: "API_TOKEN"
+          command: "gh auth token"
+    view:
+      type: table
+      columns:
+        - path: "$.name"
+          display: "Name"
+"#;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("exactly one of: token, env, command"));
+
+        let yaml = yaml.replace("          command: \"gh auth token\"\n", "");
+        let config = ConfigLoader::load_from_string(&yaml).unwrap();
+        assert!(ConfigValidator::validate(&config).is_ok());
+
+        let yaml = yaml.replace("bearer:", "digest:");
+        let config = ConfigLoader::load_from_string(&yaml).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("unknown field `digest`"));
+    }
+
+    #[test]
+    fn test_validate_http_client() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+  http:
+    proxy: "http://proxy.internal:3128"
+    connect_timeout: "5s"
+start: main
+pages:
+  main:
+    title: "Repos"
+    data:
+      type: http
+      url: "https://dev.example.com/repos"
+      client:
+        insecure: true
+        redirects: 0
+    view:
+      type: table
+      columns:
+        - path: "$.name"
+          display: "Name"
+"#;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        assert!(ConfigValidator::validate(&config).is_ok());
+
+        let bad = yaml.replace("\"5s\"", "\"soon\"");
+        let config = ConfigLoader::load_from_string(&bad).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("Invalid connect_timeout 'soon'"));
+
+        let bad = yaml.replace("redirects: 0", "ca_cert: /nonexistent/ca.pem");
+        let config = ConfigLoader::load_from_string(&bad).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("Cannot read ca_cert"));
+    }
+
+    #[test]
+    fn test_validate_notifications() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+  notifications:
+    position: bottom_left
+    timeouts:
+      error: "30s"
+start: main
+pages:
+  main:
+    title: "Main"
+    data:
+      type: cli
+      command: "date"
+    view:
+      type: text
+"#;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        assert!(ConfigValidator::validate(&config).is_ok());
+
+        let bad = yaml.replace("\"30s\"", "forever");
+        let config = ConfigLoader::load_from_string(&bad).unwrap();
+        let error = ConfigValidator::validate(&config).unwrap_err();
+        assert!(format!("{:#}", error).contains("Invalid error notification timeout 'forever'"));
+        let path = crate::config::location::error_path(&error).unwrap();
+        assert_eq!(crate::config::location::locate(&bad, path), Some((8, 7)));
+    }
+
+    #[test]
+    fn test_validate_stream_rows() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+start: main
+pages:
+  main:
+    title: "Pods"
+    data:
+      type: stream
+      command: "kubectl"
+      args: ["get", "pods", "-w"]
+      rows:
+        parse: regex
+        pattern: '^(\S+)\s+(\S+)'
+        key: "$.name"
+    view:
+      type: table
+      columns:
+        - path: "$.name"
+          display: "Name"
+"#;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("needs named groups"));
+
+        let yaml = yaml.replace("^(\\S+)", "^(?P<name>\\S+)");
+        let config = ConfigLoader::load_from_string(&yaml).unwrap();
+        assert!(ConfigValidator::validate(&config).is_ok());
+
+        let logs = yaml.replace("type: table", "type: logs");
+        let config = ConfigLoader::load_from_string(&logs).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("Streams with 'rows' need a table view"));
+    }
+
+    #[test]
+    fn test_validate_log_fields() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+start: main
+pages:
+  main:
+    title: "Logs"
+    data:
+      type: stream
+      command: "tail"
+      args: ["-f", "app.log"]
+    view:
+      type: logs
+      fields:
+        - path: "$.level"
+          level: true
+        - path: "$.msg"
+"#;
+
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("Log fields need 'parse: json'"));
+
+        let yaml = yaml.replace("type: logs", "type: logs\n      parse: json");
+        let config = ConfigLoader::load_from_string(&yaml).unwrap();
+        assert!(ConfigValidator::validate(&config).is_ok());
+
+        let highlights = "highlights:\n        - pattern: \"req-[0-9+\"\n          color: cyan";
+        let yaml = yaml.replace("parse: json", &format!("parse: json\n      {}", highlights));
+        let config = ConfigLoader::load_from_string(&yaml).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("Invalid highlight pattern 'req-[0-9+'"));
+
+        let save = "save:\n        path: \"~/logs/{{ page }}.log\"\n        command: \"gzip\"";
+        let yaml = yaml.replace("parse: json", &format!("parse: json\n      {}", save));
+        let yaml = yaml.replace("req-[0-9+", "req-[0-9]+");
+        let config = ConfigLoader::load_from_string(&yaml).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("either 'path' or 'command'"));
+    }
+
+    #[test]
+    fn test_strict_warnings() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+globals:
+  namespace: default
+keybindings:
+  custom:
+    "g e": events
+start: main
+pages:
+  main:
+    title: "Pods in {{ namespace }}"
+    data:
+      adapter: cli
+      command: "kubectl"
+      args: ["get", "pods", "-n", "{{ namespace }}", "-o", "json"]
+      items: "$.items[*]"
+    view:
+      type: table
+      columns:
+        - path: "$.metadata.name"
+          display: "Name"
+        - path: "$.status.phase"
+          display: "Status"
+          transform: "{{ value | lower }}"
+    next:
+      page: detail
+      context:
+        pod: "$.metadata.name"
+    actions:
+      - key: "ctrl+d"
+        name: "Describe"
+        page: detail
+        context:
+          pod: "{{ row.metadata.name }}"
+  detail:
+    title: "{{ pod }}"
+    data:
+      adapter: cli
+      command: "kubectl"
+      args: ["get", "pod", "{{ pod }}", "-n", "{{ main.metadata.namespace }}"]
+    view:
+      type: text
+  events:
+    title: "Events"
+    data:
+      adapter: cli
+      command: "kubectl"
+      args: ["get", "events", "-o", "json"]
+    view:
+      type: text
+"#;
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        ConfigValidator::validate(&config).unwrap();
+        assert!(ConfigValidator::strict_warnings(&config).is_empty());
+
+        let yaml = yaml
+            .replace("    \"g e\": events\n", "    \"g d\": detail\n")
+            .replace("\"$.items[*]\"", "\"$.items[\"")
+            .replace("pod: \"$.metadata.name\"", "pod: \"{{ metadata.name }}\"")
+            .replace("value | lower", "value | shout")
+            .replace("row.metadata.name }}", "row.metadata.name | shout }}");
+        let config = ConfigLoader::load_from_string(&yaml).unwrap();
+        ConfigValidator::validate(&config).unwrap();
+        let warnings = ConfigValidator::strict_warnings(&config);
+        assert_eq!(warnings.len(), 5, "{:#?}", warnings);
+        assert!(warnings[0].contains("Page 'events' can't be reached"));
+        assert!(warnings[1].contains("Page 'main': context path '{{ metadata.name }}'"));
+        assert!(warnings[2].contains("Page 'main': items '$.items['"));
+        assert!(warnings[3].starts_with("pages.main.actions[0].context.pod"));
+        assert!(warnings[3].contains("Filter 'shout' not found"));
+        assert!(warnings[4].starts_with("pages.main.view.columns[1].transform"));
+        assert!(warnings[4].contains("Filter 'shout' not found"));
+    }
+
+    #[test]
+    fn test_validate_hooks() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+start: main
+pages:
+  main:
+    title: "Pods"
+    data:
+      adapter: cli
+      command: "kubectl"
+    view:
+      type: table
+      columns: []
+    hooks:
+      on_load:
+        - notify: "Loaded {{ count }} pods"
+        - command: "logger termstack loaded"
+      on_error:
+        - set: "last_error={{ error }}"
+"#;
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        ConfigValidator::validate(&config).unwrap();
+
+        let config =
+            ConfigLoader::load_from_string(&yaml.replace("last_error=", "last_error")).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("Expected key=value"), "{}", message);
+
+        let config = ConfigLoader::load_from_string(
+            &yaml.replace("- command: \"logger termstack loaded\"", "- {}"),
+        )
+        .unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(
+            message.contains("Hook needs a 'command', 'notify' or 'set'"),
+            "{}",
+            message
+        );
+    }
+
+    #[test]
+    fn test_validate_params() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+globals:
+  cluster: prod
+start: main
+pages:
+  main:
+    title: "Pods"
+    data:
+      adapter: cli
+      command: "kubectl"
+    view:
+      type: table
+      columns: []
+    next:
+      page: logs
+      context:
+        pod: "$.metadata.name"
+  logs:
+    title: "Logs of {{ pod }}"
+    params: [cluster, pod]
+    data:
+      adapter: cli
+      command: "kubectl"
+      args: ["logs", "{{ pod }}"]
+    view:
+      type: logs
+"#;
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        ConfigValidator::validate(&config).unwrap();
+        assert!(ConfigValidator::strict_warnings(&config).is_empty());
+
+        let config =
+            ConfigLoader::load_from_string(&yaml.replace("[cluster, pod]", "[pod, container]"))
+                .unwrap();
+        ConfigValidator::validate(&config).unwrap();
+        let warnings = ConfigValidator::strict_warnings(&config);
+        assert_eq!(warnings.len(), 1, "{:#?}", warnings);
+        assert!(warnings[0].contains("Page 'logs': param 'container' is set by no navigation"));
+
+        let config =
+            ConfigLoader::load_from_string(&yaml.replace("[cluster, pod]", "[pod, pod]")).unwrap();
+        let message = format!("{:#}", ConfigValidator::validate(&config).unwrap_err());
+        assert!(message.contains("Duplicate param 'pod'"), "{}", message);
+    }
+
+    #[test]
+    fn test_error_location() {
+        use crate::config::location::{error_path, locate};
+
+        let yaml = r#"version: v1
+app:
+  name: "Test"
+start: main
+pages:
+  main:
+    title: "Main"
+    data:
+      adapter: cli
+      command: "true"
+    view:
+      type: table
+      columns:
+        - path: "$.name"
+          display: "Name"
+        - path: "$.created"
+          display: "Created"
+          format:
+            date: "%Q"
+    actions:
+      - key: "ctrl+g"
+        name: "Go"
+        page: nowhere
+"#;
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        let error = ConfigValidator::validate(&config).unwrap_err();
+        assert_eq!(locate(yaml, error_path(&error).unwrap()), Some((16, 9)));
+
+        let yaml = yaml.replace("\"%Q\"", "\"%Y\"");
+        let config = ConfigLoader::load_from_string(&yaml).unwrap();
+        let error = ConfigValidator::validate(&config).unwrap_err();
+        assert!(format!("{:#}", error).contains("Action page 'nowhere' not found"));
+        assert_eq!(locate(&yaml, error_path(&error).unwrap()), Some((23, 9)));
+    }
 }
@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::adapters::http::ResponseCache;
+use crate::config::Config;
+use crate::data::JsonPaths;
+use crate::data::auth::TokenCache;
+use crate::error::{Result, TermStackError};
+use crate::template::TemplateEngine;
+use crate::ui::theme::Themes;
+use crate::wasm::WasmPlugins;
+
+/// What one running instance shares: its config, template engine, compiled
+/// JSONPaths, theme, HTTP clients and their caches, and plugins. Handed around
+/// as an `Arc` to the app, adapters, actions, preflight checks and watchers, so
+/// instances (or a reloaded config) don't share state.
+pub struct AppContext {
+    pub config: Config,
+    pub template_engine: TemplateEngine,
+    /// The config's JSONPaths, compiled on first use
+    pub paths: JsonPaths,
+    /// Colors from `app.theme` and the pages' overrides, resolved once for
+    /// every render
+    pub themes: Themes,
+    /// Client built from `app.http`, for requests without their own options
+    pub http_client: reqwest::Client,
+    /// Clients for sources and actions with their own `client` options, by
+    /// those options (see [`crate::data::client::client`])
+    pub(crate) clients: Mutex<HashMap<String, reqwest::Client>>,
+    /// Auth tokens from commands and token endpoints
+    pub(crate) tokens: TokenCache,
+    /// GET responses kept for conditional requests
+    pub(crate) responses: ResponseCache,
+    /// Modules from `app.plugins`, whose adapters the registry adds and whose
+    /// filters the template engine has
+    pub plugins: WasmPlugins,
+}
+
+impl AppContext {
+    pub fn new(config: Config) -> Result<Arc<Self>> {
+        let http_client = crate::data::client::build(&config.app.http.clone().unwrap_or_default())?;
+        let themes = Themes::from_config(&config)?;
+        let plugins = WasmPlugins::load(&config.app.plugins)
+            .map_err(|e| TermStackError::Plugin(format!("{:#}", e)))?;
+        Ok(Arc::new(Self {
+            template_engine: TemplateEngine::with_filters(plugins.filters()),
+            config,
+            paths: JsonPaths::default(),
+            themes,
+            http_client,
+            clients: Mutex::new(HashMap::new()),
+            tokens: TokenCache::default(),
+            responses: ResponseCache::default(),
+            plugins,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigLoader;
+
+    #[test]
+    fn test_separate_instances() {
+        let load = |name: &str| {
+            let yaml = format!(
+                "version: v1\napp: {{name: {}}}\nstart: main\n\
+                 pages: {{main: {{title: Main, view: {{type: text}}}}}}",
+                name
+            );
+            AppContext::new(ConfigLoader::load_from_string(&yaml).unwrap()).unwrap()
+        };
+        let (first, second) = (load("first"), load("second"));
+        assert_eq!(first.config.app.name, "first");
+        assert_eq!(second.config.app.name, "second");
+    }
+}
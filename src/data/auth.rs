@@ -0,0 +1,257 @@
+// Credentials for http sources and actions: basic, bearer and OAuth2 client
+// credentials, with fetched tokens cached across requests
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::config::{BearerAuth, HttpAuth, OAuth2Auth};
+use crate::error::{Result, TermStackError};
+use crate::template::engine::{TemplateContext, TemplateEngine};
+
+/// Tokens from commands and token endpoints, by where they came from; one per
+/// running instance (see [`crate::context::AppContext`])
+#[derive(Debug, Default)]
+pub struct TokenCache(Mutex<HashMap<String, CachedToken>>);
+
+impl TokenCache {
+    /// A cached token that hasn't expired
+    fn get(&self, key: &str) -> Option<String> {
+        let tokens = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = tokens.get(key)?;
+        entry.expires.is_none_or(|at| Instant::now() < at).then(|| entry.token.clone())
+    }
+
+    fn insert(&self, key: String, token: CachedToken) {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).insert(key, token);
+    }
+
+    fn remove(&self, key: &str) {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).remove(key);
+    }
+}
+
+/// Tokens are renewed this long before they expire, so none expires in flight
+const EXPIRY_MARGIN: Duration = Duration::from_secs(30);
+
+/// How long a bearer token `command` may take before the request fails
+const TOKEN_COMMAND_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone)]
+struct CachedToken {
+    token: String,
+    expires: Option<Instant>,
+}
+
+/// What a request is authorized with
+#[derive(Debug, Clone, PartialEq)]
+enum Credentials {
+    Basic { username: String, password: Option<String> },
+    /// A bearer token, with its cache entry if it came from one
+    Bearer { token: String, cached: Option<String> },
+}
+
+/// Send a request with `auth` applied; tokens are fetched with `client` and
+/// kept in `tokens`. A cached token the server rejects with 401 is dropped,
+/// and the request is retried once with a fresh one.
+pub async fn send(
+    client: &Client,
+    tokens: &TokenCache,
+    request: RequestBuilder,
+    auth: Option<&HttpAuth>,
+    engine: &TemplateEngine,
+    ctx: &TemplateContext,
+) -> Result<Response> {
+    let Some(auth) = auth else {
+        return Ok(request.send().await?);
+    };
+    let retry = request.try_clone();
+    let credentials = resolve(client, tokens, auth, engine, ctx).await?;
+    let response = authorize(request, &credentials).send().await?;
+
+    if response.status() == StatusCode::UNAUTHORIZED
+        && let Credentials::Bearer { cached: Some(key), .. } = &credentials
+        && let Some(retry) = retry
+    {
+        tokens.remove(key);
+        let credentials = resolve(client, tokens, auth, engine, ctx).await?;
+        return Ok(authorize(retry, &credentials).send().await?);
+    }
+    Ok(response)
+}
+
+fn authorize(request: RequestBuilder, credentials: &Credentials) -> RequestBuilder {
+    match credentials {
+        Credentials::Basic { username, password } => {
+            request.basic_auth(username, password.as_ref())
+        }
+        Credentials::Bearer { token, .. } => request.bearer_auth(token),
+    }
+}
+
+async fn resolve(
+    client: &Client,
+    tokens: &TokenCache,
+    auth: &HttpAuth,
+    engine: &TemplateEngine,
+    ctx: &TemplateContext,
+) -> Result<Credentials> {
+    let render = |template: &str| engine.render_string(template, ctx);
+    match (&auth.basic, &auth.bearer, &auth.oauth2) {
+        (Some(basic), _, _) => Ok(Credentials::Basic {
+            username: render(&basic.username)?,
+            password: basic.password.as_deref().map(render).transpose()?,
+        }),
+        (_, Some(bearer), _) => bearer_token(tokens, bearer, &render).await,
+        (_, _, Some(oauth)) => {
+            let oauth = OAuth2Auth {
+                token_url: render(&oauth.token_url)?,
+                client_id: render(&oauth.client_id)?,
+                client_secret: render(&oauth.client_secret)?,
+                scope: oauth.scope.as_deref().map(render).transpose()?,
+            };
+            let key = format!(
+                "oauth2:{}|{}|{}",
+                oauth.token_url,
+                oauth.client_id,
+                oauth.scope.as_deref().unwrap_or("")
+            );
+            let token = match tokens.get(&key) {
+                Some(token) => token,
+                None => {
+                    let fetched = client_credentials(client, &oauth).await?;
+                    let token = fetched.token.clone();
+                    tokens.insert(key.clone(), fetched);
+                    token
+                }
+            };
+            Ok(Credentials::Bearer { token, cached: Some(key) })
+        }
+        (None, None, None) => Err(TermStackError::Config(
+            "auth needs one of: basic, bearer, oauth2".to_string(),
+        )),
+    }
+}
+
+async fn bearer_token(
+    tokens: &TokenCache,
+    bearer: &BearerAuth,
+    render: &impl Fn(&str) -> Result<String>,
+) -> Result<Credentials> {
+    if let Some(token) = &bearer.token {
+        return Ok(Credentials::Bearer { token: render(token)?, cached: None });
+    }
+    if let Some(name) = &bearer.env {
+        let token = std::env::var(name).map_err(|_| {
+            TermStackError::Config(format!("Bearer token variable {} is not set", name))
+        })?;
+        return Ok(Credentials::Bearer { token, cached: None });
+    }
+    let Some(command) = &bearer.command else {
+        return Err(TermStackError::Config(
+            "Bearer auth needs a token, env or command".to_string(),
+        ));
+    };
+    let command = render(command)?;
+    let key = format!("command:{}", command);
+    if let Some(token) = tokens.get(&key) {
+        return Ok(Credentials::Bearer { token, cached: Some(key) });
+    }
+
+    let mut cmd = crate::hook::shell_command(&command);
+    cmd.stdin(std::process::Stdio::null()).kill_on_drop(true);
+    let output = tokio::time::timeout(TOKEN_COMMAND_TIMEOUT, cmd.output())
+        .await
+        .map_err(|_| {
+            TermStackError::DataProvider(format!(
+                "Token command timed out after {}",
+                humantime::format_duration(TOKEN_COMMAND_TIMEOUT)
+            ))
+        })??;
+    if !output.status.success() {
+        return Err(TermStackError::DataProvider(format!(
+            "Token command failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    let token = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let entry = CachedToken { token: token.clone(), expires: None };
+    tokens.insert(key.clone(), entry);
+    Ok(Credentials::Bearer { token, cached: Some(key) })
+}
+
+/// Fetch a token with the client-credentials grant
+async fn client_credentials(client: &Client, oauth: &OAuth2Auth) -> Result<CachedToken> {
+    let mut form = vec![
+        ("grant_type", "client_credentials"),
+        ("client_id", oauth.client_id.as_str()),
+        ("client_secret", oauth.client_secret.as_str()),
+    ];
+    if let Some(scope) = &oauth.scope {
+        form.push(("scope", scope.as_str()));
+    }
+    let response = client.post(&oauth.token_url).form(&form).send().await?;
+    if !response.status().is_success() {
+        return Err(TermStackError::DataProvider(format!(
+            "Token request to {} failed with status {}",
+            oauth.token_url,
+            response.status()
+        )));
+    }
+    let body: Value = response.json().await?;
+    parse_token(&body).ok_or_else(|| {
+        TermStackError::DataProvider(format!("No access_token from {}", oauth.token_url))
+    })
+}
+
+/// `access_token` and `expires_in` (seconds) from a token response
+fn parse_token(body: &Value) -> Option<CachedToken> {
+    let token = body.get("access_token")?.as_str()?.to_string();
+    let expires = body
+        .get("expires_in")
+        .and_then(Value::as_u64)
+        .map(|secs| Instant::now() + Duration::from_secs(secs).saturating_sub(EXPIRY_MARGIN));
+    Some(CachedToken { token, expires })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_token() {
+        let token = parse_token(&json!({"access_token": "abc", "expires_in": 3600})).unwrap();
+        assert_eq!(token.token, "abc");
+        assert!(token.expires.unwrap() > Instant::now() + Duration::from_secs(3000));
+        assert!(parse_token(&json!({"access_token": "abc"})).unwrap().expires.is_none());
+        assert!(parse_token(&json!({"error": "invalid_client"})).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_resolve() {
+        let engine = TemplateEngine::new().unwrap();
+        let client = Client::new();
+        let tokens = TokenCache::default();
+        let ctx = TemplateContext::new().with_globals(HashMap::from([(
+            "user".to_string(),
+            json!("admin"),
+        )]));
+        let auth: HttpAuth =
+            serde_yaml::from_str("basic: {username: '{{ user }}', password: pw}").unwrap();
+        assert_eq!(
+            resolve(&client, &tokens, &auth, &engine, &ctx).await.unwrap(),
+            Credentials::Basic { username: "admin".into(), password: Some("pw".into()) }
+        );
+
+        let auth: HttpAuth = serde_yaml::from_str("bearer: {command: 'echo tok-123'}").unwrap();
+        let Credentials::Bearer { token, cached } =
+            resolve(&client, &tokens, &auth, &engine, &ctx).await.unwrap()
+        else {
+            panic!("expected a bearer token");
+        };
+        assert_eq!(token, "tok-123");
+        assert_eq!(tokens.get(&cached.unwrap()).as_deref(), Some("tok-123"));
+    }
+}
@@ -116,10 +116,14 @@ impl DataProvider for CliProvider {
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(TermStackError::DataProvider(format!(
-                "Command failed with status {}: {}",
-                output.status, stderr
-            )));
+            return Err(TermStackError::DataProvider(match output.status.code() {
+                Some(code) => format!(
+                    "Command failed with exit code {}: {}",
+                    code,
+                    stderr.trim_end()
+                ),
+                None => format!("Command was killed by a signal: {}", stderr.trim_end()),
+            }));
         }
 
         let stdout = String::from_utf8_lossy(&output.stdout);
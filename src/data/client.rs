@@ -0,0 +1,124 @@
+// HTTP clients built from `app.http` and per-source `client` options
+use std::time::Duration;
+
+use reqwest::{Certificate, Client, Proxy, redirect};
+
+use crate::config::HttpClientOptions;
+use crate::context::AppContext;
+use crate::error::{Result, TermStackError};
+
+/// A client with `options` applied over the defaults (30s timeout, up to 10
+/// redirects, proxies from the environment)
+pub fn build(options: &HttpClientOptions) -> Result<Client> {
+    let mut builder = Client::builder()
+        .timeout(Duration::from_secs(30))
+        .pool_max_idle_per_host(10);
+
+    if let Some(path) = &options.ca_cert {
+        let path = crate::util::export::expand_home(path);
+        let pem = std::fs::read(&path).map_err(|e| {
+            TermStackError::Config(format!("Cannot read ca_cert {}: {}", path.display(), e))
+        })?;
+        let certs = Certificate::from_pem_bundle(&pem).map_err(|e| {
+            TermStackError::Config(format!("Invalid ca_cert {}: {}", path.display(), e))
+        })?;
+        if certs.is_empty() {
+            return Err(TermStackError::Config(format!(
+                "No certificates in ca_cert {}",
+                path.display()
+            )));
+        }
+        for cert in certs {
+            builder = builder.add_root_certificate(cert);
+        }
+    }
+    if options.insecure == Some(true) {
+        builder = builder.danger_accept_invalid_certs(true);
+    }
+    if let Some(url) = &options.proxy {
+        let proxy = Proxy::all(url)
+            .map_err(|e| TermStackError::Config(format!("Invalid proxy '{}': {}", url, e)))?;
+        let no_proxy = options.no_proxy.as_deref().and_then(reqwest::NoProxy::from_string);
+        builder = builder.proxy(proxy.no_proxy(no_proxy));
+    }
+    if let Some(max) = options.redirects {
+        builder = builder.redirect(match max {
+            0 => redirect::Policy::none(),
+            max => redirect::Policy::limited(max),
+        });
+    }
+    if let Some(timeout) = &options.connect_timeout {
+        let timeout = humantime::parse_duration(timeout).map_err(|e| {
+            TermStackError::Config(format!("Invalid connect_timeout '{}': {}", timeout, e))
+        })?;
+        builder = builder.connect_timeout(timeout);
+    }
+    Ok(builder.build()?)
+}
+
+/// The client for a source or action: the shared one, or with `overrides`
+/// one built from the app's options with them applied on top. Those are kept
+/// per set of options, so their connection pools are reused.
+pub fn client(ctx: &AppContext, overrides: Option<&HttpClientOptions>) -> Result<Client> {
+    let Some(overrides) = overrides else {
+        return Ok(ctx.http_client.clone());
+    };
+    let options = merge(ctx.config.app.http.as_ref(), overrides);
+    let key = serde_json::to_string(&options)?;
+
+    let mut clients = ctx.clients.lock().unwrap();
+    if let Some(client) = clients.get(&key) {
+        return Ok(client.clone());
+    }
+    let client = build(&options)?;
+    clients.insert(key, client.clone());
+    Ok(client)
+}
+
+/// `overrides` with anything it leaves out taken from `base`
+fn merge(base: Option<&HttpClientOptions>, overrides: &HttpClientOptions) -> HttpClientOptions {
+    let Some(base) = base else {
+        return overrides.clone();
+    };
+    HttpClientOptions {
+        ca_cert: overrides.ca_cert.clone().or_else(|| base.ca_cert.clone()),
+        insecure: overrides.insecure.or(base.insecure),
+        proxy: overrides.proxy.clone().or_else(|| base.proxy.clone()),
+        no_proxy: overrides.no_proxy.clone().or_else(|| base.no_proxy.clone()),
+        redirects: overrides.redirects.or(base.redirects),
+        connect_timeout: overrides.connect_timeout.clone().or_else(|| base.connect_timeout.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge() {
+        let base: HttpClientOptions =
+            serde_yaml::from_str("{proxy: 'http://proxy:3128', redirects: 3}").unwrap();
+        let overrides: HttpClientOptions =
+            serde_yaml::from_str("{redirects: 0, insecure: true}").unwrap();
+        let merged = merge(Some(&base), &overrides);
+        assert_eq!(merged.proxy.as_deref(), Some("http://proxy:3128"));
+        assert_eq!(merged.redirects, Some(0));
+        assert_eq!(merged.insecure, Some(true));
+        assert_eq!(merge(None, &overrides), overrides);
+    }
+
+    #[test]
+    fn test_build() {
+        let options: HttpClientOptions = serde_yaml::from_str(
+            "{proxy: 'http://proxy:3128', no_proxy: 'localhost', connect_timeout: 2s}",
+        )
+        .unwrap();
+        assert!(build(&options).is_ok());
+
+        let options: HttpClientOptions = serde_yaml::from_str("connect_timeout: soon").unwrap();
+        assert!(build(&options).unwrap_err().to_string().contains("connect_timeout"));
+        let options: HttpClientOptions =
+            serde_yaml::from_str("ca_cert: /nonexistent/ca.pem").unwrap();
+        assert!(build(&options).unwrap_err().to_string().contains("Cannot read ca_cert"));
+    }
+}
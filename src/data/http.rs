@@ -7,7 +7,6 @@ use std::time::Duration;
 use super::provider::{DataContext, DataProvider};
 use crate::config::HttpMethod;
 use crate::error::{Result, TermStackError};
-use crate::globals;
 
 /// HTTP data provider
 #[derive(Debug, Clone)]
@@ -17,6 +16,7 @@ pub struct HttpProvider {
     pub headers: HashMap<String, String>,
     pub body: Option<String>,
     pub timeout: Duration,
+    pub client: reqwest::Client,
 }
 
 impl HttpProvider {
@@ -27,6 +27,7 @@ impl HttpProvider {
             headers: HashMap::new(),
             body: None,
             timeout: Duration::from_secs(30),
+            client: reqwest::Client::new(),
         }
     }
 
@@ -49,12 +50,18 @@ impl HttpProvider {
         self.timeout = timeout;
         self
     }
+
+    /// Send requests with `client`, e.g. an [`crate::context::AppContext`]'s
+    pub fn with_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
 }
 
 #[async_trait]
 impl DataProvider for HttpProvider {
     async fn fetch(&self, _context: &DataContext) -> Result<Value> {
-        let client = globals::http_client();
+        let client = &self.client;
 
         let method = match self.method {
             HttpMethod::GET => Method::GET,
@@ -83,7 +90,7 @@ impl DataProvider for HttpProvider {
 
         if !response.status().is_success() {
             return Err(TermStackError::DataProvider(format!(
-                "HTTP request failed with status: {}",
+                "HTTP request failed with status {}",
                 response.status()
             )));
         }
@@ -1,5 +1,7 @@
 use serde_json::Value;
 use serde_json_path::JsonPath;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
 
 use crate::error::{Result, TermStackError};
 
@@ -64,6 +66,42 @@ impl JsonPathExtractor {
     }
 }
 
+/// Compiled paths kept before the cache starts over (a config has a few hundred
+/// at most; this only bounds paths built at runtime)
+const MAX_COMPILED_PATHS: usize = 1024;
+
+/// JSONPaths compiled once and shared, for the paths views look up on every
+/// row of every frame (columns, chart series, gauge values)
+#[derive(Debug, Default)]
+pub struct JsonPaths {
+    compiled: RwLock<HashMap<String, Option<Arc<JsonPathExtractor>>>>,
+}
+
+impl JsonPaths {
+    /// The compiled form of `path`; None when it isn't a valid JSONPath
+    pub fn get(&self, path: &str) -> Option<Arc<JsonPathExtractor>> {
+        if let Ok(compiled) = self.compiled.read()
+            && let Some(extractor) = compiled.get(path)
+        {
+            return extractor.clone();
+        }
+
+        let extractor = JsonPathExtractor::new(path).ok().map(Arc::new);
+        let mut compiled = self.compiled.write().unwrap_or_else(|e| e.into_inner());
+        if compiled.len() >= MAX_COMPILED_PATHS {
+            compiled.clear();
+        }
+        compiled.insert(path.to_string(), extractor.clone());
+        extractor
+    }
+
+    /// The first value at `path` in `row`; None when there's none or the path
+    /// is invalid
+    pub fn extract(&self, path: &str, row: &Value) -> Option<Value> {
+        self.get(path)?.extract_single(row).ok().flatten()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,4 +151,16 @@ mod tests {
         assert_eq!(result.len(), 1);
         assert_eq!(result[0]["name"], "test");
     }
+
+    #[test]
+    fn test_json_paths() {
+        let paths = JsonPaths::default();
+        let row = json!({"metadata": {"name": "api-1"}});
+        assert_eq!(paths.extract("$.metadata.name", &row), Some(json!("api-1")));
+        assert_eq!(paths.extract("$.missing", &row), None);
+        assert_eq!(paths.extract("$[", &row), None);
+
+        let first = paths.get("$.metadata.name").unwrap();
+        assert!(Arc::ptr_eq(&first, &paths.get("$.metadata.name").unwrap()));
+    }
 }
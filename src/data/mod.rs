@@ -1,11 +1,15 @@
+pub mod auth;
 pub mod cli;
+pub mod client;
 pub mod http;
 pub mod jsonpath;
 pub mod provider;
 pub mod stream;
+pub mod stream_rows;
 
 pub use cli::CliProvider;
 pub use http::HttpProvider;
-pub use jsonpath::JsonPathExtractor;
+pub use jsonpath::{JsonPathExtractor, JsonPaths};
 pub use provider::DataProvider;
 pub use stream::{StreamMessage, StreamProvider};
+pub use stream_rows::RowParser;
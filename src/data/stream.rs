@@ -1,4 +1,5 @@
 use crate::error::Result;
+use regex::Regex;
 use std::process::Stdio;
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::Command;
@@ -9,6 +10,8 @@ use tokio::sync::mpsc;
 pub enum StreamMessage {
     /// New line of data received
     Data(String),
+    /// Line the command wrote to stderr
+    Stderr(String),
     /// Stream connected and started
     Connected,
     /// Stream ended normally
@@ -24,6 +27,9 @@ pub struct StreamProvider {
     shell: bool,
     working_dir: Option<String>,
     env: std::collections::HashMap<String, String>,
+    grep: Option<Regex>,
+    exclude: Option<Regex>,
+    stderr: bool,
 }
 
 impl StreamProvider {
@@ -34,6 +40,9 @@ impl StreamProvider {
             shell: false,
             working_dir: None,
             env: std::collections::HashMap::new(),
+            grep: None,
+            exclude: None,
+            stderr: true,
         }
     }
 
@@ -57,6 +66,30 @@ impl StreamProvider {
         self
     }
 
+    /// Only pass on lines matching this regex
+    pub fn with_grep(mut self, grep: Regex) -> Self {
+        self.grep = Some(grep);
+        self
+    }
+
+    /// Drop lines matching this regex
+    pub fn with_exclude(mut self, exclude: Regex) -> Self {
+        self.exclude = Some(exclude);
+        self
+    }
+
+    /// Whether to pass on stderr lines (otherwise stderr is discarded)
+    pub fn with_stderr(mut self, stderr: bool) -> Self {
+        self.stderr = stderr;
+        self
+    }
+
+    /// Whether a line passes the grep/exclude filters
+    fn accepts(&self, line: &str) -> bool {
+        self.grep.as_ref().is_none_or(|re| re.is_match(line))
+            && !self.exclude.as_ref().is_some_and(|re| re.is_match(line))
+    }
+
     /// Start streaming command output line by line
     /// Returns a receiver that will get StreamMessage updates
     pub fn start_stream(self) -> Result<mpsc::Receiver<StreamMessage>> {
@@ -88,7 +121,7 @@ impl StreamProvider {
             }
 
             let full_command = if provider.args.is_empty() {
-                provider.command
+                provider.command.clone()
             } else {
                 format!("{} {}", provider.command, provider.args.join(" "))
             };
@@ -113,7 +146,7 @@ impl StreamProvider {
 
         // Configure stdio
         cmd.stdout(Stdio::piped());
-        cmd.stderr(Stdio::piped());
+        cmd.stderr(if provider.stderr { Stdio::piped() } else { Stdio::null() });
 
         // Spawn the process
         let mut child = cmd.spawn()?;
@@ -126,13 +159,41 @@ impl StreamProvider {
             crate::error::TermStackError::DataProvider("Failed to get stdout".to_string())
         })?;
 
-        let reader = BufReader::new(stdout);
-        let mut lines = reader.lines();
+        let mut lines = Some(BufReader::new(stdout).lines());
+        let mut errors = child.stderr.take().map(|stderr| BufReader::new(stderr).lines());
+
+        // Read lines from both as they come, until both are closed
+        while lines.is_some() || errors.is_some() {
+            let message = tokio::select! {
+                line = async { lines.as_mut()?.next_line().await.ok().flatten() },
+                    if lines.is_some() =>
+                {
+                    let Some(line) = line else {
+                        lines = None;
+                        continue;
+                    };
+                    StreamMessage::Data(line)
+                }
+                line = async { errors.as_mut()?.next_line().await.ok().flatten() },
+                    if errors.is_some() =>
+                {
+                    let Some(line) = line else {
+                        errors = None;
+                        continue;
+                    };
+                    StreamMessage::Stderr(line)
+                }
+            };
+
+            // Filter at the source so dropped lines never reach the channel
+            if let StreamMessage::Data(line) | StreamMessage::Stderr(line) = &message
+                && !provider.accepts(line)
+            {
+                continue;
+            }
 
-        // Read lines as they come
-        while let Ok(Some(line)) = lines.next_line().await {
             // Send line to app
-            if tx.send(StreamMessage::Data(line)).await.is_err() {
+            if tx.send(message).await.is_err() {
                 // Receiver dropped, kill the process
                 let _ = child.kill().await;
                 break;
@@ -156,3 +217,45 @@ impl StreamProvider {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_filters() {
+        let provider = StreamProvider::new("tail".to_string());
+        assert!(provider.accepts("anything"));
+
+        let provider = StreamProvider::new("tail".to_string())
+            .with_grep(Regex::new("ERROR|WARN").unwrap())
+            .with_exclude(Regex::new("healthcheck").unwrap());
+        assert!(provider.accepts("ERROR disk full"));
+        assert!(!provider.accepts("INFO started"));
+        assert!(!provider.accepts("WARN healthcheck slow"));
+    }
+
+    #[tokio::test]
+    async fn test_stderr_lines() {
+        let collect = |provider: StreamProvider| async move {
+            let mut rx = provider.start_stream().unwrap();
+            let mut messages = Vec::new();
+            while let Some(msg) = rx.recv().await {
+                match msg {
+                    StreamMessage::Data(line) => messages.push(format!("out {}", line)),
+                    StreamMessage::Stderr(line) => messages.push(format!("err {}", line)),
+                    _ => {}
+                }
+            }
+            messages.sort();
+            messages
+        };
+        let command = "echo one; echo two >&2";
+        let provider = StreamProvider::new(command.to_string()).with_shell(true);
+        assert_eq!(collect(provider).await, vec!["err two", "out one"]);
+
+        let provider =
+            StreamProvider::new(command.to_string()).with_shell(true).with_stderr(false);
+        assert_eq!(collect(provider).await, vec!["out one"]);
+    }
+}
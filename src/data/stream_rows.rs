@@ -0,0 +1,111 @@
+// Streamed lines parsed into table rows, updated in place by key
+use regex::Regex;
+use serde_json::{Map, Value};
+
+use crate::config::{RowFormat, StreamRows};
+use crate::data::JsonPathExtractor;
+use crate::error::{Result, TermStackError};
+
+pub struct RowParser {
+    pattern: Option<Regex>,
+    key: Option<JsonPathExtractor>,
+}
+
+impl RowParser {
+    pub fn new(rows: &StreamRows) -> Result<Self> {
+        let pattern = match (rows.parse, &rows.pattern) {
+            (RowFormat::Regex, Some(pattern)) => Some(Regex::new(pattern).map_err(|e| {
+                TermStackError::DataProvider(format!("Invalid row pattern '{}': {}", pattern, e))
+            })?),
+            (RowFormat::Regex, None) => {
+                return Err(TermStackError::DataProvider(
+                    "parse: regex needs a pattern".to_string(),
+                ));
+            }
+            (RowFormat::Json, _) => None,
+        };
+        let key = rows.key.as_deref().map(JsonPathExtractor::new).transpose()?;
+        Ok(Self { pattern, key })
+    }
+
+    /// The row a line holds: a JSON object, or the pattern's named groups as
+    /// strings. Lines that aren't one (headers, blank lines) give None.
+    pub fn parse(&self, line: &str) -> Option<Value> {
+        let Some(pattern) = &self.pattern else {
+            return serde_json::from_str::<Value>(line).ok().filter(Value::is_object);
+        };
+        let captures = pattern.captures(line)?;
+        let row: Map<String, Value> = pattern
+            .capture_names()
+            .flatten()
+            .filter_map(|name| {
+                let text = captures.name(name)?.as_str();
+                Some((name.to_string(), Value::String(text.to_string())))
+            })
+            .collect();
+        Some(Value::Object(row))
+    }
+
+    /// Replace the row with the same key, or add it at the end (dropping the
+    /// oldest beyond `limit`). Without a key every row is added.
+    pub fn upsert(&self, rows: &mut Vec<Value>, row: Value, limit: usize) {
+        if let Some(key) = self.key_of(&row)
+            && let Some(existing) = rows.iter_mut().find(|r| self.key_of(r).as_ref() == Some(&key))
+        {
+            *existing = row;
+            return;
+        }
+        rows.push(row);
+        if rows.len() > limit {
+            rows.drain(..rows.len() - limit);
+        }
+    }
+
+    fn key_of(&self, row: &Value) -> Option<Value> {
+        self.key.as_ref()?.extract_single(row).ok().flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parser(yaml: &str) -> RowParser {
+        RowParser::new(&serde_yaml::from_str(yaml).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_json_rows() {
+        let parser = parser("key: $.name");
+        let mut rows = Vec::new();
+        for line in [
+            r#"{"name": "web", "status": "Pending"}"#,
+            "not json",
+            r#"{"name": "db", "status": "Running"}"#,
+            r#"{"name": "web", "status": "Running"}"#,
+        ] {
+            if let Some(row) = parser.parse(line) {
+                parser.upsert(&mut rows, row, 100);
+            }
+        }
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0]["status"], "Running");
+        assert_eq!(rows[1]["name"], "db");
+    }
+
+    #[test]
+    fn test_regex_rows() {
+        let parser = parser(r"{parse: regex, pattern: '^(?P<name>\S+)\s+\S+\s+(?P<status>\S+)'}");
+        assert_eq!(parser.parse("NAME READY STATUS").unwrap()["status"], "STATUS");
+        let row = parser.parse("web-1   1/1     Running   0   5m").unwrap();
+        assert_eq!(row, serde_json::json!({"name": "web-1", "status": "Running"}));
+        assert!(parser.parse("").is_none());
+
+        // Without a key rows are appended, keeping the newest
+        let mut rows = Vec::new();
+        for n in 0..5 {
+            parser.upsert(&mut rows, serde_json::json!({ "n": n }), 3);
+        }
+        assert_eq!(rows.first().unwrap()["n"], 2);
+    }
+}
@@ -14,12 +14,19 @@ pub enum TermStackError {
     #[error("Template error: {0}")]
     Template(String),
 
+    /// A render hit the time or output-size limit
+    #[error("Template limit exceeded: {0}")]
+    TemplateLimit(String),
+
     #[error("Navigation error: {0}")]
     Navigation(String),
 
     #[error("Action execution error: {0}")]
     Action(String),
 
+    #[error("Plugin error: {0}")]
+    Plugin(String),
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
@@ -0,0 +1,146 @@
+// Headless `termstack fetch`: a page's rows printed as JSON or CSV
+use std::str::FromStr;
+
+use serde_json::Value;
+
+use crate::app::App;
+
+/// How `termstack fetch` prints rows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// The rows as fetched (after `items` and transforms), as a JSON array
+    #[default]
+    Json,
+    /// A table page's columns as it shows them, else each row's top-level fields
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            other => Err(format!("unknown output format '{}' (json or csv)", other)),
+        }
+    }
+}
+
+/// Fetch the app's current page once and format its rows. Sources of a
+/// multi-source page that failed while others loaded are reported on stderr.
+pub async fn run(mut app: App, format: OutputFormat) -> Result<String, String> {
+    let data = app.fetch_current_page().await;
+    app.shutdown().await;
+    let data = data.map_err(|e| e.to_string())?;
+    for failure in &data.failed {
+        eprintln!("✗ Source '{}' failed: {}", failure.id, failure.error);
+    }
+
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(&data.rows)
+            .map(|json| json + "\n")
+            .map_err(|e| e.to_string()),
+        OutputFormat::Csv => {
+            let (headers, cells) = match app.table_text(data.rows.clone()) {
+                Some(table) => table,
+                None => fields_text(&data.rows),
+            };
+            Ok(to_csv(&headers, &cells))
+        }
+    }
+}
+
+/// Rows that aren't shown as a table: each top-level field seen, in order of
+/// first appearance, with strings as they are and anything else as JSON
+fn fields_text(rows: &[Value]) -> (Vec<String>, Vec<Vec<String>>) {
+    let mut headers: Vec<String> = Vec::new();
+    for row in rows {
+        if let Value::Object(fields) = row {
+            for key in fields.keys() {
+                if !headers.contains(key) {
+                    headers.push(key.clone());
+                }
+            }
+        }
+    }
+    if headers.is_empty() && !rows.is_empty() {
+        // Scalar rows (e.g. lines of text) go in a single column
+        headers.push("value".to_string());
+        let cells = rows.iter().map(|row| vec![field_text(row)]).collect();
+        return (headers, cells);
+    }
+
+    let cells = rows
+        .iter()
+        .map(|row| {
+            headers
+                .iter()
+                .map(|key| row.get(key).map(field_text).unwrap_or_default())
+                .collect()
+        })
+        .collect();
+    (headers, cells)
+}
+
+fn field_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+/// RFC 4180 CSV: fields with commas, quotes or line breaks are quoted
+fn to_csv(headers: &[String], cells: &[Vec<String>]) -> String {
+    let mut out = String::new();
+    for record in std::iter::once(headers).chain(cells.iter().map(Vec::as_slice)) {
+        let fields: Vec<String> = record.iter().map(|field| csv_field(field)).collect();
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_to_csv() {
+        let headers = vec!["Name".to_string(), "Note".to_string()];
+        let cells = vec![
+            vec!["api-1".to_string(), "ok".to_string()],
+            vec!["api-2".to_string(), "said \"no\", twice".to_string()],
+        ];
+        assert_eq!(
+            to_csv(&headers, &cells),
+            "Name,Note\napi-1,ok\napi-2,\"said \"\"no\"\", twice\"\n"
+        );
+    }
+
+    #[test]
+    fn test_fields_text() {
+        let rows = vec![
+            json!({"name": "api-1", "ready": true}),
+            json!({"name": "api-2", "labels": {"app": "api"}}),
+        ];
+        let (headers, cells) = fields_text(&rows);
+        assert_eq!(headers, ["name", "ready", "labels"]);
+        assert_eq!(cells[0], ["api-1", "true", ""]);
+        assert_eq!(cells[1], ["api-2", "", r#"{"app":"api"}"#]);
+
+        let (headers, cells) = fields_text(&[json!("line one"), json!(2)]);
+        assert_eq!(headers, ["value"]);
+        assert_eq!(cells, [["line one"], ["2"]]);
+    }
+}
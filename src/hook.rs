@@ -0,0 +1,92 @@
+// Page hooks: commands, notifications and globals set on page events
+use std::time::Duration;
+
+use tokio::process::Command;
+
+const HOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A page event hooks run on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookEvent {
+    Load,
+    Error,
+    ActionSuccess,
+    RowSelect,
+}
+
+impl HookEvent {
+    /// Its key under `hooks`
+    pub fn name(self) -> &'static str {
+        match self {
+            HookEvent::Load => "on_load",
+            HookEvent::Error => "on_error",
+            HookEvent::ActionSuccess => "on_action_success",
+            HookEvent::RowSelect => "on_row_select",
+        }
+    }
+
+    /// The hooks for it among a page's
+    pub fn hooks(self, hooks: &crate::config::PageHooks) -> &[crate::config::Hook] {
+        match self {
+            HookEvent::Load => &hooks.on_load,
+            HookEvent::Error => &hooks.on_error,
+            HookEvent::ActionSuccess => &hooks.on_action_success,
+            HookEvent::RowSelect => &hooks.on_row_select,
+        }
+    }
+}
+
+/// `command` run by the platform's shell: `sh -c`, or `cmd /C` on Windows
+pub fn shell_command(command: &str) -> Command {
+    let mut cmd = if cfg!(target_os = "windows") {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C");
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c");
+        cmd
+    };
+    cmd.arg(command);
+    cmd
+}
+
+/// Run a hook's (rendered) `command` for `event` on `page`, which are passed
+/// in `TERMSTACK_HOOK_PAGE` and `TERMSTACK_HOOK_EVENT`
+pub async fn run_command(command: &str, page: &str, event: HookEvent) -> Result<(), String> {
+    let mut cmd = shell_command(command);
+    cmd.env("TERMSTACK_HOOK_PAGE", page)
+        .env("TERMSTACK_HOOK_EVENT", event.name())
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .kill_on_drop(true);
+
+    let status = tokio::time::timeout(HOOK_TIMEOUT, cmd.status())
+        .await
+        .map_err(|_| {
+            format!(
+                "timed out after {}",
+                humantime::format_duration(HOOK_TIMEOUT)
+            )
+        })?
+        .map_err(|e| format!("failed to run: {}", e))?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(status.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_run_command() {
+        let check = "test \"$TERMSTACK_HOOK_PAGE:$TERMSTACK_HOOK_EVENT\" = pods:on_load";
+        run_command(check, "pods", HookEvent::Load).await.unwrap();
+        assert!(run_command(check, "pods", HookEvent::Error).await.is_err());
+    }
+}
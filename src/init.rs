@@ -0,0 +1,158 @@
+// `termstack init`: a starter config from a few answers
+use std::fmt::Write;
+use std::str::FromStr;
+
+/// Where the starter page gets its rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StarterAdapter {
+    /// A shell command printing JSON
+    #[default]
+    Cli,
+    /// A GET request returning JSON
+    Http,
+}
+
+impl FromStr for StarterAdapter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "cli" => Ok(Self::Cli),
+            "http" => Ok(Self::Http),
+            other => Err(format!("unknown adapter '{}' (cli or http)", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InitOptions {
+    pub name: String,
+    pub adapter: StarterAdapter,
+    /// The command (cli) or URL (http) to fetch
+    pub source: String,
+    /// JSONPath to the rows in the response
+    pub items: String,
+    /// Fields shown as table columns
+    pub columns: Vec<String>,
+}
+
+impl InitOptions {
+    /// Answers that give a dashboard which works anywhere: a command echoing
+    /// sample rows
+    pub fn sample() -> Self {
+        Self {
+            name: "My Dashboard".to_string(),
+            adapter: StarterAdapter::Cli,
+            source: default_source(StarterAdapter::Cli).to_string(),
+            items: "$[*]".to_string(),
+            columns: vec!["name".to_string(), "status".to_string()],
+        }
+    }
+}
+
+/// What to fetch when none is given for `adapter`
+pub fn default_source(adapter: StarterAdapter) -> &'static str {
+    match adapter {
+        StarterAdapter::Cli => {
+            r#"echo '[{"name": "web", "status": "running"}, {"name": "db", "status": "stopped"}]'"#
+        }
+        StarterAdapter::Http => "https://dogapi.dog/api/v2/breeds",
+    }
+}
+
+/// Enter on a row opens it on a page of its own
+const DETAILS: &str = r#"    next:
+      page: details
+      context:
+        row: "$"
+
+  details:
+    title: "Details"
+    data:
+      adapter: cli
+      command: "echo"
+      args: ["{{ row | json_encode() }}"]
+    view:
+      type: text
+      syntax: yaml
+"#;
+
+/// The config for `options`: one table page with a column per field and a
+/// detail page for the selected row
+pub fn render(options: &InitOptions) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "version: v1\n");
+    let _ = writeln!(out, "app:\n  name: {}\n", quote(&options.name));
+    let _ = writeln!(out, "start: main\n");
+    let _ = writeln!(out, "pages:\n  main:\n    title: {}", quote(&options.name));
+    let _ = writeln!(out, "    data:");
+    match options.adapter {
+        StarterAdapter::Cli => {
+            let _ = writeln!(out, "      adapter: cli");
+            let _ = writeln!(out, "      command: {}", quote(&options.source));
+            let _ = writeln!(out, "      shell: true");
+        }
+        StarterAdapter::Http => {
+            let _ = writeln!(out, "      adapter: http");
+            let _ = writeln!(out, "      url: {}", quote(&options.source));
+        }
+    }
+    let _ = writeln!(out, "      items: {}", quote(&options.items));
+    let _ = writeln!(out, "    view:\n      type: table\n      columns:");
+    for field in &options.columns {
+        let _ = writeln!(out, "        - path: {}", quote(&format!("$.{}", field)));
+        let _ = writeln!(out, "          display: {}", quote(&title_case(field)));
+    }
+    out.push_str(DETAILS);
+    out
+}
+
+/// `s` as a YAML string (a JSON string is a valid double-quoted one)
+fn quote(s: &str) -> String {
+    serde_json::to_string(s).unwrap_or_default()
+}
+
+/// `created_at` → `Created At`
+fn title_case(field: &str) -> String {
+    field
+        .split(['_', '-', '.'])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{ConfigLoader, ConfigValidator, View};
+
+    #[test]
+    fn test_render() {
+        let config = ConfigLoader::load_from_string(&render(&InitOptions::sample())).unwrap();
+        ConfigValidator::validate(&config).unwrap();
+        assert_eq!(config.app.name, "My Dashboard");
+
+        let options = InitOptions {
+            name: "Breeds \"API\"".to_string(),
+            adapter: StarterAdapter::Http,
+            source: default_source(StarterAdapter::Http).to_string(),
+            items: "$.data[*]".to_string(),
+            columns: vec!["id".to_string(), "attributes.life_span".to_string()],
+        };
+        let config = ConfigLoader::load_from_string(&render(&options)).unwrap();
+        ConfigValidator::validate(&config).unwrap();
+        assert_eq!(config.pages["main"].title, "Breeds \"API\"");
+        let View::Table(table) = &config.pages["main"].view else {
+            panic!("expected a table");
+        };
+        assert_eq!(table.columns[1].path, "$.attributes.life_span");
+        assert_eq!(table.columns[1].display, "Attributes Life Span");
+    }
+}
@@ -0,0 +1,115 @@
+// Recall of previously entered queries in a text input (Up/Down)
+
+/// Most queries kept; the oldest are dropped first
+pub const HISTORY_LIMIT: usize = 100;
+
+/// Entered queries, oldest first, with a cursor for stepping through them.
+/// The text being typed when recall starts is kept and comes back after the
+/// newest entry.
+#[derive(Debug, Default)]
+pub struct History {
+    entries: Vec<String>,
+    /// Entry shown in the input, while recalling
+    cursor: Option<usize>,
+    draft: String,
+}
+
+impl History {
+    pub fn from_entries(mut entries: Vec<String>) -> Self {
+        if entries.len() > HISTORY_LIMIT {
+            entries.drain(..entries.len() - HISTORY_LIMIT);
+        }
+        Self {
+            entries,
+            ..Self::default()
+        }
+    }
+
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    /// Remember an entered query as the newest entry (moving it up if it was
+    /// already there) and stop recalling
+    pub fn push(&mut self, query: &str) {
+        self.reset();
+        if query.is_empty() {
+            return;
+        }
+        self.entries.retain(|entry| entry != query);
+        self.entries.push(query.to_string());
+        if self.entries.len() > HISTORY_LIMIT {
+            self.entries.remove(0);
+        }
+    }
+
+    /// Step to the older entry; `current` is the input's text,
+    /// kept as the draft when recall starts. None when there's nothing older.
+    pub fn older(&mut self, current: &str) -> Option<&str> {
+        let idx = match self.cursor {
+            None if self.entries.is_empty() => return None,
+            None => {
+                self.draft = current.to_string();
+                self.entries.len() - 1
+            }
+            Some(0) => return None,
+            Some(idx) => idx - 1,
+        };
+        self.cursor = Some(idx);
+        Some(&self.entries[idx])
+    }
+
+    /// Step to the newer entry, or back to the draft after the newest.
+    /// None when not recalling.
+    pub fn newer(&mut self) -> Option<&str> {
+        let idx = self.cursor?;
+        if idx + 1 < self.entries.len() {
+            self.cursor = Some(idx + 1);
+            Some(&self.entries[idx + 1])
+        } else {
+            self.cursor = None;
+            Some(&self.draft)
+        }
+    }
+
+    /// Stop recalling, e.g. once the recalled text is edited
+    pub fn reset(&mut self) {
+        self.cursor = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recall() {
+        let mut history = History::default();
+        assert_eq!(history.older("x"), None);
+
+        history.push("!error|warn");
+        history.push("web");
+        history.push("");
+        history.push("!error|warn");
+        assert_eq!(history.entries(), ["web", "!error|warn"]);
+
+        assert_eq!(history.older("draft"), Some("!error|warn"));
+        assert_eq!(history.older("ignored"), Some("web"));
+        assert_eq!(history.older("ignored"), None);
+        assert_eq!(history.newer(), Some("!error|warn"));
+        assert_eq!(history.newer(), Some("draft"));
+        assert_eq!(history.newer(), None);
+    }
+
+    #[test]
+    fn test_limit() {
+        let entries = (0..HISTORY_LIMIT + 5).map(|i| i.to_string()).collect();
+        let mut history = History::from_entries(entries);
+        assert_eq!(history.entries().len(), HISTORY_LIMIT);
+        assert_eq!(history.entries()[0], "5");
+
+        history.push("new");
+        assert_eq!(history.entries().len(), HISTORY_LIMIT);
+        assert_eq!(history.entries()[0], "6");
+    }
+}
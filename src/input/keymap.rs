@@ -0,0 +1,729 @@
+// Keymap: resolves key events to logical built-in actions
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::config::{Keybindings, default_keybindings};
+
+/// A key plus modifiers, normalized so it can be used as a map key.
+///
+/// For character keys the case of the character carries Shift, so the SHIFT
+/// modifier is dropped (`G` and `shift+g` are the same combo). The same goes
+/// for BackTab, which terminals report as Shift+BackTab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyCombo {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyCombo {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let relevant = KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT;
+        let mut modifiers = modifiers & relevant;
+        let code = match code {
+            KeyCode::Char(c) => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                // Terminals report Ctrl/Alt+letter in either case; normalize to lowercase
+                if modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) {
+                    KeyCode::Char(c.to_ascii_lowercase())
+                } else {
+                    KeyCode::Char(c)
+                }
+            }
+            KeyCode::BackTab => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                KeyCode::BackTab
+            }
+            other => other,
+        };
+        Self { code, modifiers }
+    }
+
+    pub fn from_event(key: &KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+
+    /// Parse a key description such as `j`, `G`, `Enter`, `ctrl+d`, `alt+Left`, `shift+F5`
+    ///
+    /// # Examples
+    /// ```
+    /// # use crossterm::event::{KeyCode, KeyModifiers};
+    /// # use termstack::input::keymap::KeyCombo;
+    /// let combo = KeyCombo::parse("Ctrl+D").unwrap();
+    /// assert_eq!(combo, KeyCombo::new(KeyCode::Char('d'), KeyModifiers::CONTROL));
+    ///
+    /// let combo = KeyCombo::parse("PageDown").unwrap();
+    /// assert_eq!(combo.code, KeyCode::PageDown);
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err("Key cannot be empty".to_string());
+        }
+
+        // "+" on its own (or as the last part, e.g. "ctrl++") is the plus key
+        let (mods_part, key_part) = if s == "+" {
+            ("", "+")
+        } else if let Some(mods) = s.strip_suffix("++") {
+            (mods, "+")
+        } else {
+            match s.rsplit_once('+') {
+                Some((mods, key)) => (mods, key),
+                None => ("", s),
+            }
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        for part in mods_part.split('+').filter(|p| !p.is_empty()) {
+            modifiers |= match part.trim().to_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "meta" | "option" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                other => return Err(format!("Unknown modifier '{}' in key '{}'", other, s)),
+            };
+        }
+
+        let code = Self::parse_code(key_part)
+            .ok_or_else(|| format!("Unknown key '{}' in '{}'", key_part, s))?;
+
+        // shift+letter is the uppercase letter
+        let code = match code {
+            KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) => {
+                KeyCode::Char(c.to_ascii_uppercase())
+            }
+            other => other,
+        };
+
+        Ok(Self::new(code, modifiers))
+    }
+
+    fn parse_code(key: &str) -> Option<KeyCode> {
+        let mut chars = key.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return Some(KeyCode::Char(c));
+        }
+
+        let lower = key.to_lowercase();
+        let code = match lower.as_str() {
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "backspace" => KeyCode::Backspace,
+            "space" => KeyCode::Char(' '),
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" | "pgup" => KeyCode::PageUp,
+            "pagedown" | "pgdn" => KeyCode::PageDown,
+            "delete" | "del" => KeyCode::Delete,
+            "insert" | "ins" => KeyCode::Insert,
+            _ => {
+                let n: u8 = lower.strip_prefix('f')?.parse().ok()?;
+                if (1..=12).contains(&n) {
+                    KeyCode::F(n)
+                } else {
+                    return None;
+                }
+            }
+        };
+        Some(code)
+    }
+
+    /// Human-readable form, e.g. `Ctrl+D`, `Alt+Left`, `G`, `Space`
+    pub fn display(&self) -> String {
+        let mut out = String::new();
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            out.push_str("Ctrl+");
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            out.push_str("Alt+");
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            out.push_str("Shift+");
+        }
+
+        let key = match self.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) if self.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+                c.to_ascii_uppercase().to_string()
+            }
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::Enter => "Enter".to_string(),
+            KeyCode::Esc => "Esc".to_string(),
+            KeyCode::Tab => "Tab".to_string(),
+            KeyCode::BackTab => "BackTab".to_string(),
+            KeyCode::Backspace => "Backspace".to_string(),
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            KeyCode::Home => "Home".to_string(),
+            KeyCode::End => "End".to_string(),
+            KeyCode::PageUp => "PgUp".to_string(),
+            KeyCode::PageDown => "PgDn".to_string(),
+            KeyCode::Delete => "Del".to_string(),
+            KeyCode::Insert => "Ins".to_string(),
+            KeyCode::F(n) => format!("F{}", n),
+            other => format!("{:?}", other),
+        };
+        out.push_str(&key);
+        out
+    }
+}
+
+/// Logical built-in actions that keys resolve to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyAction {
+    Quit,
+    Back,
+    Down,
+    Up,
+    Top,
+    Bottom,
+    Select,
+    Refresh,
+    Search,
+    Follow,
+    Wrap,
+    Timestamps,
+    NextMatch,
+    PrevMatch,
+    SaveBuffer,
+    ScrollLeft,
+    ScrollRight,
+    ActionMenu,
+    RowMenu,
+    OpenLink,
+    CommandPalette,
+    Expand,
+    HalfPageDown,
+    HalfPageUp,
+    PageDown,
+    PageUp,
+    ParagraphDown,
+    ParagraphUp,
+    MoveColumnLeft,
+    MoveColumnRight,
+    Sort,
+    ToggleMark,
+    MarkAll,
+    ClearMarks,
+    Baseline,
+    ToggleDiff,
+    ViewValue,
+    PauseRefresh,
+    SwitchFocus,
+    Notifications,
+    Forward,
+    History,
+    Bookmark,
+    Bookmarks,
+    PrevRow,
+    NextRow,
+}
+
+impl KeyAction {
+    pub const ALL: &'static [KeyAction] = &[
+        KeyAction::Quit,
+        KeyAction::Back,
+        KeyAction::Down,
+        KeyAction::Up,
+        KeyAction::Top,
+        KeyAction::Bottom,
+        KeyAction::Select,
+        KeyAction::Refresh,
+        KeyAction::Search,
+        KeyAction::Follow,
+        KeyAction::Wrap,
+        KeyAction::Timestamps,
+        KeyAction::NextMatch,
+        KeyAction::PrevMatch,
+        KeyAction::SaveBuffer,
+        KeyAction::ScrollLeft,
+        KeyAction::ScrollRight,
+        KeyAction::ActionMenu,
+        KeyAction::RowMenu,
+        KeyAction::OpenLink,
+        KeyAction::CommandPalette,
+        KeyAction::Expand,
+        KeyAction::HalfPageDown,
+        KeyAction::HalfPageUp,
+        KeyAction::PageDown,
+        KeyAction::PageUp,
+        KeyAction::ParagraphDown,
+        KeyAction::ParagraphUp,
+        KeyAction::MoveColumnLeft,
+        KeyAction::MoveColumnRight,
+        KeyAction::Sort,
+        KeyAction::ToggleMark,
+        KeyAction::MarkAll,
+        KeyAction::ClearMarks,
+        KeyAction::Baseline,
+        KeyAction::ToggleDiff,
+        KeyAction::ViewValue,
+        KeyAction::PauseRefresh,
+        KeyAction::SwitchFocus,
+        KeyAction::Notifications,
+        KeyAction::Forward,
+        KeyAction::History,
+        KeyAction::Bookmark,
+        KeyAction::Bookmarks,
+        KeyAction::PrevRow,
+        KeyAction::NextRow,
+    ];
+
+    /// Name used in the `keybindings` config section
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyAction::Quit => "quit",
+            KeyAction::Back => "back",
+            KeyAction::Down => "down",
+            KeyAction::Up => "up",
+            KeyAction::Top => "top",
+            KeyAction::Bottom => "bottom",
+            KeyAction::Select => "select",
+            KeyAction::Refresh => "refresh",
+            KeyAction::Search => "search",
+            KeyAction::Follow => "follow",
+            KeyAction::Wrap => "wrap",
+            KeyAction::Timestamps => "timestamps",
+            KeyAction::NextMatch => "next_match",
+            KeyAction::PrevMatch => "prev_match",
+            KeyAction::SaveBuffer => "save_buffer",
+            KeyAction::ScrollLeft => "scroll_left",
+            KeyAction::ScrollRight => "scroll_right",
+            KeyAction::ActionMenu => "action_menu",
+            KeyAction::RowMenu => "row_menu",
+            KeyAction::OpenLink => "open_link",
+            KeyAction::CommandPalette => "command_palette",
+            KeyAction::Expand => "expand",
+            KeyAction::HalfPageDown => "half_page_down",
+            KeyAction::HalfPageUp => "half_page_up",
+            KeyAction::PageDown => "page_down",
+            KeyAction::PageUp => "page_up",
+            KeyAction::ParagraphDown => "paragraph_down",
+            KeyAction::ParagraphUp => "paragraph_up",
+            KeyAction::MoveColumnLeft => "move_column_left",
+            KeyAction::MoveColumnRight => "move_column_right",
+            KeyAction::Sort => "sort",
+            KeyAction::ToggleMark => "toggle_mark",
+            KeyAction::MarkAll => "mark_all",
+            KeyAction::ClearMarks => "clear_marks",
+            KeyAction::Baseline => "baseline",
+            KeyAction::ToggleDiff => "toggle_diff",
+            KeyAction::ViewValue => "view_value",
+            KeyAction::PauseRefresh => "pause_refresh",
+            KeyAction::SwitchFocus => "switch_focus",
+            KeyAction::Notifications => "notifications",
+            KeyAction::Forward => "forward",
+            KeyAction::History => "history",
+            KeyAction::Bookmark => "bookmark",
+            KeyAction::Bookmarks => "bookmarks",
+            KeyAction::PrevRow => "prev_row",
+            KeyAction::NextRow => "next_row",
+        }
+    }
+
+    /// Short human-readable description, e.g. for the command palette
+    pub fn description(&self) -> &'static str {
+        match self {
+            KeyAction::Quit => "Quit",
+            KeyAction::Back => "Go back",
+            KeyAction::Down => "Move down",
+            KeyAction::Up => "Move up",
+            KeyAction::Top => "Go to top",
+            KeyAction::Bottom => "Go to bottom",
+            KeyAction::Select => "Select / navigate",
+            KeyAction::Refresh => "Refresh",
+            KeyAction::Search => "Search",
+            KeyAction::Follow => "Toggle follow (logs)",
+            KeyAction::Wrap => "Toggle wrap (logs)",
+            KeyAction::Timestamps => "Line times: off / received at / relative (logs)",
+            KeyAction::NextMatch => "Next search match, showing all lines (logs)",
+            KeyAction::PrevMatch => "Previous search match, showing all lines (logs)",
+            KeyAction::SaveBuffer => "Save the log buffer to a file or command (logs)",
+            KeyAction::ScrollLeft => "Previous column / scroll left (logs)",
+            KeyAction::ScrollRight => "Next column / scroll right (logs)",
+            KeyAction::ActionMenu => "Action menu",
+            KeyAction::RowMenu => "Row menu",
+            KeyAction::OpenLink => "Open link",
+            KeyAction::CommandPalette => "Command palette",
+            KeyAction::Expand => "Expand / collapse row",
+            KeyAction::HalfPageDown => "Half page down",
+            KeyAction::HalfPageUp => "Half page up",
+            KeyAction::PageDown => "Page down",
+            KeyAction::PageUp => "Page up",
+            KeyAction::ParagraphDown => "Next paragraph (text)",
+            KeyAction::ParagraphUp => "Previous paragraph (text)",
+            KeyAction::MoveColumnLeft => "Move column left",
+            KeyAction::MoveColumnRight => "Move column right",
+            KeyAction::Sort => "Sort by column (asc / desc / off)",
+            KeyAction::ToggleMark => "Mark / unmark row for bulk actions",
+            KeyAction::MarkAll => "Mark all rows shown (or unmark them)",
+            KeyAction::ClearMarks => "Clear marked rows",
+            KeyAction::Baseline => "Snapshot the page as the diff baseline",
+            KeyAction::ToggleDiff => "Highlight cells changed since the baseline",
+            KeyAction::ViewValue => "Show the full value of the selected cell or line",
+            KeyAction::PauseRefresh => "Pause / resume auto-refresh",
+            KeyAction::SwitchFocus => "Switch between table and detail pane (split)",
+            KeyAction::Notifications => "Notification history",
+            KeyAction::Forward => "Go forward (to the page gone back from)",
+            KeyAction::History => "Pages visited (jump to one)",
+            KeyAction::Bookmark => "Bookmark the page (or remove its bookmark)",
+            KeyAction::Bookmarks => "Bookmarked pages",
+            KeyAction::PrevRow => "Show this page for the previous row of the table before it",
+            KeyAction::NextRow => "Show this page for the next row of the table before it",
+        }
+    }
+
+    /// Movement actions, which repeat when given a count prefix (`5j`)
+    pub fn is_motion(&self) -> bool {
+        matches!(
+            self,
+            KeyAction::Down
+                | KeyAction::Up
+                | KeyAction::ScrollLeft
+                | KeyAction::ScrollRight
+                | KeyAction::HalfPageDown
+                | KeyAction::HalfPageUp
+                | KeyAction::PageDown
+                | KeyAction::PageUp
+                | KeyAction::ParagraphDown
+                | KeyAction::ParagraphUp
+        )
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|a| a.name() == name.trim())
+    }
+}
+
+/// How long an incomplete key sequence waits for its next key
+pub const CHORD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Parse a key sequence such as `g g`, `z f` or `space b`.
+///
+/// Keys are separated by whitespace; a single key is a sequence of length one.
+pub fn parse_sequence(s: &str) -> Result<Vec<KeyCombo>, String> {
+    let keys = s
+        .split_whitespace()
+        .map(KeyCombo::parse)
+        .collect::<Result<Vec<_>, _>>()?;
+    if keys.is_empty() {
+        return Err("Empty key".to_string());
+    }
+    Ok(keys)
+}
+
+/// Human-readable form of a key sequence, e.g. `g g` or `Space B`
+pub fn display_sequence(keys: &[KeyCombo]) -> String {
+    keys.iter()
+        .map(|k| k.display())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Resolves key events to built-in actions.
+///
+/// Starts from `default_keybindings()` and applies `keybindings.global` from the
+/// config on top. Binding a key to `none` removes its default. Keys may be
+/// sequences (`g g`), which are resolved incrementally with `match_sequence`.
+///
+/// `keybindings.custom` binds keys to pages instead (`g p` -> `pods`); those
+/// are looked up with `match_page_sequence`.
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: HashMap<Vec<KeyCombo>, KeyAction>,
+    pages: HashMap<Vec<KeyCombo>, String>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::from_config(None).expect("default keybindings are valid")
+    }
+}
+
+impl Keymap {
+    pub fn from_config(keybindings: Option<&Keybindings>) -> Result<Self, String> {
+        let mut bindings = HashMap::new();
+
+        let defaults = default_keybindings();
+        let overrides = keybindings.map(|kb| &kb.global);
+        for (key, action) in defaults.iter().chain(overrides.into_iter().flatten()) {
+            let keys =
+                parse_sequence(key).map_err(|e| format!("Invalid keybinding '{}': {}", key, e))?;
+            if action.trim() == "none" {
+                bindings.remove(&keys);
+                continue;
+            }
+            let action = KeyAction::from_name(action).ok_or_else(|| {
+                format!("Unknown action '{}' for keybinding '{}'", action, key)
+            })?;
+            bindings.insert(keys, action);
+        }
+
+        let mut pages = HashMap::new();
+        for (key, page) in keybindings.map(|kb| &kb.custom).into_iter().flatten() {
+            let keys =
+                parse_sequence(key).map_err(|e| format!("Invalid keybinding '{}': {}", key, e))?;
+            pages.insert(keys, page.trim().to_string());
+        }
+
+        Ok(Self { bindings, pages })
+    }
+
+    /// Resolve a single key event to a built-in action
+    pub fn resolve(&self, key: &KeyEvent) -> Option<KeyAction> {
+        self.action_for(&[KeyCombo::from_event(key)])
+    }
+
+    /// Built-in action bound to exactly this key sequence
+    pub fn action_for(&self, keys: &[KeyCombo]) -> Option<KeyAction> {
+        self.bindings.get(keys).copied()
+    }
+
+    /// Match a (possibly partial) key sequence.
+    ///
+    /// Returns the action bound to exactly `keys`, and whether `keys` is also
+    /// the start of a longer binding (in which case the caller should wait).
+    pub fn match_sequence(&self, keys: &[KeyCombo]) -> (Option<KeyAction>, bool) {
+        let is_prefix = self
+            .bindings
+            .keys()
+            .any(|seq| seq.len() > keys.len() && seq.starts_with(keys));
+        (self.action_for(keys), is_prefix)
+    }
+
+    /// All built-in bindings, ordered by action (as in `KeyAction::ALL`) then keys
+    pub fn bindings(&self) -> Vec<(Vec<KeyCombo>, KeyAction)> {
+        let mut bindings: Vec<(Vec<KeyCombo>, KeyAction)> =
+            self.bindings.iter().map(|(k, a)| (k.clone(), *a)).collect();
+        let rank = |action: &KeyAction| KeyAction::ALL.iter().position(|a| a == action);
+        bindings.sort_by_cached_key(|(keys, action)| (rank(action), display_sequence(keys)));
+        bindings
+    }
+
+    /// All go-to-page shortcuts, ordered by keys
+    pub fn page_shortcuts(&self) -> Vec<(Vec<KeyCombo>, &str)> {
+        let mut shortcuts: Vec<(Vec<KeyCombo>, &str)> =
+            self.pages.iter().map(|(k, p)| (k.clone(), p.as_str())).collect();
+        shortcuts.sort_by_cached_key(|(keys, _)| display_sequence(keys));
+        shortcuts
+    }
+
+    /// Like `match_sequence`, for the go-to-page shortcuts: the page bound to
+    /// exactly `keys`, and whether `keys` starts a longer shortcut
+    pub fn match_page_sequence(&self, keys: &[KeyCombo]) -> (Option<&str>, bool) {
+        let is_prefix = self
+            .pages
+            .keys()
+            .any(|seq| seq.len() > keys.len() && seq.starts_with(keys));
+        (self.pages.get(keys).map(String::as_str), is_prefix)
+    }
+
+    /// Go-to-page shortcuts for a page, as display strings in sorted order
+    pub fn keys_for_page(&self, page: &str) -> Vec<String> {
+        let mut keys: Vec<String> = self
+            .pages
+            .iter()
+            .filter(|(_, p)| *p == page)
+            .map(|(k, _)| display_sequence(k))
+            .collect();
+        keys.sort();
+        keys
+    }
+
+    /// All keys bound to an action, as display strings in sorted order
+    pub fn keys_for(&self, action: KeyAction) -> Vec<String> {
+        let mut keys: Vec<String> = self
+            .bindings
+            .iter()
+            .filter(|(_, a)| **a == action)
+            .map(|(k, _)| display_sequence(k))
+            .collect();
+        keys.sort();
+        keys
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn test_parse_combos() {
+        assert_eq!(
+            KeyCombo::parse("j").unwrap(),
+            KeyCombo::new(KeyCode::Char('j'), KeyModifiers::NONE)
+        );
+        assert_eq!(
+            KeyCombo::parse("shift+g").unwrap(),
+            KeyCombo::new(KeyCode::Char('G'), KeyModifiers::NONE)
+        );
+        assert_eq!(
+            KeyCombo::parse("ctrl+U").unwrap(),
+            KeyCombo::new(KeyCode::Char('u'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(KeyCombo::parse("Esc").unwrap().code, KeyCode::Esc);
+        assert_eq!(KeyCombo::parse("space").unwrap().code, KeyCode::Char(' '));
+        assert_eq!(KeyCombo::parse("F5").unwrap().code, KeyCode::F(5));
+        assert_eq!(KeyCombo::parse("+").unwrap().code, KeyCode::Char('+'));
+        assert!(KeyCombo::parse("hyper+x").is_err());
+        assert!(KeyCombo::parse("F13").is_err());
+        assert!(KeyCombo::parse("").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(KeyCombo::parse("ctrl+d").unwrap().display(), "Ctrl+D");
+        assert_eq!(KeyCombo::parse("G").unwrap().display(), "G");
+        assert_eq!(KeyCombo::parse("Space").unwrap().display(), "Space");
+    }
+
+    #[test]
+    fn test_default_resolution() {
+        let keymap = Keymap::default();
+        assert_eq!(
+            keymap.resolve(&event(KeyCode::Char('j'), KeyModifiers::NONE)),
+            Some(KeyAction::Down)
+        );
+        assert_eq!(
+            keymap.resolve(&event(KeyCode::Down, KeyModifiers::NONE)),
+            Some(KeyAction::Down)
+        );
+        // Shift is carried by the character
+        assert_eq!(
+            keymap.resolve(&event(KeyCode::Char('G'), KeyModifiers::SHIFT)),
+            Some(KeyAction::Bottom)
+        );
+        assert_eq!(keymap.resolve(&event(KeyCode::Char('z'), KeyModifiers::NONE)), None);
+        assert_eq!(
+            keymap.resolve(&event(KeyCode::Char('d'), KeyModifiers::CONTROL)),
+            Some(KeyAction::HalfPageDown)
+        );
+        assert_eq!(
+            keymap.resolve(&event(KeyCode::PageUp, KeyModifiers::NONE)),
+            Some(KeyAction::PageUp)
+        );
+        assert_eq!(
+            keymap.resolve(&event(KeyCode::Char('>'), KeyModifiers::SHIFT)),
+            Some(KeyAction::MoveColumnRight)
+        );
+        assert_eq!(
+            keymap.resolve(&event(KeyCode::BackTab, KeyModifiers::SHIFT)),
+            Some(KeyAction::SwitchFocus)
+        );
+        assert_eq!(
+            keymap.resolve(&event(KeyCode::Char('s'), KeyModifiers::NONE)),
+            Some(KeyAction::Sort)
+        );
+        assert_eq!(
+            keymap.resolve(&event(KeyCode::Char(' '), KeyModifiers::NONE)),
+            Some(KeyAction::ToggleMark)
+        );
+        assert!(KeyAction::PageUp.is_motion());
+        assert!(!KeyAction::Top.is_motion());
+    }
+
+    #[test]
+    fn test_config_overrides() {
+        let mut global = HashMap::new();
+        global.insert("x".to_string(), "quit".to_string());
+        global.insert("q".to_string(), "none".to_string());
+        global.insert("ctrl+n".to_string(), "down".to_string());
+        let keybindings = Keybindings {
+            global,
+            custom: HashMap::new(),
+        };
+
+        let keymap = Keymap::from_config(Some(&keybindings)).unwrap();
+        assert_eq!(
+            keymap.resolve(&event(KeyCode::Char('x'), KeyModifiers::NONE)),
+            Some(KeyAction::Quit)
+        );
+        assert_eq!(keymap.resolve(&event(KeyCode::Char('q'), KeyModifiers::NONE)), None);
+        assert_eq!(
+            keymap.resolve(&event(KeyCode::Char('n'), KeyModifiers::CONTROL)),
+            Some(KeyAction::Down)
+        );
+        // Defaults for other keys are kept
+        assert_eq!(
+            keymap.resolve(&event(KeyCode::Char('k'), KeyModifiers::NONE)),
+            Some(KeyAction::Up)
+        );
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let mut global = HashMap::new();
+        global.insert("x".to_string(), "teleport".to_string());
+        let keybindings = Keybindings {
+            global,
+            custom: HashMap::new(),
+        };
+        assert!(Keymap::from_config(Some(&keybindings)).is_err());
+    }
+
+    #[test]
+    fn test_sequences() {
+        assert_eq!(parse_sequence("g g").unwrap().len(), 2);
+        assert_eq!(
+            parse_sequence("space b").unwrap(),
+            vec![
+                KeyCombo::new(KeyCode::Char(' '), KeyModifiers::NONE),
+                KeyCombo::new(KeyCode::Char('b'), KeyModifiers::NONE),
+            ]
+        );
+        assert_eq!(display_sequence(&parse_sequence("z  f").unwrap()), "z f");
+        assert!(parse_sequence("  ").is_err());
+        assert!(parse_sequence("g hyper+x").is_err());
+
+        let mut global = HashMap::new();
+        global.insert("z f".to_string(), "follow".to_string());
+        global.insert("g g".to_string(), "top".to_string());
+        let keybindings = Keybindings {
+            global,
+            custom: HashMap::new(),
+        };
+        let keymap = Keymap::from_config(Some(&keybindings)).unwrap();
+
+        let z = parse_sequence("z").unwrap();
+        assert_eq!(keymap.match_sequence(&z), (None, true));
+        let zf = parse_sequence("z f").unwrap();
+        assert_eq!(keymap.match_sequence(&zf), (Some(KeyAction::Follow), false));
+        // `g` is still bound on its own, but now also starts `g g`
+        let g = parse_sequence("g").unwrap();
+        assert_eq!(keymap.match_sequence(&g), (Some(KeyAction::Top), true));
+        assert_eq!(keymap.keys_for(KeyAction::Top), vec!["g", "g g"]);
+        let zx = parse_sequence("z x").unwrap();
+        assert_eq!(keymap.match_sequence(&zx), (None, false));
+    }
+
+    #[test]
+    fn test_page_shortcuts() {
+        let mut custom = HashMap::new();
+        custom.insert("g p".to_string(), "pods".to_string());
+        custom.insert("g n".to_string(), "nodes".to_string());
+        let keybindings = Keybindings {
+            global: HashMap::new(),
+            custom,
+        };
+        let keymap = Keymap::from_config(Some(&keybindings)).unwrap();
+
+        // `g` keeps its built-in binding but now waits for a second key
+        let g = parse_sequence("g").unwrap();
+        assert_eq!(keymap.match_page_sequence(&g), (None, true));
+        assert_eq!(keymap.match_sequence(&g), (Some(KeyAction::Top), false));
+        let gp = parse_sequence("g p").unwrap();
+        assert_eq!(keymap.match_page_sequence(&gp), (Some("pods"), false));
+        assert_eq!(keymap.keys_for_page("nodes"), vec!["g n"]);
+    }
+}
@@ -1,13 +1,24 @@
 // Input handling module for keyboard actions and key parsing
+pub mod history;
+pub mod keymap;
+pub mod report;
+
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 /// Represents a parsed action key from configuration
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ActionKey {
     /// Simple single character key (legacy format)
     Simple(char),
     /// Control + character combination
     Ctrl(char),
+    /// Alt + character combination
+    Alt(char),
+    /// Any other key: function keys, Tab, Home/End, PageUp/PageDown, Delete,
+    /// or a character with several modifiers (e.g. "ctrl+alt+x", "shift+F5")
+    Combo(keymap::KeyCombo),
+    /// Several keys pressed one after another, e.g. "g p" or "space b"
+    Sequence(Vec<keymap::KeyCombo>),
 }
 
 impl ActionKey {
@@ -16,14 +27,28 @@ impl ActionKey {
     /// Supports formats:
     /// - Single char: "l", "d", "e" (legacy format)
     /// - Ctrl combination: "ctrl+l", "Ctrl+L", "CTRL+L" (case insensitive)
+    /// - Alt combination: "alt+l"
+    /// - Special keys with optional modifiers: "F5", "shift+F1", "Tab", "Home",
+    ///   "PageDown", "Delete", "ctrl+alt+x" (see [`keymap::KeyCombo::parse`])
+    /// - Sequences of keys separated by spaces: "g p", "space b", "z ctrl+f"
     ///
     /// # Examples
     /// ```
+    /// # use termstack::input::ActionKey;
     /// let key = ActionKey::parse("l").unwrap();
     /// assert_eq!(key, ActionKey::Simple('l'));
     ///
     /// let key = ActionKey::parse("ctrl+l").unwrap();
     /// assert_eq!(key, ActionKey::Ctrl('l'));
+    ///
+    /// let key = ActionKey::parse("alt+x").unwrap();
+    /// assert_eq!(key, ActionKey::Alt('x'));
+    ///
+    /// let key = ActionKey::parse("shift+F5").unwrap();
+    /// assert_eq!(key.display(), "Shift+F5");
+    ///
+    /// let key = ActionKey::parse("space b").unwrap();
+    /// assert_eq!(key.display(), "Space b");
     /// ```
     pub fn parse(s: &str) -> Result<Self, String> {
         let s = s.trim();
@@ -32,8 +57,19 @@ impl ActionKey {
             return Err("Key cannot be empty".to_string());
         }
 
-        // Check for ctrl+ prefix (case insensitive)
-        if let Some(stripped) = s.to_lowercase().strip_prefix("ctrl+") {
+        if s.split_whitespace().nth(1).is_some() {
+            let keys = s
+                .split_whitespace()
+                .map(|part| Self::parse(part).map(|key| key.to_keys()))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format!("Invalid key sequence '{}': {}", s, e))?;
+            return Ok(ActionKey::Sequence(keys.concat()));
+        }
+
+        // Check for ctrl+<char> (case insensitive)
+        if let Some(stripped) = s.to_lowercase().strip_prefix("ctrl+")
+            && stripped.chars().count() <= 1
+        {
             if stripped.len() != 1 {
                 return Err(format!(
                     "Invalid Ctrl combination '{}': expected single character after 'ctrl+'",
@@ -53,10 +89,25 @@ impl ActionKey {
             let ch = s.chars().next().unwrap();
             Ok(ActionKey::Simple(ch))
         } else {
-            Err(format!(
-                "Invalid key format '{}': expected single character or 'ctrl+X'",
-                s
-            ))
+            let combo = keymap::KeyCombo::parse(s).map_err(|e| {
+                format!(
+                    "Invalid key format '{}': expected single character, 'ctrl+X', 'alt+X' \
+                    or a special key like 'F5' ({})",
+                    s, e
+                )
+            })?;
+            Ok(Self::from_combo(combo))
+        }
+    }
+
+    /// Canonical ActionKey for a combo: plain, Ctrl+ and Alt+ characters use
+    /// their dedicated variants so equal keys compare equal.
+    fn from_combo(combo: keymap::KeyCombo) -> Self {
+        match (combo.code, combo.modifiers) {
+            (KeyCode::Char(c), m) if m.is_empty() => ActionKey::Simple(c),
+            (KeyCode::Char(c), KeyModifiers::CONTROL) => ActionKey::Ctrl(c),
+            (KeyCode::Char(c), KeyModifiers::ALT) => ActionKey::Alt(c),
+            _ => ActionKey::Combo(combo),
         }
     }
 
@@ -64,6 +115,8 @@ impl ActionKey {
     ///
     /// # Examples
     /// ```
+    /// # use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    /// # use termstack::input::ActionKey;
     /// let key = ActionKey::Ctrl('l');
     ///
     /// // Matches Ctrl+L
@@ -84,6 +137,14 @@ impl ActionKey {
                 matches!(key.code, KeyCode::Char(c) if c.to_ascii_lowercase() == *ch)
                     && key.modifiers.contains(KeyModifiers::CONTROL)
             }
+            ActionKey::Alt(ch) => {
+                matches!(key.code, KeyCode::Char(c) if c.to_ascii_lowercase() == *ch)
+                    && key.modifiers.contains(KeyModifiers::ALT)
+                    && !key.modifiers.contains(KeyModifiers::CONTROL)
+            }
+            ActionKey::Combo(combo) => keymap::KeyCombo::from_event(key) == *combo,
+            // A single event never completes a sequence; see `to_keys`
+            ActionKey::Sequence(_) => false,
         }
     }
 
@@ -91,20 +152,30 @@ impl ActionKey {
     ///
     /// # Examples
     /// ```
+    /// # use termstack::input::ActionKey;
     /// assert_eq!(ActionKey::Simple('l').display(), "l");
     /// assert_eq!(ActionKey::Ctrl('l').display(), "Ctrl+L");
+    /// assert_eq!(ActionKey::Alt('l').display(), "Alt+L");
     /// ```
     pub fn display(&self) -> String {
         match self {
             ActionKey::Simple(ch) => ch.to_string(),
             ActionKey::Ctrl(ch) => format!("Ctrl+{}", ch.to_ascii_uppercase()),
+            ActionKey::Alt(ch) => format!("Alt+{}", ch.to_ascii_uppercase()),
+            ActionKey::Combo(combo) => combo.display(),
+            ActionKey::Sequence(keys) => keymap::display_sequence(keys),
         }
     }
 
-    /// Get the character component of the key (without modifiers)
-    pub fn char(&self) -> char {
+    /// Get the character component of the key (without modifiers), if it has one
+    pub fn char(&self) -> Option<char> {
         match self {
-            ActionKey::Simple(ch) | ActionKey::Ctrl(ch) => *ch,
+            ActionKey::Simple(ch) | ActionKey::Ctrl(ch) | ActionKey::Alt(ch) => Some(*ch),
+            ActionKey::Combo(combo) => match combo.code {
+                KeyCode::Char(ch) => Some(ch),
+                _ => None,
+            },
+            ActionKey::Sequence(_) => None,
         }
     }
 
@@ -112,6 +183,24 @@ impl ActionKey {
     pub fn is_ctrl(&self) -> bool {
         matches!(self, ActionKey::Ctrl(_))
     }
+
+    /// Whether the key triggers its action directly from normal mode.
+    /// Only legacy single-char keys need the action menu.
+    pub fn is_direct(&self) -> bool {
+        !matches!(self, ActionKey::Simple(_))
+    }
+
+    /// The equivalent key sequence, for lookups in the [`keymap::Keymap`]
+    pub fn to_keys(&self) -> Vec<keymap::KeyCombo> {
+        let combo = match self {
+            ActionKey::Simple(ch) => keymap::KeyCombo::new(KeyCode::Char(*ch), KeyModifiers::NONE),
+            ActionKey::Ctrl(ch) => keymap::KeyCombo::new(KeyCode::Char(*ch), KeyModifiers::CONTROL),
+            ActionKey::Alt(ch) => keymap::KeyCombo::new(KeyCode::Char(*ch), KeyModifiers::ALT),
+            ActionKey::Combo(combo) => *combo,
+            ActionKey::Sequence(keys) => return keys.clone(),
+        };
+        vec![combo]
+    }
 }
 
 #[cfg(test)]
@@ -167,4 +256,97 @@ mod tests {
         let event = KeyEvent::new(KeyCode::Char('L'), KeyModifiers::CONTROL);
         assert!(ctrl_key.matches(&event));
     }
+
+    #[test]
+    fn test_parse_extended() {
+        assert_eq!(ActionKey::parse("alt+x").unwrap(), ActionKey::Alt('x'));
+        assert_eq!(ActionKey::parse("Alt+X").unwrap(), ActionKey::Alt('x'));
+        // Combos that reduce to a dedicated variant are canonicalized
+        assert_eq!(ActionKey::parse("shift+g").unwrap(), ActionKey::Simple('G'));
+        assert_eq!(ActionKey::parse("control+k").unwrap(), ActionKey::Ctrl('k'));
+
+        for (input, code, modifiers) in [
+            ("F5", KeyCode::F(5), KeyModifiers::NONE),
+            ("shift+F12", KeyCode::F(12), KeyModifiers::SHIFT),
+            ("Tab", KeyCode::Tab, KeyModifiers::NONE),
+            ("home", KeyCode::Home, KeyModifiers::NONE),
+            ("End", KeyCode::End, KeyModifiers::NONE),
+            ("PageUp", KeyCode::PageUp, KeyModifiers::NONE),
+            ("ctrl+PageDown", KeyCode::PageDown, KeyModifiers::CONTROL),
+            ("Delete", KeyCode::Delete, KeyModifiers::NONE),
+            ("ctrl+alt+x", KeyCode::Char('x'), KeyModifiers::CONTROL | KeyModifiers::ALT),
+        ] {
+            assert_eq!(
+                ActionKey::parse(input).unwrap(),
+                ActionKey::Combo(keymap::KeyCombo::new(code, modifiers)),
+                "parsing {}",
+                input
+            );
+        }
+
+        assert!(ActionKey::parse("F13").is_err());
+        assert!(ActionKey::parse("super+x").is_err());
+    }
+
+    #[test]
+    fn test_matches_extended() {
+        let alt = ActionKey::Alt('x');
+        assert!(alt.matches(&KeyEvent::new(KeyCode::Char('x'), KeyModifiers::ALT)));
+        assert!(!alt.matches(&KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE)));
+        assert!(!alt.matches(&KeyEvent::new(
+            KeyCode::Char('x'),
+            KeyModifiers::ALT | KeyModifiers::CONTROL
+        )));
+
+        let f5 = ActionKey::parse("shift+F5").unwrap();
+        assert!(f5.matches(&KeyEvent::new(KeyCode::F(5), KeyModifiers::SHIFT)));
+        assert!(!f5.matches(&KeyEvent::new(KeyCode::F(5), KeyModifiers::NONE)));
+
+        let tab = ActionKey::parse("Tab").unwrap();
+        assert!(tab.matches(&KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE)));
+        assert!(tab.is_direct());
+        assert!(!ActionKey::Simple('l').is_direct());
+    }
+
+    #[test]
+    fn test_display_extended() {
+        assert_eq!(ActionKey::Alt('x').display(), "Alt+X");
+        assert_eq!(ActionKey::parse("shift+F1").unwrap().display(), "Shift+F1");
+        assert_eq!(ActionKey::parse("ctrl+alt+x").unwrap().display(), "Ctrl+Alt+X");
+        assert_eq!(ActionKey::parse("PageDown").unwrap().display(), "PgDn");
+    }
+
+    #[test]
+    fn test_to_keys() {
+        let keymap = keymap::Keymap::default();
+        assert_eq!(
+            keymap.action_for(&ActionKey::Simple('r').to_keys()),
+            Some(keymap::KeyAction::Refresh)
+        );
+        assert_eq!(
+            keymap.action_for(&ActionKey::Ctrl('c').to_keys()),
+            Some(keymap::KeyAction::Quit)
+        );
+        assert_eq!(keymap.action_for(&ActionKey::Simple('z').to_keys()), None);
+        assert_eq!(keymap.action_for(&ActionKey::Ctrl('r').to_keys()), None);
+    }
+
+    #[test]
+    fn test_parse_sequence() {
+        let key = ActionKey::parse("g p").unwrap();
+        assert_eq!(
+            key.to_keys(),
+            vec![
+                ActionKey::Simple('g').to_keys()[0],
+                ActionKey::Simple('p').to_keys()[0]
+            ]
+        );
+        assert!(key.is_direct());
+        assert_eq!(key.char(), None);
+        assert_eq!(ActionKey::parse("z ctrl+f").unwrap().display(), "z Ctrl+F");
+        assert_eq!(ActionKey::parse("space  b").unwrap().display(), "Space b");
+        // Sequences never match a single event
+        assert!(!key.matches(&KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE)));
+        assert!(ActionKey::parse("g F13").is_err());
+    }
 }
@@ -0,0 +1,207 @@
+// Resolved keymap listing for `termstack keys`
+use super::ActionKey;
+use super::keymap::{KeyCombo, Keymap, display_sequence};
+use crate::config::Config;
+
+/// One row of the keymap listing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyRow {
+    /// `global` or the page id
+    pub scope: String,
+    pub keys: String,
+    /// What the keys do: a built-in action name, `go to <page>` or `action: <name>`
+    pub binding: String,
+    /// `default`, `config` (keybindings section) or `page` (page actions)
+    pub source: &'static str,
+    /// Conflict or reachability note
+    pub note: Option<String>,
+}
+
+impl KeyRow {
+    pub fn is_conflict(&self) -> bool {
+        self.note.is_some()
+    }
+}
+
+/// Every binding in effect: built-ins (defaults plus `keybindings.global`),
+/// go-to-page shortcuts (`keybindings.custom`) and each page's actions, with
+/// notes on keys that shadow, delay or hide one another.
+pub fn keymap_rows(config: &Config) -> Result<Vec<KeyRow>, String> {
+    let keymap = Keymap::from_config(config.keybindings.as_ref())?;
+    let defaults = Keymap::default();
+    let shortcuts = keymap.page_shortcuts();
+    let mut rows = Vec::new();
+
+    // Keys that start a longer chord wait for the next key before running
+    let is_prefix = |keys: &[KeyCombo]| {
+        let longer = |seq: &[KeyCombo]| seq.len() > keys.len() && seq.starts_with(keys);
+        keymap.bindings().iter().any(|(seq, _)| longer(seq))
+            || shortcuts.iter().any(|(seq, _)| longer(seq))
+    };
+
+    for (keys, action) in keymap.bindings() {
+        let note = if let Some((_, page)) = shortcuts.iter().find(|(seq, _)| *seq == keys) {
+            Some(format!("shadowed by go-to shortcut for '{}'", page))
+        } else if is_prefix(&keys) {
+            Some("waits for the next key of a longer chord".to_string())
+        } else {
+            None
+        };
+        rows.push(KeyRow {
+            scope: "global".to_string(),
+            keys: display_sequence(&keys),
+            binding: action.name().to_string(),
+            source: if defaults.action_for(&keys) == Some(action) {
+                "default"
+            } else {
+                "config"
+            },
+            note,
+        });
+    }
+
+    for (keys, page) in &shortcuts {
+        let note = keymap
+            .action_for(keys)
+            .map(|builtin| format!("overrides built-in '{}'", builtin.name()));
+        rows.push(KeyRow {
+            scope: "global".to_string(),
+            keys: display_sequence(keys),
+            binding: format!("go to {}", page),
+            source: "config",
+            note,
+        });
+    }
+
+    let mut page_ids: Vec<&String> = config.pages.keys().collect();
+    page_ids.sort();
+    for page_id in page_ids {
+        for action in config.pages[page_id].actions.iter().flatten() {
+            let Ok(parsed) = ActionKey::parse(&action.key) else {
+                continue;
+            };
+            let keys = parsed.to_keys();
+            let builtin = keymap.action_for(&keys);
+            let note = if !parsed.is_direct() {
+                Some(match builtin {
+                    Some(b) => format!("action menu only (key is built-in '{}')", b.name()),
+                    None => "action menu only".to_string(),
+                })
+            } else if let Some(b) = builtin {
+                Some(format!("overrides built-in '{}' on this page", b.name()))
+            } else if let Some((_, page)) = shortcuts.iter().find(|(seq, _)| *seq == keys) {
+                Some(format!("overrides go-to shortcut for '{}' on this page", page))
+            } else if keys.len() > 1
+                && let Some(b) = keymap.action_for(&keys[..1])
+            {
+                Some(format!(
+                    "'{}' ({}) waits for the next key on this page",
+                    keys[0].display(),
+                    b.name()
+                ))
+            } else {
+                None
+            };
+            rows.push(KeyRow {
+                scope: page_id.clone(),
+                keys: display_sequence(&keys),
+                binding: format!("action: {}", action.name),
+                source: "page",
+                note,
+            });
+        }
+    }
+
+    Ok(rows)
+}
+
+/// Format rows as an aligned plain-text table, notes flagged with `!`
+pub fn format_rows(rows: &[KeyRow]) -> String {
+    use unicode_width::UnicodeWidthStr;
+
+    let header = ["SCOPE", "KEYS", "BINDING", "SOURCE"];
+    let cells: Vec<[&str; 4]> = rows
+        .iter()
+        .map(|r| [r.scope.as_str(), r.keys.as_str(), r.binding.as_str(), r.source])
+        .collect();
+    let mut widths = header.map(str::width);
+    for row in &cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.width());
+        }
+    }
+
+    let line = |cols: [&str; 4], note: &str| {
+        let mut out = String::new();
+        for (cell, width) in cols.iter().zip(widths) {
+            out.push_str(cell);
+            out.push_str(&" ".repeat(width - cell.width() + 2));
+        }
+        out.push_str(note);
+        out.trim_end().to_string()
+    };
+
+    let mut out = line(header, "NOTE");
+    out.push('\n');
+    for (row, cols) in rows.iter().zip(cells) {
+        let note = row.note.as_ref().map(|n| format!("! {}", n)).unwrap_or_default();
+        out.push_str(&line(cols, &note));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigLoader;
+
+    #[test]
+    fn test_keymap_rows() {
+        let yaml = r#"
+version: v1
+app:
+  name: "Test App"
+start: main
+keybindings:
+  global:
+    "ctrl+q": quit
+  custom:
+    "g m": main
+pages:
+  main:
+    title: "Main"
+    data:
+      type: cli
+      command: "echo"
+    view:
+      type: table
+      columns: []
+    actions:
+      - key: "ctrl+d"
+        name: "Describe"
+        command: "true"
+      - key: "r"
+        name: "Restart"
+        command: "true"
+"#;
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        let rows = keymap_rows(&config).unwrap();
+        let find = |scope: &str, keys: &str| {
+            rows.iter().find(|r| r.scope == scope && r.keys == keys).unwrap()
+        };
+
+        assert_eq!(find("global", "j").source, "default");
+        assert_eq!(find("global", "Ctrl+Q").source, "config");
+        assert!(find("global", "g").note.as_ref().unwrap().contains("waits"));
+        assert_eq!(find("global", "g m").binding, "go to main");
+
+        let describe = find("main", "Ctrl+D");
+        assert!(describe.note.as_ref().unwrap().contains("half_page_down"));
+        assert!(find("main", "r").note.as_ref().unwrap().contains("action menu only"));
+
+        let table = format_rows(&rows);
+        assert!(table.starts_with("SCOPE"));
+        assert!(table.contains("! overrides built-in 'half_page_down' on this page"));
+    }
+}
@@ -1,15 +1,26 @@
 pub mod action;
+pub mod alert;
 pub mod adapters;
 pub mod app;
 pub mod config;
+pub mod context;
 pub mod data;
-pub mod globals;
+pub mod fetch;
+pub mod hook;
+pub mod init;
 pub mod input;
+pub mod lint;
+pub mod lua;
 pub mod navigation;
+pub mod preflight;
+pub mod print;
 pub mod template;
+pub mod testing;
 pub mod ui;
 pub mod util;
 pub mod view;
+pub mod wasm;
+pub mod watch;
 
 pub mod error;
 
@@ -0,0 +1,340 @@
+// `termstack --lint`: warnings about a config that loads but has likely mistakes
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::adapters::registry::AdapterRegistry;
+use crate::app::App;
+use crate::config::location::{Segment, key, locate};
+use crate::config::{Config, DataSource, SingleOrStream};
+use crate::context::AppContext;
+use crate::data::JsonPathExtractor;
+use crate::input::ActionKey;
+use crate::input::keymap::Keymap;
+use crate::navigation::NavigationContext;
+
+/// One warning, with the line of the YAML it is about when that can be found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+/// Checks that need no data: duplicate action keys on a page, action keys
+/// that collide with built-in bindings, globals no template uses and legacy
+/// fields. `source` is the config's text, for line numbers.
+pub fn check(config: &Config, source: &str) -> Vec<Lint> {
+    let lint = |path: &[Segment], message: String| Lint {
+        line: locate(source, path).map(|(line, _)| line),
+        message,
+    };
+    let mut lints = Vec::new();
+    let keymap = Keymap::from_config(config.keybindings.as_ref()).unwrap_or_default();
+
+    for (page_id, page) in &config.pages {
+        let mut seen: HashMap<Vec<_>, &str> = HashMap::new();
+        for (idx, action) in page.actions.iter().flatten().enumerate() {
+            let at = [
+                key("pages"),
+                key(page_id),
+                key("actions"),
+                Segment::Index(idx),
+                key("key"),
+            ];
+            let Ok(parsed) = ActionKey::parse(&action.key) else {
+                continue;
+            };
+            let keys = parsed.to_keys();
+            if let Some(first) = seen.insert(keys.clone(), &action.name) {
+                lints.push(lint(
+                    &at,
+                    format!(
+                        "Page '{}': actions '{}' and '{}' share the key '{}'",
+                        page_id,
+                        first,
+                        action.name,
+                        parsed.display()
+                    ),
+                ));
+            }
+            if let Some(builtin) = keymap.action_for(&keys) {
+                let effect = if parsed.is_direct() {
+                    "overrides the built-in"
+                } else {
+                    "only runs from the action menu: it is taken by the built-in"
+                };
+                lints.push(lint(
+                    &at,
+                    format!(
+                        "Page '{}': action '{}' key '{}' {} '{}'",
+                        page_id,
+                        action.name,
+                        parsed.display(),
+                        effect,
+                        builtin.name()
+                    ),
+                ));
+            } else if keys.len() > 1
+                && let Some(builtin) = keymap.action_for(&keys[..1])
+            {
+                lints.push(lint(
+                    &at,
+                    format!(
+                        "Page '{}': action '{}' key '{}' makes the built-in '{}' ({}) wait \
+                         for the next key on this page",
+                        page_id,
+                        action.name,
+                        parsed.display(),
+                        builtin.name(),
+                        keys[0].display()
+                    ),
+                ));
+            }
+            if !parsed.is_ctrl() && action.key.len() == 1 {
+                lints.push(lint(
+                    &at,
+                    format!(
+                        "Page '{}': action '{}' uses the legacy single-key format '{}'; \
+                         use 'ctrl+{}'",
+                        page_id, action.name, action.key, action.key
+                    ),
+                ));
+            }
+        }
+
+        let sources = match &page.data {
+            Some(DataSource::SingleOrStream(SingleOrStream::Single(single))) => {
+                vec![(vec![key("pages"), key(page_id), key("data")], single)]
+            }
+            Some(DataSource::Multi(multi)) => (multi.sources.iter().enumerate())
+                .map(|(idx, named)| {
+                    let at = vec![key("pages"), key(page_id), key("data")];
+                    (
+                        [at, vec![key("sources"), Segment::Index(idx)]].concat(),
+                        &named.source,
+                    )
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        for (at, source) in sources {
+            if source.adapter.is_none()
+                && let Some(adapter) = source.get_adapter_name()
+            {
+                lints.push(lint(
+                    &[at.as_slice(), &[key("type")]].concat(),
+                    format!(
+                        "Page '{}': 'type: {}' is the legacy form of 'adapter: {}'",
+                        page_id, adapter, adapter
+                    ),
+                ));
+            }
+        }
+    }
+
+    for name in unused_globals(config) {
+        lints.push(lint(
+            &[key("globals"), key(&name)],
+            format!("Global '{}' isn't used by any template", name),
+        ));
+    }
+
+    sort(&mut lints);
+    lints
+}
+
+/// Fetch each table page once and flag columns whose path matches none of the
+/// rows. Pages that can't be fetched without navigation context (or at all)
+/// and pages with no rows are skipped.
+pub async fn check_sample(
+    ctx: &Arc<AppContext>,
+    registry: AdapterRegistry,
+    source: &str,
+) -> Vec<Lint> {
+    let config = &ctx.config;
+    let registry = Arc::new(registry);
+    let nav_context = NavigationContext::new().with_globals(config.globals.clone());
+
+    let mut fetches = tokio::task::JoinSet::new();
+    for (page_id, page) in &config.pages {
+        if page.view.table().is_none()
+            || !matches!(
+                page.data,
+                Some(DataSource::SingleOrStream(SingleOrStream::Single(_)))
+            )
+        {
+            continue;
+        }
+        let (page_id, page) = (page_id.clone(), page.clone());
+        let (nav_context, registry) = (nav_context.clone(), registry.clone());
+        fetches.spawn(async move {
+            let rows = App::fetch_data_static(&page, &nav_context, &registry).await;
+            (page_id, rows)
+        });
+    }
+
+    let mut lints = Vec::new();
+    while let Some(Ok((page_id, rows))) = fetches.join_next().await {
+        let Ok(rows) = rows else {
+            continue;
+        };
+        let Some(table) = config.pages[&page_id]
+            .view
+            .table()
+            .filter(|_| !rows.is_empty())
+        else {
+            continue;
+        };
+        for (idx, col) in table.columns.iter().enumerate() {
+            if col.path.is_empty() {
+                continue;
+            }
+            let Ok(extractor) = JsonPathExtractor::new(&col.path) else {
+                continue;
+            };
+            let matches = rows.iter().any(|row| {
+                extractor
+                    .extract_single(row)
+                    .ok()
+                    .flatten()
+                    .is_some_and(|v| !v.is_null())
+            });
+            if !matches {
+                let at = [
+                    key("pages"),
+                    key(&page_id),
+                    key("view"),
+                    key("columns"),
+                    Segment::Index(idx),
+                ];
+                lints.push(Lint {
+                    line: locate(source, &at).map(|(line, _)| line),
+                    message: format!(
+                        "Page '{}': column '{}' path '{}' matched none of {} sampled rows",
+                        page_id,
+                        col.display,
+                        col.path,
+                        rows.len()
+                    ),
+                });
+            }
+        }
+    }
+    if let Ok(registry) = Arc::try_unwrap(registry) {
+        registry.shutdown().await;
+    }
+
+    sort(&mut lints);
+    lints
+}
+
+/// Order by line, lints without one last
+pub fn sort(lints: &mut [Lint]) {
+    lints.sort_by(|a, b| {
+        (a.line.is_none(), a.line, &a.message).cmp(&(b.line.is_none(), b.line, &b.message))
+    });
+}
+
+/// Globals whose name appears in no string of the config outside `globals`
+fn unused_globals(config: &Config) -> Vec<String> {
+    let Ok(serde_json::Value::Object(mut root)) = serde_json::to_value(config) else {
+        return Vec::new();
+    };
+    root.remove("globals");
+    let root = serde_json::Value::Object(root);
+    let mut strings = Vec::new();
+    collect_strings(&root, &mut strings);
+
+    let used: HashSet<&str> = strings
+        .iter()
+        .flat_map(|s| s.split(|c: char| !c.is_alphanumeric() && c != '_'))
+        .collect();
+    let mut unused: Vec<String> = config
+        .globals
+        .keys()
+        .filter(|name| !used.contains(name.as_str()))
+        .cloned()
+        .collect();
+    unused.sort();
+    unused
+}
+
+fn collect_strings<'a>(value: &'a serde_json::Value, out: &mut Vec<&'a str>) {
+    match value {
+        serde_json::Value::String(s) => out.push(s),
+        serde_json::Value::Array(items) => items.iter().for_each(|item| collect_strings(item, out)),
+        serde_json::Value::Object(fields) => {
+            fields
+                .values()
+                .for_each(|field| collect_strings(field, out));
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigLoader;
+
+    const CONFIG: &str = r#"version: v1
+app:
+  name: "Lint"
+globals:
+  namespace: default
+  unused_thing: 1
+start: main
+pages:
+  main:
+    title: "Pods in {{ namespace }}"
+    data:
+      type: cli
+      command: "kubectl"
+    view:
+      type: table
+      columns:
+        - path: "$.name"
+          display: "Name"
+    actions:
+      - key: "ctrl+e"
+        name: "Edit"
+        command: "true"
+      - key: "ctrl+d"
+        name: "Delete"
+        command: "true"
+      - key: "e"
+        name: "Events"
+        command: "true"
+"#;
+
+    #[test]
+    fn test_check() {
+        let config = ConfigLoader::load_from_string(CONFIG).unwrap();
+        let lints = check(&config, CONFIG);
+        assert_eq!(lints.len(), 4, "{:#?}", lints);
+        assert_eq!(lints[0].line, Some(6));
+        assert!(lints[0].message.contains("Global 'unused_thing'"));
+        assert_eq!(lints[1].line, Some(12));
+        assert!(lints[1].message.contains("'type: cli' is the legacy form"));
+        assert_eq!(lints[2].line, Some(23));
+        assert!(
+            lints[2]
+                .message
+                .contains("'Ctrl+D' overrides the built-in 'half_page_down'")
+        );
+        assert_eq!(lints[3].line, Some(26));
+        assert!(lints[3].message.contains("legacy single-key format 'e'"));
+
+        let yaml = format!(
+            "{}      - key: \"Ctrl+E\"\n        name: \"Exec\"\n",
+            CONFIG
+        );
+        let config = ConfigLoader::load_from_string(&yaml).unwrap();
+        let lints = check(&config, &yaml);
+        assert_eq!(lints[4].line, Some(29));
+        assert!(
+            lints[4]
+                .message
+                .contains("actions 'Edit' and 'Exec' share the key 'Ctrl+E'")
+        );
+    }
+}
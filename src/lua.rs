@@ -0,0 +1,207 @@
+// Lua page hooks (`lua:` on a page): transform fetched rows, veto Enter and keys
+use mlua::serde::SerializeOptions;
+use mlua::{HookTriggers, Lua, LuaOptions, LuaSerdeExt, StdLib, VmState};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Longest a hook may run before it's stopped
+pub const LUA_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Most memory a hook's Lua state may use
+const LUA_MEMORY_LIMIT: usize = 64 << 20;
+
+/// Base library functions hooks don't get: they read files or write to the
+/// terminal under the TUI
+const REMOVED_GLOBALS: &[&str] = &["dofile", "loadfile", "print"];
+
+/// What an `on_select` or `on_key` hook decided
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// Go on as without the hook
+    Allow,
+    /// Stop here, with a message to show if the hook returned one
+    Veto(Option<String>),
+}
+
+/// A fresh Lua state for one hook run: the base, table, string, math and utf8
+/// libraries only (no io, os or require), limited in memory and time, with
+/// `globals` and `vars` set
+fn state(globals: &HashMap<String, Value>, vars: &[(&str, Option<&Value>)]) -> mlua::Result<Lua> {
+    let libs = StdLib::TABLE | StdLib::STRING | StdLib::MATH | StdLib::UTF8;
+    let lua = Lua::new_with(libs, LuaOptions::default())?;
+    lua.set_memory_limit(LUA_MEMORY_LIMIT)?;
+    let started = Instant::now();
+    lua.set_hook(
+        HookTriggers::default().every_nth_instruction(10_000),
+        move |_, _| {
+            if started.elapsed() > LUA_TIMEOUT {
+                let limit = humantime::format_duration(LUA_TIMEOUT);
+                return Err(mlua::Error::runtime(format!("timed out after {}", limit)));
+            }
+            Ok(VmState::Continue)
+        },
+    )?;
+
+    let scope = lua.globals();
+    for name in REMOVED_GLOBALS {
+        scope.set(*name, mlua::Nil)?;
+    }
+    // JSON nulls become nil, so `if row.field then` reads as it would in JSON
+    let options = SerializeOptions::new()
+        .serialize_none_to_null(false)
+        .serialize_unit_to_null(false);
+    scope.set("globals", lua.to_value_with(globals, options)?)?;
+    for (name, value) in vars {
+        scope.set(*name, lua.to_value_with(value, options)?)?;
+    }
+    Ok(lua)
+}
+
+/// Check that `code` compiles, without running it
+pub fn check(code: &str, name: &str) -> Result<(), String> {
+    let lua = Lua::new_with(StdLib::NONE, LuaOptions::default()).map_err(|e| e.to_string())?;
+    lua.load(code).set_name(name).into_function().map(|_| ()).map_err(|e| e.to_string())
+}
+
+/// Run `on_data` on freshly fetched `rows`: the list it returns, else `rows`
+/// as it left them
+pub fn on_data(
+    code: &str,
+    rows: Vec<Value>,
+    globals: &HashMap<String, Value>,
+) -> Result<Vec<Value>, String> {
+    let rows = Value::Array(rows);
+    let run = || -> mlua::Result<Value> {
+        let lua = state(globals, &[("rows", Some(&rows))])?;
+        let returned: mlua::Value = lua.load(code).set_name("on_data").eval()?;
+        let result = match returned {
+            mlua::Value::Nil => lua.globals().get("rows")?,
+            returned => returned,
+        };
+        lua.from_value(result)
+    };
+    match run().map_err(|e| e.to_string())? {
+        Value::Array(rows) => Ok(rows),
+        // An empty table reads as an object
+        Value::Object(fields) if fields.is_empty() => Ok(Vec::new()),
+        other => Err(format!("on_data must leave a list of rows, not {}", other)),
+    }
+}
+
+/// Run `on_select` for Enter on `row`; a `false` it returns keeps the page from
+/// opening its `next` page
+pub fn on_select(
+    code: &str,
+    row: Option<&Value>,
+    globals: &HashMap<String, Value>,
+) -> Result<Verdict, String> {
+    verdict(code, "on_select", globals, &[("row", row)])
+}
+
+/// Run `on_key` for `key` (as shown in help, `d` or `Ctrl+D`) with the selected
+/// `row`; a `false` it returns swallows the key
+pub fn on_key(
+    code: &str,
+    key: &str,
+    row: Option<&Value>,
+    globals: &HashMap<String, Value>,
+) -> Result<Verdict, String> {
+    let key = Value::String(key.to_string());
+    verdict(code, "on_key", globals, &[("key", Some(&key)), ("row", row)])
+}
+
+/// Run a hook that may veto: `return false` (and optionally a message) stops
+/// what it was asked about, anything else lets it go on
+fn verdict(
+    code: &str,
+    name: &str,
+    globals: &HashMap<String, Value>,
+    vars: &[(&str, Option<&Value>)],
+) -> Result<Verdict, String> {
+    let run = || -> mlua::Result<Verdict> {
+        let lua = state(globals, vars)?;
+        let returned: mlua::MultiValue = lua.load(code).set_name(name).eval()?;
+        let mut returned = returned.into_iter();
+        Ok(match returned.next() {
+            Some(mlua::Value::Boolean(false)) => {
+                let message = returned.next().and_then(|m| m.to_string().ok());
+                Verdict::Veto(message.filter(|m| !m.is_empty() && m != "nil"))
+            }
+            _ => Verdict::Allow,
+        })
+    };
+    run().map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_on_data() {
+        let globals = HashMap::from([("threshold".to_string(), json!(2))]);
+        let rows = vec![
+            json!({"name": "a", "restarts": 1, "owner": null}),
+            json!({"name": "b", "restarts": 5, "owner": "ops"}),
+        ];
+
+        // Derived fields, rows changed in place
+        let code = "for _, row in ipairs(rows) do \
+                    row.flaky = row.restarts > globals.threshold \
+                    row.owned = row.owner ~= nil end";
+        let derived = on_data(code, rows.clone(), &globals).unwrap();
+        assert_eq!(derived[0]["flaky"], false);
+        assert_eq!(derived[0]["owned"], false);
+        assert_eq!(derived[1]["flaky"], true);
+        assert_eq!(derived[1]["owned"], true);
+
+        // A returned list replaces the rows
+        let code = "local kept = {} \
+                    for _, row in ipairs(rows) do \
+                      if row.restarts > globals.threshold then table.insert(kept, row) end \
+                    end \
+                    return kept";
+        assert_eq!(on_data(code, rows.clone(), &globals).unwrap(), vec![rows[1].clone()]);
+        assert!(on_data("return {}", rows.clone(), &globals).unwrap().is_empty());
+
+        let error = on_data("return 42", rows.clone(), &globals).unwrap_err();
+        assert!(error.contains("must leave a list of rows"), "{}", error);
+        assert!(on_data("rows = nil +", rows, &globals).is_err());
+    }
+
+    #[test]
+    fn test_verdicts() {
+        let globals = HashMap::from([("env".to_string(), json!("prod"))]);
+        let row = json!({"name": "db-0", "protected": true});
+
+        let code = "if row and row.protected then return false, 'protected: ' .. row.name end";
+        assert_eq!(
+            on_select(code, Some(&row), &globals),
+            Ok(Verdict::Veto(Some("protected: db-0".to_string())))
+        );
+        assert_eq!(on_select(code, None, &globals), Ok(Verdict::Allow));
+        assert_eq!(on_select("return true", Some(&row), &globals), Ok(Verdict::Allow));
+
+        let code = "if key == 'd' and globals.env == 'prod' then return false end";
+        assert_eq!(on_key(code, "d", Some(&row), &globals), Ok(Verdict::Veto(None)));
+        assert_eq!(on_key(code, "j", Some(&row), &globals), Ok(Verdict::Allow));
+    }
+
+    #[test]
+    fn test_sandbox() {
+        let globals = HashMap::new();
+        for code in ["os.execute('true')", "io.open('/etc/passwd')", "dofile('/etc/passwd')"] {
+            let error = on_select(code, None, &globals).unwrap_err();
+            assert!(error.contains("nil value"), "{}: {}", code, error);
+        }
+        let error = on_select("while true do end", None, &globals).unwrap_err();
+        assert!(error.contains("timed out"), "{}", error);
+        let error = on_select("local t = {} while true do t[#t + 1] = t end", None, &globals);
+        assert!(error.is_err());
+
+        assert!(check("if row then return false end", "on_select").is_ok());
+        assert!(check("if row then", "on_select").is_err());
+    }
+}
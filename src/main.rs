@@ -1,27 +1,171 @@
-use clap::Parser;
-use std::path::PathBuf;
+use clap::{Parser, Subcommand};
+use std::collections::HashMap;
+use std::io::{BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
 
 use termstack::{
     app::App,
-    config::{ConfigLoader, ConfigValidator},
-    globals,
+    config::{ConfigFormat, ConfigLoader, ConfigValidator},
+    context::AppContext,
+    fetch::OutputFormat,
+    init::{InitOptions, StarterAdapter},
+    util::session::SessionMarker,
 };
 
 #[derive(Parser)]
 #[command(name = "termstack", version)]
 #[command(about = "A generic TUI framework for building dashboards from YAML config", long_about = None)]
+#[command(subcommand_negates_reqs = true)]
 struct Cli {
-    /// Path to the YAML configuration file
-    #[arg(value_name = "CONFIG")]
-    config: PathBuf,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Path to the configuration file (YAML, or JSON/TOML by extension)
+    #[arg(value_name = "CONFIG", required = true)]
+    config: Option<PathBuf>,
+
+    /// Config syntax (yaml, json or toml) when the extension doesn't say
+    #[arg(long, global = true, value_name = "FORMAT")]
+    format: Option<ConfigFormat>,
+
+    /// Report likely mistakes (key collisions, unused globals, legacy fields,
+    /// columns that match no fetched row) and exit
+    #[arg(long)]
+    lint: bool,
+
+    /// Override a global, e.g. `--set namespace=prod` (repeatable)
+    #[arg(long = "set", global = true, value_name = "KEY=VALUE")]
+    sets: Vec<String>,
+
+    /// Start on this page instead of the config's `start`
+    #[arg(long, value_name = "ID")]
+    page: Option<String>,
+
+    /// Render this page once as plain text to stdout and exit, e.g. for a cron
+    /// job or CI summary (width from the terminal or COLUMNS)
+    #[arg(long, value_name = "PAGE", conflicts_with_all = ["page", "validate", "lint"])]
+    print: Option<String>,
+
+    /// Navigation context for the start page, as if it had been opened from a
+    /// row, e.g. `--context namespace=kube-system` (repeatable)
+    #[arg(long = "context", global = true, value_name = "KEY=VALUE")]
+    contexts: Vec<String>,
 
     /// Validate config and exit (don't run TUI)
     #[arg(long)]
     validate: bool,
 
+    /// With --validate, also fail on unreachable pages, JSONPaths that don't
+    /// parse and templates that fail a dry run
+    #[arg(long, requires = "validate")]
+    strict: bool,
+
     /// Verbose output
     #[arg(short, long)]
     verbose: bool,
+
+    /// Start without auto-refresh and without starting streams (press `r` to start them)
+    #[arg(long)]
+    safe_mode: bool,
+
+    /// Don't run the `app.preflight` checks before starting
+    #[arg(long)]
+    skip_preflight: bool,
+
+    /// Hide and disable every page action, e.g. for a shared wallboard
+    #[arg(long)]
+    read_only: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Write a starter config, asking for anything not given as a flag
+    Init {
+        /// Where to write the config
+        #[arg(value_name = "PATH", default_value = "termstack.yaml")]
+        path: PathBuf,
+
+        /// Dashboard name
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Where rows come from: cli or http
+        #[arg(long)]
+        adapter: Option<StarterAdapter>,
+
+        /// Shell command printing JSON (cli adapter)
+        #[arg(long, conflicts_with = "url")]
+        command: Option<String>,
+
+        /// URL returning JSON (http adapter)
+        #[arg(long)]
+        url: Option<String>,
+
+        /// JSONPath to the rows in the output
+        #[arg(long)]
+        items: Option<String>,
+
+        /// Comma-separated fields to show as table columns
+        #[arg(long, value_delimiter = ',')]
+        columns: Vec<String>,
+
+        /// Don't ask; use defaults for anything not given
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Overwrite PATH if it exists
+        #[arg(long)]
+        force: bool,
+    },
+    /// Print the resolved keymap (defaults, config overrides, page actions) and flag conflicts
+    Keys {
+        /// Path to the configuration file
+        #[arg(value_name = "CONFIG")]
+        config: PathBuf,
+    },
+    /// Start the TUI on a page, e.g. `termstack open k8s.yaml logs --context pod=api-1`
+    Open {
+        /// Path to the configuration file
+        #[arg(value_name = "CONFIG")]
+        config: PathBuf,
+
+        /// Page to start on
+        #[arg(value_name = "PAGE")]
+        page: String,
+    },
+    /// Fetch a page once without the TUI and print its rows, e.g.
+    /// `termstack fetch k8s.yaml pods --context namespace=prod -o csv`
+    Fetch {
+        /// Path to the configuration file
+        #[arg(value_name = "CONFIG")]
+        config: PathBuf,
+
+        /// Page to fetch
+        #[arg(value_name = "PAGE")]
+        page: String,
+
+        /// Output format: json (the rows as fetched) or csv (the table's columns)
+        #[arg(long, short, value_name = "FORMAT", default_value = "json")]
+        output: OutputFormat,
+    },
+    /// Refresh pages without the TUI and print alert transitions
+    Watch {
+        /// Path to the configuration file
+        #[arg(value_name = "CONFIG")]
+        config: PathBuf,
+
+        /// Comma-separated pages to watch (default: every page with alerts)
+        #[arg(long, value_delimiter = ',')]
+        pages: Vec<String>,
+
+        /// Only print alert transitions, not a line per refresh
+        #[arg(long)]
+        alerts_only: bool,
+
+        /// Fetch each page once and exit (status 2 if any alert is firing)
+        #[arg(long)]
+        once: bool,
+    },
 }
 
 #[tokio::main]
@@ -30,9 +174,78 @@ async fn main() -> color_eyre::Result<()> {
 
     let cli = Cli::parse();
 
+    match &cli.command {
+        Some(Command::Init {
+            path,
+            name,
+            adapter,
+            command,
+            url,
+            items,
+            columns,
+            yes,
+            force,
+        }) => {
+            let adapter = adapter.or(url.as_ref().map(|_| StarterAdapter::Http));
+            let answers = InitAnswers {
+                name: name.clone(),
+                adapter,
+                source: command.clone().or_else(|| url.clone()),
+                items: items.clone(),
+                columns: columns.clone(),
+            };
+            init(path, answers, *yes, *force);
+            return Ok(());
+        }
+        Some(Command::Keys { config }) => {
+            print_keys(config, cli.format, &cli.sets);
+            return Ok(());
+        }
+        Some(Command::Watch {
+            config,
+            pages,
+            alerts_only,
+            once,
+        }) => {
+            let options = termstack::watch::WatchOptions {
+                alerts_only: *alerts_only,
+                once: *once,
+            };
+            return watch(config, cli.format, &cli.sets, pages, &options).await;
+        }
+        Some(Command::Fetch {
+            config,
+            page,
+            output,
+        }) => {
+            let context = parse_contexts(&cli.contexts);
+            return fetch(config, cli.format, &cli.sets, page, context, *output).await;
+        }
+        Some(Command::Open { .. }) | None => {}
+    }
+    let (config_path, start_page) = match &cli.command {
+        Some(Command::Open { config, page }) => (config.clone(), Some(page.clone())),
+        _ => (
+            cli.config.clone().expect("CONFIG is required without a subcommand"),
+            cli.page.clone(),
+        ),
+    };
+
+    let start_context = parse_contexts(&cli.contexts);
+
+    if let Some(page) = &cli.print {
+        return print(&config_path, cli.format, &cli.sets, page, start_context).await;
+    }
+
     // Load config
-    println!("Loading config from: {:?}", cli.config);
-    let config = match ConfigLoader::load_from_file(&cli.config) {
+    println!("Loading config from: {:?}", config_path);
+    let config = load_config(&config_path, cli.format, &cli.sets).map(|mut config| {
+        if let Some(page) = &start_page {
+            config.start = page.clone();
+        }
+        config
+    });
+    let config = match config {
         Ok(cfg) => {
             println!("✓ Config loaded successfully");
             cfg
@@ -44,10 +257,17 @@ async fn main() -> color_eyre::Result<()> {
         }
     };
 
+    if cli.lint {
+        std::process::exit(lint(&config_path, config).await);
+    }
+
     // Validate config
     println!("Validating config...");
     if let Err(e) = ConfigValidator::validate(&config) {
         eprintln!("✗ Config validation failed: {}", e);
+        if let Some(location) = location(&config_path, &e) {
+            eprintln!("{}", location);
+        }
         eprintln!("\nFull error chain:");
         for cause in e.chain() {
             eprintln!("  - {}", cause);
@@ -56,17 +276,28 @@ async fn main() -> color_eyre::Result<()> {
     }
     println!("✓ Config is valid");
 
+    if cli.strict {
+        let warnings = ConfigValidator::strict_warnings(&config);
+        for warning in &warnings {
+            eprintln!("  ✗ {}", warning);
+        }
+        if !warnings.is_empty() {
+            eprintln!("\n✗ {} strict check(s) failed", warnings.len());
+            std::process::exit(1);
+        }
+        println!("✓ Strict checks passed");
+    }
+
     // If validate-only mode, exit here
     if cli.validate {
         println!("\n✓ Configuration is valid!");
         return Ok(());
     }
 
-    // Initialize globals
-    globals::init_config(config.clone())
-        .map_err(|e| color_eyre::eyre::eyre!("Failed to initialize config: {}", e))?;
-    globals::init_template_engine()
-        .map_err(|e| color_eyre::eyre::eyre!("Failed to initialize template engine: {}", e))?;
+    // Shared by the app, adapters, actions and preflight checks
+    let ctx = AppContext::new(config)
+        .map_err(|e| color_eyre::eyre::eyre!("Failed to initialize: {}", e))?;
+    let config = &ctx.config;
 
     // Show config summary
     if cli.verbose {
@@ -81,16 +312,442 @@ async fn main() -> color_eyre::Result<()> {
     }
 
     // Initialize adapter registry with default adapters
-    let adapter_registry = termstack::adapters::registry::AdapterRegistry::with_defaults();
+    let adapter_registry =
+        termstack::adapters::registry::AdapterRegistry::with_defaults(ctx.clone());
+
+    // Run startup checks before taking over the terminal
+    if !cli.skip_preflight && !config.app.preflight.is_empty() && !run_preflight(&ctx).await {
+        std::process::exit(1);
+    }
+
+    // Detect a crashed previous session and offer safe mode
+    let marker = SessionMarker::new();
+    let mut safe_mode = cli.safe_mode;
+    if let Some(previous) = marker.as_ref().and_then(|m| m.take_leftover())
+        && !safe_mode
+    {
+        safe_mode = prompt_safe_mode(&previous);
+    }
+
+    let app = App::new(ctx.clone(), adapter_registry)
+        .map_err(|e| color_eyre::eyre::eyre!("{}", e))?
+        .with_safe_mode(safe_mode)
+        .with_read_only(cli.read_only)
+        .with_context(start_context);
+
+    // Only a session that got as far as the TUI leaves a marker
+    if let Some(marker) = &marker
+        && let Err(e) = marker.create(&config_path)
+    {
+        eprintln!("Warning: could not write session marker: {}", e);
+    }
 
     // Run TUI
     println!("Starting TUI...\n");
     let terminal = ratatui::init();
-    let app = App::new(config, adapter_registry).map_err(|e| color_eyre::eyre::eyre!("{}", e))?;
     let result = app
         .run(terminal)
         .await
         .map_err(|e| color_eyre::eyre::eyre!("{}", e));
     ratatui::restore();
+
+    // Clean exit: the next launch shouldn't offer safe mode
+    if let Some(marker) = &marker {
+        marker.remove();
+    }
     result
 }
+
+/// Run `app.preflight` checks and print them as a checklist. Returns whether to
+/// start the TUI: true if all checks passed or the user chose to continue anyway.
+async fn run_preflight(ctx: &std::sync::Arc<AppContext>) -> bool {
+    println!("Running preflight checks...");
+    let results = termstack::preflight::run_checks(ctx, &ctx.config.app.preflight).await;
+
+    for result in &results {
+        if result.passed {
+            println!("  ✓ {}", result.name);
+        } else {
+            match &result.detail {
+                Some(detail) => println!("  ✗ {} — {}", result.name, detail),
+                None => println!("  ✗ {}", result.name),
+            }
+            if let Some(hint) = &result.hint {
+                println!("    → {}", hint);
+            }
+        }
+    }
+
+    let failed = results.iter().filter(|r| !r.passed).count();
+    if failed == 0 {
+        println!("✓ All preflight checks passed");
+        return true;
+    }
+
+    println!("\n✗ {} of {} preflight checks failed", failed, results.len());
+    if !std::io::stdin().is_terminal() {
+        return false;
+    }
+
+    print!("Continue anyway? [y/N] ");
+    let _ = std::io::stdout().flush();
+    let mut answer = String::new();
+    if std::io::stdin().lock().read_line(&mut answer).is_err() {
+        return false;
+    }
+    matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+/// `--lint`: print lint warnings as `file:line: message`, fetching a sample of
+/// each table page if the config is valid. Returns the exit status: 1 if
+/// anything was reported.
+async fn lint(config_path: &Path, config: termstack::config::Config) -> i32 {
+    let source = std::fs::read_to_string(config_path).unwrap_or_default();
+    let mut lints = termstack::lint::check(&config, &source);
+
+    let valid = match ConfigValidator::validate(&config) {
+        Ok(()) => true,
+        Err(e) => {
+            eprintln!("✗ Config validation failed: {:#}", e);
+            if let Some(location) = location(config_path, &e) {
+                eprintln!("{}", location);
+            }
+            false
+        }
+    };
+    if valid && let Ok(ctx) = AppContext::new(config) {
+        println!("Fetching a sample of each table page...");
+        let registry = termstack::adapters::registry::AdapterRegistry::with_defaults(ctx.clone());
+        lints.extend(termstack::lint::check_sample(&ctx, registry, &source).await);
+        termstack::lint::sort(&mut lints);
+    }
+
+    for lint in &lints {
+        match lint.line {
+            Some(line) => println!("{}:{}: {}", config_path.display(), line, lint.message),
+            None => println!("{}: {}", config_path.display(), lint.message),
+        }
+    }
+    if lints.is_empty() && valid {
+        println!("✓ No lint warnings");
+        return 0;
+    }
+    if !lints.is_empty() {
+        println!("\n! {} warning(s)", lints.len());
+    }
+    1
+}
+
+/// Ask whether to start in safe mode after an unclean exit. Defaults to yes;
+/// never prompts (and returns false) when stdin isn't a terminal.
+fn prompt_safe_mode(previous_config: &str) -> bool {
+    if !std::io::stdin().is_terminal() {
+        return false;
+    }
+
+    println!(
+        "\n⚠ The previous session ({}) did not exit cleanly.",
+        previous_config.trim()
+    );
+    print!("Start in safe mode (no auto-refresh, streams not started)? [Y/n] ");
+    let _ = std::io::stdout().flush();
+
+    let mut answer = String::new();
+    if std::io::stdin().lock().read_line(&mut answer).is_err() {
+        return false;
+    }
+    !matches!(answer.trim().to_lowercase().as_str(), "n" | "no")
+}
+
+/// `termstack init` answers given as flags
+struct InitAnswers {
+    name: Option<String>,
+    adapter: Option<StarterAdapter>,
+    source: Option<String>,
+    items: Option<String>,
+    columns: Vec<String>,
+}
+
+/// `termstack init`: fill in what the flags left out (by asking, on a terminal
+/// without `--yes`), then write the starter config to `path`
+fn init(path: &Path, answers: InitAnswers, yes: bool, force: bool) {
+    if path.exists() && !force {
+        eprintln!("✗ {} already exists (use --force to overwrite)", path.display());
+        std::process::exit(1);
+    }
+    let interactive = !yes && std::io::stdin().is_terminal();
+    let ask = |question: &str, default: &str| {
+        if interactive {
+            prompt(question, default)
+        } else {
+            default.to_string()
+        }
+    };
+
+    let sample = InitOptions::sample();
+    let name = answers
+        .name
+        .unwrap_or_else(|| ask("Dashboard name", &sample.name));
+    let adapter = match answers.adapter {
+        Some(adapter) => adapter,
+        None => loop {
+            match ask("Adapter (cli or http)", "cli").parse() {
+                Ok(adapter) => break adapter,
+                Err(e) => println!("  {}", e),
+            }
+        },
+    };
+    let default_source = termstack::init::default_source(adapter);
+    let source = answers.source.unwrap_or_else(|| match adapter {
+        StarterAdapter::Cli => ask("Command printing JSON", default_source),
+        StarterAdapter::Http => ask("URL returning JSON", default_source),
+    });
+    // The sample rows' fields only fit the sample source
+    let (default_items, default_columns) = if source == default_source {
+        match adapter {
+            StarterAdapter::Cli => (sample.items.clone(), sample.columns.join(",")),
+            StarterAdapter::Http => ("$.data[*]".to_string(), "id,attributes.name".to_string()),
+        }
+    } else {
+        ("$[*]".to_string(), "name".to_string())
+    };
+    let items = answers
+        .items
+        .unwrap_or_else(|| ask("JSONPath to the rows", &default_items));
+    let columns = match answers.columns {
+        columns if !columns.is_empty() => columns,
+        _ => ask("Columns (comma-separated fields)", &default_columns)
+            .split(',')
+            .map(String::from)
+            .collect(),
+    };
+    let columns = columns
+        .iter()
+        .map(|field| field.trim().trim_start_matches("$.").to_string())
+        .filter(|field| !field.is_empty())
+        .collect();
+
+    let options = InitOptions {
+        name,
+        adapter,
+        source,
+        items,
+        columns,
+    };
+    if let Err(e) = std::fs::write(path, termstack::init::render(&options)) {
+        eprintln!("✗ Cannot write {}: {}", path.display(), e);
+        std::process::exit(1);
+    }
+    println!("✓ Wrote {}", path.display());
+    println!("\nRun it:\n  termstack {}", path.display());
+}
+
+/// Ask on stdout and read a line; empty (or unreadable) input gives `default`
+fn prompt(question: &str, default: &str) -> String {
+    print!("{} [{}]: ", question, default);
+    let _ = std::io::stdout().flush();
+    let mut answer = String::new();
+    match std::io::stdin().lock().read_line(&mut answer) {
+        Ok(_) if !answer.trim().is_empty() => answer.trim().to_string(),
+        _ => default.to_string(),
+    }
+}
+
+/// Load a config in `format`, or the one its extension names, with the
+/// `--set` overrides applied to its globals
+fn load_config(
+    path: &Path,
+    format: Option<ConfigFormat>,
+    sets: &[String],
+) -> anyhow::Result<termstack::config::Config> {
+    let format = format.unwrap_or_else(|| ConfigFormat::from_path(path));
+    let mut config = ConfigLoader::load_from_file_as(path, format)?;
+    for assignment in sets {
+        config.set_global(assignment).map_err(|e| anyhow::anyhow!("--set: {}", e))?;
+    }
+    Ok(config)
+}
+
+/// Where in the config file a validation error is, `file:line:column` and the
+/// line, if the validator recorded a path for it and the path is in the text
+fn location(config_path: &Path, error: &anyhow::Error) -> Option<String> {
+    use termstack::config::location::{error_path, locate, snippet};
+
+    let source = std::fs::read_to_string(config_path).ok()?;
+    let (line, column) = locate(&source, error_path(error)?)?;
+    Some(format!(
+        "  --> {}:{}:{}\n{}",
+        config_path.display(),
+        line,
+        column,
+        snippet(&source, line, column)
+    ))
+}
+
+/// [`load_config`] and validate it, for the headless commands; the error is
+/// ready to print, with its [`location`] in the file when there is one
+fn load_valid_config(
+    config_path: &Path,
+    format: Option<ConfigFormat>,
+    sets: &[String],
+) -> Result<termstack::config::Config, String> {
+    load_config(config_path, format, sets)
+        .and_then(|config| ConfigValidator::validate(&config).map(|()| config))
+        .map_err(|e| match location(config_path, &e) {
+            Some(location) => format!("{:#}\n{}", e, location),
+            None => format!("{:#}", e),
+        })
+}
+
+/// `termstack keys`: print the resolved keymap as a table. Exits with an error
+/// if the config can't be loaded or its keybindings don't parse.
+fn print_keys(config_path: &Path, format: Option<ConfigFormat>, sets: &[String]) {
+    let rows = load_config(config_path, format, sets)
+        .map_err(|e| format!("{:#}", e))
+        .and_then(|config| termstack::input::report::keymap_rows(&config));
+    let rows = match rows {
+        Ok(rows) => rows,
+        Err(e) => {
+            eprintln!("✗ {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    print!("{}", termstack::input::report::format_rows(&rows));
+    let flagged = rows.iter().filter(|r| r.is_conflict()).count();
+    if flagged > 0 {
+        println!("\n! {} binding(s) flagged", flagged);
+    }
+}
+
+/// `--context` assignments by key; exits on a malformed one
+fn parse_contexts(assignments: &[String]) -> HashMap<String, serde_json::Value> {
+    let mut context = HashMap::new();
+    for assignment in assignments {
+        if let Err(e) = termstack::config::assign_value(&mut context, assignment) {
+            eprintln!("✗ --context: {}", e);
+            std::process::exit(1);
+        }
+    }
+    context
+}
+
+/// `--print`: render a page once on stdout, errors on stderr
+async fn print(
+    config_path: &Path,
+    format: Option<ConfigFormat>,
+    sets: &[String],
+    page: &str,
+    context: HashMap<String, serde_json::Value>,
+) -> color_eyre::Result<()> {
+    let config = load_valid_config(config_path, format, sets).and_then(|mut config| {
+        termstack::print::check_page(&config, page)?;
+        config.start = page.to_string();
+        Ok(config)
+    });
+    let config = match config {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("✗ {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let ctx = AppContext::new(config)
+        .map_err(|e| color_eyre::eyre::eyre!("Failed to initialize: {}", e))?;
+    let adapter_registry =
+        termstack::adapters::registry::AdapterRegistry::with_defaults(ctx.clone());
+    let app = App::new(ctx, adapter_registry)
+        .map_err(|e| color_eyre::eyre::eyre!("{}", e))?
+        .with_context(context);
+
+    match termstack::print::run(app, termstack::print::size()).await {
+        Ok(text) => {
+            print!("{}", text);
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("✗ {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// `termstack fetch`: print a page's rows on stdout, errors on stderr
+async fn fetch(
+    config_path: &Path,
+    format: Option<ConfigFormat>,
+    sets: &[String],
+    page: &str,
+    context: HashMap<String, serde_json::Value>,
+    output: OutputFormat,
+) -> color_eyre::Result<()> {
+    let config = load_valid_config(config_path, format, sets).and_then(|mut config| {
+        if !config.pages.contains_key(page) {
+            return Err(format!("Unknown page '{}'", page));
+        }
+        config.start = page.to_string();
+        Ok(config)
+    });
+    let config = match config {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("✗ {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let ctx = AppContext::new(config)
+        .map_err(|e| color_eyre::eyre::eyre!("Failed to initialize: {}", e))?;
+    let adapter_registry =
+        termstack::adapters::registry::AdapterRegistry::with_defaults(ctx.clone());
+    let app = App::new(ctx, adapter_registry)
+        .map_err(|e| color_eyre::eyre::eyre!("{}", e))?
+        .with_context(context);
+
+    match termstack::fetch::run(app, output).await {
+        Ok(text) => {
+            print!("{}", text);
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("✗ {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// `termstack watch`: load and validate the config, then refresh the pages
+/// headlessly until interrupted (or once, with `--once`)
+async fn watch(
+    config_path: &Path,
+    format: Option<ConfigFormat>,
+    sets: &[String],
+    pages: &[String],
+    options: &termstack::watch::WatchOptions,
+) -> color_eyre::Result<()> {
+    let config = load_valid_config(config_path, format, sets).and_then(|config| {
+        termstack::watch::resolve_pages(&config, pages).map(|pages| (config, pages))
+    });
+    let (config, pages) = match config {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            eprintln!("✗ {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let ctx = AppContext::new(config)
+        .map_err(|e| color_eyre::eyre::eyre!("Failed to initialize: {}", e))?;
+
+    if !options.alerts_only && !options.once {
+        println!("Watching {} (Ctrl+C to stop)", pages.join(", "));
+    }
+    let adapter_registry =
+        termstack::adapters::registry::AdapterRegistry::with_defaults(ctx.clone());
+    let firing = termstack::watch::run(&ctx, pages, options, adapter_registry).await;
+    if options.once && firing > 0 {
+        std::process::exit(2);
+    }
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+
+/// Default number of visits kept
+const DEFAULT_MAX_VISITS: usize = 100;
+
+/// A page as it was visited: enough to open it the same way again
+#[derive(Debug, Clone)]
+pub struct Visit {
+    pub page_id: String,
+    /// Its breadcrumb label (title and the context it was opened with)
+    pub label: String,
+    /// Context it was opened with, by name
+    pub context: HashMap<String, Value>,
+    /// The context values shown next to its title
+    pub opened_with: Vec<String>,
+    pub time: chrono::DateTime<chrono::Local>,
+}
+
+/// A page bookmarked with the context it was opened with, so a drill-down
+/// (the logs of one pod) can be opened again directly
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub page_id: String,
+    pub label: String,
+    #[serde(default)]
+    pub context: HashMap<String, Value>,
+    #[serde(default)]
+    pub opened_with: Vec<String>,
+}
+
+impl Bookmark {
+    /// Whether it's of `page_id` opened with `context`
+    pub fn is(&self, page_id: &str, context: &HashMap<String, Value>) -> bool {
+        self.page_id == page_id && &self.context == context
+    }
+}
+
+/// Pages visited this session, most recent first, each page and context once.
+/// Unlike the navigation stack it keeps pages gone back from.
+#[derive(Debug, Clone)]
+pub struct VisitHistory {
+    visits: VecDeque<Visit>,
+    max_size: usize,
+}
+
+impl VisitHistory {
+    pub fn new(max_size: usize) -> Self {
+        Self {
+            visits: VecDeque::new(),
+            max_size,
+        }
+    }
+
+    /// Record a visit, moving an earlier visit to the same page with the same
+    /// context to the front
+    pub fn record(&mut self, visit: Visit) {
+        self.visits
+            .retain(|v| v.page_id != visit.page_id || v.context != visit.context);
+        self.visits.push_front(visit);
+        self.visits.truncate(self.max_size);
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Visit> {
+        self.visits.get(index)
+    }
+
+    pub fn visits(&self) -> impl Iterator<Item = &Visit> {
+        self.visits.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.visits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.visits.is_empty()
+    }
+}
+
+impl Default for VisitHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_VISITS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn visit(page_id: &str, namespace: &str) -> Visit {
+        Visit {
+            page_id: page_id.to_string(),
+            label: page_id.to_string(),
+            context: HashMap::from([("namespace".to_string(), json!(namespace))]),
+            opened_with: vec![namespace.to_string()],
+            time: chrono::Local::now(),
+        }
+    }
+
+    #[test]
+    fn test_record() {
+        let mut history = VisitHistory::new(3);
+        history.record(visit("pods", "default"));
+        history.record(visit("pods", "kube-system"));
+        history.record(visit("logs", "default"));
+        history.record(visit("pods", "default"));
+
+        let visited: Vec<(&str, &str)> = history
+            .visits()
+            .map(|v| (v.page_id.as_str(), v.opened_with[0].as_str()))
+            .collect();
+        assert_eq!(
+            visited,
+            [("pods", "default"), ("logs", "default"), ("pods", "kube-system")]
+        );
+
+        history.record(visit("events", "default"));
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.get(2).map(|v| v.page_id.as_str()), Some("logs"));
+    }
+}
@@ -1,7 +1,9 @@
 pub mod context;
+pub mod history;
 pub mod router;
 pub mod stack;
 
 pub use context::{ContextStats, NavigationContext};
+pub use history::{Bookmark, Visit, VisitHistory};
 pub use router::Router;
-pub use stack::{NavigationFrame, NavigationStack};
+pub use stack::{NavigationFrame, NavigationStack, RowLink, ViewSnapshot};
@@ -1,26 +1,111 @@
 use std::sync::Arc;
 
-use crate::config::Config;
-use crate::error::Result;
+use crate::config::Page;
+use crate::context::AppContext;
+use crate::error::{Result, TermStackError};
+use crate::navigation::NavigationContext;
 
 /// Router for resolving page navigation
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Router {
-    config: Arc<Config>,
+    ctx: Arc<AppContext>,
 }
 
 impl Router {
-    pub fn new(config: Arc<Config>) -> Self {
-        Self { config }
+    pub fn new(ctx: Arc<AppContext>) -> Self {
+        Self { ctx }
     }
 
-    pub fn get_page(&self, page_id: &str) -> Result<&crate::config::Page> {
-        self.config.pages.get(page_id).ok_or_else(|| {
-            crate::error::TermStackError::Navigation(format!("Page not found: {}", page_id))
-        })
+    pub fn get_page(&self, page_id: &str) -> Result<&Page> {
+        self.ctx
+            .config
+            .pages
+            .get(page_id)
+            .ok_or_else(|| TermStackError::Navigation(format!("Page not found: {}", page_id)))
     }
 
     pub fn start_page(&self) -> &str {
-        &self.config.start
+        &self.ctx.config.start
+    }
+
+    /// The page `page_id`, once each of its `params` is set in `context` (by
+    /// the way to it or as a global) to something other than null
+    pub fn resolve(&self, page_id: &str, context: &NavigationContext) -> Result<&Page> {
+        let page = self.get_page(page_id)?;
+        let missing = missing_params(page, context);
+        if missing.is_empty() {
+            return Ok(page);
+        }
+        let names: Vec<String> = missing.iter().map(|name| format!("'{}'", name)).collect();
+        Err(TermStackError::Navigation(format!(
+            "Page '{}' needs {} {}, not set on the way to it",
+            page_id,
+            if missing.len() == 1 {
+                "parameter"
+            } else {
+                "parameters"
+            },
+            names.join(", ")
+        )))
+    }
+}
+
+/// The `params` of `page` not set in `context`
+pub fn missing_params<'a>(page: &'a Page, context: &NavigationContext) -> Vec<&'a str> {
+    page.params
+        .iter()
+        .filter(|name| {
+            let value = context
+                .get_page_context(name)
+                .or_else(|| context.get_global(name));
+            value.is_none_or(|value| value.is_null())
+        })
+        .map(String::as_str)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigLoader;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_resolve() {
+        let yaml = r#"
+version: v1
+app: {name: test}
+start: pods
+globals: {cluster: prod}
+pages:
+  pods:
+    title: Pods
+    data: {adapter: cli, command: echo}
+    view: {type: table, columns: [{path: "$.name", display: Name}]}
+  logs:
+    title: Logs
+    params: [cluster, namespace, pod]
+    data: {adapter: cli, command: echo}
+    view: {type: table, columns: [{path: "$.line", display: Line}]}
+"#;
+        let config = ConfigLoader::load_from_string(yaml).unwrap();
+        let router = Router::new(AppContext::new(config).unwrap());
+        let mut context = NavigationContext::new()
+            .with_globals(HashMap::from([("cluster".to_string(), json!("prod"))]));
+
+        assert!(router.resolve("pods", &context).is_ok());
+        context.set_page_context("namespace".to_string(), json!(null));
+        let err = router.resolve("logs", &context).unwrap_err().to_string();
+        assert!(
+            err.contains("needs parameters 'namespace', 'pod'"),
+            "{}",
+            err
+        );
+
+        context.set_page_context("namespace".to_string(), json!("default"));
+        context.set_page_context("pod".to_string(), json!("web-1"));
+        assert!(router.resolve("logs", &context).is_ok());
+        assert!(router.resolve("events", &context).is_err());
     }
 }
@@ -1,13 +1,25 @@
 use serde_json::Value;
 use std::collections::{HashMap, VecDeque};
 
+use crate::config::TableSort;
+use crate::view::form::FormState;
+use crate::view::logline::Timestamps;
+
 /// A single frame in the navigation stack
 #[derive(Debug, Clone)]
 pub struct NavigationFrame {
     pub page_id: String,
+    /// Context the page was opened with, by name, restored on returning to it
     pub context: HashMap<String, Value>,
     pub scroll_offset: usize,
     pub selected_index: usize,
+    pub view: ViewSnapshot,
+    /// The page's title as it was shown, for the breadcrumb
+    pub title: String,
+    /// Context values the page was opened with, shown next to its title
+    pub opened_with: Vec<String>,
+    /// How the next page was opened from this one's selected row
+    pub link: Option<RowLink>,
 }
 
 impl NavigationFrame {
@@ -17,10 +29,48 @@ impl NavigationFrame {
             context: HashMap::new(),
             scroll_offset: 0,
             selected_index: 0,
+            view: ViewSnapshot::default(),
+            title: String::new(),
+            opened_with: Vec::new(),
+            link: None,
         }
     }
 }
 
+/// How a page was opened from a table row, to open it again for the row before
+/// or after (`[` / `]`)
+#[derive(Debug, Clone)]
+pub struct RowLink {
+    pub page: String,
+    /// Context names to JSONPaths into the row (`next`), or to templates
+    /// rendered with it (navigate actions)
+    pub context: HashMap<String, String>,
+    pub templates: bool,
+    /// Rows the table showed, so `]` knows the last
+    pub rows: usize,
+}
+
+/// How a page was being viewed when the user navigated away from it, so going
+/// back shows it the same way
+#[derive(Debug, Clone, Default)]
+pub struct ViewSnapshot {
+    pub search_query: String,
+    /// Whether the query was applied as a filter
+    pub search_applied: bool,
+    pub search_case_sensitive: bool,
+    /// Sort picked at runtime; `Some(None)` when the user turned sorting off
+    pub sort_override: Option<Option<TableSort>>,
+    pub focused_column: usize,
+    pub column_offset: usize,
+    pub logs_follow: bool,
+    pub logs_wrap: bool,
+    pub logs_horizontal_scroll: usize,
+    /// Timestamps picked with `t` (None: the page's default)
+    pub logs_timestamps: Option<Timestamps>,
+    /// A form page's values, kept while the user looks at what they submitted
+    pub form: Option<FormState>,
+}
+
 /// Navigation stack for managing page history (optimized with VecDeque for O(1) pop_front)
 #[derive(Debug, Clone)]
 pub struct NavigationStack {
@@ -0,0 +1,178 @@
+// Preflight checks run before the TUI starts (`app.preflight`)
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::process::Command;
+
+use crate::config::PreflightCheck;
+use crate::context::AppContext;
+use crate::template::engine::TemplateContext;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Outcome of a single preflight check
+#[derive(Debug, Clone)]
+pub struct PreflightResult {
+    pub name: String,
+    pub passed: bool,
+    /// Failure reason (first line of stderr, HTTP status, timeout...)
+    pub detail: Option<String>,
+    pub hint: Option<String>,
+}
+
+/// Run all checks concurrently, returning results in config order. Templates
+/// see the config's `globals`.
+pub async fn run_checks(ctx: &Arc<AppContext>, checks: &[PreflightCheck]) -> Vec<PreflightResult> {
+    let template_ctx = TemplateContext::new().with_globals(ctx.config.globals.clone());
+
+    let handles: Vec<_> = checks
+        .iter()
+        .map(|check| {
+            let check = check.clone();
+            let ctx = ctx.clone();
+            let template_ctx = template_ctx.clone();
+            tokio::spawn(async move { run_check(&ctx, &check, &template_ctx).await })
+        })
+        .collect();
+
+    let mut results = Vec::with_capacity(handles.len());
+    for (handle, check) in handles.into_iter().zip(checks) {
+        let outcome = handle
+            .await
+            .unwrap_or_else(|e| Err(format!("check panicked: {}", e)));
+        results.push(PreflightResult {
+            name: check.name.clone(),
+            passed: outcome.is_ok(),
+            detail: outcome.err(),
+            hint: check.hint.clone(),
+        });
+    }
+    results
+}
+
+async fn run_check(
+    ctx: &AppContext,
+    check: &PreflightCheck,
+    template_ctx: &TemplateContext,
+) -> Result<(), String> {
+    let timeout = check
+        .timeout
+        .as_deref()
+        .map(humantime::parse_duration)
+        .transpose()
+        .map_err(|e| format!("invalid timeout: {}", e))?
+        .unwrap_or(DEFAULT_TIMEOUT);
+
+    let render = |template: &str| {
+        ctx.template_engine
+            .render_string(template, template_ctx)
+            .map_err(|e| format!("template error: {}", e))
+    };
+
+    if let Some(command) = &check.command {
+        run_command(&render(command)?, timeout).await
+    } else if let Some(url) = &check.http {
+        run_http(&ctx.http_client, &render(url)?, timeout).await
+    } else {
+        Err("check has neither 'command' nor 'http'".to_string())
+    }
+}
+
+async fn run_command(command: &str, timeout: Duration) -> Result<(), String> {
+    let mut cmd = if cfg!(target_os = "windows") {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C");
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c");
+        cmd
+    };
+    cmd.arg(command).kill_on_drop(true);
+
+    let output = tokio::time::timeout(timeout, cmd.output())
+        .await
+        .map_err(|_| format!("timed out after {}", humantime::format_duration(timeout)))?
+        .map_err(|e| format!("failed to run: {}", e))?;
+
+    if output.status.success() {
+        return Ok(());
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let first_line = stderr
+        .lines()
+        .chain(stdout.lines())
+        .map(str::trim)
+        .find(|l| !l.is_empty());
+
+    Err(match first_line {
+        Some(line) => format!("{} ({})", line, output.status),
+        None => output.status.to_string(),
+    })
+}
+
+async fn run_http(client: &reqwest::Client, url: &str, timeout: Duration) -> Result<(), String> {
+    let response = client.get(url).timeout(timeout).send().await.map_err(|e| {
+        if e.is_timeout() {
+            format!("timed out after {}", humantime::format_duration(timeout))
+        } else {
+            e.to_string()
+        }
+    })?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(format!("HTTP {}", response.status()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(name: &str, command: &str) -> PreflightCheck {
+        PreflightCheck {
+            name: name.to_string(),
+            command: Some(command.to_string()),
+            http: None,
+            hint: Some("fix it".to_string()),
+            timeout: None,
+        }
+    }
+
+    fn context() -> Arc<AppContext> {
+        let yaml = "version: v1\napp: {name: t}\nstart: main\n\
+                    pages: {main: {title: Main, view: {type: text}}}";
+        AppContext::new(crate::config::ConfigLoader::load_from_string(yaml).unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_command_checks() {
+        let checks = vec![
+            check("ok", "exit 0"),
+            check("broken", "echo 'context not set' >&2; exit 3"),
+        ];
+
+        let results = run_checks(&context(), &checks).await;
+        assert_eq!(results.len(), 2);
+
+        assert!(results[0].passed);
+        assert_eq!(results[0].name, "ok");
+
+        assert!(!results[1].passed);
+        assert!(results[1].detail.as_deref().unwrap().contains("context not set"));
+        assert_eq!(results[1].hint.as_deref(), Some("fix it"));
+    }
+
+    #[tokio::test]
+    async fn test_command_timeout() {
+        let mut slow = check("slow", "sleep 5");
+        slow.timeout = Some("100ms".to_string());
+
+        let results = run_checks(&context(), &[slow]).await;
+        assert!(!results[0].passed);
+        assert!(results[0].detail.as_deref().unwrap().contains("timed out"));
+    }
+}
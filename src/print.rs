@@ -0,0 +1,62 @@
+// `--print`: a page rendered once as plain text, for cron jobs and CI summaries
+use ratatui::{Terminal, backend::TestBackend};
+
+use crate::app::App;
+use crate::config::{Config, DataSource, SingleOrStream};
+
+/// Size rendered at when there's no terminal and `COLUMNS`/`LINES` aren't set
+const DEFAULT_SIZE: (u16, u16) = (120, 40);
+
+/// Whether `page_id` can be printed: stream pages have no data until they run
+pub fn check_page(config: &Config, page_id: &str) -> Result<(), String> {
+    let page = config
+        .pages
+        .get(page_id)
+        .ok_or_else(|| format!("Unknown page '{}'", page_id))?;
+    if matches!(
+        page.data,
+        Some(DataSource::SingleOrStream(SingleOrStream::Stream(_)))
+    ) {
+        return Err(format!("Page '{}' is a stream page", page_id));
+    }
+    Ok(())
+}
+
+/// Width and height to render at: the terminal's if stdout is one, else
+/// `COLUMNS` and `LINES`, else 120x40
+pub fn size() -> (u16, u16) {
+    use std::io::IsTerminal;
+
+    if std::io::stdout().is_terminal()
+        && let Ok(size) = crossterm::terminal::size()
+    {
+        return size;
+    }
+    let var = |name: &str| {
+        std::env::var(name)
+            .ok()
+            .and_then(|value| value.parse::<u16>().ok())
+            .filter(|&n| n > 0)
+    };
+    (
+        var("COLUMNS").unwrap_or(DEFAULT_SIZE.0),
+        var("LINES").unwrap_or(DEFAULT_SIZE.1),
+    )
+}
+
+/// Load the app's current page and render it once as text, `width` wide. A
+/// table page is as tall as its rows need, anything else `height`. Fails if
+/// the page couldn't be loaded.
+pub async fn run(mut app: App, (width, height): (u16, u16)) -> Result<String, String> {
+    app.load_headless().await;
+    app.shutdown().await;
+    if let Some(error) = app.page_error() {
+        return Err(error);
+    }
+
+    let height = app.full_height().unwrap_or(height);
+    let mut terminal =
+        Terminal::new(TestBackend::new(width, height)).map_err(|e| e.to_string())?;
+    app.draw_print(&mut terminal).map_err(|e| e.to_string())?;
+    Ok(crate::ui::terminal::text(terminal.backend().buffer()))
+}
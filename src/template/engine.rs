@@ -1,42 +1,129 @@
 use serde_json::Value;
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::sync::{Arc, RwLock};
 use tera::{Context, Tera};
 
 use super::filters;
+use super::guard::RenderGuard;
 use crate::error::{Result, TermStackError};
 
 /// Template engine for rendering dynamic content (optimized with Arc<RwLock> for shared access)
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct TemplateEngine {
     tera: Arc<RwLock<Tera>>,
+    guard: Arc<RenderGuard>,
+    /// Builds a Tera instance with the built-in and custom filters
+    new_tera: Arc<dyn Fn() -> Tera + Send + Sync>,
+}
+
+impl std::fmt::Debug for TemplateEngine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TemplateEngine").finish_non_exhaustive()
+    }
+}
+
+/// A filter added at runtime (by a WASM plugin, see [`crate::wasm`]) on top of
+/// the built-in ones
+pub type CustomFilter =
+    Arc<dyn Fn(&Value, &HashMap<String, Value>) -> tera::Result<Value> + Send + Sync>;
+
+/// Compiled templates kept before the cache starts over (config templates are a
+/// few hundred at most; this only bounds templates built at runtime)
+const MAX_COMPILED_TEMPLATES: usize = 1024;
+
+/// A Tera instance with the custom filters registered
+fn new_tera(custom: &[(String, CustomFilter)]) -> Tera {
+    let mut tera = Tera::default();
+
+    // Register custom filters
+    tera.register_filter("timeago", filters::timeago);
+    tera.register_filter("filesizeformat", filters::filesizeformat);
+    tera.register_filter("status_color", filters::status_color);
+    tera.register_filter("percent", filters::percent);
+    tera.register_filter("boolean_icon", filters::boolean_icon);
+    tera.register_filter("shell_quote", filters::shell_quote);
+    for (name, filter) in custom {
+        let filter = filter.clone();
+        tera.register_filter(name, move |value: &Value, args: &HashMap<String, Value>| {
+            filter(value, args)
+        });
+    }
+    tera
 }
 
 impl TemplateEngine {
     pub fn new() -> Result<Self> {
-        let mut tera = Tera::default();
-
-        // Register custom filters
-        tera.register_filter("timeago", filters::timeago);
-        tera.register_filter("filesizeformat", filters::filesizeformat);
-        tera.register_filter("status_color", filters::status_color);
+        Ok(Self::with_filters(Vec::new()))
+    }
 
-        Ok(Self {
-            tera: Arc::new(RwLock::new(tera)),
-        })
+    /// A template engine with `filters` available besides the built-in ones
+    pub fn with_filters(filters: Vec<(String, CustomFilter)>) -> Self {
+        let factory: Arc<dyn Fn() -> Tera + Send + Sync> = Arc::new(move || new_tera(&filters));
+        let guard_factory = factory.clone();
+        Self {
+            tera: Arc::new(RwLock::new(factory())),
+            guard: Arc::new(RenderGuard::new(move || guard_factory())),
+            new_tera: factory,
+        }
     }
 
-    /// Render a template string with the given context (optimized - no cloning!)
+    /// Render a template string with the given context. Each distinct template
+    /// is compiled on first use and rendered from the compiled form afterwards,
+    /// under a shared lock so renders don't wait on each other.
     pub fn render_string(&self, template: &str, context: &TemplateContext) -> Result<String> {
         let tera_context = context.to_tera_context();
+        let name = Self::template_name(template);
+        let render_error =
+            |e: tera::Error| TermStackError::Template(format!("Template rendering error: {}", e));
+
+        {
+            let tera = self.tera.read().map_err(Self::lock_error)?;
+            if tera.get_template(&name).is_ok() {
+                return tera.render(&name, &tera_context).map_err(render_error);
+            }
+        }
 
-        // Use write lock for rendering (only locks during actual rendering, not cloning)
-        let mut tera = self.tera.write().map_err(|e| {
-            TermStackError::Template(format!("Failed to acquire template lock: {}", e))
-        })?;
+        let mut tera = self.tera.write().map_err(Self::lock_error)?;
+        if tera.get_template_names().count() >= MAX_COMPILED_TEMPLATES {
+            *tera = (self.new_tera)();
+        }
+        tera.add_raw_template(&name, template).map_err(render_error)?;
+        tera.render(&name, &tera_context).map_err(render_error)
+    }
+
+    fn template_name(template: &str) -> String {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        template.hash(&mut hasher);
+        format!("__string_{:x}", hasher.finish())
+    }
+
+    fn lock_error<T>(e: std::sync::PoisonError<T>) -> TermStackError {
+        TermStackError::Template(format!("Failed to acquire template lock: {}", e))
+    }
+
+    /// Render a template that is rendered over and over (transforms and style
+    /// conditions, once per cell per frame). It is compiled once and rendered
+    /// under the render guard's time and size limits, failing with
+    /// `TemplateLimit` instead of blocking the UI.
+    pub fn render_cached(&self, template: &str, context: &TemplateContext) -> Result<String> {
+        self.guard.render(template, context.to_tera_context())
+    }
 
-        tera.render_str(template, &tera_context)
-            .map_err(|e| TermStackError::Template(format!("Template rendering error: {}", e)))
+    /// Compile a template meant for [`render_cached`] ahead of its first render
+    /// (say every column transform when a page loads), reporting syntax errors
+    ///
+    /// [`render_cached`]: TemplateEngine::render_cached
+    pub fn precompile(&self, template: &str) -> Result<()> {
+        self.guard.compile(template)
+    }
+
+    /// Forget every compiled template. Call when the templates' source changes
+    /// (a config reload) so stale compiled forms and tripped limits don't linger.
+    pub fn invalidate(&self) {
+        self.guard.reset();
+        let mut tera = self.tera.write().unwrap_or_else(|e| e.into_inner());
+        *tera = (self.new_tera)();
     }
 
     /// Render a template and parse result as JSON value
@@ -52,6 +139,86 @@ impl TemplateEngine {
     pub fn is_template(s: &str) -> bool {
         s.contains("{{") && s.contains("}}")
     }
+
+    /// Render `template` once against `context` without real data, failing on
+    /// what would fail with any data: syntax errors and unknown filters, tests
+    /// and functions. Variables the context lacks are filled in as null, and
+    /// errors that come from those nulls (a filter given the wrong type) pass.
+    pub fn dry_run(&self, template: &str, context: &TemplateContext) -> Result<()> {
+        let error = |e: &tera::Error| TermStackError::Template(describe(e));
+        let mut tera = (self.new_tera)();
+        tera.add_raw_template("dry_run", template)
+            .map_err(|e| error(&e))?;
+
+        let mut values = context.to_tera_context().into_json();
+        for _ in 0..MAX_DRY_RUN_FILLS {
+            let context = Context::from_value(values.clone()).map_err(|e| error(&e))?;
+            let Err(e) = tera.render("dry_run", &context) else {
+                return Ok(());
+            };
+            let mut source: Option<&dyn std::error::Error> = Some(&e);
+            while let Some(cause) = source {
+                if let Some(cause) = cause.downcast_ref::<tera::Error>()
+                    && matches!(
+                        cause.kind,
+                        tera::ErrorKind::FilterNotFound(_)
+                            | tera::ErrorKind::TestNotFound(_)
+                            | tera::ErrorKind::FunctionNotFound(_)
+                    )
+                {
+                    return Err(error(cause));
+                }
+                source = cause.source();
+            }
+            let Some(name) = missing_variable(&describe(&e)) else {
+                return Ok(());
+            };
+            if !fill_null(&mut values, &name) {
+                return Ok(());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Variables [`TemplateEngine::dry_run`] fills in before giving up on a template
+const MAX_DRY_RUN_FILLS: usize = 64;
+
+/// A Tera error with its causes, which hold the detail (line, column, name)
+fn describe(e: &tera::Error) -> String {
+    let mut message = e.to_string();
+    let mut source = std::error::Error::source(e);
+    while let Some(cause) = source {
+        message.push_str(": ");
+        message.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    message
+}
+
+/// The variable a "Variable `x` not found" render error names
+fn missing_variable(message: &str) -> Option<String> {
+    let rest = &message[message.find("Variable `")? + "Variable `".len()..];
+    Some(rest[..rest.find('`')?].to_string())
+}
+
+/// Set `name` (`a.b.c`) to null in `values`, making objects of the values on
+/// the way. False when it can't be (an index, or a field of a non-null value).
+fn fill_null(values: &mut Value, name: &str) -> bool {
+    if name.contains('[') {
+        return false;
+    }
+    let mut slot = values;
+    for field in name.split('.') {
+        if slot.is_null() {
+            *slot = Value::Object(Default::default());
+        }
+        let Value::Object(object) = slot else {
+            return false;
+        };
+        slot = object.entry(field).or_insert(Value::Null);
+    }
+    true
 }
 
 impl Default for TemplateEngine {
@@ -227,6 +394,53 @@ mod tests {
         assert_eq!(result, "Status: running");
     }
 
+    #[test]
+    fn test_render_cached() {
+        let engine = TemplateEngine::new().unwrap();
+        let template = "{{ value == 'Failed' }}";
+
+        for (status, expected) in [("Failed", "true"), ("Running", "false")] {
+            let ctx = TemplateContext::new().with_page_context("value".to_string(), json!(status));
+            assert_eq!(engine.render_cached(template, &ctx).unwrap(), expected);
+        }
+        assert!(engine.render_cached("{{ unclosed", &TemplateContext::new()).is_err());
+    }
+
+    #[test]
+    fn test_render_string_compiles_once() {
+        let engine = TemplateEngine::new().unwrap();
+        let compiled = || engine.tera.read().unwrap().get_template_names().count();
+
+        for name in ["a", "b", "c"] {
+            let ctx = TemplateContext::new().with_current(json!({"name": name}));
+            let rendered = engine.render_string("Hello {{ name }}", &ctx).unwrap();
+            assert_eq!(rendered, format!("Hello {}", name));
+        }
+        assert_eq!(compiled(), 1);
+
+        // A template that doesn't parse isn't kept
+        assert!(engine.render_string("{{ unclosed", &TemplateContext::new()).is_err());
+        assert_eq!(compiled(), 1);
+    }
+
+    #[test]
+    fn test_dry_run() {
+        let context = TemplateContext::new().with_globals(HashMap::from([(
+            "namespace".to_string(),
+            json!("default"),
+        )]));
+        let engine = TemplateEngine::new().unwrap();
+        let dry_run = |template| engine.dry_run(template, &context);
+        assert!(dry_run("{{ namespace }}/{{ row.metadata.name | upper }}").is_ok());
+        assert!(dry_run("{% if row.ready %}{{ row.age | timeago }}{% endif %}").is_ok());
+        assert!(dry_run("{{ pods.name | default(value='-') }}").is_ok());
+
+        assert!(dry_run("{{ unclosed").is_err());
+        let error = dry_run("{{ row.name | shout }}").unwrap_err().to_string();
+        assert!(error.contains("Filter 'shout' not found"), "{}", error);
+        assert!(dry_run("{{ now(utc=true) }} {{ nope() }}").is_err());
+    }
+
     #[test]
     fn test_is_template() {
         assert!(TemplateEngine::is_template("{{ var }}"));
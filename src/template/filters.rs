@@ -19,9 +19,14 @@ pub fn timeago(value: &Value, _args: &HashMap<String, Value>) -> TeraResult<Valu
         .map_err(|e| tera::Error::msg(format!("Failed to parse timestamp: {}", e)))?;
 
     let now = Utc::now();
-    let duration = now.signed_duration_since(parsed.with_timezone(&Utc));
+    let result = format_age(now.signed_duration_since(parsed.with_timezone(&Utc)));
 
-    let result = if duration.num_seconds() < 60 {
+    to_value(result).map_err(|e| tera::Error::msg(format!("Failed to convert to value: {}", e)))
+}
+
+/// Format an age as a compact single-unit string ("42s", "5m", "3h", "2d")
+pub fn format_age(duration: chrono::Duration) -> String {
+    if duration.num_seconds() < 60 {
         format!("{}s", duration.num_seconds())
     } else if duration.num_minutes() < 60 {
         format!("{}m", duration.num_minutes())
@@ -29,9 +34,7 @@ pub fn timeago(value: &Value, _args: &HashMap<String, Value>) -> TeraResult<Valu
         format!("{}h", duration.num_hours())
     } else {
         format!("{}d", duration.num_days())
-    };
-
-    to_value(result).map_err(|e| tera::Error::msg(format!("Failed to convert to value: {}", e)))
+    }
 }
 
 /// Format bytes as human-readable file size (e.g., "1.5 GB")
@@ -72,6 +75,53 @@ pub fn status_color(value: &Value, _args: &HashMap<String, Value>) -> TeraResult
     to_value(color).map_err(|e| tera::Error::msg(format!("Failed to convert to value: {}", e)))
 }
 
+/// Format a fraction as a percentage (e.g., 0.425 → "42.5%")
+///
+/// Accepts an optional `decimals` argument (default 1).
+pub fn percent(value: &Value, args: &HashMap<String, Value>) -> TeraResult<Value> {
+    let fraction = match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse::<f64>().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| tera::Error::msg("percent filter expects a number"))?;
+    let decimals = args.get("decimals").and_then(Value::as_u64).unwrap_or(1) as usize;
+
+    let result = format!("{:.*}%", decimals, fraction * 100.0);
+
+    to_value(result).map_err(|e| tera::Error::msg(format!("Failed to convert to value: {}", e)))
+}
+
+/// Render truthy values as ✓ and falsy ones as ✗
+pub fn boolean_icon(value: &Value, _args: &HashMap<String, Value>) -> TeraResult<Value> {
+    let truthy = match value {
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64().is_some_and(|n| n != 0.0),
+        Value::String(s) => matches!(
+            s.trim().to_lowercase().as_str(),
+            "true" | "yes" | "y" | "on" | "1"
+        ),
+        Value::Null => false,
+        Value::Array(a) => !a.is_empty(),
+        Value::Object(o) => !o.is_empty(),
+    };
+
+    to_value(if truthy { "✓" } else { "✗" })
+        .map_err(|e| tera::Error::msg(format!("Failed to convert to value: {}", e)))
+}
+
+/// Quote a value as one word for a POSIX shell (`it's` → `'it'\''s'`);
+/// non-strings are quoted as their JSON
+pub fn shell_quote(value: &Value, _args: &HashMap<String, Value>) -> TeraResult<Value> {
+    let text = match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+    let result = format!("'{}'", text.replace('\'', "'\\''"));
+
+    to_value(result).map_err(|e| tera::Error::msg(format!("Failed to convert to value: {}", e)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,4 +165,29 @@ mod tests {
             "red"
         );
     }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(percent(&json!(0.425), &HashMap::new()).unwrap(), json!("42.5%"));
+        let mut args = HashMap::new();
+        args.insert("decimals".to_string(), json!(0));
+        assert_eq!(percent(&json!("0.5"), &args).unwrap(), json!("50%"));
+        assert!(percent(&json!("n/a"), &HashMap::new()).is_err());
+    }
+
+    #[test]
+    fn test_boolean_icon() {
+        assert_eq!(boolean_icon(&json!(true), &HashMap::new()).unwrap(), json!("✓"));
+        assert_eq!(boolean_icon(&json!("Yes"), &HashMap::new()).unwrap(), json!("✓"));
+        assert_eq!(boolean_icon(&json!(0), &HashMap::new()).unwrap(), json!("✗"));
+        assert_eq!(boolean_icon(&json!(null), &HashMap::new()).unwrap(), json!("✗"));
+    }
+
+    #[test]
+    fn test_shell_quote() {
+        let quote = |value: Value| shell_quote(&value, &HashMap::new()).unwrap();
+        assert_eq!(quote(json!("api-1")), json!("'api-1'"));
+        assert_eq!(quote(json!("it's; rm -rf ~")), json!("'it'\\''s; rm -rf ~'"));
+        assert_eq!(quote(json!(3)), json!("'3'"));
+    }
 }
@@ -0,0 +1,380 @@
+// Time- and size-limited rendering for templates evaluated on every frame
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use tera::{Context, Tera};
+
+use crate::error::{Result, TermStackError};
+
+/// Longest a single render may take before it is abandoned
+pub const RENDER_TIMEOUT: Duration = Duration::from_millis(250);
+
+/// Largest output a single render may produce
+pub const MAX_OUTPUT_BYTES: usize = 64 * 1024;
+
+/// How long a template that timed out fails fast before it's tried again
+pub const TRIP_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Abandoned renders still running before new workers are refused
+const MAX_RUNAWAY: usize = 4;
+
+/// Idle workers kept for reuse
+const MAX_IDLE_WORKERS: usize = 8;
+
+/// Builds the Tera instance each worker compiles and renders with
+type NewTera = Arc<dyn Fn() -> Tera + Send + Sync>;
+
+struct Job {
+    name: String,
+    template: String,
+    /// None compiles the template without rendering it
+    context: Option<Context>,
+}
+
+enum Failure {
+    Limit(String),
+    Template(String),
+}
+
+/// Render output that refuses to grow past the size limit, or at all once the
+/// render has been abandoned, so Tera stops at its next write either way
+struct Output<'a> {
+    bytes: Vec<u8>,
+    abandoned: &'a AtomicBool,
+    exceeded: bool,
+}
+
+impl Write for Output<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.abandoned.load(Ordering::SeqCst) {
+            return Err(std::io::Error::other("render abandoned"));
+        }
+        if self.bytes.len() + buf.len() > MAX_OUTPUT_BYTES {
+            self.exceeded = true;
+            return Err(std::io::Error::other("output limit"));
+        }
+        self.bytes.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A render thread with its own Tera instance and compiled-template cache
+struct Worker {
+    jobs: mpsc::Sender<Job>,
+    results: mpsc::Receiver<std::result::Result<String, Failure>>,
+    /// Set when a render timed out; the thread stops at its next write
+    abandoned: Arc<AtomicBool>,
+    /// The guard's generation when spawned; workers from before a reset aren't reused
+    generation: usize,
+}
+
+/// Counts an abandoned worker's thread as running until it exits
+struct Runaway {
+    abandoned: Arc<AtomicBool>,
+    running: Arc<AtomicUsize>,
+}
+
+impl Drop for Runaway {
+    fn drop(&mut self) {
+        if self.abandoned.load(Ordering::SeqCst) {
+            self.running.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+}
+
+impl Worker {
+    fn spawn(new_tera: NewTera, running: Arc<AtomicUsize>, generation: usize) -> Self {
+        let (jobs, job_rx) = mpsc::channel::<Job>();
+        let (result_tx, results) = mpsc::channel();
+        let abandoned = Arc::new(AtomicBool::new(false));
+        let runaway = Runaway {
+            abandoned: abandoned.clone(),
+            running,
+        };
+
+        thread::spawn(move || {
+            let runaway = runaway;
+            let mut tera = new_tera();
+            for job in job_rx {
+                let result = Self::run(&mut tera, &job, &runaway.abandoned);
+                // The caller gave up on us (timeout) - exit quietly
+                if result_tx.send(result).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            jobs,
+            results,
+            abandoned,
+            generation,
+        }
+    }
+
+    fn run(
+        tera: &mut Tera,
+        job: &Job,
+        abandoned: &AtomicBool,
+    ) -> std::result::Result<String, Failure> {
+        let error = |e: tera::Error| Failure::Template(format!("Template rendering error: {}", e));
+        if tera.get_template(&job.name).is_err() {
+            tera.add_raw_template(&job.name, &job.template).map_err(error)?;
+        }
+        let Some(context) = &job.context else {
+            return Ok(String::new());
+        };
+
+        let mut output = Output {
+            bytes: Vec::new(),
+            abandoned,
+            exceeded: false,
+        };
+        match tera.render_to(&job.name, context, &mut output) {
+            Ok(()) => String::from_utf8(output.bytes)
+                .map_err(|e| Failure::Template(format!("Template rendering error: {}", e))),
+            Err(_) if output.exceeded => Err(Failure::Limit(format!(
+                "output exceeds {} KiB",
+                MAX_OUTPUT_BYTES / 1024
+            ))),
+            Err(e) => Err(error(e)),
+        }
+    }
+}
+
+/// Renders templates off the UI thread with a time and output-size limit, so a
+/// runaway template (say a loop over a huge array in a transform) turns into an
+/// error instead of freezing the UI.
+///
+/// Renders run on a small pool of worker threads, each with its own compiled
+/// templates, so concurrent renders don't wait on each other. Output stops at
+/// the size limit as it's written. A render that times out is abandoned: it
+/// stops at its next write (a loop that writes nothing runs to the end on its
+/// own), its template fails fast for [`TRIP_COOLDOWN`], and a fresh worker
+/// takes over.
+pub struct RenderGuard {
+    new_tera: NewTera,
+    idle: Mutex<Vec<Worker>>,
+    /// Templates that timed out, and when
+    tripped: Mutex<HashMap<String, Instant>>,
+    cooldown: Duration,
+    /// Abandoned workers whose thread is still running
+    runaway: Arc<AtomicUsize>,
+    generation: AtomicUsize,
+}
+
+impl std::fmt::Debug for RenderGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RenderGuard").finish_non_exhaustive()
+    }
+}
+
+impl RenderGuard {
+    pub fn new(new_tera: impl Fn() -> Tera + Send + Sync + 'static) -> Self {
+        Self {
+            new_tera: Arc::new(new_tera),
+            idle: Mutex::new(Vec::new()),
+            tripped: Mutex::new(HashMap::new()),
+            cooldown: TRIP_COOLDOWN,
+            runaway: Arc::new(AtomicUsize::new(0)),
+            generation: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn render(&self, template: &str, context: Context) -> Result<String> {
+        self.render_with_timeout(template, Some(context), RENDER_TIMEOUT)
+    }
+
+    /// Compile a template ahead of its first render, reporting syntax errors
+    pub fn compile(&self, template: &str) -> Result<()> {
+        self.render_with_timeout(template, None, RENDER_TIMEOUT).map(|_| ())
+    }
+
+    /// Drop every compiled template and forget which ones timed out, e.g.
+    /// after the config they came from changed
+    pub fn reset(&self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        self.idle.lock().unwrap_or_else(|e| e.into_inner()).clear();
+        self.tripped.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+
+    fn render_with_timeout(
+        &self,
+        template: &str,
+        context: Option<Context>,
+        timeout: Duration,
+    ) -> Result<String> {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        template.hash(&mut hasher);
+        let name = format!("__guarded_{:x}", hasher.finish());
+
+        {
+            let mut tripped = self.tripped.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(at) = tripped.get(&name) {
+                if at.elapsed() < self.cooldown {
+                    return Err(TermStackError::TemplateLimit(
+                        "disabled after timing out".to_string(),
+                    ));
+                }
+                tripped.remove(&name);
+            }
+        }
+
+        let worker = self.take_worker()?;
+        let job = Job {
+            name: name.clone(),
+            template: template.to_string(),
+            context,
+        };
+        if worker.jobs.send(job).is_err() {
+            return Err(TermStackError::Template("Render worker stopped".to_string()));
+        }
+
+        match worker.results.recv_timeout(timeout) {
+            Ok(result) => {
+                self.put_back(worker);
+                result.map_err(|failure| match failure {
+                    Failure::Limit(e) => TermStackError::TemplateLimit(e),
+                    Failure::Template(e) => TermStackError::Template(e),
+                })
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                self.runaway.fetch_add(1, Ordering::SeqCst);
+                worker.abandoned.store(true, Ordering::SeqCst);
+                self.tripped
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .insert(name, Instant::now());
+                Err(TermStackError::TemplateLimit(format!(
+                    "timed out after {}ms",
+                    timeout.as_millis()
+                )))
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                Err(TermStackError::Template("Render worker stopped".to_string()))
+            }
+        }
+    }
+
+    /// An idle worker, or a new one unless too many abandoned renders are still running
+    fn take_worker(&self) -> Result<Worker> {
+        if let Some(worker) = self.idle.lock().unwrap_or_else(|e| e.into_inner()).pop() {
+            return Ok(worker);
+        }
+        if self.runaway.load(Ordering::SeqCst) >= MAX_RUNAWAY {
+            return Err(TermStackError::TemplateLimit(
+                "too many renders still running after timing out".to_string(),
+            ));
+        }
+        let generation = self.generation.load(Ordering::SeqCst);
+        Ok(Worker::spawn(self.new_tera.clone(), self.runaway.clone(), generation))
+    }
+
+    fn put_back(&self, worker: Worker) {
+        let mut idle = self.idle.lock().unwrap_or_else(|e| e.into_inner());
+        if worker.generation == self.generation.load(Ordering::SeqCst)
+            && idle.len() < MAX_IDLE_WORKERS
+        {
+            idle.push(worker);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_and_output_limit() {
+        let guard = RenderGuard::new(Tera::default);
+        let mut context = Context::new();
+        context.insert("value", "x");
+
+        assert_eq!(guard.render("{{ value }}!", context.clone()).unwrap(), "x!");
+
+        let huge = "{% for i in range(end=100000) %}{{ value }}{% endfor %}";
+        let err = guard.render(huge, context).unwrap_err();
+        assert!(matches!(err, TermStackError::TemplateLimit(_)));
+        assert!(err.to_string().contains("output exceeds 64 KiB"), "{}", err);
+    }
+
+    #[test]
+    fn test_timeout_trips_template() {
+        let mut guard = RenderGuard::new(Tera::default);
+        let slow = "{% for i in range(end=3000) %}{% for j in range(end=3000) %}\
+                    {% endfor %}{% endfor %}done";
+
+        let err = guard
+            .render_with_timeout(slow, Some(Context::new()), Duration::from_millis(1))
+            .unwrap_err();
+        assert!(err.to_string().contains("timed out"));
+
+        // Fails fast for a while, while other templates keep working
+        let err = guard.render(slow, Context::new()).unwrap_err();
+        assert!(err.to_string().contains("disabled"));
+        assert_eq!(guard.render("ok", Context::new()).unwrap(), "ok");
+
+        // Until the cache is reset
+        guard.reset();
+        assert!(guard.compile(slow).is_ok());
+
+        // ...or the cooldown is over
+        guard.cooldown = Duration::ZERO;
+        guard
+            .render_with_timeout(slow, Some(Context::new()), Duration::from_millis(1))
+            .unwrap_err();
+        assert!(guard.compile(slow).is_ok());
+    }
+
+    #[test]
+    fn test_abandoned_render_stops() {
+        let guard = RenderGuard::new(Tera::default);
+        // Writes a little now and then, for far longer than the timeout
+        let slow = "{% for i in range(end=20000) %}{% for j in range(end=5000) %}\
+                    {% endfor %}.{% endfor %}";
+
+        guard
+            .render_with_timeout(slow, Some(Context::new()), Duration::from_millis(20))
+            .unwrap_err();
+        assert_eq!(guard.runaway.load(Ordering::SeqCst), 1);
+
+        let started = Instant::now();
+        while guard.runaway.load(Ordering::SeqCst) > 0 {
+            assert!(started.elapsed() < Duration::from_secs(5), "abandoned render kept running");
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn test_concurrent_renders() {
+        let guard = Arc::new(RenderGuard::new(Tera::default));
+        let handles: Vec<_> = (0..4)
+            .map(|n| {
+                let guard = guard.clone();
+                thread::spawn(move || {
+                    let mut context = Context::new();
+                    context.insert("n", &n);
+                    guard.render("{{ n }}", context).unwrap()
+                })
+            })
+            .collect();
+        let outputs: Vec<String> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert_eq!(outputs, ["0", "1", "2", "3"]);
+    }
+
+    #[test]
+    fn test_compile() {
+        let guard = RenderGuard::new(Tera::default);
+        assert!(guard.compile("{{ value }}").is_ok());
+        assert!(guard.compile("{{ unclosed").is_err());
+    }
+}
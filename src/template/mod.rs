@@ -1,4 +1,5 @@
 pub mod engine;
 pub mod filters;
+pub mod guard;
 
 pub use engine::TemplateEngine;
@@ -0,0 +1,329 @@
+// Snapshot tests for configs: a config's pages driven with canned data, read back as text
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{Terminal, backend::TestBackend};
+use serde_json::{Value, json};
+
+use crate::adapters::DataSourceAdapter;
+use crate::adapters::registry::AdapterRegistry;
+use crate::app::App;
+use crate::config::schema::SingleDataSource;
+use crate::config::{Config, ConfigLoader, ConfigValidator, DataSource, SingleOrStream, View};
+use crate::context::AppContext;
+use crate::data::provider::DataContext;
+use crate::input::keymap::parse_sequence;
+
+/// The adapter every faked source is switched to
+const FIXTURE_ADAPTER: &str = "fixture";
+
+/// A config set up for a test, with canned data in place of its data sources.
+///
+/// Data is keyed by page id; a source of a multi-source page by
+/// `page.source_id` and a dashboard panel by `page.N` (N counting from 0).
+/// It's what the source would have returned, before `items` picks the rows,
+/// so a test covers the config's JSONPaths and transforms too. A source with
+/// no data fails to load, and nothing is ever run; stream sources aren't
+/// faked, so stream pages can't be tested this way.
+///
+/// ```
+/// use serde_json::json;
+/// use termstack::testing::Harness;
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let yaml = r#"
+/// version: v1
+/// app: {name: k8s}
+/// start: pods
+/// pages:
+///   pods:
+///     title: Pods
+///     data: {adapter: cli, command: kubectl, args: [get, pods, -o, json], items: "$.items[*]"}
+///     view: {type: table, columns: [{path: "$.name", display: Name}]}
+/// "#;
+/// let mut session = Harness::from_yaml(yaml)
+///     .unwrap()
+///     .with_data("pods", json!({"items": [{"name": "api-1"}, {"name": "web-1"}]}))
+///     .with_size(60, 16)
+///     .start()
+///     .await
+///     .unwrap();
+/// session.press("j").await.unwrap();
+/// let screen = session.screen().unwrap();
+/// assert!(screen.contains("Row 2/2"), "{}", screen);
+/// # });
+/// ```
+pub struct Harness {
+    config: Config,
+    data: HashMap<String, Value>,
+    context: HashMap<String, Value>,
+    size: (u16, u16),
+}
+
+impl Harness {
+    /// A YAML config, validated as on launch
+    pub fn from_yaml(yaml: &str) -> Result<Self> {
+        Self::new(ConfigLoader::load_from_string(yaml)?)
+    }
+
+    /// A config file (YAML, or JSON/TOML by extension), validated as on launch
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        Self::new(ConfigLoader::load_from_file(path)?)
+    }
+
+    fn new(config: Config) -> Result<Self> {
+        ConfigValidator::validate(&config)?;
+        Ok(Self {
+            config,
+            data: HashMap::new(),
+            context: HashMap::new(),
+            size: (80, 24),
+        })
+    }
+
+    /// What the source `key` returns
+    pub fn with_data(mut self, key: &str, data: Value) -> Self {
+        self.data.insert(key.to_string(), data);
+        self
+    }
+
+    /// Navigation context for the start page, as `--context key=value` sets
+    pub fn with_context(mut self, key: &str, value: Value) -> Self {
+        self.context.insert(key.to_string(), value);
+        self
+    }
+
+    /// Start on this page instead of the config's `start`, as `--page` does
+    pub fn with_page(mut self, page: &str) -> Self {
+        self.config.start = page.to_string();
+        self
+    }
+
+    /// Screen size, 80x24 by default
+    pub fn with_size(mut self, width: u16, height: u16) -> Self {
+        self.size = (width, height);
+        self
+    }
+
+    /// Open the start page and wait for its data
+    pub async fn start(mut self) -> Result<Session> {
+        if !self.config.pages.contains_key(&self.config.start) {
+            return Err(anyhow!("Unknown page '{}'", self.config.start));
+        }
+        fake_sources(&mut self.config);
+
+        let ctx = AppContext::new(self.config)?;
+        let data = Arc::new(Mutex::new(self.data));
+        let mut registry = AdapterRegistry::new();
+        registry.register(Arc::new(FixtureAdapter { data: data.clone() }));
+        let mut app = App::new(ctx, registry)?
+            .isolated()
+            .with_context(self.context);
+        app.load_headless().await;
+
+        let (width, height) = self.size;
+        let terminal = Terminal::new(TestBackend::new(width, height))?;
+        Ok(Session {
+            app,
+            terminal,
+            data,
+        })
+    }
+}
+
+/// A running app under test
+pub struct Session {
+    app: App,
+    terminal: Terminal<TestBackend>,
+    data: Arc<Mutex<HashMap<String, Value>>>,
+}
+
+impl Session {
+    /// Press keys, written as in `keybindings` and separated by spaces
+    /// (`j`, `g g`, `ctrl+d`, `enter`), waiting after each for what it loads
+    pub async fn press(&mut self, keys: &str) -> Result<()> {
+        for combo in parse_sequence(keys).map_err(|e| anyhow!(e))? {
+            self.key(KeyEvent::new(combo.code, combo.modifiers)).await;
+        }
+        Ok(())
+    }
+
+    /// Type text, e.g. into the search prompt or a form field
+    pub async fn type_text(&mut self, text: &str) {
+        for c in text.chars() {
+            self.key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+                .await;
+        }
+    }
+
+    /// Change what the source `key` returns from now on, e.g. before pressing
+    /// `r` to refresh
+    pub fn set_data(&mut self, key: &str, data: Value) {
+        if let Ok(mut sources) = self.data.lock() {
+            sources.insert(key.to_string(), data);
+        }
+    }
+
+    async fn key(&mut self, key: KeyEvent) {
+        self.app.handle_key(key).await;
+        self.app.settle().await;
+    }
+
+    /// The app itself, for the crate's own tests
+    #[cfg(test)]
+    pub(crate) fn app(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    /// Id of the page shown
+    pub fn page(&self) -> &str {
+        self.app.current_page()
+    }
+
+    /// The screen as text, one line per row without trailing spaces
+    pub fn screen(&mut self) -> Result<String> {
+        self.app.draw(&mut self.terminal)?;
+        Ok(crate::ui::terminal::text(self.terminal.backend().buffer()))
+    }
+}
+
+/// Switch every non-stream data source in `config` to the fixture adapter,
+/// keeping the key its data is found under
+fn fake_sources(config: &mut Config) {
+    for (page_id, page) in config.pages.iter_mut() {
+        match &mut page.data {
+            Some(DataSource::SingleOrStream(SingleOrStream::Single(source))) => {
+                fake_source(source, page_id.clone());
+            }
+            Some(DataSource::Multi(multi)) => {
+                for named in &mut multi.sources {
+                    fake_source(&mut named.source, format!("{}.{}", page_id, named.id));
+                }
+            }
+            Some(DataSource::SingleOrStream(SingleOrStream::Stream(_))) | None => {}
+        }
+        if let View::Dashboard(dashboard) = &mut page.view {
+            for (index, panel) in dashboard.panels.iter_mut().enumerate() {
+                fake_source(&mut panel.data, format!("{}.{}", page_id, index));
+            }
+        }
+    }
+}
+
+fn fake_source(source: &mut SingleDataSource, key: String) {
+    source.adapter = Some(FIXTURE_ADAPTER.to_string());
+    source.source_type = None;
+    source
+        .config
+        .insert(FIXTURE_ADAPTER.to_string(), json!(key));
+}
+
+/// Answers each faked source with its test data
+struct FixtureAdapter {
+    data: Arc<Mutex<HashMap<String, Value>>>,
+}
+
+#[async_trait]
+impl DataSourceAdapter for FixtureAdapter {
+    fn name(&self) -> &str {
+        FIXTURE_ADAPTER
+    }
+
+    async fn fetch(&self, source: &SingleDataSource, _ctx: &DataContext) -> Result<Value> {
+        let key = source
+            .config
+            .get(FIXTURE_ADAPTER)
+            .and_then(|v| v.as_str())
+            .unwrap_or_default();
+        let data = self.data.lock().map_err(|e| anyhow!("{}", e))?;
+        data.get(key)
+            .cloned()
+            .ok_or_else(|| anyhow!("No test data for '{}'", key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+version: v1
+app: {name: test}
+start: namespaces
+pages:
+  namespaces:
+    title: Namespaces
+    data: {adapter: cli, command: kubectl, items: "$.items[*]"}
+    view:
+      type: table
+      columns: [{path: "$.name", display: Name}]
+    next: {page: pods, context: {namespace: "$.name"}}
+  pods:
+    title: Pods
+    params: [namespace]
+    data:
+      sources:
+        - {id: running, adapter: cli, command: kubectl, items: "$[*]"}
+        - {id: pending, adapter: cli, command: kubectl, items: "$[*]"}
+      merge: true
+    view:
+      type: table
+      columns: [{path: "$.pod", display: Pod}, {path: "$.cpu", display: CPU}]
+  overview:
+    title: Overview
+    view:
+      type: dashboard
+      panels:
+        - title: Nodes
+          data: {adapter: http, url: "http://localhost/nodes"}
+          view: {type: table, columns: [{path: "$.node", display: Node}]}
+"#;
+
+    #[tokio::test]
+    async fn test_session() {
+        let mut session = Harness::from_yaml(CONFIG)
+            .unwrap()
+            .with_data(
+                "namespaces",
+                json!({"items": [{"name": "default"}, {"name": "prod"}]}),
+            )
+            .with_data("pods.running", json!([{"pod": "api-1", "cpu": "250m"}]))
+            .with_data("pods.pending", json!([{"pod": "web-1"}]))
+            .with_size(60, 14)
+            .start()
+            .await
+            .unwrap();
+        assert!(session.screen().unwrap().contains("prod"));
+
+        session.press("j enter").await.unwrap();
+        assert_eq!(session.page(), "pods");
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("Pods (prod)"), "{}", screen);
+        assert!(screen.contains("api-1 250m"), "{}", screen);
+        assert!(screen.contains("web-1"), "{}", screen);
+        assert!(session.press("ctrl+nope").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_missing_data() {
+        let mut session = Harness::from_yaml(CONFIG)
+            .unwrap()
+            .with_page("overview")
+            .start()
+            .await
+            .unwrap();
+        let screen = session.screen().unwrap();
+        assert!(screen.contains("No test data for"), "{}", screen);
+
+        let session = Harness::from_yaml(CONFIG)
+            .unwrap()
+            .with_page("nope")
+            .start()
+            .await;
+        assert!(session.is_err());
+    }
+}
@@ -1,2 +1,138 @@
-/// Breadcrumb navigation widget (to be implemented)
-pub struct Breadcrumb;
+// Breadcrumb: the pages on the navigation stack, fitted to the header
+use unicode_width::UnicodeWidthStr;
+
+use crate::util::text::truncate;
+
+pub const SEPARATOR: &str = " > ";
+
+/// Longest a title may be once the breadcrumb doesn't fit
+const SHORT_TITLE: usize = 20;
+
+/// One entry of the breadcrumb as drawn
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Crumb {
+    /// A page the user navigated on from, by its 1-based place on the stack
+    /// (what Alt+number jumps back to)
+    Frame(usize, String),
+    /// Pages left out to make room
+    Elided,
+    Current(String),
+}
+
+impl Crumb {
+    fn width(&self) -> usize {
+        match self {
+            Crumb::Frame(n, title) => n.to_string().len() + 1 + title.width(),
+            Crumb::Elided => 1,
+            Crumb::Current(title) => title.width(),
+        }
+    }
+}
+
+/// A page's title followed by the context values it was opened with that the
+/// title doesn't already show: `pods (kube-system)`
+pub fn label(title: &str, context: &[String]) -> String {
+    let missing: Vec<&str> = context
+        .iter()
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty() && !title.contains(value))
+        .collect();
+    if missing.is_empty() {
+        title.to_string()
+    } else {
+        format!("{} ({})", title, missing.join(", "))
+    }
+}
+
+/// The breadcrumb for the `frames` left and the `current` page in `width`
+/// columns: titles are shortened first, then the oldest pages after the
+/// first are elided, then the first, and last the current title is cut
+pub fn fit(frames: &[String], current: &str, width: usize) -> Vec<Crumb> {
+    let total = |crumbs: &[Crumb]| {
+        crumbs.iter().map(Crumb::width).sum::<usize>()
+            + SEPARATOR.len() * crumbs.len().saturating_sub(1)
+    };
+
+    let mut crumbs: Vec<Crumb> = frames
+        .iter()
+        .enumerate()
+        .map(|(i, title)| Crumb::Frame(i + 1, title.clone()))
+        .chain(std::iter::once(Crumb::Current(current.to_string())))
+        .collect();
+    if total(&crumbs) <= width {
+        return crumbs;
+    }
+
+    for crumb in &mut crumbs {
+        if let Crumb::Frame(_, title) | Crumb::Current(title) = crumb {
+            *title = truncate(title, SHORT_TITLE);
+        }
+    }
+    let current = crumbs.pop().unwrap_or(Crumb::Elided);
+    let frames = crumbs;
+
+    // Keep the first page and the `kept` pages before the current one
+    for kept in (0..frames.len()).rev() {
+        let mut crumbs = vec![frames[0].clone()];
+        if kept + 1 < frames.len() {
+            crumbs.push(Crumb::Elided);
+        }
+        crumbs.extend(frames[frames.len() - kept..].iter().cloned());
+        crumbs.push(current.clone());
+        if total(&crumbs) <= width {
+            return crumbs;
+        }
+    }
+
+    let mut crumbs = Vec::new();
+    if !frames.is_empty() {
+        crumbs.push(Crumb::Elided);
+    }
+    let used = total(&crumbs) + if crumbs.is_empty() { 0 } else { SEPARATOR.len() };
+    if let Crumb::Current(title) = current {
+        crumbs.push(Crumb::Current(truncate(&title, width.saturating_sub(used))));
+    }
+    crumbs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label() {
+        let context = vec!["kube-system".to_string()];
+        assert_eq!(label("pods", &context), "pods (kube-system)");
+        assert_eq!(label("Pods in kube-system", &context), "Pods in kube-system");
+        assert_eq!(label("pods", &[]), "pods");
+    }
+
+    #[test]
+    fn test_fit() {
+        let frames: Vec<String> = ["clusters", "namespaces (prod-eu-west-1)", "pods (kube-system)"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let current = "coredns-5d78c9869d-abcde";
+
+        let crumbs = fit(&frames, current, 200);
+        assert_eq!(crumbs.len(), 4);
+        assert_eq!(crumbs[1], Crumb::Frame(2, frames[1].clone()));
+
+        // Shortened titles
+        let crumbs = fit(&frames, current, 85);
+        assert_eq!(crumbs[1], Crumb::Frame(2, "namespaces (prod-eu…".to_string()));
+        assert_eq!(crumbs[3], Crumb::Current("coredns-5d78c9869d-…".to_string()));
+
+        // The middle page elided, then all but the current one
+        let crumbs = fit(&frames, current, 60);
+        assert_eq!(crumbs[1], Crumb::Elided);
+        assert_eq!(crumbs[2], Crumb::Frame(3, "pods (kube-system)".to_string()));
+        assert_eq!(fit(&frames, current, 30).len(), 2);
+        assert_eq!(
+            fit(&frames, current, 12),
+            [Crumb::Elided, Crumb::Current("coredns…".to_string())]
+        );
+        assert_eq!(fit(&[], current, 8), [Crumb::Current("coredns…".to_string())]);
+    }
+}
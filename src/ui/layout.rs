@@ -1,2 +1,31 @@
+use ratatui::layout::Rect;
+
 /// Layout manager (to be implemented)
 pub struct LayoutManager;
+
+/// A popup's area: centered in `area`, four fifths as wide and two thirds as
+/// tall, but at least 3 lines (a bordered line) when `area` has them
+pub fn centered_rect(area: Rect) -> Rect {
+    let width = area.width - area.width / 5;
+    let height = (area.height - area.height / 3).max(3).min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_centered_rect() {
+        assert_eq!(centered_rect(Rect::new(0, 0, 100, 30)), Rect::new(10, 5, 80, 20));
+        assert_eq!(centered_rect(Rect::new(5, 2, 50, 4)), Rect::new(10, 2, 40, 3));
+        // Never taller than the area
+        assert_eq!(centered_rect(Rect::new(0, 0, 20, 2)), Rect::new(2, 0, 16, 2));
+        assert_eq!(centered_rect(Rect::new(0, 0, 0, 0)), Rect::new(0, 0, 0, 0));
+    }
+}
@@ -5,6 +5,8 @@ use ratatui::{
     Frame,
 };
 
+use crate::ui::theme::Theme;
+
 /// Braille pattern spinner frames for smooth animation
 /// Uses Unicode Braille patterns (U+2800 to U+28FF) for a professional look
 pub const SPINNER_FRAMES: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
@@ -58,7 +60,12 @@ impl Default for Spinner {
 }
 
 /// Render a fancy centered loading indicator with spinner in the given area
-pub fn render_loading_indicator(frame: &mut Frame, area: Rect, spinner_frame: usize) {
+pub fn render_loading_indicator(
+    frame: &mut Frame,
+    area: Rect,
+    spinner_frame: usize,
+    theme: &Theme,
+) {
     use ratatui::style::Modifier;
     use ratatui::text::{Line, Span};
 
@@ -71,7 +78,7 @@ pub fn render_loading_indicator(frame: &mut Frame, area: Rect, spinner_frame: us
         Line::from(vec![Span::styled(
             format!("   {}   ", spinner_char),
             Style::default()
-                .fg(Color::Cyan)
+                .fg(theme.accent)
                 .add_modifier(Modifier::BOLD),
         )]),
         Line::from(""),
@@ -79,10 +86,10 @@ pub fn render_loading_indicator(frame: &mut Frame, area: Rect, spinner_frame: us
             Span::styled(
                 "Loading",
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(theme.warning)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled("...", Style::default().fg(Color::DarkGray)),
+            Span::styled("...", Style::default().fg(theme.muted)),
         ]),
         Line::from(""),
     ];
@@ -92,8 +99,8 @@ pub fn render_loading_indicator(frame: &mut Frame, area: Rect, spinner_frame: us
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan))
-                .style(Style::default()),
+                .border_style(Style::default().fg(theme.accent))
+                .style(theme.base()),
         );
 
     // Center both vertically and horizontally
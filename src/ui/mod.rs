@@ -2,5 +2,6 @@ pub mod breadcrumb;
 pub mod layout;
 pub mod loading;
 pub mod statusbar;
+pub mod terminal;
 pub mod theme;
 pub mod toast;
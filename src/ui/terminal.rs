@@ -0,0 +1,354 @@
+// What the terminal can display, and fitting a rendered frame to it
+use ratatui::{
+    buffer::Buffer,
+    style::{Color, Modifier},
+};
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::ui::loading::SPINNER_FRAMES;
+
+/// How many colors the terminal shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorSupport {
+    /// `NO_COLOR` or a dumb terminal: no colors at all
+    None,
+    /// The 16 ANSI colors
+    Basic,
+    /// The xterm 256-color palette
+    Ansi256,
+    /// 24-bit RGB
+    TrueColor,
+}
+
+/// Colors and glyphs the terminal supports, detected once at startup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+    pub colors: ColorSupport,
+    /// Whether non-ASCII glyphs (●, ⏸, the braille spinner, box borders) show
+    pub unicode: bool,
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self {
+            colors: ColorSupport::TrueColor,
+            unicode: true,
+        }
+    }
+}
+
+impl Capabilities {
+    pub fn detect() -> Self {
+        Self::from_env(|name| std::env::var(name).ok())
+    }
+
+    /// Capabilities from the environment variables `var` returns:
+    /// - `NO_COLOR` (non-empty) or `TERM=dumb` turn colors off
+    /// - `COLORTERM=truecolor|24bit` (or Windows Terminal) means 24-bit colors,
+    ///   a `TERM` ending in `256color` the 256-color palette, anything else the
+    ///   16 basic colors
+    /// - the first of `LC_ALL`, `LC_CTYPE` and `LANG` that is set decides
+    ///   whether glyphs are UTF-8; the Linux console and dumb terminals get
+    ///   ASCII
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Self {
+        let set = |name: &str| var(name).filter(|value| !value.is_empty());
+        let term = set("TERM").unwrap_or_default().to_lowercase();
+        let colorterm = set("COLORTERM").unwrap_or_default().to_lowercase();
+
+        let colors = if set("NO_COLOR").is_some() || term == "dumb" {
+            ColorSupport::None
+        } else if colorterm == "truecolor"
+            || colorterm == "24bit"
+            || term.contains("truecolor")
+            || term.contains("direct")
+            || set("WT_SESSION").is_some()
+        {
+            ColorSupport::TrueColor
+        } else if term.contains("256color") {
+            ColorSupport::Ansi256
+        } else {
+            ColorSupport::Basic
+        };
+
+        let unicode = if term == "dumb" || term == "linux" {
+            false
+        } else {
+            match ["LC_ALL", "LC_CTYPE", "LANG"]
+                .iter()
+                .find_map(|name| set(name))
+            {
+                Some(locale) => {
+                    let locale = locale.to_lowercase();
+                    locale.contains("utf-8") || locale.contains("utf8")
+                }
+                // No locale at all: most terminals are UTF-8 anyway
+                None => true,
+            }
+        };
+
+        Self { colors, unicode }
+    }
+
+    /// Rewrite a rendered frame for this terminal: colors downgraded to the
+    /// palette it has, glyphs it can't show replaced with ASCII. Without colors,
+    /// cells drawn on a background other than `base_bg` (the selected row,
+    /// highlights, badges) are shown reversed so they still stand out.
+    pub fn adapt(&self, buffer: &mut Buffer, base_bg: Color) {
+        if self.colors == ColorSupport::TrueColor && self.unicode {
+            return;
+        }
+        for cell in buffer.content.iter_mut() {
+            if self.colors == ColorSupport::None {
+                if cell.bg != Color::Reset && cell.bg != base_bg {
+                    cell.modifier.insert(Modifier::REVERSED);
+                }
+                cell.fg = Color::Reset;
+                cell.bg = Color::Reset;
+            } else {
+                cell.fg = self.colors.downgrade(cell.fg);
+                cell.bg = self.colors.downgrade(cell.bg);
+            }
+            if !self.unicode
+                && !cell.symbol().is_ascii()
+                && let Some(ascii) = ascii(cell.symbol())
+            {
+                cell.set_symbol(ascii);
+            }
+        }
+    }
+}
+
+/// A drawn buffer as plain text, one line per row without trailing spaces
+/// (`--print` and snapshots)
+pub fn text(buffer: &Buffer) -> String {
+    let mut lines = Vec::new();
+    for row in buffer.content.chunks(buffer.area.width.max(1) as usize) {
+        let mut line = String::new();
+        // Cells a wide glyph covers hold a blank that isn't shown
+        let mut covered = 0;
+        for cell in row {
+            if covered > 0 {
+                covered -= 1;
+                continue;
+            }
+            line.push_str(cell.symbol());
+            covered = cell.symbol().width().saturating_sub(1);
+        }
+        lines.push(line.trim_end().to_string());
+    }
+    while lines.last().is_some_and(String::is_empty) {
+        lines.pop();
+    }
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+impl ColorSupport {
+    /// The closest color this terminal can show
+    pub fn downgrade(self, color: Color) -> Color {
+        match (self, color) {
+            (Self::TrueColor, _) | (_, Color::Reset) => color,
+            (Self::None, _) => Color::Reset,
+            (Self::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(nearest_256(r, g, b)),
+            (Self::Ansi256, _) => color,
+            (Self::Basic, Color::Rgb(r, g, b)) => nearest_basic(r, g, b),
+            (Self::Basic, Color::Indexed(n)) if n < 16 => BASIC[n as usize].0,
+            (Self::Basic, Color::Indexed(n)) => {
+                let (r, g, b) = indexed_rgb(n);
+                nearest_basic(r, g, b)
+            }
+            (Self::Basic, _) => color,
+        }
+    }
+}
+
+/// The 16 ANSI colors with xterm's default RGB values, in palette order
+const BASIC: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Channel values of the 6x6x6 color cube (indexes 16-231)
+const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+    d(r1, r2) + d(g1, g2) + d(b1, b2)
+}
+
+fn nearest_basic(r: u8, g: u8, b: u8) -> Color {
+    BASIC
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb, (r, g, b)))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::Reset)
+}
+
+/// The cube color or gray (232-255) closest to an RGB color
+fn nearest_256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |c: u8| {
+        (0..CUBE.len())
+            .min_by_key(|&i| (CUBE[i] as i32 - c as i32).abs())
+            .unwrap_or(0) as u8
+    };
+    let cube = 16 + 36 * level(r) + 6 * level(g) + level(b);
+    let average = ((r as u32 + g as u32 + b as u32) / 3) as i32;
+    let gray = 232 + ((average - 8).clamp(0, 230) / 10) as u8;
+    if distance(indexed_rgb(gray), (r, g, b)) < distance(indexed_rgb(cube), (r, g, b)) {
+        gray
+    } else {
+        cube
+    }
+}
+
+fn indexed_rgb(n: u8) -> (u8, u8, u8) {
+    match n {
+        0..16 => BASIC[n as usize].1,
+        16..232 => {
+            let n = n - 16;
+            (
+                CUBE[(n / 36) as usize],
+                CUBE[(n / 6 % 6) as usize],
+                CUBE[(n % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + 10 * (n - 232);
+            (level, level, level)
+        }
+    }
+}
+
+/// An ASCII stand-in for a glyph the UI draws, if there is one
+fn ascii(symbol: &str) -> Option<&'static str> {
+    let c = symbol.chars().next()?;
+    if let Some(idx) = SPINNER_FRAMES.iter().position(|&frame| frame == c) {
+        return Some(["|", "/", "-", "\\"][idx % 4]);
+    }
+    Some(match c {
+        '─' | '━' | '═' | '┄' | '┈' | '╌' => "-",
+        '│' | '┃' | '║' | '┆' | '┊' | '╎' => "|",
+        '┌' | '┐' | '└' | '┘' | '├' | '┤' | '┬' | '┴' | '┼' | '╭' | '╮' | '╰' | '╯' | '╔' | '╗'
+        | '╚' | '╝' | '┏' | '┓' | '┗' | '┛' => "+",
+        '●' | '•' | '◆' | '★' => "*",
+        '○' | '◦' => "o",
+        '⏸' => "=",
+        '⏹' | '■' => "#",
+        '▶' | '▸' | '►' | '→' | '›' | '»' => ">",
+        '◀' | '◂' | '◄' | '←' | '‹' | '«' => "<",
+        '▲' | '↑' => "^",
+        '▼' | '↓' => "v",
+        '✓' | '✔' => "+",
+        '✗' | '✘' | '×' => "x",
+        '⚠' => "!",
+        'ℹ' => "i",
+        '⟳' | '↻' | '🔍' => "@",
+        '…' | '·' => ".",
+        '█' | '▉' | '▊' | '▋' | '▌' | '▍' | '▎' | '▏' | '▇' | '▆' | '▅' | '▄' | '▓' => {
+            "#"
+        }
+        '▃' | '▂' | '▁' => "_",
+        '▒' | '░' => ":",
+        // Chart points
+        '\u{2801}'..='\u{28ff}' => ".",
+        '\u{2800}' => " ",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::style::Style;
+
+    fn env(vars: &[(&str, &str)]) -> Capabilities {
+        Capabilities::from_env(|name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        })
+    }
+
+    #[test]
+    fn test_from_env() {
+        let caps = env(&[("TERM", "xterm-256color"), ("LANG", "en_US.UTF-8")]);
+        assert_eq!(caps.colors, ColorSupport::Ansi256);
+        assert!(caps.unicode);
+
+        let caps = env(&[("TERM", "xterm-256color"), ("COLORTERM", "truecolor")]);
+        assert_eq!(caps, Capabilities::default());
+
+        let caps = env(&[("TERM", "xterm"), ("NO_COLOR", "1"), ("LC_ALL", "C")]);
+        assert_eq!(caps.colors, ColorSupport::None);
+        assert!(!caps.unicode);
+        // An empty NO_COLOR doesn't count
+        assert_eq!(env(&[("NO_COLOR", "")]).colors, ColorSupport::Basic);
+        // LC_ALL wins over LANG
+        assert!(!env(&[("LC_ALL", "POSIX"), ("LANG", "en_US.utf8")]).unicode);
+        assert!(!env(&[("TERM", "linux")]).unicode);
+    }
+
+    #[test]
+    fn test_downgrade() {
+        let orange = Color::Rgb(255, 135, 0);
+        assert_eq!(ColorSupport::TrueColor.downgrade(orange), orange);
+        assert_eq!(ColorSupport::Ansi256.downgrade(orange), Color::Indexed(208));
+        assert_eq!(
+            ColorSupport::Ansi256.downgrade(Color::Rgb(48, 48, 48)),
+            Color::Indexed(236)
+        );
+        assert_eq!(ColorSupport::Basic.downgrade(orange), Color::Yellow);
+        assert_eq!(
+            ColorSupport::Basic.downgrade(Color::Indexed(9)),
+            Color::LightRed
+        );
+        assert_eq!(
+            ColorSupport::Basic.downgrade(Color::Indexed(236)),
+            Color::Black
+        );
+        assert_eq!(ColorSupport::Basic.downgrade(Color::Cyan), Color::Cyan);
+        assert_eq!(ColorSupport::None.downgrade(Color::Cyan), Color::Reset);
+    }
+
+    #[test]
+    fn test_adapt() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        buffer.set_string(0, 0, "●⠙│", Style::default().fg(Color::Green));
+        buffer.set_string(3, 0, "x", Style::default().bg(Color::DarkGray));
+
+        let caps = Capabilities {
+            colors: ColorSupport::None,
+            unicode: false,
+        };
+        caps.adapt(&mut buffer, Color::Reset);
+        let symbols: Vec<&str> = buffer.content.iter().map(|cell| cell.symbol()).collect();
+        assert_eq!(symbols, ["*", "/", "|", "x"]);
+        assert_eq!(buffer.content[0].fg, Color::Reset);
+        assert!(!buffer.content[0].modifier.contains(Modifier::REVERSED));
+        assert_eq!(buffer.content[3].bg, Color::Reset);
+        assert!(buffer.content[3].modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn test_text() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 3));
+        buffer.set_string(0, 0, "名前 ok", Style::default());
+        buffer.set_string(1, 1, "x", Style::default());
+        assert_eq!(text(&buffer), "名前 o\n x\n");
+    }
+}
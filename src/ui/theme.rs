@@ -1,2 +1,380 @@
-/// Theme configuration (to be implemented)
-pub struct Theme;
+// Colors the UI is drawn with, by role, resolved once from `app.theme`
+use ratatui::style::{Color, Style};
+use ratatui::widgets::{Block, Borders};
+use std::collections::HashMap;
+
+use crate::config::{BUILTIN_THEMES, Config, ThemePalette, View, builtin_theme};
+use crate::error::{Result, TermStackError};
+use crate::view::style::parse_color;
+
+/// The color roles a theme sets
+pub const ROLES: &[&str] = &[
+    "fg",
+    "bg",
+    "dim",
+    "muted",
+    "border",
+    "accent",
+    "secondary",
+    "heading",
+    "selected",
+    "highlight",
+    "inverse",
+    "success",
+    "warning",
+    "error",
+    "info",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub name: String,
+    /// Text
+    pub fg: Color,
+    /// Screen and popup background
+    pub bg: Color,
+    /// Secondary text: labels, values in panels, inactive fields
+    pub dim: Color,
+    /// Hints, placeholders, empty gauge tracks and unfocused borders
+    pub muted: Color,
+    /// Borders of the header, content and status bar
+    pub border: Color,
+    /// Titles, key hints, focused borders and the menu selection
+    pub accent: Color,
+    /// A second accent, for actions and chart series
+    pub secondary: Color,
+    /// Table headers, the search input and the focused form field
+    pub heading: Color,
+    /// Background of the selected table row
+    pub selected: Color,
+    /// Background of search matches and changed cells
+    pub highlight: Color,
+    /// Text drawn on accent, highlight or status colors
+    pub inverse: Color,
+    pub success: Color,
+    pub warning: Color,
+    pub error: Color,
+    pub info: Color,
+}
+
+impl Theme {
+    /// The theme `app.theme` names: a built-in, or one under `themes`
+    pub fn from_config(config: &Config) -> Result<Self> {
+        Self::named(&config.app.theme, &config.themes)
+    }
+
+    /// A built-in theme or one of `custom` by name
+    pub fn named(name: &str, custom: &HashMap<String, ThemePalette>) -> Result<Self> {
+        let colors = resolve(name, custom, &mut Vec::new())?;
+        Self::from_colors(name, &colors)
+    }
+
+    /// This theme with a page's or panel's overrides applied: `base` (if set)
+    /// replaces it, then each color, which may name a role of the base
+    pub fn with_overrides(
+        &self,
+        overrides: &ThemePalette,
+        custom: &HashMap<String, ThemePalette>,
+    ) -> Result<Self> {
+        let mut theme = match &overrides.base {
+            Some(base) => Self::named(base, custom)?,
+            None => self.clone(),
+        };
+        let base = theme.clone();
+        for (role, value) in &overrides.colors {
+            let color = base.color(value).ok_or_else(|| {
+                TermStackError::Config(format!("Invalid color '{}' for '{}'", value, role))
+            })?;
+            *theme.role_mut(role)? = color;
+        }
+        Ok(theme)
+    }
+
+    /// A color as written in the config: a role of this theme (`success`,
+    /// `accent`, ...) or anything [`parse_color`] reads
+    pub fn color(&self, name: &str) -> Option<Color> {
+        let color = match name.trim() {
+            "fg" => self.fg,
+            "bg" => self.bg,
+            "dim" => self.dim,
+            "muted" => self.muted,
+            "border" => self.border,
+            "accent" => self.accent,
+            "secondary" => self.secondary,
+            "heading" => self.heading,
+            "selected" => self.selected,
+            "highlight" => self.highlight,
+            "inverse" => self.inverse,
+            "success" => self.success,
+            "warning" => self.warning,
+            "error" => self.error,
+            "info" => self.info,
+            other => return parse_color(other),
+        };
+        Some(color)
+    }
+
+    fn role_mut(&mut self, role: &str) -> Result<&mut Color> {
+        Ok(match role {
+            "fg" => &mut self.fg,
+            "bg" => &mut self.bg,
+            "dim" => &mut self.dim,
+            "muted" => &mut self.muted,
+            "border" => &mut self.border,
+            "accent" => &mut self.accent,
+            "secondary" => &mut self.secondary,
+            "heading" => &mut self.heading,
+            "selected" => &mut self.selected,
+            "highlight" => &mut self.highlight,
+            "inverse" => &mut self.inverse,
+            "success" => &mut self.success,
+            "warning" => &mut self.warning,
+            "error" => &mut self.error,
+            "info" => &mut self.info,
+            other => return Err(unknown_role(other)),
+        })
+    }
+
+    /// A theme from colors by role; every role must be set
+    pub fn from_colors(name: &str, colors: &HashMap<String, String>) -> Result<Self> {
+        if let Some(role) = colors.keys().find(|role| !ROLES.contains(&role.as_str())) {
+            return Err(unknown_role(role));
+        }
+        let color = |role: &str| {
+            let value = colors.get(role).ok_or_else(|| {
+                TermStackError::Config(format!("Theme '{}' has no '{}' color", name, role))
+            })?;
+            parse_color(value).ok_or_else(|| {
+                TermStackError::Config(format!("Invalid color '{}' for '{}'", value, role))
+            })
+        };
+        Ok(Self {
+            name: name.to_string(),
+            fg: color("fg")?,
+            bg: color("bg")?,
+            dim: color("dim")?,
+            muted: color("muted")?,
+            border: color("border")?,
+            accent: color("accent")?,
+            secondary: color("secondary")?,
+            heading: color("heading")?,
+            selected: color("selected")?,
+            highlight: color("highlight")?,
+            inverse: color("inverse")?,
+            success: color("success")?,
+            warning: color("warning")?,
+            error: color("error")?,
+            info: color("info")?,
+        })
+    }
+
+    /// Text in `fg` on `bg`, what the screen is cleared to
+    pub fn base(&self) -> Style {
+        Style::default().fg(self.fg).bg(self.bg)
+    }
+
+    /// A bordered block in the `border` color, for the header, content and
+    /// status bar
+    pub fn block(&self) -> Block<'static> {
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.border))
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        let colors = crate::config::default_theme().colors;
+        Self::from_colors("default", &colors).expect("the default theme parses")
+    }
+}
+
+/// The app's theme and those of the pages and dashboard panels that override
+/// it, resolved once
+#[derive(Debug, Clone, Default)]
+pub struct Themes {
+    pub app: Theme,
+    pages: HashMap<String, Theme>,
+    panels: HashMap<(String, usize), Theme>,
+}
+
+impl Themes {
+    pub fn from_config(config: &Config) -> Result<Self> {
+        let app = Theme::from_config(config)?;
+        let mut themes = Self {
+            app,
+            ..Default::default()
+        };
+        for (page_id, page) in &config.pages {
+            let page_theme = match &page.theme {
+                Some(overrides) => themes.app.with_overrides(overrides, &config.themes)?,
+                None => themes.app.clone(),
+            };
+            if let View::Dashboard(dashboard) = &page.view {
+                for (idx, panel) in dashboard.panels.iter().enumerate() {
+                    if let Some(overrides) = &panel.theme {
+                        let theme = page_theme.with_overrides(overrides, &config.themes)?;
+                        themes.panels.insert((page_id.clone(), idx), theme);
+                    }
+                }
+            }
+            if page.theme.is_some() {
+                themes.pages.insert(page_id.clone(), page_theme);
+            }
+        }
+        Ok(themes)
+    }
+
+    /// The theme `page_id` is drawn with
+    pub fn page(&self, page_id: &str) -> &Theme {
+        self.pages.get(page_id).unwrap_or(&self.app)
+    }
+
+    /// The theme of panel `idx` of dashboard page `page_id`
+    pub fn panel(&self, page_id: &str, idx: usize) -> &Theme {
+        self.panels
+            .get(&(page_id.to_string(), idx))
+            .unwrap_or_else(|| self.page(page_id))
+    }
+}
+
+/// Whether `name` is a color a config may use: a theme role or a color
+pub fn is_color(name: &str) -> bool {
+    ROLES.contains(&name.trim()) || parse_color(name).is_some()
+}
+
+fn unknown_role(role: &str) -> TermStackError {
+    TermStackError::Config(format!(
+        "Unknown theme color '{}' (one of: {})",
+        role,
+        ROLES.join(", ")
+    ))
+}
+
+/// Colors of theme `name`, following custom themes' `base` down to a built-in
+fn resolve(
+    name: &str,
+    custom: &HashMap<String, ThemePalette>,
+    seen: &mut Vec<String>,
+) -> Result<HashMap<String, String>> {
+    if seen.iter().any(|s| s == name) {
+        seen.push(name.to_string());
+        return Err(TermStackError::Config(format!(
+            "Theme bases form a cycle: {}",
+            seen.join(" -> ")
+        )));
+    }
+    if let Some(palette) = custom.get(name) {
+        seen.push(name.to_string());
+        let mut colors = resolve(palette.base.as_deref().unwrap_or("default"), custom, seen)?;
+        colors.extend(palette.colors.clone());
+        return Ok(colors);
+    }
+    builtin_theme(name)
+        .map(|theme| theme.colors)
+        .ok_or_else(|| {
+            TermStackError::Config(format!(
+                "Unknown theme '{}' (built in: {}; or one under themes)",
+                name,
+                BUILTIN_THEMES.join(", ")
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigLoader;
+
+    fn config(theme: &str, themes: &str) -> Config {
+        let yaml = format!(
+            "version: v1\napp: {{name: T, theme: {}}}\nstart: main\n{}\n\
+             pages: {{main: {{title: Main, view: {{type: text}}}}}}",
+            theme, themes
+        );
+        ConfigLoader::load_from_string(&yaml).unwrap()
+    }
+
+    #[test]
+    fn test_builtin_themes() {
+        for name in BUILTIN_THEMES {
+            let theme = Theme::from_config(&config(name, "")).unwrap();
+            assert_eq!(theme.name, *name);
+        }
+        let theme = Theme::default();
+        assert_eq!((theme.fg, theme.accent), (Color::Reset, Color::Cyan));
+        assert_eq!(
+            Theme::from_config(&config("solarized", "")).unwrap().bg,
+            Color::Rgb(0x00, 0x2b, 0x36)
+        );
+    }
+
+    #[test]
+    fn test_custom_theme() {
+        let themes = "themes:\n  ocean: {base: dark, colors: {accent: '#00afaf'}}\n  \
+                      deep: {base: ocean, colors: {bg: black}}";
+        let theme = Theme::from_config(&config("deep", themes)).unwrap();
+        assert_eq!(theme.accent, Color::Rgb(0x00, 0xaf, 0xaf));
+        assert_eq!(theme.bg, Color::Black);
+        assert_eq!(theme.fg, Color::Rgb(0xe4, 0xe4, 0xe4));
+        // Without a base, the rest comes from the default theme
+        let theme = Theme::from_config(&config("plain", "themes: {plain: {}}")).unwrap();
+        assert_eq!(theme.accent, Color::Cyan);
+
+        let error = |theme: &str, themes: &str| {
+            Theme::from_config(&config(theme, themes))
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(error("nope", "").contains("Unknown theme 'nope'"));
+        assert!(error("a", "themes: {a: {colors: {acent: red}}}").contains("'acent'"));
+        assert!(error("a", "themes: {a: {colors: {fg: bleu}}}").contains("'bleu' for 'fg'"));
+        assert!(error("a", "themes: {a: {base: b}, b: {base: a}}").contains("cycle: a -> b -> a"));
+    }
+
+    #[test]
+    fn test_page_themes() {
+        let yaml = r##"version: v1
+app: {name: T, theme: dark}
+start: main
+pages:
+  main:
+    title: Main
+    view: {type: text}
+    theme:
+      colors: {border: error, selected: "#303030"}
+  light:
+    title: Light
+    view: {type: text}
+    theme: {base: light}
+  board:
+    title: Board
+    theme: {colors: {accent: red}}
+    view:
+      type: dashboard
+      panels:
+        - title: One
+          data: {adapter: cli, command: date}
+          view: {type: text}
+          theme: {colors: {border: accent}}
+        - title: Two
+          data: {adapter: cli, command: date}
+          view: {type: text}
+"##;
+        let config = crate::config::ConfigLoader::load_from_string(yaml).unwrap();
+        let themes = Themes::from_config(&config).unwrap();
+        let dark = Theme::named("dark", &HashMap::new()).unwrap();
+        assert_eq!(themes.page("main").border, dark.error);
+        assert_eq!(themes.page("main").selected, Color::Rgb(0x30, 0x30, 0x30));
+        assert_eq!(themes.page("main").accent, dark.accent);
+        assert_eq!(themes.page("light").name, "light");
+        assert_eq!(themes.page("nowhere"), &themes.app);
+        // A panel starts from its page's theme
+        assert_eq!(themes.panel("board", 0).border, Color::Red);
+        assert_eq!(themes.panel("board", 1), themes.page("board"));
+
+        assert_eq!(dark.color("warning"), Some(dark.warning));
+        assert_eq!(dark.color("red"), Some(Color::Red));
+        assert!(is_color("success") && !is_color("sucess"));
+    }
+}
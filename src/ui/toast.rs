@@ -1,2 +1,281 @@
-/// Toast notification manager (to be implemented)
-pub struct ToastManager;
+// Toast notifications: a stack of timed messages plus the history behind it
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::config::{NotificationOptions, ToastPosition};
+use crate::ui::theme::Theme;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastKind {
+    Success,
+    Error,
+    Info,
+    Warning,
+}
+
+impl ToastKind {
+    pub fn icon(self) -> &'static str {
+        match self {
+            ToastKind::Success => "\u{2713}",
+            ToastKind::Error => "\u{2717}",
+            ToastKind::Info => "\u{2139}",
+            ToastKind::Warning => "\u{26a0}",
+        }
+    }
+
+    pub fn color(self, theme: &Theme) -> ratatui::style::Color {
+        match self {
+            ToastKind::Success => theme.success,
+            ToastKind::Error => theme.error,
+            ToastKind::Info => theme.info,
+            ToastKind::Warning => theme.warning,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub message: String,
+    pub kind: ToastKind,
+    /// Times the same message came in a row; shown as `(x3)`
+    pub count: usize,
+    /// When it was (last) raised, for the timeout
+    pub raised: Instant,
+    /// Wall-clock time, for the history
+    pub time: chrono::DateTime<chrono::Local>,
+}
+
+impl Toast {
+    fn text(&self) -> String {
+        if self.count > 1 {
+            format!("{} (x{})", self.message, self.count)
+        } else {
+            self.message.clone()
+        }
+    }
+}
+
+/// Toasts on screen, newest last, and every notification raised (up to the
+/// history size), newest first
+pub struct ToastManager {
+    position: ToastPosition,
+    max_visible: usize,
+    /// Timeouts by `ToastKind`
+    timeouts: [Duration; 4],
+    history_size: usize,
+    active: VecDeque<Toast>,
+    history: VecDeque<Toast>,
+}
+
+impl ToastManager {
+    /// Toasts per `app.notifications` (whose timeouts the validator checked;
+    /// ones that don't parse get the default)
+    pub fn new(options: Option<&NotificationOptions>) -> Self {
+        let options = options.cloned().unwrap_or_default();
+        let timeout = |value: &Option<String>, default: u64| {
+            value
+                .as_deref()
+                .and_then(|t| humantime::parse_duration(t).ok())
+                .unwrap_or(Duration::from_secs(default))
+        };
+        Self {
+            position: options.position,
+            max_visible: options.max_visible.unwrap_or(3).max(1),
+            timeouts: [
+                timeout(&options.timeouts.success, 3),
+                timeout(&options.timeouts.error, 8),
+                timeout(&options.timeouts.info, 3),
+                timeout(&options.timeouts.warning, 5),
+            ],
+            history_size: options.history.unwrap_or(100),
+            active: VecDeque::new(),
+            history: VecDeque::new(),
+        }
+    }
+
+    /// Show a message. One repeating the newest toast bumps its count (and
+    /// restarts its timeout) instead of stacking a copy.
+    pub fn push(&mut self, message: impl Into<String>, kind: ToastKind) {
+        let toast = Toast {
+            message: message.into(),
+            kind,
+            count: 1,
+            raised: Instant::now(),
+            time: chrono::Local::now(),
+        };
+
+        match self.active.back_mut() {
+            Some(last) if last.message == toast.message && last.kind == kind => {
+                last.count += 1;
+                last.raised = toast.raised;
+            }
+            _ => {
+                self.active.push_back(toast.clone());
+                while self.active.len() > self.max_visible {
+                    self.active.pop_front();
+                }
+            }
+        }
+
+        match self.history.front_mut() {
+            Some(last) if last.message == toast.message && last.kind == kind => {
+                last.count += 1;
+                last.time = toast.time;
+            }
+            _ => {
+                self.history.push_front(toast);
+                self.history.truncate(self.history_size);
+            }
+        }
+    }
+
+    /// Drop toasts whose time is up; true if any were
+    pub fn expire(&mut self, now: Instant) -> bool {
+        let before = self.active.len();
+        let timeouts = self.timeouts;
+        self.active
+            .retain(|toast| now.duration_since(toast.raised) < timeouts[toast.kind as usize]);
+        self.active.len() != before
+    }
+
+    pub fn active(&self) -> impl Iterator<Item = &Toast> {
+        self.active.iter()
+    }
+
+    /// Notifications raised so far, newest first
+    pub fn history(&self) -> impl Iterator<Item = &Toast> {
+        self.history.iter()
+    }
+
+    pub fn history_len(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Stack the toasts in their corner of `area`, newest nearest the edge
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let width = 50.min(area.width.saturating_sub(2));
+        if width < 10 {
+            return;
+        }
+        let top = matches!(
+            self.position,
+            ToastPosition::TopRight | ToastPosition::TopLeft
+        );
+        let x = match self.position {
+            ToastPosition::TopRight | ToastPosition::BottomRight => area.x + area.width - width - 1,
+            ToastPosition::TopLeft | ToastPosition::BottomLeft => area.x + 1,
+        };
+
+        let mut offset = 1;
+        for toast in self.active.iter().rev() {
+            if offset + 3 > area.height {
+                break;
+            }
+            let y = if top {
+                area.y + offset
+            } else {
+                area.y + area.height - offset - 3
+            };
+            offset += 3;
+
+            let color = toast.kind.color(theme);
+            let text = crate::util::text::truncate(
+                &format!("{} {}", toast.kind.icon(), toast.text()),
+                width.saturating_sub(2) as usize,
+            );
+            let toast_area = Rect::new(x, y, width, 3);
+            frame.render_widget(Clear, toast_area);
+            frame.render_widget(
+                Paragraph::new(Line::from(Span::styled(
+                    text,
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                )))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(color))
+                        .style(theme.base()),
+                ),
+                toast_area,
+            );
+        }
+    }
+
+    /// Lines of the history overlay, newest first
+    pub fn history_lines(&self, theme: &Theme) -> Vec<Line<'static>> {
+        self.history
+            .iter()
+            .map(|toast| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{} ", toast.time.format("%H:%M:%S")),
+                        Style::default().fg(theme.muted),
+                    ),
+                    Span::styled(
+                        format!("{} ", toast.kind.icon()),
+                        Style::default().fg(toast.kind.color(theme)),
+                    ),
+                    Span::styled(toast.text(), Style::default().fg(theme.fg)),
+                ])
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ToastTimeouts;
+
+    #[test]
+    fn test_stack() {
+        let options = NotificationOptions {
+            max_visible: Some(2),
+            history: Some(3),
+            ..Default::default()
+        };
+        let mut toasts = ToastManager::new(Some(&options));
+        toasts.push("one", ToastKind::Success);
+        toasts.push("two", ToastKind::Error);
+        toasts.push("two", ToastKind::Error);
+        toasts.push("three", ToastKind::Info);
+
+        let active: Vec<String> = toasts.active().map(Toast::text).collect();
+        assert_eq!(active, ["two (x2)", "three"]);
+        let history: Vec<String> = toasts.history().map(Toast::text).collect();
+        assert_eq!(history, ["three", "two (x2)", "one"]);
+
+        toasts.push("four", ToastKind::Info);
+        assert_eq!(toasts.history_len(), 3);
+    }
+
+    #[test]
+    fn test_expire() {
+        let options = NotificationOptions {
+            timeouts: ToastTimeouts {
+                error: Some("10s".into()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut toasts = ToastManager::new(Some(&options));
+        toasts.push("saved", ToastKind::Success);
+        toasts.push("failed", ToastKind::Error);
+
+        assert!(!toasts.expire(Instant::now()));
+        assert!(toasts.expire(Instant::now() + Duration::from_secs(4)));
+        let active: Vec<&str> = toasts.active().map(|t| t.message.as_str()).collect();
+        assert_eq!(active, ["failed"]);
+        assert!(toasts.expire(Instant::now() + Duration::from_secs(11)));
+        assert_eq!(toasts.active().count(), 0);
+        assert_eq!(toasts.history_len(), 2);
+    }
+}
@@ -0,0 +1,96 @@
+use std::path::PathBuf;
+use std::process::Stdio;
+
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+/// A path with a leading `~/` taken from `$HOME`
+pub fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Write `text` to a file, creating its directory if needed
+pub fn write_file(path: &std::path::Path, text: &str) -> std::io::Result<()> {
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, text)
+}
+
+/// Pipe `text` into a shell command (`cmd /C` on Windows) and wait for it,
+/// failing on a non-zero exit with the command's stderr. Input is written
+/// while stderr is read, so a chatty command can't block on either pipe.
+pub async fn pipe_to_command(command: &str, text: String) -> std::io::Result<()> {
+    let mut cmd = if cfg!(target_os = "windows") {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C");
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c");
+        cmd
+    };
+    let mut child = cmd
+        .arg(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()?;
+    let stdin = child.stdin.take();
+    let write = async move {
+        if let Some(mut stdin) = stdin {
+            // A command that stops reading early is not an error of ours
+            let _ = stdin.write_all(text.as_bytes()).await;
+        }
+    };
+    let ((), output) = tokio::join!(write, child.wait_with_output());
+    let output = output?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(std::io::Error::other(match stderr.trim() {
+            "" => format!("exited with {}", output.status),
+            message => message.to_string(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_home() {
+        assert_eq!(expand_home("/tmp/a.log"), PathBuf::from("/tmp/a.log"));
+        assert_eq!(expand_home("a.log"), PathBuf::from("a.log"));
+        if let Some(home) = std::env::var_os("HOME") {
+            assert_eq!(expand_home("~/logs/a.log"), PathBuf::from(home).join("logs/a.log"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_write_and_pipe() {
+        let dir = std::env::temp_dir().join(format!("termstack-export-{}", std::process::id()));
+        let path = dir.join("nested/out.log");
+        write_file(&path, "one\ntwo\n").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "one\ntwo\n");
+
+        let copy = dir.join("copy.log");
+        let command = format!("cat > '{}'", copy.display());
+        pipe_to_command(&command, "piped\n".to_string()).await.unwrap();
+        assert_eq!(std::fs::read_to_string(&copy).unwrap(), "piped\n");
+        let err = pipe_to_command("echo nope >&2; exit 3", String::new()).await.unwrap_err();
+        assert_eq!(err.to_string(), "nope");
+
+        // More input than a pipe buffers, and more stderr, without deadlocking
+        let text = "x".repeat(1 << 20);
+        let err = pipe_to_command("head -c 200000 /dev/zero >&2; exit 1", text).await.unwrap_err();
+        assert_eq!(err.to_string().len(), 200000);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,70 @@
+// Fuzzy matching for the command palette
+
+/// Score `text` against a fuzzy `pattern`, or None if the pattern's characters
+/// don't all appear in order. Matching is case-insensitive; higher is better.
+///
+/// Consecutive matches and matches at word starts score extra, so `gp` ranks
+/// "goto pods" above "group", and shorter texts win ties.
+pub fn score(pattern: &str, text: &str) -> Option<i64> {
+    let pattern: Vec<char> = pattern
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if pattern.is_empty() {
+        return Some(0);
+    }
+
+    let mut score = 0i64;
+    let mut next = 0;
+    let mut prev_matched = false;
+    let mut prev_char: Option<char> = None;
+
+    for ch in text.chars() {
+        let lower = ch.to_lowercase().next().unwrap_or(ch);
+        if next < pattern.len() && lower == pattern[next] {
+            score += 1;
+            if prev_matched {
+                score += 5;
+            }
+            let word_start = prev_char.is_none_or(|p| !p.is_alphanumeric())
+                || (ch.is_uppercase() && prev_char.is_some_and(|p| p.is_lowercase()));
+            if word_start {
+                score += 10;
+            }
+            next += 1;
+            prev_matched = true;
+        } else {
+            prev_matched = false;
+        }
+        prev_char = Some(ch);
+    }
+
+    (next == pattern.len()).then(|| score * 100 - text.chars().count() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score_matches() {
+        assert!(score("pod", "Pods").is_some());
+        assert!(score("gp", "goto pods").is_some());
+        assert!(score("PDS", "pods").is_some());
+        assert!(score("spod", "pods").is_none());
+        assert_eq!(score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn test_score_ranking() {
+        // Word starts beat a shorter text with mid-word matches
+        assert!(score("gp", "goto pods").unwrap() > score("gp", "group").unwrap());
+
+        // Consecutive matches beat scattered ones
+        assert!(score("dep", "undeploy").unwrap() > score("dep", "undo step").unwrap());
+
+        // Shorter text wins ties
+        assert!(score("log", "logs").unwrap() > score("log", "logs-archive").unwrap());
+    }
+}
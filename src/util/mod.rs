@@ -1 +1,8 @@
-// Utility modules (to be implemented in future phases)
+// Utility modules
+pub mod clipboard;
+pub mod export;
+pub mod fuzzy;
+pub mod open;
+pub mod session;
+pub mod state;
+pub mod text;
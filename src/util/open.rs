@@ -0,0 +1,87 @@
+use std::process::{Command, Stdio};
+
+/// Open a URL with the platform's default handler.
+///
+/// The opener is spawned with all stdio discarded so it cannot draw over
+/// the TUI, and reaped from a background thread once it exits. On Windows
+/// the URL goes to `rundll32` as a plain argument rather than through
+/// `cmd`, which would interpret `&`, `|` and `^` in it.
+pub fn open_url(url: &str) -> std::io::Result<()> {
+    let mut cmd = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(target_os = "windows") {
+        let mut cmd = Command::new("rundll32");
+        cmd.arg("url.dll,FileProtocolHandler");
+        cmd
+    } else {
+        Command::new("xdg-open")
+    };
+
+    let mut child = cmd
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    std::thread::spawn(move || child.wait());
+    Ok(())
+}
+
+/// A rendered link made safe to open or emit in an OSC 8 escape: trimmed,
+/// with control characters (C0, DEL and C1) percent-encoded. `None` unless it
+/// starts with a URI scheme (`https:`, `file:`, ...), so text that isn't a
+/// URL is never handed to the opener or the terminal.
+pub fn sanitize_url(url: &str) -> Option<String> {
+    let url = url.trim();
+    let scheme = url.split_once(':')?.0;
+    let mut chars = scheme.chars();
+    if !chars.next()?.is_ascii_alphabetic()
+        || !chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    {
+        return None;
+    }
+
+    let mut out = String::with_capacity(url.len());
+    for c in url.chars() {
+        if c.is_control() {
+            let mut buf = [0; 4];
+            for byte in c.encode_utf8(&mut buf).bytes() {
+                out.push_str(&format!("%{:02X}", byte));
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_url() {
+        assert_eq!(
+            sanitize_url(" https://example.com/a?b=1&c=2 ").as_deref(),
+            Some("https://example.com/a?b=1&c=2")
+        );
+        assert_eq!(
+            sanitize_url("https://x.io/\x1b]8;;evil\x1b\\\x07").as_deref(),
+            Some("https://x.io/%1B]8;;evil%1B\\%07")
+        );
+        assert_eq!(
+            sanitize_url("https://x.io/\u{9b}31m").as_deref(),
+            Some("https://x.io/%C2%9B31m")
+        );
+        assert_eq!(
+            sanitize_url("file:///tmp/report.html").as_deref(),
+            Some("file:///tmp/report.html")
+        );
+
+        assert_eq!(sanitize_url(""), None);
+        assert_eq!(sanitize_url("api-1"), None);
+        assert_eq!(sanitize_url("-x:y"), None);
+        assert_eq!(sanitize_url("/tmp/report.html"), None);
+        assert_eq!(sanitize_url("not a url: really"), None);
+    }
+}
@@ -0,0 +1,160 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MARKER_PREFIX: &str = "session-";
+const MARKER_SUFFIX: &str = ".lock";
+
+/// Directory for termstack runtime state (`$XDG_STATE_HOME/termstack`,
+/// falling back to `~/.local/state/termstack`).
+pub fn state_dir() -> Option<PathBuf> {
+    if let Some(dir) = std::env::var_os("XDG_STATE_HOME").filter(|d| !d.is_empty()) {
+        return Some(PathBuf::from(dir).join("termstack"));
+    }
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/state/termstack"))
+}
+
+/// Marker file that exists for the lifetime of a TUI session.
+///
+/// Each session has its own, named after its process id (`session-<pid>.lock`),
+/// and removes it on clean exit. A marker whose process is no longer running
+/// means that session crashed or was killed; markers of sessions still running
+/// alongside this one are left alone.
+#[derive(Debug)]
+pub struct SessionMarker {
+    dir: PathBuf,
+    pid: u32,
+}
+
+impl SessionMarker {
+    /// Marker for this process in the default state directory
+    pub fn new() -> Option<Self> {
+        state_dir().map(|dir| Self::in_dir(&dir))
+    }
+
+    /// Marker for this process in a specific directory
+    pub fn in_dir(dir: &Path) -> Self {
+        Self::for_pid(dir, std::process::id())
+    }
+
+    fn for_pid(dir: &Path, pid: u32) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+            pid,
+        }
+    }
+
+    fn path(&self) -> PathBuf {
+        marker_path(&self.dir, self.pid)
+    }
+
+    /// Take the markers left by sessions that crashed: they're removed, and the
+    /// config path the most recent of them was running is returned
+    pub fn take_leftover(&self) -> Option<String> {
+        let mut stale: Vec<(std::time::SystemTime, PathBuf)> = fs::read_dir(&self.dir)
+            .ok()?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| {
+                let name = entry.file_name();
+                let pid = name
+                    .to_str()
+                    .and_then(|name| name.strip_prefix(MARKER_PREFIX))
+                    .and_then(|name| name.strip_suffix(MARKER_SUFFIX))
+                    .and_then(|pid| pid.parse::<u32>().ok());
+                pid.is_some_and(|pid| pid != self.pid && !process_alive(pid))
+            })
+            .map(|entry| {
+                let modified = entry.metadata().and_then(|m| m.modified());
+                (modified.unwrap_or(std::time::UNIX_EPOCH), entry.path())
+            })
+            .collect();
+        stale.sort();
+
+        let previous = stale.last().and_then(|(_, path)| fs::read_to_string(path).ok());
+        for (_, path) in &stale {
+            let _ = fs::remove_file(path);
+        }
+        previous
+    }
+
+    /// Create the marker for the current session
+    pub fn create(&self, config_path: &Path) -> std::io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.path(), config_path.display().to_string())
+    }
+
+    /// Remove the marker on clean exit
+    pub fn remove(&self) {
+        let _ = fs::remove_file(self.path());
+    }
+}
+
+fn marker_path(dir: &Path, pid: u32) -> PathBuf {
+    dir.join(format!("{}{}{}", MARKER_PREFIX, pid, MARKER_SUFFIX))
+}
+
+/// Whether a process with this id is running
+#[cfg(unix)]
+fn process_alive(pid: u32) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    // Signal 0 only checks the process exists; EPERM means it does but isn't ours
+    let sent = unsafe { libc::kill(pid, 0) } == 0;
+    sent || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+/// Whether a process with this id is running
+#[cfg(not(unix))]
+fn process_alive(pid: u32) -> bool {
+    std::process::Command::new("tasklist")
+        .args(["/FI", &format!("PID eq {}", pid), "/NH"])
+        .output()
+        .map(|out| String::from_utf8_lossy(&out.stdout).contains(&pid.to_string()))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_marker_lifecycle() {
+        let dir = std::env::temp_dir().join(format!("termstack-session-{}", std::process::id()));
+        let marker = SessionMarker::in_dir(&dir);
+
+        assert!(marker.take_leftover().is_none());
+
+        marker.create(Path::new("examples/demo.yaml")).unwrap();
+        assert!(marker.path().exists());
+        // Our own marker isn't a leftover
+        assert!(marker.take_leftover().is_none());
+
+        marker.remove();
+        assert!(!marker.path().exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_leftover_needs_dead_process() {
+        let dir = std::env::temp_dir().join(format!("termstack-leftover-{}", std::process::id()));
+        let marker = SessionMarker::in_dir(&dir);
+
+        // A session still running alongside this one
+        let mut child = std::process::Command::new("sleep").arg("5").spawn().unwrap();
+        let running = SessionMarker::for_pid(&dir, child.id());
+        running.create(Path::new("examples/k8s.yaml")).unwrap();
+        assert!(marker.take_leftover().is_none());
+        assert!(running.path().exists());
+
+        // ...until it dies without removing its marker
+        child.kill().unwrap();
+        child.wait().unwrap();
+        assert_eq!(marker.take_leftover().as_deref(), Some("examples/k8s.yaml"));
+        assert!(!running.path().exists());
+        assert!(marker.take_leftover().is_none());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::navigation::Bookmark;
+
+const STATE_FILE: &str = "view-state.json";
+
+/// UI adjustments made at runtime that should survive restarts, such as
+/// column order. Stored as JSON in the state directory, keyed per page.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ViewState {
+    /// Page key -> column display names, in the user's order
+    #[serde(default)]
+    column_orders: HashMap<String, Vec<String>>,
+    /// Entered search queries, oldest first (only with `app.save_search_history`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    search_history: Vec<String>,
+    /// App name -> bookmarked pages (only with `app.save_bookmarks`)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    bookmarks: HashMap<String, Vec<Bookmark>>,
+}
+
+impl ViewState {
+    /// State file in the default state directory
+    pub fn default_path() -> Option<PathBuf> {
+        super::session::state_dir().map(|dir| dir.join(STATE_FILE))
+    }
+
+    /// Load saved state; a missing or unreadable file yields empty state
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        fs::write(path, json)
+    }
+
+    /// Display order for `columns` (indices into it). Saved names come first in
+    /// their saved order; columns the saved order doesn't know keep their
+    /// config position after them, and stale names are ignored.
+    pub fn column_order(&self, key: &str, columns: &[&str]) -> Vec<usize> {
+        let Some(saved) = self.column_orders.get(key) else {
+            return (0..columns.len()).collect();
+        };
+        let mut order: Vec<usize> = Vec::with_capacity(columns.len());
+        for name in saved {
+            if let Some(idx) =
+                (0..columns.len()).find(|&i| columns[i] == name && !order.contains(&i))
+            {
+                order.push(idx);
+            }
+        }
+        let rest: Vec<usize> = (0..columns.len()).filter(|i| !order.contains(i)).collect();
+        order.extend(rest);
+        order
+    }
+
+    pub fn set_column_order(&mut self, key: &str, names: Vec<String>) {
+        self.column_orders.insert(key.to_string(), names);
+    }
+
+    pub fn search_history(&self) -> &[String] {
+        &self.search_history
+    }
+
+    pub fn set_search_history(&mut self, queries: Vec<String>) {
+        self.search_history = queries;
+    }
+
+    pub fn bookmarks(&self, app: &str) -> &[Bookmark] {
+        self.bookmarks.get(app).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    pub fn set_bookmarks(&mut self, app: &str, bookmarks: Vec<Bookmark>) {
+        if bookmarks.is_empty() {
+            self.bookmarks.remove(app);
+        } else {
+            self.bookmarks.insert(app.to_string(), bookmarks);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_order() {
+        let mut state = ViewState::default();
+        let columns = ["Name", "Status", "Age"];
+        assert_eq!(state.column_order("app/pods", &columns), vec![0, 1, 2]);
+
+        state.set_column_order(
+            "app/pods",
+            vec!["Age".to_string(), "Gone".to_string(), "Name".to_string()],
+        );
+        // Stale names are skipped, unknown columns keep their place at the end
+        assert_eq!(state.column_order("app/pods", &columns), vec![2, 0, 1]);
+        assert_eq!(state.column_order("app/nodes", &columns), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = std::env::temp_dir().join(format!("termstack-state-{}", std::process::id()));
+        let path = dir.join(STATE_FILE);
+
+        assert!(ViewState::load(&path).column_orders.is_empty());
+
+        let mut state = ViewState::default();
+        state.set_column_order("app/pods", vec!["Status".to_string(), "Name".to_string()]);
+        state.set_search_history(vec!["!error|warn".to_string()]);
+        let bookmark = Bookmark {
+            page_id: "logs".to_string(),
+            label: "logs (api-7f9c)".to_string(),
+            context: HashMap::from([("pod".to_string(), serde_json::json!("api-7f9c"))]),
+            opened_with: vec!["api-7f9c".to_string()],
+        };
+        state.set_bookmarks("app", vec![bookmark.clone()]);
+        state.save(&path).unwrap();
+
+        let loaded = ViewState::load(&path);
+        assert_eq!(loaded.column_order("app/pods", &["Name", "Status"]), vec![1, 0]);
+        assert_eq!(loaded.search_history(), ["!error|warn"]);
+        assert_eq!(loaded.bookmarks("app"), [bookmark]);
+        assert!(loaded.bookmarks("other").is_empty());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,80 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Terminal columns `text` takes up
+pub fn width(text: &str) -> usize {
+    text.width()
+}
+
+/// The longest start of `text` fitting in `width` columns. Cuts fall between
+/// grapheme clusters, so a wide character, emoji or accented letter is never
+/// split (a wide one that doesn't fit is left out whole).
+pub fn cut(text: &str, width: usize) -> &str {
+    let mut used = 0;
+    for (idx, grapheme) in text.grapheme_indices(true) {
+        used += grapheme.width();
+        if used > width {
+            return &text[..idx];
+        }
+    }
+    text
+}
+
+/// `text` cut to `width` columns, ending in "…" when anything was cut
+pub fn truncate(text: &str, width: usize) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    format!("{}…", cut(text, width - 1))
+}
+
+/// `text` cut or padded with spaces to exactly `width` columns
+pub fn pad(text: &str, width: usize) -> String {
+    let text = cut(text, width);
+    format!("{}{}", text, " ".repeat(width - text.width()))
+}
+
+/// `text` after its first `columns` columns; a wide character straddling the
+/// boundary is dropped
+pub fn skip(text: &str, columns: usize) -> &str {
+    let mut used = 0;
+    for (idx, grapheme) in text.grapheme_indices(true) {
+        if used >= columns {
+            return &text[idx..];
+        }
+        used += grapheme.width();
+    }
+    ""
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("hello", 5), "hello");
+        assert_eq!(truncate("hello world", 6), "hello…");
+        assert_eq!(truncate("hello", 0), "");
+        // Wide characters take two columns and are never split
+        assert_eq!(truncate("日本語テキスト", 6), "日本…");
+        assert_eq!(width(&truncate("日本語テキスト", 6)), 5);
+        // A flag is two code points but one grapheme
+        assert_eq!(truncate("🇯🇵🇫🇷 flags", 5), "🇯🇵🇫🇷…");
+        // A combining accent stays with its letter
+        assert_eq!(truncate("cafe\u{301} au lait", 5), "cafe\u{301}…");
+    }
+
+    #[test]
+    fn test_cut_pad_skip() {
+        assert_eq!(cut("日本語", 3), "日");
+        assert_eq!(pad("日本語", 3), "日 ");
+        assert_eq!(pad("ab", 4), "ab  ");
+        assert_eq!(skip("日本語", 2), "本語");
+        assert_eq!(skip("日本語", 1), "本語");
+        assert_eq!(skip("abc", 5), "");
+    }
+}
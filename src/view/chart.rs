@@ -0,0 +1,173 @@
+// Points plotted by `chart` views: series pulled out of rows, a rolling history
+// across refreshes, and axis bounds
+use serde_json::Value;
+
+use super::column::{parse_date, parse_number};
+use crate::config::ChartView;
+use crate::data::JsonPaths;
+
+/// Each series' points (x, y), oldest first, in the order the view lists them
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChartData {
+    pub series: Vec<Vec<(f64, f64)>>,
+    /// x values are Unix timestamps (from dates, or fetch times)
+    pub x_is_time: bool,
+}
+
+impl ChartData {
+    /// Points from one fetch of `rows`: one per row and series with a number
+    /// at the series' `y`. x is the row's `x` value, else the fetch time `now`
+    /// when the chart keeps `history`, else the row's position.
+    pub fn from_rows(paths: &JsonPaths, view: &ChartView, rows: &[Value], now: f64) -> Self {
+        let mut x_is_time = view.x.is_none() && view.history.is_some();
+        let xs: Vec<Option<f64>> = rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| match &view.x {
+                Some(path) => {
+                    let value = paths.extract(path, row)?;
+                    if let Some(x) = parse_number(&value) {
+                        return Some(x);
+                    }
+                    let date = parse_date(&value)?;
+                    x_is_time = true;
+                    Some(date.timestamp_millis() as f64 / 1000.0)
+                }
+                None if view.history.is_some() => Some(now),
+                None => Some(i as f64),
+            })
+            .collect();
+        let series = view
+            .series
+            .iter()
+            .map(|series| {
+                rows.iter()
+                    .zip(&xs)
+                    .filter_map(|(row, &x)| {
+                        let y = paths.extract(&series.y, row).as_ref().and_then(parse_number)?;
+                        Some((x?, y))
+                    })
+                    .collect()
+            })
+            .collect();
+        Self { series, x_is_time }
+    }
+
+    /// Add a fetch's points after the ones kept so far, keeping the last
+    /// `limit` of each series
+    pub fn append(&mut self, fetched: ChartData, limit: usize) {
+        self.series.resize(fetched.series.len(), Vec::new());
+        for (points, new) in self.series.iter_mut().zip(fetched.series) {
+            points.extend(new);
+            let excess = points.len().saturating_sub(limit);
+            points.drain(..excess);
+        }
+        self.x_is_time = fetched.x_is_time;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.series.iter().all(Vec::is_empty)
+    }
+
+    /// x and y bounds over every point. A single value gets some room around
+    /// it, and y is padded so lines don't run along the border; `y_min` /
+    /// `y_max` replace the computed ends.
+    pub fn bounds(&self, y_min: Option<f64>, y_max: Option<f64>) -> Option<([f64; 2], [f64; 2])> {
+        let points = || self.series.iter().flatten();
+        let (x_lo, x_hi) = range(points().map(|&(x, _)| x))?;
+        let (y_lo, y_hi) = range(points().map(|&(_, y)| y))?;
+        let x = if x_lo == x_hi { [x_lo - 1.0, x_hi + 1.0] } else { [x_lo, x_hi] };
+        let pad = if y_lo == y_hi { y_lo.abs().max(1.0) * 0.1 } else { (y_hi - y_lo) * 0.05 };
+        let y = [y_min.unwrap_or(y_lo - pad), y_max.unwrap_or(y_hi + pad)];
+        Some((x, y))
+    }
+}
+
+/// Short axis label: whole numbers without decimals, others to two places
+pub fn axis_label(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        let text = format!("{:.2}", value);
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+}
+
+fn range(values: impl Iterator<Item = f64>) -> Option<(f64, f64)> {
+    values.filter(|v| v.is_finite()).fold(None, |acc, v| match acc {
+        None => Some((v, v)),
+        Some((lo, hi)) => Some((lo.min(v), hi.max(v))),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{ChartKind, ChartSeries};
+    use serde_json::json;
+
+    fn view(x: Option<&str>, history: Option<usize>) -> ChartView {
+        ChartView {
+            series: vec![
+                ChartSeries { name: "cpu".into(), y: "$.cpu".into(), color: None },
+                ChartSeries { name: "mem".into(), y: "$.mem".into(), color: None },
+            ],
+            kind: ChartKind::Line,
+            x: x.map(String::from),
+            history,
+            x_label: None,
+            y_label: None,
+            y_min: None,
+            y_max: None,
+        }
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let paths = JsonPaths::default();
+        let rows = [
+            json!({"t": 10, "cpu": 1.5, "mem": "40"}),
+            json!({"t": 20, "cpu": "n/a", "mem": 45}),
+        ];
+        let data = ChartData::from_rows(&paths, &view(None, None), &rows, 0.0);
+        assert_eq!(data.series, vec![vec![(0.0, 1.5)], vec![(0.0, 40.0), (1.0, 45.0)]]);
+        assert!(!data.x_is_time);
+
+        let data = ChartData::from_rows(&paths, &view(Some("$.t"), None), &rows, 0.0);
+        assert_eq!(data.series[1], vec![(10.0, 40.0), (20.0, 45.0)]);
+
+        let dated = [json!({"t": "2024-01-01T00:00:10Z", "cpu": 3})];
+        let data = ChartData::from_rows(&paths, &view(Some("$.t"), None), &dated, 0.0);
+        assert_eq!(data.series[0], vec![(1704067210.0, 3.0)]);
+        assert!(data.x_is_time);
+    }
+
+    #[test]
+    fn test_history() {
+        let paths = JsonPaths::default();
+        let view = view(None, Some(2));
+        let mut data = ChartData::default();
+        for (now, cpu) in [(100.0, 1), (105.0, 2), (110.0, 3)] {
+            let row = [json!({ "cpu": cpu })];
+            data.append(ChartData::from_rows(&paths, &view, &row, now), 2);
+        }
+        assert_eq!(data.series[0], vec![(105.0, 2.0), (110.0, 3.0)]);
+        assert!(data.series[1].is_empty());
+        assert!(data.x_is_time);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let data = ChartData { series: vec![vec![(0.0, 10.0), (4.0, 30.0)]], x_is_time: false };
+        assert_eq!(data.bounds(None, None), Some(([0.0, 4.0], [9.0, 31.0])));
+        assert_eq!(data.bounds(Some(0.0), None), Some(([0.0, 4.0], [0.0, 31.0])));
+
+        let flat = ChartData { series: vec![vec![(5.0, 0.0)]], x_is_time: false };
+        assert_eq!(flat.bounds(None, None), Some(([4.0, 6.0], [-0.1, 0.1])));
+        assert_eq!(ChartData::default().bounds(None, None), None);
+
+        assert_eq!(axis_label(42.0), "42");
+        assert_eq!(axis_label(2.5), "2.5");
+        assert_eq!(axis_label(1.0 / 3.0), "0.33");
+    }
+}
@@ -0,0 +1,597 @@
+// Column type hints: default alignment, typed sorting and formatting
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, Utc};
+use humansize::{BINARY, format_size};
+use ratatui::text::{Line, Span};
+use serde_json::Value;
+use std::cmp::Ordering;
+
+use crate::config::{Aggregate, Alignment, ColumnFormat, ColumnType, SortAs};
+use crate::util::text;
+
+/// Alignment used when a typed column doesn't set `align` explicitly
+pub fn default_alignment(column_type: ColumnType) -> Alignment {
+    match column_type {
+        ColumnType::Number | ColumnType::Duration | ColumnType::Size => Alignment::Right,
+        ColumnType::String | ColumnType::Date => Alignment::Left,
+    }
+}
+
+/// Compare two values according to the column type. Values that can't be
+/// parsed as the type sort after those that can.
+pub fn compare(column_type: ColumnType, a: &Value, b: &Value) -> Ordering {
+    if column_type == ColumnType::String {
+        return natural_cmp(&as_text(a), &as_text(b));
+    }
+
+    match (sort_key(column_type, a), sort_key(column_type, b)) {
+        (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => as_text(a).cmp(&as_text(b)),
+    }
+}
+
+/// A value as a number to sort a typed column by; None for strings
+fn sort_key(column_type: ColumnType, v: &Value) -> Option<f64> {
+    match column_type {
+        ColumnType::String => None,
+        ColumnType::Number => parse_number(v),
+        ColumnType::Date => parse_date(v).map(|dt| dt.timestamp_millis() as f64),
+        ColumnType::Duration => parse_duration_secs(v),
+        ColumnType::Size => parse_size(v),
+    }
+}
+
+/// Compare two values for sorting a column. `Auto` is resolved per column
+/// with [`auto_sort_kind`] first; on its own it compares as natural text.
+pub fn sort_compare(sort_as: SortAs, a: &Value, b: &Value) -> Ordering {
+    match sort_as {
+        SortAs::Auto | SortAs::Natural => natural_cmp(&as_text(a), &as_text(b)),
+        SortAs::Text => as_text(a).cmp(&as_text(b)),
+        SortAs::Number => compare(ColumnType::Number, a, b),
+        SortAs::Date => compare(ColumnType::Date, a, b),
+        SortAs::Duration => compare(ColumnType::Duration, a, b),
+        SortAs::Size => compare(ColumnType::Size, a, b),
+    }
+}
+
+/// Whether a value can't be read as the kind a column sorts by. Such values
+/// sort after the rest whichever way the column is sorted.
+pub fn unsortable(sort_as: SortAs, value: &Value) -> bool {
+    let kind = match sort_as {
+        SortAs::Auto | SortAs::Natural | SortAs::Text => return false,
+        SortAs::Number => ColumnType::Number,
+        SortAs::Date => ColumnType::Date,
+        SortAs::Duration => ColumnType::Duration,
+        SortAs::Size => ColumnType::Size,
+    };
+    sort_key(kind, value).is_none()
+}
+
+/// The kind an untyped column sorts by, from all its values: numbers, else
+/// dates, sizes with units or durations when every non-null value parses as
+/// one, and natural text otherwise. One kind for the whole column keeps the
+/// order consistent (comparing each pair by what both parse as wouldn't be).
+pub fn auto_sort_kind<'a>(values: impl IntoIterator<Item = &'a Value> + Clone) -> SortAs {
+    let present = || values.clone().into_iter().filter(|v| !v.is_null());
+    if present().next().is_none() {
+        return SortAs::Natural;
+    }
+    [SortAs::Number, SortAs::Date, SortAs::Size, SortAs::Duration]
+        .into_iter()
+        .find(|&kind| present().all(|v| !unsortable(kind, v)))
+        .unwrap_or(SortAs::Natural)
+}
+
+/// Natural text order: runs of digits compare by numeric value and the rest
+/// case-insensitively, so "pod-2" < "pod-10" and "v1.9" < "v1.10"
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (mut a_chars, mut b_chars) = (a.chars().peekable(), b.chars().peekable());
+    loop {
+        match (a_chars.peek().copied(), b_chars.peek().copied()) {
+            (None, None) => return a.cmp(b),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let take_digits = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+                    let mut run = String::new();
+                    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+                        run.push(c);
+                    }
+                    run
+                };
+                let (x_run, y_run) = (take_digits(&mut a_chars), take_digits(&mut b_chars));
+                let (x_num, y_num) = (x_run.trim_start_matches('0'), y_run.trim_start_matches('0'));
+                let ord = x_num.len().cmp(&y_num.len()).then_with(|| x_num.cmp(y_num));
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+            }
+            (Some(x), Some(y)) => {
+                let ord = x.to_lowercase().cmp(y.to_lowercase());
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+                a_chars.next();
+                b_chars.next();
+            }
+        }
+    }
+}
+
+/// Default display for a typed value, or None to fall back to the raw value.
+///
+/// - `date`: age relative to now ("5m", "3d")
+/// - `duration`: numeric seconds as "1h30m"
+/// - `size`: numeric bytes as "1.50 KiB"
+pub fn format(column_type: ColumnType, value: &Value) -> Option<String> {
+    match column_type {
+        ColumnType::String | ColumnType::Number => None,
+        ColumnType::Date => parse_date(value).map(|dt| {
+            crate::template::filters::format_age(Utc::now().signed_duration_since(dt))
+        }),
+        ColumnType::Duration => value
+            .as_f64()
+            .map(|secs| format_duration_secs(secs.max(0.0) as u64)),
+        ColumnType::Size => value.as_f64().map(|bytes| format_size(bytes.max(0.0) as u64, BINARY)),
+    }
+}
+
+/// Apply a column's `format` to a cell: boolean text, a date format, or number
+/// precision and thousands separators, then the prefix and suffix (after a
+/// negative number's sign). `text` is what the cell would show otherwise and
+/// is kept when no option applies.
+pub fn apply_format(
+    format: &ColumnFormat,
+    column_type: Option<ColumnType>,
+    value: &Value,
+    text: String,
+) -> String {
+    let numeric = matches!(column_type, None | Some(ColumnType::Number));
+    let formatted = match value {
+        Value::Bool(b) => format.bool.as_ref().map(|[yes, no]| if *b { yes } else { no }.clone()),
+        _ if let Some(pattern) = format.date.as_deref() => parse_date(value)
+            .filter(|_| is_valid_date_format(pattern))
+            .map(|dt| dt.with_timezone(&Local).format(pattern).to_string()),
+        _ if numeric && (format.precision.is_some() || format.thousands) => parse_number(value)
+            .map(|n| format_decimal(n, format.precision, format.thousands)),
+        _ => None,
+    };
+
+    let text = formatted.unwrap_or(text);
+    if text.is_empty() {
+        return text;
+    }
+    // A negative number's sign goes in front of the prefix: -$1,000.00
+    let (sign, text) = match text.strip_prefix('-') {
+        Some(digits) if looks_numeric(&text) => ("-", digits),
+        _ => ("", text.as_str()),
+    };
+    format!(
+        "{}{}{}{}",
+        sign,
+        format.prefix.as_deref().unwrap_or_default(),
+        text,
+        format.suffix.as_deref().unwrap_or_default()
+    )
+}
+
+/// Whether a `format.date` pattern is usable (chrono panics on bad specifiers)
+pub fn is_valid_date_format(pattern: &str) -> bool {
+    use chrono::format::{Item, StrftimeItems};
+    !StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error))
+}
+
+/// A number to `precision` decimal places (as-is without one), optionally
+/// with comma-grouped thousands
+fn format_decimal(n: f64, precision: Option<usize>, thousands: bool) -> String {
+    let text = match precision {
+        Some(p) => format!("{:.*}", p, n),
+        None => n.to_string(),
+    };
+    if !thousands {
+        return text;
+    }
+
+    let (sign, unsigned) = text.split_at(usize::from(text.starts_with('-')));
+    let (int, frac) = unsigned.split_at(unsigned.find('.').unwrap_or(unsigned.len()));
+    let mut grouped = String::new();
+    for (i, c) in int.chars().enumerate() {
+        if i > 0 && (int.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    format!("{}{}{}", sign, grouped, frac)
+}
+
+/// Footer value of a column over the shown rows' values. `count` counts the
+/// non-null values; the others combine the values that parse as the column's
+/// type (numbers when untyped) and format the result the same way as cells.
+/// Dates only have a min and max. None when nothing parses.
+pub fn aggregate(
+    kind: Aggregate,
+    column_type: Option<ColumnType>,
+    values: &[Value],
+) -> Option<String> {
+    if kind == Aggregate::Count {
+        return Some(values.iter().filter(|v| !v.is_null()).count().to_string());
+    }
+
+    let column_type = column_type.unwrap_or(ColumnType::Number);
+    let key: fn(&Value) -> Option<f64> = match column_type {
+        ColumnType::String | ColumnType::Number => parse_number,
+        ColumnType::Date => |v| parse_date(v).map(|dt| dt.timestamp() as f64),
+        ColumnType::Duration => parse_duration_secs,
+        ColumnType::Size => parse_size,
+    };
+    let numbers: Vec<f64> = values.iter().filter_map(key).collect();
+    if numbers.is_empty()
+        || (column_type == ColumnType::Date && matches!(kind, Aggregate::Sum | Aggregate::Avg))
+    {
+        return None;
+    }
+
+    let sum: f64 = numbers.iter().sum();
+    let result = match kind {
+        Aggregate::Sum => sum,
+        Aggregate::Avg => sum / numbers.len() as f64,
+        Aggregate::Min => numbers.iter().copied().fold(f64::INFINITY, f64::min),
+        Aggregate::Max => numbers.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        Aggregate::Count => unreachable!("handled above"),
+    };
+
+    match column_type {
+        ColumnType::String | ColumnType::Number => Some(format_number(result)),
+        ColumnType::Date => format(ColumnType::Date, &Value::from(result as i64)),
+        ColumnType::Duration => Some(format_duration_secs(result.max(0.0) as u64)),
+        ColumnType::Size => Some(format_size(result.max(0.0) as u64, BINARY)),
+    }
+}
+
+/// Whether displayed cell text is a plain number (`42`, `-1.5`, `1,024`)
+pub fn looks_numeric(text: &str) -> bool {
+    let text = text.trim().replace(',', "");
+    !text.is_empty() && text.parse::<f64>().is_ok()
+}
+
+/// Share `available` columns of space between auto-sized columns that each
+/// want `wanted` columns. Narrow columns get what they want; whatever is left
+/// is split evenly among the wider ones, so one long column can't starve the rest.
+pub fn fit_widths(wanted: &[u16], available: u16) -> Vec<u16> {
+    let mut order: Vec<usize> = (0..wanted.len()).collect();
+    order.sort_by_key(|&i| wanted[i]);
+
+    let mut widths = vec![0; wanted.len()];
+    let mut remaining = available;
+    for (n, &i) in order.iter().enumerate() {
+        let share = remaining / (wanted.len() - n) as u16;
+        widths[i] = wanted[i].min(share);
+        remaining -= widths[i];
+    }
+    widths
+}
+
+/// Cut a cell's text to `width` columns, ending in "…" when anything was cut
+pub fn truncate_line(line: Line<'_>, width: u16) -> Line<'_> {
+    let width = width as usize;
+    if line.width() <= width {
+        return line;
+    }
+
+    let mut remaining = width.saturating_sub(1);
+    let mut spans: Vec<Span> = Vec::new();
+    for span in &line.spans {
+        let kept = text::cut(&span.content, remaining);
+        remaining -= text::width(kept);
+        let cut_short = kept.len() < span.content.len();
+        spans.push(Span::styled(kept.to_string(), span.style));
+        if cut_short {
+            break;
+        }
+    }
+    if width > 0 {
+        let style = spans.last().map(|s| s.style).unwrap_or_default();
+        spans.push(Span::styled("…", style));
+    }
+
+    let mut truncated = Line::from(spans).style(line.style);
+    truncated.alignment = line.alignment;
+    truncated
+}
+
+fn as_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Numbers, numeric strings (`"1,234.5"`) and booleans (as 1 / 0)
+pub fn parse_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().replace(',', "").parse().ok(),
+        Value::Bool(b) => Some(if *b { 1.0 } else { 0.0 }),
+        _ => None,
+    }
+}
+
+/// RFC 3339, `YYYY-MM-DD HH:MM:SS`, `YYYY-MM-DD`, or a Unix timestamp in seconds
+pub fn parse_date(value: &Value) -> Option<DateTime<Utc>> {
+    match value {
+        Value::Number(n) => DateTime::from_timestamp(n.as_i64()?, 0),
+        Value::String(s) => {
+            let s = s.trim();
+            DateTime::parse_from_rfc3339(s)
+                .map(|dt| dt.with_timezone(&Utc))
+                .ok()
+                .or_else(|| {
+                    NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+                        .ok()
+                        .map(|dt| dt.and_utc())
+                })
+                .or_else(|| {
+                    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+                        .ok()
+                        .and_then(|d| d.and_hms_opt(0, 0, 0))
+                        .map(|dt| dt.and_utc())
+                })
+        }
+        _ => None,
+    }
+}
+
+/// Numeric seconds or a humantime string ("1h 30m", "2d3h", "500ms")
+fn parse_duration_secs(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => {
+            let s = s.trim();
+            s.parse::<f64>()
+                .ok()
+                .or_else(|| humantime::parse_duration(s).ok().map(|d| d.as_secs_f64()))
+        }
+        _ => None,
+    }
+}
+
+/// Numeric bytes or a size with unit ("512Mi", "1.5 GB", "10k")
+fn parse_size(value: &Value) -> Option<f64> {
+    let s = match value {
+        Value::Number(n) => return n.as_f64(),
+        Value::String(s) => s.trim(),
+        _ => return None,
+    };
+
+    let split = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+    let number: f64 = number.parse().ok()?;
+
+    let multiplier = match unit.trim().to_lowercase().as_str() {
+        "" | "b" => 1.0,
+        "k" | "kb" => 1e3,
+        "m" | "mb" => 1e6,
+        "g" | "gb" => 1e9,
+        "t" | "tb" => 1e12,
+        "ki" | "kib" => 1024.0,
+        "mi" | "mib" => 1024.0_f64.powi(2),
+        "gi" | "gib" => 1024.0_f64.powi(3),
+        "ti" | "tib" => 1024.0_f64.powi(4),
+        _ => return None,
+    };
+
+    Some(number * multiplier)
+}
+
+/// Whole numbers as-is, others to two decimals ("12", "3.5", "0.33")
+fn format_number(n: f64) -> String {
+    if n.fract() == 0.0 && n.abs() < 1e15 {
+        format!("{}", n as i64)
+    } else {
+        let s = format!("{:.2}", n);
+        s.trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+}
+
+/// Compact duration: the largest unit and, when nonzero, the one just below
+/// it ("2d3h", "1h30m", "45s"; 86401 seconds is "1d")
+fn format_duration_secs(secs: u64) -> String {
+    let units = [(86_400, "d"), (3_600, "h"), (60, "m"), (1, "s")];
+    let Some(largest) = units.iter().position(|&(size, _)| secs >= size) else {
+        return "0s".to_string();
+    };
+
+    let (size, suffix) = units[largest];
+    let mut out = format!("{}{}", secs / size, suffix);
+    if let Some(&(next, next_suffix)) = units.get(largest + 1) {
+        let rest = secs % size / next;
+        if rest > 0 {
+            out.push_str(&format!("{}{}", rest, next_suffix));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_default_alignment() {
+        assert_eq!(default_alignment(ColumnType::Number), Alignment::Right);
+        assert_eq!(default_alignment(ColumnType::Size), Alignment::Right);
+        assert_eq!(default_alignment(ColumnType::String), Alignment::Left);
+    }
+
+    #[test]
+    fn test_compare_number() {
+        // Numeric, not lexicographic
+        assert_eq!(compare(ColumnType::Number, &json!("10"), &json!("9")), Ordering::Greater);
+        assert_eq!(compare(ColumnType::Number, &json!(2), &json!("1,000")), Ordering::Less);
+        // Unparseable values sort last
+        assert_eq!(compare(ColumnType::Number, &json!("n/a"), &json!(1)), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_compare_size_and_duration() {
+        assert_eq!(compare(ColumnType::Size, &json!("512Mi"), &json!("1Gi")), Ordering::Less);
+        assert_eq!(compare(ColumnType::Size, &json!("2 KB"), &json!(1500)), Ordering::Greater);
+        assert_eq!(compare(ColumnType::Duration, &json!("90s"), &json!("1h")), Ordering::Less);
+        assert_eq!(compare(ColumnType::Duration, &json!("2d3h"), &json!(3600)), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_aggregate() {
+        let values = [json!(1), json!("2.5"), json!(null), json!("n/a")];
+        assert_eq!(aggregate(Aggregate::Sum, None, &values).unwrap(), "3.5");
+        assert_eq!(aggregate(Aggregate::Avg, None, &values).unwrap(), "1.75");
+        assert_eq!(aggregate(Aggregate::Max, None, &values).unwrap(), "2.5");
+        assert_eq!(aggregate(Aggregate::Count, None, &values).unwrap(), "3");
+
+        let sizes = [json!("512Mi"), json!("1.5Gi")];
+        assert_eq!(aggregate(Aggregate::Sum, Some(ColumnType::Size), &sizes).unwrap(), "2 GiB");
+        let durations = [json!("90s"), json!(30)];
+        assert_eq!(
+            aggregate(Aggregate::Min, Some(ColumnType::Duration), &durations).unwrap(),
+            "30s"
+        );
+        let dates = [json!("2024-01-02")];
+        assert!(aggregate(Aggregate::Sum, Some(ColumnType::Date), &dates).is_none());
+        assert!(aggregate(Aggregate::Sum, None, &[json!("n/a")]).is_none());
+    }
+
+    #[test]
+    fn test_apply_format() {
+        let money = ColumnFormat {
+            precision: Some(2),
+            thousands: true,
+            prefix: Some("$".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(apply_format(&money, None, &json!(1234567.891), String::new()), "$1,234,567.89");
+        assert_eq!(apply_format(&money, None, &json!("-1000"), String::new()), "-$1,000.00");
+        // Not a number: the text as it was, with the prefix
+        assert_eq!(apply_format(&money, None, &json!("n/a"), "n/a".into()), "$n/a");
+
+        let flags = ColumnFormat {
+            bool: Some(["yes".to_string(), "no".to_string()]),
+            suffix: Some("!".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(apply_format(&flags, None, &json!(false), "false".into()), "no!");
+
+        let date = ColumnFormat {
+            date: Some("%Y".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(apply_format(&date, None, &json!("2024-06-01"), "x".into()), "2024");
+        assert_eq!(format_decimal(999.5, Some(0), true), "1,000");
+        assert!(!is_valid_date_format("%Y-%Q"));
+    }
+
+    #[test]
+    fn test_truncate_line() {
+        let text = |line: Line| line.to_string();
+        assert_eq!(text(truncate_line(Line::from("short"), 10)), "short");
+        assert_eq!(text(truncate_line(Line::from("abcdefgh"), 5)), "abcd…");
+        let spans = Line::from(vec![Span::raw("ab"), Span::raw("cdef")]);
+        assert_eq!(text(truncate_line(spans, 4)), "abc…");
+        // Wide characters aren't split
+        assert_eq!(text(truncate_line(Line::from("日本語"), 4)), "日…");
+    }
+
+    #[test]
+    fn test_compare_date() {
+        assert_eq!(
+            compare(ColumnType::Date, &json!("2024-01-02"), &json!("2023-12-31T23:00:00Z")),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_natural_cmp() {
+        assert_eq!(natural_cmp("pod-2", "pod-10"), Ordering::Less);
+        assert_eq!(natural_cmp("v1.10", "v1.9"), Ordering::Greater);
+        assert_eq!(natural_cmp("Node-b", "node-a"), Ordering::Greater);
+        assert_eq!(natural_cmp("pod", "pod-1"), Ordering::Less);
+        assert_eq!(natural_cmp("a007", "a7"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_auto_sort_kind() {
+        let kind = |values: &[Value]| auto_sort_kind(values);
+        assert_eq!(kind(&[json!("10"), json!(9), Value::Null]), SortAs::Number);
+        assert_eq!(kind(&[json!("1.5Gi"), json!("900Mi")]), SortAs::Size);
+        assert_eq!(kind(&[json!("45s"), json!("2m30s")]), SortAs::Duration);
+        assert_eq!(
+            kind(&[json!("2024-03-01T00:00:00+02:00"), json!("2024-02-29T23:00:00Z")]),
+            SortAs::Date
+        );
+        assert_eq!(kind(&[json!("pod-10"), json!("pod-2")]), SortAs::Natural);
+        assert_eq!(kind(&[Value::Null]), SortAs::Natural);
+
+        // Mixed units: "10" is a size (bytes) and a number but "5m" isn't a number
+        // and "1Gi" isn't a duration, so every value is compared as a size
+        let mixed = [json!("10"), json!("5m"), json!("1Gi"), json!("2k")];
+        assert_eq!(kind(&mixed), SortAs::Size);
+        // Values no single kind parses fall back to text, so the order is still total
+        let mixed = [json!("10"), json!("5m"), json!("1h"), json!("abc")];
+        assert_eq!(kind(&mixed), SortAs::Natural);
+        let mut sorted = mixed.to_vec();
+        sorted.sort_by(|a, b| sort_compare(SortAs::Natural, a, b));
+        assert_eq!(sorted, [json!("1h"), json!("5m"), json!("10"), json!("abc")]);
+    }
+
+    #[test]
+    fn test_sort_compare() {
+        assert_eq!(sort_compare(SortAs::Text, &json!("pod-10"), &json!("pod-2")), Ordering::Less);
+        assert_eq!(
+            sort_compare(SortAs::Natural, &json!("pod-10"), &json!("pod-2")),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_unsortable() {
+        assert!(unsortable(SortAs::Number, &json!("n/a")));
+        assert!(!unsortable(SortAs::Number, &json!("1,000")));
+        assert!(unsortable(SortAs::Duration, &json!("soon")));
+        assert!(!unsortable(SortAs::Natural, &json!("soon")));
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(format(ColumnType::Size, &json!(1536)).as_deref(), Some("1.50 KiB"));
+        assert_eq!(format(ColumnType::Duration, &json!(5400)).as_deref(), Some("1h30m"));
+        assert_eq!(format(ColumnType::Duration, &json!(0)).as_deref(), Some("0s"));
+        assert_eq!(format_duration_secs(86_401), "1d");
+        assert_eq!(format_duration_secs(93_784), "1d2h");
+        assert_eq!(format_duration_secs(3_605), "1h");
+        assert_eq!(format_duration_secs(61), "1m1s");
+        assert_eq!(format(ColumnType::Number, &json!(42)), None);
+        assert!(format(ColumnType::Date, &json!("2024-01-01T00:00:00Z")).is_some());
+    }
+
+    #[test]
+    fn test_looks_numeric() {
+        assert!(looks_numeric("42"));
+        assert!(looks_numeric(" -1.5"));
+        assert!(looks_numeric("1,024"));
+        assert!(!looks_numeric("3d"));
+        assert!(!looks_numeric(""));
+    }
+
+    #[test]
+    fn test_fit_widths() {
+        // Everything fits
+        assert_eq!(fit_widths(&[5, 10], 40), vec![5, 10]);
+        // The long column gets what the short ones leave
+        assert_eq!(fit_widths(&[5, 80, 8], 40), vec![5, 27, 8]);
+        // Two long columns split the space evenly
+        assert_eq!(fit_widths(&[60, 4, 60], 40), vec![18, 4, 18]);
+    }
+}
@@ -0,0 +1,80 @@
+// Grid placement of dashboard panels
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
+
+use crate::config::DashboardView;
+
+/// Screen area of each panel, in order. Panels fill a row left to right and
+/// start the next one when their span doesn't fit. A row with fixed-height
+/// panels is as tall as the tallest; the other rows share what's left.
+pub fn layout(view: &DashboardView, area: Rect) -> Vec<Rect> {
+    let columns = view.columns.max(1);
+    let mut rows: Vec<Vec<usize>> = Vec::new();
+    let mut used = columns;
+    for (i, panel) in view.panels.iter().enumerate() {
+        let span = panel.span.clamp(1, columns);
+        if used + span > columns {
+            rows.push(Vec::new());
+            used = 0;
+        }
+        if let Some(row) = rows.last_mut() {
+            row.push(i);
+        }
+        used += span;
+    }
+
+    let heights = rows.iter().map(|row| {
+        match row.iter().filter_map(|&i| view.panels[i].height).max() {
+            Some(height) => Constraint::Length(height),
+            None => Constraint::Fill(1),
+        }
+    });
+    let row_areas = Layout::vertical(heights).split(area);
+
+    let mut rects = vec![Rect::default(); view.panels.len()];
+    for (row, &row_area) in rows.iter().zip(row_areas.iter()) {
+        let widths = row.iter().map(|&i| {
+            let span = view.panels[i].span.clamp(1, columns);
+            Constraint::Ratio(span as u32, columns as u32)
+        });
+        let cells = Layout::horizontal(widths).flex(Flex::Start).split(row_area);
+        for (&i, &cell) in row.iter().zip(cells.iter()) {
+            rects[i] = cell;
+        }
+    }
+    rects
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DashboardPanel;
+
+    fn panel(span: u16, height: Option<u16>) -> DashboardPanel {
+        DashboardPanel {
+            title: "panel".into(),
+            data: serde_yaml::from_str("adapter: cli\ncommand: date").unwrap(),
+            view: serde_yaml::from_str("type: text").unwrap(),
+            span,
+            height,
+            theme: None,
+        }
+    }
+
+    #[test]
+    fn test_layout() {
+        let view = DashboardView {
+            columns: 2,
+            panels: vec![panel(1, None), panel(1, Some(5)), panel(2, None), panel(1, None)],
+        };
+        let rects = layout(&view, Rect::new(0, 0, 80, 25));
+        assert_eq!(
+            rects,
+            vec![
+                Rect::new(0, 0, 40, 5),
+                Rect::new(40, 0, 40, 5),
+                Rect::new(0, 5, 80, 10),
+                Rect::new(0, 15, 40, 10),
+            ]
+        );
+    }
+}
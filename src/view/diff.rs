@@ -0,0 +1,156 @@
+// Baseline snapshots of a page's rows and cell-level comparison against them
+use chrono::{DateTime, Local};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+
+use crate::data::JsonPathExtractor;
+
+/// How a cell differs from the baseline
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellChange {
+    /// The row is in the baseline but this cell's value isn't the same
+    Changed,
+    /// The row wasn't in the baseline at all
+    Added,
+}
+
+/// Counts shown next to the page title while diffing
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiffSummary {
+    pub changed: usize,
+    pub added: usize,
+    pub removed: usize,
+}
+
+/// A page's rows as they were when the snapshot was taken, by row identity
+#[derive(Debug, Clone)]
+pub struct Baseline {
+    pub name: String,
+    pub taken: DateTime<Local>,
+    rows: HashMap<String, Value>,
+}
+
+impl Baseline {
+    /// Snapshot `rows`, identified by `keys` (see [`super::selection::row_key`])
+    pub fn capture(name: impl Into<String>, keys: &[String], rows: &[Value]) -> Self {
+        Self {
+            name: name.into(),
+            taken: Local::now(),
+            rows: keys.iter().cloned().zip(rows.iter().cloned()).collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Compare the value at `path` (compiled once per column) in the current
+    /// row with the baseline row
+    pub fn cell_change(
+        &self,
+        key: &str,
+        path: &JsonPathExtractor,
+        current: Option<&Value>,
+    ) -> Option<CellChange> {
+        let Some(before) = self.rows.get(key) else {
+            return Some(CellChange::Added);
+        };
+        let before = extract(path, before);
+        (before.as_ref() != current).then_some(CellChange::Changed)
+    }
+
+    /// Rows with at least one changed cell among `paths`, new rows and rows
+    /// that are gone since the snapshot
+    pub fn summary(
+        &self,
+        keys: &[String],
+        rows: &[Value],
+        paths: &[&JsonPathExtractor],
+    ) -> DiffSummary {
+        let mut summary = DiffSummary::default();
+        for (key, row) in keys.iter().zip(rows) {
+            let Some(before) = self.rows.get(key) else {
+                summary.added += 1;
+                continue;
+            };
+            if paths.iter().any(|path| extract(path, before) != extract(path, row)) {
+                summary.changed += 1;
+            }
+        }
+        let present: HashSet<&str> = keys.iter().map(String::as_str).collect();
+        summary.removed = self.rows.keys().filter(|k| !present.contains(k.as_str())).count();
+        summary
+    }
+}
+
+/// Rows of `before` whose key isn't among `after_keys`, in their old order
+pub fn removed_rows(before_keys: &[String], before: &[Value], after_keys: &[String]) -> Vec<Value> {
+    let present: HashSet<&str> = after_keys.iter().map(String::as_str).collect();
+    before_keys
+        .iter()
+        .zip(before)
+        .filter(|(key, _)| !present.contains(key.as_str()))
+        .map(|(_, row)| row.clone())
+        .collect()
+}
+
+fn extract(path: &JsonPathExtractor, row: &Value) -> Option<Value> {
+    path.extract_single(row).ok().flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_cell_changes() {
+        let keys = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let before = [
+            json!({"name": "a", "image": "v1", "replicas": 2}),
+            json!({"name": "b", "image": "v1", "replicas": 2}),
+            json!({"name": "c", "image": "v1", "replicas": 2}),
+        ];
+        let baseline = Baseline::capture("pre-release", &keys, &before);
+        assert_eq!(baseline.len(), 3);
+
+        let path = |p: &str| JsonPathExtractor::new(p).unwrap();
+        let (name, image, replicas) = (path("$.name"), path("$.image"), path("$.replicas"));
+        let v2 = json!("v2");
+        assert_eq!(baseline.cell_change("a", &image, Some(&v2)), Some(CellChange::Changed));
+        assert_eq!(baseline.cell_change("a", &replicas, Some(&json!(2))), None);
+        assert_eq!(baseline.cell_change("a", &path("$.missing"), None), None);
+        assert_eq!(baseline.cell_change("d", &image, Some(&v2)), Some(CellChange::Added));
+
+        let keys = vec!["a".to_string(), "b".to_string(), "d".to_string()];
+        let after = [
+            json!({"name": "a", "image": "v2", "replicas": 2}),
+            json!({"name": "b", "image": "v1", "replicas": 2}),
+            json!({"name": "d", "image": "v2", "replicas": 1}),
+        ];
+        let summary = baseline.summary(&keys, &after, &[&name, &image, &replicas]);
+        assert_eq!(
+            summary,
+            DiffSummary {
+                changed: 1,
+                added: 1,
+                removed: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_removed_rows() {
+        let before_keys = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let before = [json!({"name": "a"}), json!({"name": "b"}), json!({"name": "c"})];
+        let after_keys = vec!["b".to_string(), "d".to_string()];
+        assert_eq!(
+            removed_rows(&before_keys, &before, &after_keys),
+            vec![json!({"name": "a"}), json!({"name": "c"})]
+        );
+    }
+}
@@ -0,0 +1,84 @@
+// A row's fields as indented key/value lines, for peeking at nested data in place
+use serde_json::Value;
+
+/// Lay out `value` YAML-style: one `key: value` line per scalar field (keys in
+/// sorted order), nested objects and arrays indented two spaces beneath their key
+pub fn field_lines(value: &Value) -> Vec<String> {
+    let mut lines = Vec::new();
+    push_fields(value, 0, &mut lines);
+    lines
+}
+
+fn push_fields(value: &Value, indent: usize, lines: &mut Vec<String>) {
+    let pad = " ".repeat(indent);
+    match value {
+        Value::Object(map) => {
+            for (key, field) in map {
+                match scalar(field) {
+                    Some(text) => lines.push(format!("{pad}{key}: {text}")),
+                    None => {
+                        lines.push(format!("{pad}{key}:"));
+                        push_fields(field, indent + 2, lines);
+                    }
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                match scalar(item) {
+                    Some(text) => lines.push(format!("{pad}- {text}")),
+                    None => {
+                        lines.push(format!("{pad}-"));
+                        push_fields(item, indent + 2, lines);
+                    }
+                }
+            }
+        }
+        other => lines.push(format!("{pad}{}", scalar(other).unwrap_or_default())),
+    }
+}
+
+/// Inline text for scalars and empty containers; None for anything to nest
+fn scalar(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Object(map) if map.is_empty() => Some("{}".to_string()),
+        Value::Array(items) if items.is_empty() => Some("[]".to_string()),
+        Value::Object(_) | Value::Array(_) => None,
+        other => Some(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_field_lines() {
+        let row = json!({
+            "name": "web",
+            "replicas": 2,
+            "labels": {"app": "web", "tier": {}},
+            "ports": [80, {"name": "tls", "port": 443}],
+            "node": null
+        });
+        assert_eq!(
+            field_lines(&row),
+            vec![
+                "labels:",
+                "  app: web",
+                "  tier: {}",
+                "name: web",
+                "node: null",
+                "ports:",
+                "  - 80",
+                "  -",
+                "    name: tls",
+                "    port: 443",
+                "replicas: 2",
+            ]
+        );
+        assert_eq!(field_lines(&json!("plain")), vec!["plain"]);
+    }
+}
@@ -0,0 +1,156 @@
+// Column filters typed in the search box: `status:Failed`, `cpu>80`, `age<=7`
+use std::cmp::Ordering;
+
+use regex::Regex;
+
+/// How a column filter compares the cell with its value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterOp {
+    /// `col:value` - the cell contains the value (`col:!regex` for a regex)
+    Contains,
+    /// `col=value`
+    Equals,
+    /// `col!=value`
+    NotEquals,
+    /// `col>value`
+    Greater,
+    /// `col>=value`
+    GreaterEq,
+    /// `col<value`
+    Less,
+    /// `col<=value`
+    LessEq,
+}
+
+/// One `field<op>value` term of a search query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterTerm<'a> {
+    pub field: &'a str,
+    pub op: FilterOp,
+    pub value: &'a str,
+}
+
+/// Split a search token into a field, operator and value. The field is a
+/// column name (letters, digits, `_`, `-`, `.`) and the value may be empty only
+/// for `:` (`status:` matches any cell that has a value).
+pub fn parse_term(token: &str) -> Option<FilterTerm<'_>> {
+    let end = token
+        .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '-' | '.')))
+        .filter(|&end| end > 0)?;
+    let (field, rest) = token.split_at(end);
+    let (op, value) = [
+        (">=", FilterOp::GreaterEq),
+        ("<=", FilterOp::LessEq),
+        ("!=", FilterOp::NotEquals),
+        (":", FilterOp::Contains),
+        ("=", FilterOp::Equals),
+        (">", FilterOp::Greater),
+        ("<", FilterOp::Less),
+    ]
+    .into_iter()
+    .find_map(|(symbol, op)| rest.strip_prefix(symbol).map(|value| (op, value)))?;
+    if value.is_empty() && op != FilterOp::Contains {
+        return None;
+    }
+    Some(FilterTerm { field, op, value })
+}
+
+impl FilterOp {
+    /// The regex of a `col:!regex` filter, to compile once rather than for each
+    /// row; it ignores case unless `case_sensitive`. None for other filters and
+    /// for a pattern that doesn't compile (which matches nothing).
+    pub fn regex(self, value: &str, case_sensitive: bool) -> Option<Regex> {
+        let pattern = value.strip_prefix('!').filter(|_| self == FilterOp::Contains)?;
+        match case_sensitive {
+            true => Regex::new(pattern),
+            false => Regex::new(&format!("(?i){}", pattern)),
+        }
+        .ok()
+    }
+
+    /// Compare a cell's text with the filter value. Both sides compare as
+    /// numbers when they parse as one (a trailing `%` is allowed), otherwise as
+    /// text, so ISO dates order correctly too. A `!regex` value is matched with
+    /// `regex`, from [`Self::regex`].
+    pub fn matches(
+        self,
+        cell: &str,
+        value: &str,
+        case_sensitive: bool,
+        regex: Option<&Regex>,
+    ) -> bool {
+        if self == FilterOp::Contains {
+            return match value.strip_prefix('!') {
+                Some(_) => regex.is_some_and(|re| re.is_match(cell)),
+                None if case_sensitive => cell.contains(value),
+                None => cell.to_lowercase().contains(&value.to_lowercase()),
+            };
+        }
+
+        let ordering = match (number(cell), number(value)) {
+            (Some(a), Some(b)) => a.partial_cmp(&b),
+            _ if case_sensitive => Some(cell.cmp(value)),
+            _ => Some(cell.to_lowercase().cmp(&value.to_lowercase())),
+        };
+        let Some(ordering) = ordering else {
+            return false;
+        };
+        match self {
+            FilterOp::Equals => ordering == Ordering::Equal,
+            FilterOp::NotEquals => ordering != Ordering::Equal,
+            FilterOp::Greater => ordering == Ordering::Greater,
+            FilterOp::GreaterEq => ordering != Ordering::Less,
+            FilterOp::Less => ordering == Ordering::Less,
+            FilterOp::LessEq => ordering != Ordering::Greater,
+            FilterOp::Contains => unreachable!("handled above"),
+        }
+    }
+}
+
+fn number(text: &str) -> Option<f64> {
+    let text = text.trim();
+    text.strip_suffix('%').unwrap_or(text).trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_term() {
+        let term = |field, op, value| Some(FilterTerm { field, op, value });
+        assert_eq!(parse_term("status:Failed"), term("status", FilterOp::Contains, "Failed"));
+        assert_eq!(parse_term("cpu>80"), term("cpu", FilterOp::Greater, "80"));
+        assert_eq!(parse_term("cpu>=80"), term("cpu", FilterOp::GreaterEq, "80"));
+        assert_eq!(parse_term("node!=a:b"), term("node", FilterOp::NotEquals, "a:b"));
+        assert_eq!(parse_term("status:"), term("status", FilterOp::Contains, ""));
+        assert_eq!(parse_term("web"), None);
+        assert_eq!(parse_term(":x"), None);
+        assert_eq!(parse_term("cpu>"), None);
+        assert_eq!(parse_term("a=b"), term("a", FilterOp::Equals, "b"));
+    }
+
+    #[test]
+    fn test_matches() {
+        let matches = |op: FilterOp, cell, value, case_sensitive| {
+            let regex = op.regex(value, case_sensitive);
+            op.matches(cell, value, case_sensitive, regex.as_ref())
+        };
+        assert!(matches(FilterOp::Contains, "CrashLoopBackOff", "crash", false));
+        assert!(!matches(FilterOp::Contains, "CrashLoopBackOff", "crash", true));
+        assert!(matches(FilterOp::Contains, "Failed", "!^F", false));
+        // Regexes follow the case setting too; a broken one matches nothing
+        assert!(matches(FilterOp::Contains, "Failed", "!^f", false));
+        assert!(!matches(FilterOp::Contains, "Failed", "!^f", true));
+        assert!(!matches(FilterOp::Contains, "Failed", "!(", false));
+        assert!(FilterOp::Equals.regex("!^F", false).is_none());
+        assert!(matches(FilterOp::Greater, "95", "80", false));
+        // Numbers compare as numbers, not text
+        assert!(matches(FilterOp::Greater, "100", "80", false));
+        assert!(matches(FilterOp::LessEq, "75%", "80", false));
+        assert!(matches(FilterOp::Equals, "2.0", "2", false));
+        assert!(matches(FilterOp::NotEquals, "Running", "running", true));
+        assert!(!matches(FilterOp::NotEquals, "Running", "running", false));
+        assert!(matches(FilterOp::Less, "2024-01-15", "2024-02-01", false));
+    }
+}
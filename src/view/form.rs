@@ -0,0 +1,187 @@
+// Values of a form page as the user edits them
+use serde_json::{Map, Value};
+
+use crate::config::{FieldKind, FormField};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    Text(String),
+    /// Index into the field's options
+    Select(usize),
+    Checkbox(bool),
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FormState {
+    pub values: Vec<FieldValue>,
+    pub focused: usize,
+    /// Why the last submit was refused, e.g. a required field left empty
+    pub error: Option<String>,
+}
+
+impl FormState {
+    /// Start each field at its default; text defaults come already rendered
+    pub fn new(fields: &[FormField], defaults: Vec<Option<Value>>) -> Self {
+        let values = fields
+            .iter()
+            .zip(defaults.into_iter().chain(std::iter::repeat(None)))
+            .map(|(field, default)| match field.kind {
+                FieldKind::Text => FieldValue::Text(match default {
+                    Some(Value::String(s)) => s,
+                    Some(Value::Null) | None => String::new(),
+                    Some(other) => other.to_string(),
+                }),
+                FieldKind::Select => {
+                    let text = match default {
+                        Some(Value::String(s)) => s,
+                        Some(other) => other.to_string(),
+                        None => String::new(),
+                    };
+                    FieldValue::Select(field.options.iter().position(|o| *o == text).unwrap_or(0))
+                }
+                FieldKind::Checkbox => FieldValue::Checkbox(match default {
+                    Some(Value::Bool(b)) => b,
+                    Some(Value::String(s)) => s.trim() == "true",
+                    _ => false,
+                }),
+            })
+            .collect();
+        Self { values, focused: 0, error: None }
+    }
+
+    /// Move the focus to the next (or previous) field, wrapping around
+    pub fn focus_next(&mut self, forward: bool) {
+        let count = self.values.len().max(1);
+        self.focused = match forward {
+            true => (self.focused + 1) % count,
+            false => (self.focused + count - 1) % count,
+        };
+    }
+
+    /// A typed character: appended to a text field; Space changes a select
+    /// or checkbox
+    pub fn type_char(&mut self, fields: &[FormField], c: char) {
+        match self.values.get_mut(self.focused) {
+            Some(FieldValue::Text(text)) => text.push(c),
+            Some(_) if c == ' ' => self.change(fields, true),
+            _ => {}
+        }
+    }
+
+    pub fn backspace(&mut self) {
+        if let Some(FieldValue::Text(text)) = self.values.get_mut(self.focused) {
+            text.pop();
+        }
+    }
+
+    /// Pick the next (or previous) option of a select, or toggle a checkbox
+    pub fn change(&mut self, fields: &[FormField], forward: bool) {
+        let options = fields.get(self.focused).map_or(0, |f| f.options.len()).max(1);
+        match self.values.get_mut(self.focused) {
+            Some(FieldValue::Select(idx)) => {
+                *idx = match forward {
+                    true => (*idx + 1) % options,
+                    false => (*idx + options - 1) % options,
+                };
+            }
+            Some(FieldValue::Checkbox(checked)) => *checked = !*checked,
+            _ => {}
+        }
+    }
+
+    /// The first required field left empty, as a message
+    pub fn missing(&self, fields: &[FormField]) -> Option<String> {
+        fields.iter().zip(&self.values).find_map(|(field, value)| match value {
+            FieldValue::Text(text) if field.required && text.trim().is_empty() => {
+                Some(format!("{} is required", label(field)))
+            }
+            _ => None,
+        })
+    }
+
+    /// The values as an object keyed by field name
+    pub fn to_row(&self, fields: &[FormField]) -> Value {
+        let map: Map<String, Value> = fields
+            .iter()
+            .zip(&self.values)
+            .map(|(field, value)| {
+                let value = match value {
+                    FieldValue::Text(text) => Value::String(text.clone()),
+                    FieldValue::Select(idx) => {
+                        field.options.get(*idx).cloned().map_or(Value::Null, Value::String)
+                    }
+                    FieldValue::Checkbox(checked) => Value::Bool(*checked),
+                };
+                (field.name.clone(), value)
+            })
+            .collect();
+        Value::Object(map)
+    }
+}
+
+/// A field's label, defaulting to its name
+pub fn label(field: &FormField) -> &str {
+    field.label.as_deref().unwrap_or(&field.name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn fields() -> Vec<FormField> {
+        serde_yaml::from_str(
+            r#"
+- name: user
+  label: "User name"
+  required: true
+- name: role
+  type: select
+  options: [admin, viewer]
+- name: active
+  type: checkbox
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_defaults_and_row() {
+        let fields = fields();
+        let form = FormState::new(&fields, vec![None, Some(json!("viewer")), Some(json!(true))]);
+        assert_eq!(
+            form.values,
+            vec![FieldValue::Text(String::new()), FieldValue::Select(1), FieldValue::Checkbox(true)]
+        );
+        assert_eq!(form.missing(&fields), Some("User name is required".to_string()));
+        assert_eq!(
+            form.to_row(&fields),
+            json!({"user": "", "role": "viewer", "active": true})
+        );
+    }
+
+    #[test]
+    fn test_editing() {
+        let fields = fields();
+        let mut form = FormState::new(&fields, Vec::new());
+        for c in "bob!".chars() {
+            form.type_char(&fields, c);
+        }
+        form.backspace();
+        form.focus_next(true);
+        form.type_char(&fields, ' ');
+        form.change(&fields, true);
+        form.focus_next(true);
+        form.change(&fields, false);
+        form.focus_next(true);
+        assert_eq!(form.focused, 0);
+        form.focus_next(false);
+        assert_eq!(form.focused, 2);
+
+        assert_eq!(form.missing(&fields), None);
+        assert_eq!(
+            form.to_row(&fields),
+            json!({"user": "bob", "role": "admin", "active": true})
+        );
+    }
+}
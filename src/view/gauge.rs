@@ -0,0 +1,121 @@
+// Items shown by `bargauge` / `gauge` views and the colors their thresholds pick
+use serde_json::Value;
+
+use super::chart::axis_label;
+use super::column::parse_number;
+use crate::config::{GaugeView, Threshold};
+use crate::data::JsonPaths;
+
+/// Color below every threshold when the view doesn't set one
+const DEFAULT_COLOR: &str = "green";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GaugeItem {
+    pub label: String,
+    pub value: f64,
+}
+
+/// One item per row with a number at `value`, labelled by `label` (or numbered)
+pub fn items<'a>(
+    paths: &JsonPaths,
+    view: &GaugeView,
+    rows: impl IntoIterator<Item = &'a Value>,
+) -> Vec<GaugeItem> {
+    rows.into_iter()
+        .enumerate()
+        .filter_map(|(i, row)| {
+            let value = paths.extract(&view.value, row).as_ref().and_then(parse_number)?;
+            let label = match view.label.as_deref().and_then(|path| paths.extract(path, row)) {
+                Some(Value::String(s)) => s,
+                Some(other) => other.to_string(),
+                None => (i + 1).to_string(),
+            };
+            Some(GaugeItem { label, value })
+        })
+        .collect()
+}
+
+/// Value of a full bar: `max`, else 100 for gauges and the largest value for
+/// bar gauges
+pub fn full_scale(view: &GaugeView, items: &[GaugeItem], bars: bool) -> f64 {
+    match view.max {
+        Some(max) => max,
+        None if bars => items.iter().map(|item| item.value).fold(0.0, f64::max),
+        None => 100.0,
+    }
+}
+
+/// Color for `value`: that of the highest threshold it reaches, else the
+/// view's base color
+pub fn color_for(view: &GaugeView, value: f64) -> &str {
+    threshold_color(&view.thresholds, view.color.as_deref(), value)
+}
+
+/// Color of the highest of `thresholds` that `value` reaches, else `base`
+/// (or green)
+pub fn threshold_color<'a>(
+    thresholds: &'a [Threshold],
+    base: Option<&'a str>,
+    value: f64,
+) -> &'a str {
+    thresholds
+        .iter()
+        .filter(|t| value >= t.value)
+        .max_by(|a, b| a.value.total_cmp(&b.value))
+        .map(|t| t.color.as_str())
+        .unwrap_or(base.unwrap_or(DEFAULT_COLOR))
+}
+
+/// A value with the view's unit, e.g. "73.5%"
+pub fn format_value(view: &GaugeView, value: f64) -> String {
+    format!("{}{}", axis_label(value), view.unit.as_deref().unwrap_or(""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn view() -> GaugeView {
+        GaugeView {
+            value: "$.used".into(),
+            label: Some("$.mount".into()),
+            max: None,
+            unit: Some("%".into()),
+            color: None,
+            thresholds: vec![
+                Threshold { value: 90.0, color: "red".into() },
+                Threshold { value: 75.0, color: "yellow".into() },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_items() {
+        let rows = [
+            json!({"mount": "/", "used": 42}),
+            json!({"mount": "/data", "used": "n/a"}),
+            json!({"used": "91.5"}),
+        ];
+        let view = view();
+        let items = items(&JsonPaths::default(), &view, &rows);
+        assert_eq!(
+            items,
+            vec![
+                GaugeItem { label: "/".into(), value: 42.0 },
+                GaugeItem { label: "3".into(), value: 91.5 },
+            ]
+        );
+        assert_eq!(full_scale(&view, &items, false), 100.0);
+        assert_eq!(full_scale(&view, &items, true), 91.5);
+        assert_eq!(format_value(&view, 91.5), "91.5%");
+    }
+
+    #[test]
+    fn test_color_for() {
+        let view = view();
+        assert_eq!(color_for(&view, 10.0), "green");
+        assert_eq!(color_for(&view, 75.0), "yellow");
+        assert_eq!(color_for(&view, 95.0), "red");
+    }
+}
@@ -0,0 +1,253 @@
+// Buckets counted by `histogram` and `heatmap` views, and the block characters
+// they're drawn with
+use serde_json::Value;
+
+use super::chart::axis_label;
+use super::column::{parse_date, parse_number};
+use crate::config::{HeatmapView, HistogramView};
+use crate::data::JsonPaths;
+
+/// Most columns a heatmap keeps; older intervals are dropped
+const MAX_COLUMNS: usize = 1000;
+
+/// Shades for a heatmap cell, from empty to the busiest
+const SHADES: [char; 5] = [' ', '░', '▒', '▓', '█'];
+
+/// Partial blocks in eighths, for the end of a horizontal bar
+const EIGHTHS: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+/// Partial blocks in eighths, for the top of a vertical bar
+const RISING_EIGHTHS: [char; 8] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇'];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bucket {
+    pub start: f64,
+    pub end: f64,
+    pub count: usize,
+}
+
+/// Items counted into the view's buckets; items without a number at `value`
+/// or outside a fixed `min`/`max` are left out
+pub fn histogram<'a>(
+    paths: &JsonPaths,
+    view: &HistogramView,
+    rows: impl IntoIterator<Item = &'a Value>,
+) -> Vec<Bucket> {
+    let values: Vec<f64> = rows
+        .into_iter()
+        .filter_map(|row| paths.extract(&view.value, row).as_ref().and_then(parse_number))
+        .collect();
+    let mut buckets = empty_buckets(&values, view.buckets, view.min, view.max);
+    for &value in &values {
+        if let Some(i) = bucket_index(&buckets, value) {
+            buckets[i].count += 1;
+        }
+    }
+    buckets
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Heatmap {
+    /// Start of each column as a Unix timestamp, oldest first
+    pub times: Vec<i64>,
+    /// Value band of each row, lowest first; a single unbounded band when the
+    /// view has no `value`
+    pub bands: Vec<Bucket>,
+    /// Items per column, then per band
+    pub counts: Vec<Vec<usize>>,
+}
+
+impl Heatmap {
+    /// The largest count of a single cell
+    pub fn peak(&self) -> usize {
+        self.counts.iter().flatten().copied().max().unwrap_or(0)
+    }
+}
+
+/// Items counted per `interval` seconds of their time and, with a `value`, per
+/// band. Columns run from the earliest interval to the latest, gaps included.
+pub fn heatmap<'a>(
+    paths: &JsonPaths,
+    view: &HeatmapView,
+    rows: impl IntoIterator<Item = &'a Value>,
+    interval: i64,
+) -> Heatmap {
+    let interval = interval.max(1);
+    let points: Vec<(i64, Option<f64>)> = rows
+        .into_iter()
+        .filter_map(|row| {
+            let time = parse_date(&paths.extract(&view.time, row)?)?.timestamp();
+            match &view.value {
+                Some(path) => Some((time, Some(parse_number(&paths.extract(path, row)?)?))),
+                None => Some((time, None)),
+            }
+        })
+        .collect();
+    let (Some(first), Some(last)) = (
+        points.iter().map(|&(t, _)| t.div_euclid(interval)).min(),
+        points.iter().map(|&(t, _)| t.div_euclid(interval)).max(),
+    ) else {
+        return Heatmap::default();
+    };
+    let first = first.max(last - MAX_COLUMNS as i64 + 1);
+
+    let bands = match view.value {
+        Some(_) => {
+            let values: Vec<f64> = points.iter().filter_map(|&(_, v)| v).collect();
+            empty_buckets(&values, view.buckets, view.min, view.max)
+        }
+        None => vec![Bucket { start: f64::NEG_INFINITY, end: f64::INFINITY, count: 0 }],
+    };
+    let times: Vec<i64> = (first..=last).map(|slot| slot * interval).collect();
+    let mut counts = vec![vec![0; bands.len()]; times.len()];
+    for (time, value) in points {
+        let slot = time.div_euclid(interval);
+        if slot < first {
+            continue;
+        }
+        let band = match value {
+            Some(value) => bucket_index(&bands, value),
+            None => Some(0),
+        };
+        if let Some(band) = band {
+            counts[(slot - first) as usize][band] += 1;
+        }
+    }
+    Heatmap { times, bands, counts }
+}
+
+/// `count` equal buckets from `min` to `max`, each bound left out taken from
+/// the values
+fn empty_buckets(values: &[f64], count: usize, min: Option<f64>, max: Option<f64>) -> Vec<Bucket> {
+    let finite = || values.iter().copied().filter(|v| v.is_finite());
+    let lo = min.or_else(|| finite().reduce(f64::min));
+    let hi = max.or_else(|| finite().reduce(f64::max));
+    let (Some(lo), Some(hi)) = (lo, hi) else {
+        return Vec::new();
+    };
+    // A single distinct value still gets buckets of some width
+    let hi = if hi > lo { hi } else { lo + 1.0 };
+    let count = count.max(1);
+    let width = (hi - lo) / count as f64;
+    (0..count)
+        .map(|i| Bucket {
+            start: lo + width * i as f64,
+            end: if i + 1 == count { hi } else { lo + width * (i + 1) as f64 },
+            count: 0,
+        })
+        .collect()
+}
+
+/// Bucket holding `value`; the last one includes its upper bound
+fn bucket_index(buckets: &[Bucket], value: f64) -> Option<usize> {
+    let (first, last) = (buckets.first()?, buckets.last()?);
+    if !(first.start..=last.end).contains(&value) {
+        return None;
+    }
+    let width = (last.end - first.start) / buckets.len() as f64;
+    Some((((value - first.start) / width) as usize).min(buckets.len() - 1))
+}
+
+/// A bucket's range with the view's unit, e.g. "10-20ms"
+pub fn bucket_label(bucket: &Bucket, unit: Option<&str>) -> String {
+    let unit = unit.unwrap_or("");
+    format!("{}-{}{}", axis_label(bucket.start), axis_label(bucket.end), unit)
+}
+
+/// A horizontal bar `fraction` of `width` cells long, to the nearest eighth
+pub fn bar(fraction: f64, width: usize) -> String {
+    let eighths = (fraction.clamp(0.0, 1.0) * width as f64 * 8.0).round() as usize;
+    let mut bar = "█".repeat(eighths / 8);
+    if !eighths.is_multiple_of(8) {
+        bar.push(EIGHTHS[eighths % 8]);
+    }
+    bar
+}
+
+/// The cells of a vertical bar `fraction` of `height` cells tall, bottom first
+pub fn column(fraction: f64, height: usize) -> Vec<char> {
+    let eighths = (fraction.clamp(0.0, 1.0) * height as f64 * 8.0).round() as usize;
+    (0..height)
+        .map(|row| match eighths.saturating_sub(row * 8) {
+            0 => ' ',
+            n if n >= 8 => '█',
+            n => RISING_EIGHTHS[n],
+        })
+        .collect()
+}
+
+/// A heatmap cell's shade for `fraction` of the busiest cell; any item at all
+/// shows
+pub fn shade(fraction: f64) -> char {
+    if fraction <= 0.0 {
+        return SHADES[0];
+    }
+    let level = (fraction.min(1.0) * (SHADES.len() - 1) as f64).ceil() as usize;
+    SHADES[level.max(1)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn histogram_view(min: Option<f64>) -> HistogramView {
+        HistogramView {
+            value: "$.ms".into(),
+            buckets: 4,
+            min,
+            max: None,
+            unit: Some("ms".into()),
+            color: None,
+        }
+    }
+
+    #[test]
+    fn test_histogram() {
+        let paths = JsonPaths::default();
+        let rows: Vec<Value> =
+            [0, 10, 15, 39, 40, 40].iter().map(|ms| json!({"ms": ms})).collect();
+        let buckets = histogram(&paths, &histogram_view(None), &rows);
+        let counts: Vec<usize> = buckets.iter().map(|b| b.count).collect();
+        assert_eq!(counts, vec![1, 2, 0, 3]);
+        assert_eq!(bucket_label(&buckets[1], Some("ms")), "10-20ms");
+
+        let buckets = histogram(&paths, &histogram_view(Some(12.0)), &rows);
+        assert_eq!(buckets.iter().map(|b| b.count).sum::<usize>(), 4);
+        assert!(histogram(&paths, &histogram_view(None), &[json!({"ms": "n/a"})]).is_empty());
+    }
+
+    #[test]
+    fn test_heatmap() {
+        let paths = JsonPaths::default();
+        let view: HeatmapView =
+            serde_yaml::from_str("time: $.at\nvalue: $.ms\nbuckets: 2\nmin: 0\nmax: 100").unwrap();
+        let rows = [
+            json!({"at": "2024-01-01T10:00:05Z", "ms": 10}),
+            json!({"at": "2024-01-01T10:00:50Z", "ms": 90}),
+            json!({"at": "2024-01-01T10:02:00Z", "ms": 20}),
+            json!({"at": "2024-01-01T10:02:30Z", "ms": 500}),
+            json!({"ms": 5}),
+        ];
+        let map = heatmap(&paths, &view, &rows, 60);
+        assert_eq!(map.times.len(), 3);
+        assert_eq!(map.times[0] % 60, 0);
+        assert_eq!(map.counts, vec![vec![1, 1], vec![0, 0], vec![1, 0]]);
+        assert_eq!(map.peak(), 1);
+
+        let view: HeatmapView = serde_yaml::from_str("time: $.at").unwrap();
+        let map = heatmap(&paths, &view, &rows, 60);
+        assert_eq!(map.counts, vec![vec![2], vec![0], vec![2]]);
+    }
+
+    #[test]
+    fn test_blocks() {
+        assert_eq!(bar(0.5, 4), "██");
+        assert_eq!(bar(0.3, 4), "█▎");
+        assert_eq!(bar(0.0, 4), "");
+        assert_eq!(column(0.5, 3), vec!['█', '▄', ' ']);
+        assert_eq!(shade(0.0), ' ');
+        assert_eq!(shade(0.01), '░');
+        assert_eq!(shade(1.0), '█');
+    }
+}
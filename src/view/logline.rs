@@ -0,0 +1,196 @@
+// Structured (JSON) log lines split into the fields a logs view shows
+use chrono::{DateTime, Local};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use serde_json::Value;
+
+use crate::config::LogField;
+use crate::data::JsonPathExtractor;
+use crate::ui::theme::Theme;
+use crate::util::text;
+
+/// Keys tried for each default field, with the default field's width
+const DEFAULT_FIELDS: [(&[&str], Option<usize>, bool); 3] = [
+    (&["timestamp", "time", "ts", "@timestamp"], None, false),
+    (&["level", "lvl", "severity"], Some(7), true),
+    (&["message", "msg"], None, false),
+];
+
+/// Time shown before each line of a logs view: none, when the line was
+/// received, or how long ago
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Timestamps {
+    #[default]
+    Off,
+    Received,
+    Relative,
+}
+
+impl Timestamps {
+    /// The mode `t` switches to
+    pub fn next(self) -> Self {
+        match self {
+            Timestamps::Off => Timestamps::Received,
+            Timestamps::Received => Timestamps::Relative,
+            Timestamps::Relative => Timestamps::Off,
+        }
+    }
+
+    /// The prefix for a line received at `received`, padded so lines stay
+    /// aligned; empty when off
+    pub fn prefix(self, received: DateTime<Local>, now: DateTime<Local>) -> String {
+        match self {
+            Timestamps::Off => String::new(),
+            Timestamps::Received => format!("{} ", received.format("%H:%M:%S%.3f")),
+            Timestamps::Relative => {
+                let secs = (now - received).num_seconds().max(0);
+                let age = match secs {
+                    0..60 => format!("{}s", secs),
+                    60..3600 => format!("{}m", secs / 60),
+                    3600..86400 => format!("{}h", secs / 3600),
+                    _ => format!("{}d", secs / 86400),
+                };
+                format!("{:>7} ", format!("{} ago", age))
+            }
+        }
+    }
+}
+
+/// One field of a parsed line, ready to lay out
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogValue {
+    pub text: String,
+    pub width: Option<usize>,
+    pub level: bool,
+}
+
+/// The line's fields, in order, when it is a JSON object; `fields` empty
+/// means the default timestamp, level and message
+pub fn parse(fields: &[LogField], line: &str) -> Option<Vec<LogValue>> {
+    let object: Value = serde_json::from_str(line.trim()).ok()?;
+    if !object.is_object() {
+        return None;
+    }
+    if fields.is_empty() {
+        return Some(
+            DEFAULT_FIELDS
+                .iter()
+                .map(|&(keys, width, level)| LogValue {
+                    text: keys.iter().find_map(|key| object.get(key)).map(text).unwrap_or_default(),
+                    width,
+                    level,
+                })
+                .collect(),
+        );
+    }
+    Some(
+        fields
+            .iter()
+            .map(|field| LogValue {
+                text: JsonPathExtractor::new(&field.path)
+                    .ok()
+                    .and_then(|e| e.extract_single(&object).ok().flatten())
+                    .as_ref()
+                    .map(text)
+                    .unwrap_or_default(),
+                width: field.width.map(usize::from),
+                level: field.level,
+            })
+            .collect(),
+    )
+}
+
+/// The fields as a styled line: each padded to its width and separated by a
+/// space, levels colored, the first (usually the time) dimmed
+pub fn to_line(values: Vec<LogValue>, theme: &Theme) -> Line<'static> {
+    let last = values.len().saturating_sub(1);
+    let mut spans = Vec::with_capacity(values.len() * 2);
+    for (i, value) in values.into_iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::raw(" "));
+        }
+        let style = match (value.level, i) {
+            (true, _) => level_style(&value.text, theme),
+            (false, 0) if last > 0 => Style::default().fg(theme.muted),
+            _ => Style::default(),
+        };
+        let text = match value.width {
+            Some(width) if i < last => text::pad(&value.text, width),
+            _ => value.text,
+        };
+        spans.push(Span::styled(text, style));
+    }
+    Line::from(spans)
+}
+
+/// Color for a level name; unknown levels keep the default color
+pub fn level_style(level: &str, theme: &Theme) -> Style {
+    let color = match level.trim().to_ascii_lowercase().as_str() {
+        "fatal" | "panic" | "critical" | "crit" | "error" | "err" => theme.error,
+        "warn" | "warning" => theme.warning,
+        "info" | "notice" => theme.success,
+        "debug" => theme.info,
+        "trace" => theme.muted,
+        _ => return Style::default(),
+    };
+    Style::default().fg(color).add_modifier(Modifier::BOLD)
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s
+            .chars()
+            .map(|c| if matches!(c, '\n' | '\t') { ' ' } else { c })
+            .filter(|c| !c.is_control())
+            .collect(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Color;
+
+    #[test]
+    fn test_parse_defaults() {
+        let line = r#"{"ts": "10:00:01", "severity": "warn", "msg": "disk\nfull", "pid": 7}"#;
+        let values = parse(&[], line).unwrap();
+        let texts: Vec<&str> = values.iter().map(|v| v.text.as_str()).collect();
+        assert_eq!(texts, vec!["10:00:01", "warn", "disk full"]);
+
+        let line = to_line(values, &Theme::default());
+        let shown: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(shown, "10:00:01 warn    disk full");
+        assert_eq!(line.spans[2].style.fg, Some(Color::Yellow));
+
+        assert!(parse(&[], "plain text").is_none());
+        assert!(parse(&[], "[1, 2]").is_none());
+    }
+
+    #[test]
+    fn test_timestamps() {
+        use chrono::TimeZone;
+
+        let received = Local.with_ymd_and_hms(2024, 1, 1, 10, 0, 5).unwrap();
+        let now = received + chrono::Duration::seconds(125);
+        assert_eq!(Timestamps::Off.prefix(received, now), "");
+        assert_eq!(Timestamps::Received.prefix(received, now), "10:00:05.000 ");
+        assert_eq!(Timestamps::Relative.prefix(received, now), " 2m ago ");
+        assert_eq!(Timestamps::Relative.prefix(received, received), " 0s ago ");
+        assert_eq!(Timestamps::Off.next().next().next(), Timestamps::Off);
+    }
+
+    #[test]
+    fn test_parse_fields() {
+        let fields: Vec<LogField> = serde_yaml::from_str(
+            "- path: $.req.method\n  width: 3\n- path: $.status\n  width: 5\n- path: $.req.url",
+        )
+        .unwrap();
+        let values = parse(&fields, r#"{"req": {"method": "DELETE", "url": "/x"}}"#).unwrap();
+        let line = to_line(values, &Theme::default());
+        let shown: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(shown, "DEL       /x");
+    }
+}
@@ -1,2 +1,16 @@
-// View module - currently all rendering is handled in app.rs
-// This module can be expanded in the future for specialized view components
+// View module - most rendering is handled in app.rs
+// Standalone view helpers live here
+pub mod chart;
+pub mod column;
+pub mod dashboard;
+pub mod diff;
+pub mod fields;
+pub mod filter;
+pub mod form;
+pub mod gauge;
+pub mod histogram;
+pub mod logline;
+pub mod progress;
+pub mod style;
+pub mod selection;
+pub mod window;
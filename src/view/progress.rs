@@ -0,0 +1,65 @@
+// Inline progress bars of table columns with a `progress` option
+use serde_json::Value;
+
+use super::column::parse_number;
+use super::histogram::bar;
+use crate::config::ProgressColumn;
+use crate::data::JsonPaths;
+
+/// Percentage of the row's maximum reached by its value, when both are numbers
+/// and the maximum isn't zero. `path` is the column's, used without `value_path`.
+pub fn percent(
+    paths: &JsonPaths,
+    progress: &ProgressColumn,
+    path: &str,
+    row: &Value,
+) -> Option<f64> {
+    let value_path = progress.value_path.as_deref().unwrap_or(path);
+    let value = parse_number(&paths.extract(value_path, row)?)?;
+    let max = match &progress.max_path {
+        Some(max_path) => parse_number(&paths.extract(max_path, row)?)?,
+        None => progress.max.unwrap_or(100.0),
+    };
+    (max != 0.0).then(|| value / max * 100.0)
+}
+
+/// The filled part of a `width`-cell bar and the track after it
+pub fn segments(percent: f64, width: u16) -> (String, String) {
+    let width = width as usize;
+    let filled = bar(percent / 100.0, width);
+    let track = "░".repeat(width.saturating_sub(filled.chars().count()));
+    (filled, track)
+}
+
+/// A percentage as shown after the bar, e.g. "73%"
+pub fn label(percent: f64) -> String {
+    format!("{:.0}%", percent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_percent() {
+        let paths = JsonPaths::default();
+        let progress: ProgressColumn =
+            serde_yaml::from_str("value_path: $.used\nmax_path: $.limit").unwrap();
+        let row = json!({"used": 512, "limit": "2048", "pct": 40});
+        assert_eq!(percent(&paths, &progress, "", &row), Some(25.0));
+        assert_eq!(percent(&paths, &progress, "", &json!({"used": 5, "limit": 0})), None);
+        assert_eq!(percent(&paths, &progress, "", &json!({"used": 5})), None);
+
+        let progress: ProgressColumn = serde_yaml::from_str("width: 4").unwrap();
+        assert_eq!(percent(&paths, &progress, "$.pct", &row), Some(40.0));
+        assert_eq!(label(40.0), "40%");
+    }
+
+    #[test]
+    fn test_segments() {
+        assert_eq!(segments(50.0, 4), ("██".to_string(), "░░".to_string()));
+        assert_eq!(segments(150.0, 2), ("██".to_string(), String::new()));
+        assert_eq!(segments(0.0, 2), (String::new(), "░░".to_string()));
+    }
+}
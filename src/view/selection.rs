@@ -0,0 +1,107 @@
+// Row identity and multi-row selection that survives refreshes and filtering
+use serde_json::Value;
+use std::collections::{BTreeSet, HashSet};
+
+use crate::data::JsonPathExtractor;
+
+/// Fields tried, in order, when a table doesn't set `row_key`
+const DEFAULT_KEY_PATHS: &[&str] = &["$.metadata.uid", "$.uid", "$.id"];
+
+/// Identity of a row: the value at `row_key`, else the first of
+/// `metadata.uid`, `uid` or `id` that is present, else the whole row.
+pub fn row_key(row_key: Option<&str>, row: &Value) -> String {
+    let extract = |path: &str| {
+        JsonPathExtractor::new(path)
+            .ok()?
+            .extract_single(row)
+            .ok()
+            .flatten()
+            .filter(|v| !v.is_null())
+    };
+    let value = match row_key {
+        Some(path) => extract(path),
+        None => DEFAULT_KEY_PATHS.iter().find_map(|path| extract(path)),
+    };
+    match value {
+        Some(Value::String(s)) => s,
+        Some(other) => other.to_string(),
+        None => row.to_string(),
+    }
+}
+
+/// Rows marked for bulk actions, remembered by identity rather than position
+#[derive(Debug, Default)]
+pub struct Selection {
+    keys: BTreeSet<String>,
+}
+
+impl Selection {
+    /// Mark or unmark a row; returns whether it's now marked
+    pub fn toggle(&mut self, key: &str) -> bool {
+        if self.keys.remove(key) {
+            false
+        } else {
+            self.keys.insert(key.to_string());
+            true
+        }
+    }
+
+    pub fn insert(&mut self, key: &str) {
+        self.keys.insert(key.to_string());
+    }
+
+    pub fn remove(&mut self, key: &str) {
+        self.keys.remove(key);
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.keys.contains(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.keys.clear();
+    }
+
+    /// Forget rows that are gone from the data (e.g. deleted since the last refresh)
+    pub fn retain_present(&mut self, present: &HashSet<&str>) {
+        self.keys.retain(|key| present.contains(key.as_str()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_row_key() {
+        let pod = json!({"metadata": {"uid": "abc", "name": "web"}, "id": 7});
+        assert_eq!(row_key(None, &pod), "abc");
+        assert_eq!(row_key(Some("$.metadata.name"), &pod), "web");
+        assert_eq!(row_key(None, &json!({"id": 7})), "7");
+        // No identity field: the row itself
+        assert_eq!(row_key(None, &json!({"name": "x"})), r#"{"name":"x"}"#);
+    }
+
+    #[test]
+    fn test_selection() {
+        let mut selection = Selection::default();
+        assert!(selection.toggle("a"));
+        selection.insert("b");
+        assert!(!selection.toggle("a"));
+        assert!(selection.toggle("c"));
+        assert_eq!(selection.len(), 2);
+
+        selection.retain_present(&HashSet::from(["b"]));
+        assert!(selection.contains("b"));
+        assert!(!selection.contains("c"));
+    }
+}
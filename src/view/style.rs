@@ -0,0 +1,213 @@
+// Conditional styling: condition expressions, truthiness and colors
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Span;
+use regex::Regex;
+use std::borrow::Cow;
+
+use crate::config::{ConditionalStyle, LogHighlight};
+use crate::ui::theme::Theme;
+
+/// Template for a style condition. Conditions may be written as templates
+/// (`"{{ value == 'Failed' }}"`) or as bare expressions (`"value == 'Failed'"`).
+pub fn condition_template(condition: &str) -> Cow<'_, str> {
+    if condition.contains("{{") || condition.contains("{%") {
+        Cow::Borrowed(condition)
+    } else {
+        Cow::Owned(format!("{{{{ {} }}}}", condition.trim()))
+    }
+}
+
+/// Whether a rendered condition counts as a match: anything but empty,
+/// `false`, `0`, `null` or `none` (case-insensitive).
+pub fn is_truthy(rendered: &str) -> bool {
+    let rendered = rendered.trim();
+    !(rendered.is_empty()
+        || rendered == "0"
+        || ["false", "null", "none"]
+            .iter()
+            .any(|falsy| rendered.eq_ignore_ascii_case(falsy)))
+}
+
+/// Style a matching rule applies (colors that don't parse are skipped). Role
+/// names (`success`, `error`, ...) take the color from `theme`.
+pub fn rule_style(rule: &ConditionalStyle, theme: &Theme) -> Style {
+    let mut style = Style::default();
+    if let Some(color) = rule.color.as_deref().and_then(|color| theme.color(color)) {
+        style = style.fg(color);
+    }
+    if let Some(bg) = rule.bg.as_deref().and_then(|bg| theme.color(bg)) {
+        style = style.bg(bg);
+    }
+    if rule.bold {
+        style = style.add_modifier(Modifier::BOLD);
+    }
+    if rule.dim {
+        style = style.add_modifier(Modifier::DIM);
+    }
+    style
+}
+
+/// Style of a logs view `highlights` rule
+pub fn highlight_style(rule: &LogHighlight, theme: &Theme) -> Style {
+    let mut style = Style::default();
+    if let Some(color) = rule.color.as_deref().and_then(|color| theme.color(color)) {
+        style = style.fg(color);
+    }
+    if let Some(bg) = rule.bg.as_deref().and_then(|bg| theme.color(bg)) {
+        style = style.bg(bg);
+    }
+    if rule.bold {
+        style = style.add_modifier(Modifier::BOLD);
+    }
+    style
+}
+
+/// Parse a color: a name (`red`, `lightblue`, `darkgray`, ...; `reset` for the
+/// terminal's own), a hex `#rrggbb` value or a 256-color palette index (`208`)
+pub fn parse_color(color_str: &str) -> Option<Color> {
+    let color_str = color_str.trim();
+    if let Some(hex) = color_str.strip_prefix('#') {
+        if hex.len() != 6 {
+            return None;
+        }
+        let rgb = u32::from_str_radix(hex, 16).ok()?;
+        return Some(Color::Rgb((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8));
+    }
+    if let Ok(index) = color_str.parse::<u8>() {
+        return Some(Color::Indexed(index));
+    }
+    match color_str.to_lowercase().as_str() {
+        "black" => Some(Color::Black),
+        "red" => Some(Color::Red),
+        "green" => Some(Color::Green),
+        "yellow" => Some(Color::Yellow),
+        "blue" => Some(Color::Blue),
+        "magenta" => Some(Color::Magenta),
+        "cyan" => Some(Color::Cyan),
+        "gray" | "grey" => Some(Color::Gray),
+        "darkgray" | "darkgrey" => Some(Color::DarkGray),
+        "lightred" => Some(Color::LightRed),
+        "lightgreen" => Some(Color::LightGreen),
+        "lightyellow" => Some(Color::LightYellow),
+        "lightblue" => Some(Color::LightBlue),
+        "lightmagenta" => Some(Color::LightMagenta),
+        "lightcyan" => Some(Color::LightCyan),
+        "white" => Some(Color::White),
+        "reset" | "default" => Some(Color::Reset),
+        _ => None,
+    }
+}
+
+/// Mark every match of `regex` in the theme's highlight colors. Matching runs over the
+/// spans' combined text, so a match that crosses differently styled spans
+/// (syntax tokens, ANSI colors) is still found; each piece keeps its own style
+/// outside the match.
+pub fn highlight_matches<'a>(
+    spans: Vec<Span<'a>>,
+    regex: &Regex,
+    theme: &Theme,
+) -> Vec<Span<'a>> {
+    style_matches(spans, regex, |style| style.bg(theme.highlight).fg(theme.inverse))
+}
+
+/// Restyle every match of `regex` with `restyle` applied to each matched
+/// piece's own style, as `highlight_matches` does
+pub fn style_matches<'a>(
+    spans: Vec<Span<'a>>,
+    regex: &Regex,
+    restyle: impl Fn(Style) -> Style,
+) -> Vec<Span<'a>> {
+    let text: String = spans.iter().map(|span| span.content.as_ref()).collect();
+    let matches: Vec<_> = regex
+        .find_iter(&text)
+        .map(|m| m.range())
+        .filter(|range| !range.is_empty())
+        .collect();
+    if matches.is_empty() {
+        return spans;
+    }
+
+    let mut result = Vec::with_capacity(spans.len() + 2 * matches.len());
+    let mut offset = 0;
+    for span in spans {
+        let (start, end) = (offset, offset + span.content.len());
+        offset = end;
+        let overlapping: Vec<_> =
+            matches.iter().filter(|m| m.start < end && m.end > start).collect();
+        if overlapping.is_empty() {
+            result.push(span);
+            continue;
+        }
+        let mut cut = start;
+        for m in overlapping {
+            let (from, to) = (m.start.max(start), m.end.min(end));
+            if from > cut {
+                let before = span.content[cut - start..from - start].to_string();
+                result.push(Span::styled(before, span.style));
+            }
+            result.push(Span::styled(
+                span.content[from - start..to - start].to_string(),
+                restyle(span.style),
+            ));
+            cut = to;
+        }
+        if cut < end {
+            result.push(Span::styled(span.content[cut - start..].to_string(), span.style));
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_condition_template() {
+        assert_eq!(condition_template("{{ value == 'x' }}"), "{{ value == 'x' }}");
+        assert_eq!(condition_template(" value == 'x' "), "{{ value == 'x' }}");
+    }
+
+    #[test]
+    fn test_is_truthy() {
+        assert!(is_truthy("true"));
+        assert!(is_truthy(" yes\n"));
+        assert!(!is_truthy("false"));
+        assert!(!is_truthy("False"));
+        assert!(!is_truthy("0"));
+        assert!(!is_truthy(""));
+    }
+
+    #[test]
+    fn test_highlight_matches() {
+        let key = Style::default().fg(Color::Cyan);
+        let spans = vec![Span::styled("name", key), Span::raw(": web-1")];
+        let regex = Regex::new("(?i)e: w").unwrap();
+        let highlighted = highlight_matches(spans, &regex, &Theme::default());
+
+        let pieces: Vec<(&str, Style)> =
+            highlighted.iter().map(|s| (s.content.as_ref(), s.style)).collect();
+        let hit = |style: Style| style.bg(Color::Yellow).fg(Color::Black);
+        assert_eq!(
+            pieces,
+            vec![
+                ("nam", key),
+                ("e", hit(key)),
+                (": w", hit(Style::default())),
+                ("eb-1", Style::default()),
+            ]
+        );
+
+        let untouched = highlight_matches(vec![Span::raw("abc")], &regex, &Theme::default());
+        assert_eq!(untouched, vec![Span::raw("abc")]);
+    }
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(parse_color("Red"), Some(Color::Red));
+        assert_eq!(parse_color("#ff8000"), Some(Color::Rgb(255, 128, 0)));
+        assert_eq!(parse_color("208"), Some(Color::Indexed(208)));
+        assert_eq!(parse_color("#fff"), None);
+        assert_eq!(parse_color("reddish"), None);
+    }
+}
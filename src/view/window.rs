@@ -0,0 +1,80 @@
+// Which table rows are on screen, so only those get built into widgets
+use std::ops::Range;
+
+/// Rows that fit in `max_height` lines, scrolled from `offset` just enough to
+/// keep `selected` in view. Mirrors how ratatui's `Table` picks its rows, so a
+/// table built from only this range renders the same as one built from all.
+/// A row taller than `max_height` counts as `max_height` (the caller clips it
+/// to that), so the selected row is always in the window.
+pub fn visible_rows(
+    heights: &[u16],
+    selected: Option<usize>,
+    offset: usize,
+    max_height: u16,
+) -> Range<usize> {
+    if heights.is_empty() {
+        return 0..0;
+    }
+    let row = |idx: usize| heights[idx].min(max_height.max(1));
+    let mut start = offset.min(heights.len() - 1);
+    let mut end = start;
+    let mut height: u16 = 0;
+    for idx in start..heights.len() {
+        if height.saturating_add(row(idx)) > max_height {
+            break;
+        }
+        height += row(idx);
+        end += 1;
+    }
+
+    let Some(selected) = selected.map(|s| s.min(heights.len() - 1)) else {
+        return start..end;
+    };
+    while selected >= end {
+        height = height.saturating_add(row(end));
+        end += 1;
+        while height > max_height {
+            height = height.saturating_sub(row(start));
+            start += 1;
+        }
+    }
+    while selected < start {
+        start -= 1;
+        height = height.saturating_add(row(start));
+        while height > max_height {
+            end -= 1;
+            height = height.saturating_sub(row(end));
+        }
+    }
+    start..end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visible_rows() {
+        let heights = [1; 100];
+        assert_eq!(visible_rows(&heights, Some(0), 0, 10), 0..10);
+        // Scrolls down just far enough to show the selection, and back up
+        assert_eq!(visible_rows(&heights, Some(15), 0, 10), 6..16);
+        assert_eq!(visible_rows(&heights, Some(3), 6, 10), 3..13);
+        assert_eq!(visible_rows(&heights, Some(99), 95, 10), 95..100);
+        assert_eq!(visible_rows(&heights, None, 0, 10), 0..10);
+        assert_eq!(visible_rows(&[], Some(0), 0, 10), 0..0);
+
+        // An expanded row takes several lines
+        let mut heights = [1; 20];
+        heights[2] = 5;
+        assert_eq!(visible_rows(&heights, Some(0), 0, 10), 0..6);
+        assert_eq!(visible_rows(&heights, Some(7), 0, 10), 2..8);
+
+        // One taller than the viewport is still shown when selected, clipped
+        heights[2] = 15;
+        assert_eq!(visible_rows(&heights, Some(2), 0, 10), 2..3);
+        assert_eq!(visible_rows(&heights, Some(2), 2, 10), 2..3);
+        assert_eq!(visible_rows(&heights, Some(3), 2, 10), 3..4);
+        assert_eq!(visible_rows(&heights, Some(1), 2, 10), 1..2);
+    }
+}
@@ -0,0 +1,374 @@
+// WASM plugins (`app.plugins`): sandboxed modules adding adapters and template filters
+use anyhow::{Context as _, Result, anyhow, bail};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+use wasmtime::{Engine, InstancePre, Linker, Module, Store, StoreLimits, StoreLimitsBuilder};
+
+use crate::adapters::plugin::PROTOCOL_VERSION;
+use crate::config::schema::WasmPlugin;
+use crate::template::engine::CustomFilter;
+
+/// Instructions (wasmtime fuel) a call may run unless the plugin sets `fuel`
+pub const DEFAULT_FUEL: u64 = 100_000_000;
+
+/// Memory a module may grow to unless the plugin sets `max_memory_mb`, in MiB
+pub const DEFAULT_MAX_MEMORY_MB: usize = 64;
+
+/// Adapter names the built-in adapters already answer to
+const BUILTIN_ADAPTERS: &[&str] = &["cli", "http", "script", "plugin", "stream"];
+
+/// What a module's `register` answers
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Registration {
+    #[serde(default)]
+    adapters: Vec<String>,
+    #[serde(default)]
+    filters: Vec<String>,
+}
+
+/// A compiled plugin module
+///
+/// Modules export their `memory`, `alloc(len: i32) -> i32` and any of
+/// `register`, `fetch` and `filter`, each `(ptr: i32, len: i32) -> i64`: given a
+/// JSON request written at `ptr`, they answer with JSON elsewhere in memory,
+/// returned as `ptr << 32 | len`.
+///
+/// - `register` gets `{"protocol": 1}` once at startup and answers
+///   `{"adapters": [...], "filters": [...]}` with the names it provides
+/// - `fetch` gets `{"protocol": 1, "adapter": ..., "config": {...}, "context": {...}}`,
+///   `config` being the data source's fields with templates rendered, and answers
+///   `{"data": ...}` or `{"error": "..."}` like a stdio plugin
+/// - `filter` gets `{"protocol": 1, "filter": ..., "value": ..., "args": {...}}`
+///   and answers `{"value": ...}` or `{"error": "..."}`
+///
+/// Modules get no imports (no files, network, clock or environment), and every
+/// call runs in a fresh instance within the plugin's fuel and memory.
+pub struct WasmModule {
+    path: String,
+    instance: InstancePre<StoreLimits>,
+    fuel: u64,
+    max_memory: usize,
+}
+
+impl WasmModule {
+    fn load(engine: &Engine, plugin: &WasmPlugin) -> Result<Self> {
+        let module = Module::from_file(engine, &plugin.path).map_err(|e| anyhow!("{:#}", e))?;
+        if let Some(import) = module.imports().next() {
+            bail!(
+                "Module imports '{}.{}', but plugins get no host functions",
+                import.module(),
+                import.name()
+            );
+        }
+        let instance = Linker::new(engine)
+            .instantiate_pre(&module)
+            .map_err(|e| anyhow!("{:#}", e))?;
+        Ok(Self {
+            path: plugin.path.clone(),
+            instance,
+            fuel: plugin.fuel.unwrap_or(DEFAULT_FUEL),
+            max_memory: plugin.max_memory_mb.unwrap_or(DEFAULT_MAX_MEMORY_MB) << 20,
+        })
+    }
+
+    /// Where the module was loaded from
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Send `request` to the `export` function and parse its answer
+    pub fn call(&self, export: &str, request: &Value) -> Result<Value> {
+        self.call_raw(export, request)
+            .with_context(|| format!("Plugin '{}' failed in '{}'", self.path, export))
+    }
+
+    fn call_raw(&self, export: &str, request: &Value) -> Result<Value> {
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(self.max_memory)
+            .instances(1)
+            .build();
+        let mut store = Store::new(self.instance.module().engine(), limits);
+        store.limiter(|limits| limits);
+        store.set_fuel(self.fuel).map_err(|e| anyhow!("{:#}", e))?;
+
+        let trap = |e: wasmtime::Error| match e.downcast_ref::<wasmtime::Trap>() {
+            Some(wasmtime::Trap::OutOfFuel) => {
+                anyhow!("Ran out of fuel after {} instructions", self.fuel)
+            }
+            _ => anyhow!("{:#}", e),
+        };
+        let instance = self.instance.instantiate(&mut store).map_err(trap)?;
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or_else(|| anyhow!("Module exports no 'memory'"))?;
+        let alloc = instance
+            .get_typed_func::<i32, i32>(&mut store, "alloc")
+            .map_err(|e| anyhow!("Module exports no usable 'alloc': {:#}", e))?;
+        let function = instance
+            .get_typed_func::<(i32, i32), i64>(&mut store, export)
+            .map_err(|e| anyhow!("Module exports no usable '{}': {:#}", export, e))?;
+
+        let input = request.to_string();
+        let len = i32::try_from(input.len()).context("Request too large")?;
+        let ptr = alloc.call(&mut store, len).map_err(trap)?;
+        memory
+            .write(&mut store, ptr as u32 as usize, input.as_bytes())
+            .map_err(|_| anyhow!("'alloc' returned memory out of bounds"))?;
+
+        let answer = function.call(&mut store, (ptr, len)).map_err(trap)?;
+        let (ptr, len) = ((answer >> 32) as u32 as usize, answer as u32 as usize);
+        let output = memory
+            .data(&store)
+            .get(ptr..ptr + len)
+            .ok_or_else(|| anyhow!("Answer is out of bounds"))?;
+        serde_json::from_slice(output).map_err(|e| anyhow!("Answer is not JSON: {}", e))
+    }
+
+    /// Fetch a data source using the module's `adapter`
+    pub fn fetch(&self, adapter: &str, config: Value, context: Value) -> Result<Value> {
+        let request = json!({
+            "protocol": PROTOCOL_VERSION,
+            "adapter": adapter,
+            "config": config,
+            "context": context,
+        });
+        answer(self.call("fetch", &request)?, "data")
+            .map_err(|e| anyhow!("Plugin '{}' error: {}", self.path, e))
+    }
+
+    /// Apply the module's `filter` to `value`
+    pub fn filter(
+        &self,
+        filter: &str,
+        value: &Value,
+        args: &HashMap<String, Value>,
+    ) -> tera::Result<Value> {
+        let request = json!({
+            "protocol": PROTOCOL_VERSION,
+            "filter": filter,
+            "value": value,
+            "args": args,
+        });
+        let response = self
+            .call("filter", &request)
+            .map_err(|e| tera::Error::msg(format!("{:#}", e)))?;
+        answer(response, "value").map_err(tera::Error::msg)
+    }
+}
+
+/// The `field` of a plugin's answer, or the error it reported
+fn answer(mut response: Value, field: &str) -> std::result::Result<Value, String> {
+    if let Some(error) = response.get("error").filter(|e| !e.is_null()) {
+        return Err(error
+            .as_str()
+            .map(String::from)
+            .unwrap_or(error.to_string()));
+    }
+    response
+        .get_mut(field)
+        .map(Value::take)
+        .ok_or_else(|| format!("Answer has neither '{}' nor 'error'", field))
+}
+
+/// The modules `app.plugins` declares and what they registered, loaded once
+/// per [`AppContext`](crate::context::AppContext)
+#[derive(Default)]
+pub struct WasmPlugins {
+    adapters: Vec<(String, Arc<WasmModule>)>,
+    filters: Vec<(String, Arc<WasmModule>)>,
+}
+
+impl WasmPlugins {
+    /// Compile every module and ask it what it registers. Fails on a module
+    /// that doesn't load or register, or a name that's already taken.
+    pub fn load(plugins: &[WasmPlugin]) -> Result<Self> {
+        let mut loaded = Self::default();
+        if plugins.is_empty() {
+            return Ok(loaded);
+        }
+
+        let mut config = wasmtime::Config::new();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config).map_err(|e| anyhow!("{:#}", e))?;
+        for plugin in plugins {
+            let module = WasmModule::load(&engine, plugin)
+                .with_context(|| format!("Failed to load plugin '{}'", plugin.path))?;
+            let registration = module.call("register", &json!({"protocol": PROTOCOL_VERSION}))?;
+            let registration: Registration = serde_json::from_value(registration)
+                .with_context(|| format!("Plugin '{}' registered nonsense", plugin.path))?;
+
+            let module = Arc::new(module);
+            for name in registration.adapters {
+                if BUILTIN_ADAPTERS.contains(&name.as_str())
+                    || loaded.adapters.iter().any(|(taken, _)| *taken == name)
+                {
+                    bail!(
+                        "Plugin '{}': adapter '{}' is already taken",
+                        plugin.path,
+                        name
+                    );
+                }
+                loaded.adapters.push((name, module.clone()));
+            }
+            for name in registration.filters {
+                if loaded.filters.iter().any(|(taken, _)| *taken == name) {
+                    bail!(
+                        "Plugin '{}': filter '{}' is already taken",
+                        plugin.path,
+                        name
+                    );
+                }
+                loaded.filters.push((name, module.clone()));
+            }
+        }
+        Ok(loaded)
+    }
+
+    /// Adapters the plugins registered, by name
+    pub fn adapters(&self) -> impl Iterator<Item = (&str, &Arc<WasmModule>)> {
+        self.adapters
+            .iter()
+            .map(|(name, module)| (name.as_str(), module))
+    }
+
+    /// Filters the plugins registered, ready for the template engine
+    pub fn filters(&self) -> Vec<(String, CustomFilter)> {
+        self.filters
+            .iter()
+            .map(|(name, module)| {
+                let (filter, module) = (name.clone(), module.clone());
+                let call: CustomFilter =
+                    Arc::new(move |value, args| module.filter(&filter, value, args));
+                (name.clone(), call)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::registry::AdapterRegistry;
+    use crate::config::ConfigLoader;
+    use crate::config::schema::SingleDataSource;
+    use crate::context::AppContext;
+    use crate::data::provider::DataContext;
+    use crate::template::engine::TemplateContext;
+
+    /// Write `wat` where a config can point at it
+    fn module_file(name: &str, wat: &str) -> String {
+        let path = std::env::temp_dir().join(format!(
+            "termstack-wasm-{}-{}.wat",
+            name,
+            std::process::id()
+        ));
+        std::fs::write(&path, wat).unwrap();
+        path.display().to_string()
+    }
+
+    /// A module registering `registration` that answers with the request:
+    /// `fetch` as `{"data": request}`, `filter` as is (so its `value`)
+    fn echo_module(registration: &str) -> String {
+        format!(
+            r#"(module
+  (memory (export "memory") 1)
+  (data (i32.const 0) "{}")
+  (data (i32.const 1024) "{{\"data\":")
+  (func (export "alloc") (param i32) (result i32) (i32.const 1032))
+  (func (export "register") (param i32 i32) (result i64) (i64.const {}))
+  (func (export "fetch") (param $ptr i32) (param $len i32) (result i64)
+    (i32.store8 (i32.add (local.get $ptr) (local.get $len)) (i32.const 125))
+    (i64.or (i64.shl (i64.const 1024) (i64.const 32))
+            (i64.extend_i32_u (i32.add (local.get $len) (i32.const 9)))))
+  (func (export "filter") (param $ptr i32) (param $len i32) (result i64)
+    (i64.or (i64.shl (i64.extend_i32_u (local.get $ptr)) (i64.const 32))
+            (i64.extend_i32_u (local.get $len)))))"#,
+            registration.replace('"', "\\\""),
+            registration.len()
+        )
+    }
+
+    fn context(plugins: &str) -> crate::error::Result<Arc<AppContext>> {
+        let yaml = format!(
+            "version: v1\napp: {{name: t, plugins: [{}]}}\nglobals: {{repo: termstack}}\n\
+             start: main\npages: {{main: {{title: M, view: {{type: text}}}}}}",
+            plugins
+        );
+        AppContext::new(ConfigLoader::load_from_string(&yaml).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_plugin_adapter_and_filter() {
+        let path = module_file(
+            "echo",
+            &echo_module(r#"{"adapters": ["echo"], "filters": ["echo"]}"#),
+        );
+        let ctx = context(&format!("{{path: '{}'}}", path)).unwrap();
+
+        let source: SingleDataSource =
+            serde_yaml::from_str("adapter: echo\nrepo: \"{{ repo }}\"\nlimit: [10]").unwrap();
+        let data_ctx = DataContext::new().with_globals(ctx.config.globals.clone());
+        let registry = AdapterRegistry::with_defaults(ctx.clone());
+        let request = registry.fetch(&source, &data_ctx).await.unwrap();
+        assert_eq!(request["protocol"], PROTOCOL_VERSION);
+        assert_eq!(request["adapter"], "echo");
+        assert_eq!(
+            request["config"],
+            json!({"repo": "termstack", "limit": [10]})
+        );
+        assert_eq!(request["context"]["globals"]["repo"], "termstack");
+
+        let globals = TemplateContext::new().with_globals(ctx.config.globals.clone());
+        let rendered = ctx
+            .template_engine
+            .render_string("{{ repo | echo(times=2) }}", &globals)
+            .unwrap();
+        assert_eq!(rendered, "termstack");
+        assert!(
+            ctx.template_engine
+                .dry_run("{{ x | echo }}", &globals)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_plugins_are_sandboxed() {
+        let error = |plugins: &str| match context(plugins) {
+            Ok(_) => panic!("{} loaded", plugins),
+            Err(e) => e.to_string(),
+        };
+
+        let path = module_file("import", r#"(module (import "env" "now" (func)))"#);
+        let message = error(&format!("{{path: '{}'}}", path));
+        assert!(message.contains("imports 'env.now'"), "{}", message);
+
+        let spin = echo_module("{}").replace(
+            "(result i64) (i64.const 2))",
+            "(result i64) (loop $spin (br $spin)) (i64.const 2))",
+        );
+        let path = module_file("spin", &spin);
+        let message = error(&format!("{{path: '{}', fuel: 1000}}", path));
+        assert!(
+            message.contains("Ran out of fuel after 1000"),
+            "{}",
+            message
+        );
+
+        let hungry = echo_module("{}").replace("\"memory\") 1)", "\"memory\") 20)");
+        let path = module_file("hungry", &hungry);
+        assert!(context(&format!("{{path: '{}'}}", path)).is_ok());
+        let message = error(&format!("{{path: '{}', max_memory_mb: 1}}", path));
+        assert!(message.contains("exceeds memory limits"), "{}", message);
+
+        let path = module_file("clash", &echo_module(r#"{"adapters": ["cli"]}"#));
+        let message = error(&format!("{{path: '{}'}}", path));
+        assert!(
+            message.contains("adapter 'cli' is already taken"),
+            "{}",
+            message
+        );
+    }
+}
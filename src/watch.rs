@@ -0,0 +1,156 @@
+// Headless `termstack watch`: refresh pages without the TUI and report alerts
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::adapters::registry::AdapterRegistry;
+use crate::alert::{AlertEvent, AlertTracker};
+use crate::app::{App, PageData};
+use crate::config::{Config, DataSource, SingleOrStream};
+use crate::context::AppContext;
+use crate::navigation::NavigationContext;
+use crate::template::engine::TemplateContext;
+
+/// Refresh interval for pages that don't set `refresh_interval`
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+    /// Print alert transitions only, not a line per refresh
+    pub alerts_only: bool,
+    /// Fetch each page once and exit
+    pub once: bool,
+}
+
+/// Pages to watch, checked against the config. Stream pages and multi-source
+/// pages can't be refreshed headlessly.
+pub fn resolve_pages(config: &Config, requested: &[String]) -> Result<Vec<String>, String> {
+    let mut pages: Vec<String> = if requested.is_empty() {
+        config
+            .pages
+            .iter()
+            .filter(|(_, page)| !page.alerts.is_empty())
+            .map(|(id, _)| id.clone())
+            .collect()
+    } else {
+        requested.to_vec()
+    };
+    pages.sort();
+    pages.dedup();
+
+    if pages.is_empty() {
+        return Err("No pages to watch: no page defines alerts (use --pages)".to_string());
+    }
+    for page_id in &pages {
+        let page = config
+            .pages
+            .get(page_id)
+            .ok_or_else(|| format!("Unknown page '{}'", page_id))?;
+        if !matches!(
+            page.data,
+            Some(DataSource::SingleOrStream(SingleOrStream::Single(_)) | DataSource::Multi(_))
+        ) {
+            return Err(format!("Page '{}' is a stream or dashboard page", page_id));
+        }
+    }
+    Ok(pages)
+}
+
+/// Refresh every page on its own interval until interrupted, printing alert
+/// transitions (and running alert commands). With `once`, fetch each page a
+/// single time and return how many alerts are firing.
+pub async fn run(
+    ctx: &Arc<AppContext>,
+    pages: Vec<String>,
+    options: &WatchOptions,
+    adapter_registry: AdapterRegistry,
+) -> usize {
+    let config = &ctx.config;
+    let adapter_registry = Arc::new(adapter_registry);
+    let nav_context = NavigationContext::new().with_globals(config.globals.clone());
+
+    let handles: Vec<_> = pages
+        .into_iter()
+        .map(|page_id| {
+            let page = config.pages[&page_id].clone();
+            let interval = config.refresh_interval(&page).unwrap_or(DEFAULT_INTERVAL);
+            let nav_context = nav_context.clone();
+            let adapter_registry = adapter_registry.clone();
+            let options = options.clone();
+            let ctx = ctx.clone();
+
+            tokio::spawn(async move {
+                let base = TemplateContext::new().with_globals(nav_context.globals.clone());
+                let mut tracker = AlertTracker::new();
+                let mut timer = tokio::time::interval(interval);
+                timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+                loop {
+                    timer.tick().await;
+                    match App::fetch_page_static(&page, &nav_context, &adapter_registry).await {
+                        Ok(PageData { rows, failed }) => {
+                            for failure in failed {
+                                eprintln!(
+                                    "{}  {}: source '{}' failed: {}",
+                                    timestamp(),
+                                    page_id,
+                                    failure.id,
+                                    failure.error
+                                );
+                            }
+                            if !options.alerts_only {
+                                println!("{}  {}: {} rows", timestamp(), page_id, rows.len());
+                            }
+                            let events = tracker.evaluate(
+                                &ctx.template_engine,
+                                &page_id,
+                                &page.alerts,
+                                &rows,
+                                &base,
+                            );
+                            for event in events {
+                                report(&event, &page.alerts).await;
+                            }
+                        }
+                        Err(e) => eprintln!("{}  {}: fetch failed: {}", timestamp(), page_id, e),
+                    }
+                    if options.once {
+                        return tracker.firing_count();
+                    }
+                }
+            })
+        })
+        .collect();
+
+    let mut firing = 0;
+    for handle in handles {
+        firing += handle.await.unwrap_or(0);
+    }
+    adapter_registry.shutdown().await;
+    firing
+}
+
+/// Print a transition and run the alert's command, if any
+async fn report(event: &AlertEvent, alerts: &[crate::config::Alert]) {
+    println!(
+        "{}  {:<8} {}/{}: {}",
+        timestamp(),
+        event.state.label(),
+        event.page,
+        event.alert,
+        event.message
+    );
+
+    let command = alerts
+        .iter()
+        .find(|a| a.name == event.alert)
+        .and_then(|a| a.command.as_deref());
+    if let Some(command) = command
+        && let Err(e) = crate::alert::run_hook(command, event).await
+    {
+        eprintln!("{}  {}/{}: command failed: {}", timestamp(), event.page, event.alert, e);
+    }
+}
+
+fn timestamp() -> String {
+    chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}